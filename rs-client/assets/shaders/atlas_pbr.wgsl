@@ -672,6 +672,12 @@ fn fragment(
             water_scene_reflection_valid,
         );
     }
+    // debug_flags.w carries the lightning flash; vanilla maxes out sky light while a bolt is lit.
+    let lightning_flash = clamp(lighting_uniform.debug_flags.w, 0.0, 1.0);
+    if lightning_flash > 0.0 {
+        let flashed = min(out.color.rgb * 1.6 + vec3(0.06), vec3(1.0));
+        out.color = vec4(mix(out.color.rgb, flashed, lightning_flash * 0.6), out.color.a);
+    }
     if !fixed_debug_state && shading_model != 1 {
         out.color = vec4(apply_color_grading(out.color.rgb), out.color.a);
     }
@@ -1,10 +1,18 @@
+use bevy::ecs::system::SystemParam;
 use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
+use rs_ui::ConnectUiState;
 use rs_utils::{
-    AppState, ApplicationState, InventoryState, PlayerStatus, SoundCategory, SoundEvent,
-    SoundEventQueue, ToNet, ToNetMessage, UiState,
+    AppState, ApplicationState, InventoryState, MobMetadata, PlayerStatus,
+    SPECTATOR_MENU_PAGE_SIZE, SoundCategory, SoundEvent, SoundEventQueue, SpectatorMenuEntry,
+    SpectatorMenuState, ToNet, ToNetMessage, UiState,
 };
 
+use crate::entities::{RemoteEntityMetadata, RemoteEntityRegistry};
+
+/// Vanilla hides the spectator menu after a few seconds without input.
+const SPECTATOR_MENU_TIMEOUT_SECS: f32 = 5.0;
+
 const HOTBAR_DIGIT_KEYS: [(KeyCode, u8); 9] = [
     (KeyCode::Digit1, 0),
     (KeyCode::Digit2, 1),
@@ -35,6 +43,11 @@ pub fn hotbar_input_system(
     {
         return;
     }
+    if player_status.gamemode == 3 {
+        // Spectators have no hotbar; number keys drive the spectator menu instead.
+        mouse_wheel_events.clear();
+        return;
+    }
 
     if keys.just_pressed(KeyCode::KeyQ) {
         let ctrl = keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight);
@@ -105,3 +118,121 @@ pub fn hotbar_input_system(
         });
     }
 }
+
+/// Game state deciding whether the spectator menu may open, and which players it lists.
+#[derive(SystemParam)]
+pub struct SpectatorMenuContext<'w, 's> {
+    app_state: Res<'w, AppState>,
+    ui_state: Res<'w, UiState>,
+    player_status: Res<'w, PlayerStatus>,
+    connect_ui: Res<'w, ConnectUiState>,
+    registry: Res<'w, RemoteEntityRegistry>,
+    _marker: std::marker::PhantomData<&'s ()>,
+}
+
+pub fn spectator_menu_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    context: SpectatorMenuContext,
+    to_net: Res<ToNet>,
+    mut menu: ResMut<SpectatorMenuState>,
+) {
+    let SpectatorMenuContext {
+        app_state,
+        ui_state,
+        player_status,
+        connect_ui,
+        registry,
+        ..
+    } = context;
+    if !matches!(app_state.0, ApplicationState::Connected)
+        || ui_state.chat_open
+        || ui_state.paused
+        || ui_state.inventory_open
+        || player_status.dead
+        || player_status.gamemode != 3
+    {
+        if menu.open {
+            menu.close();
+        }
+        return;
+    }
+
+    if menu.open {
+        menu.idle_secs += time.delta_secs();
+        if menu.idle_secs > SPECTATOR_MENU_TIMEOUT_SECS {
+            menu.close();
+        }
+    }
+
+    let Some(slot) = HOTBAR_DIGIT_KEYS
+        .iter()
+        .find_map(|(key, slot)| keys.just_pressed(*key).then_some(*slot as usize))
+    else {
+        return;
+    };
+
+    let mut players: Vec<SpectatorMenuEntry> = registry
+        .player_name_by_uuid
+        .iter()
+        .filter(|(_, name)| !name.eq_ignore_ascii_case(&connect_ui.username))
+        .map(|(uuid, name)| SpectatorMenuEntry {
+            uuid: uuid.clone(),
+            name: name.clone(),
+        })
+        .collect();
+    players.sort_by_key(|entry| entry.name.to_lowercase());
+    menu.players = players;
+    menu.idle_secs = 0.0;
+
+    if !menu.open {
+        menu.open = true;
+        menu.page = 0;
+        return;
+    }
+    menu.page = menu.page.min(menu.page_count() - 1);
+
+    if slot == SPECTATOR_MENU_PAGE_SIZE {
+        if menu.page_count() > 1 {
+            menu.page = (menu.page + 1) % menu.page_count();
+        } else {
+            menu.close();
+        }
+        return;
+    }
+    let Some(target) = menu
+        .page_entries()
+        .get(slot)
+        .map(|entry| entry.uuid.clone())
+    else {
+        return;
+    };
+    let _ = to_net.0.send(ToNetMessage::SpectateTeleport { target });
+    menu.close();
+}
+
+/// Copies the horse type into an open horse window from the horse's metadata, which decides
+/// whether the window has an armor slot.
+pub fn sync_open_horse_window_type(
+    mut inventory: ResMut<InventoryState>,
+    registry: Res<RemoteEntityRegistry>,
+    metadata: Query<&RemoteEntityMetadata>,
+) {
+    let Some(window) = inventory.open_window.as_ref() else {
+        return;
+    };
+    let horse_type = window
+        .entity_id
+        .and_then(|entity_id| registry.by_server_id.get(&entity_id))
+        .and_then(|entity| metadata.get(*entity).ok())
+        .and_then(|metadata| match metadata.0.mob {
+            MobMetadata::Horse(horse) => Some(horse.horse_type),
+            _ => None,
+        });
+    if horse_type.is_some()
+        && window.horse_type != horse_type
+        && let Some(window) = inventory.open_window.as_mut()
+    {
+        window.horse_type = horse_type;
+    }
+}
@@ -6,18 +6,22 @@ use bevy::prelude::*;
 use rs_render::{ChunkUpdateQueue, WorldUpdate};
 use rs_ui::{ChatAutocompleteState, ConnectUiState};
 use rs_utils::{
-    AppState, ApplicationState, Chat, FromNet, FromNetMessage, InventoryMessage, InventoryState,
-    PlayerStatus, ScoreboardMessage, ScoreboardState, SoundCategory, SoundEvent, SoundEventQueue,
-    TabListHeaderFooter, TitleMessage, TitleOverlayState, WorldTime,
+    AppState, ApplicationState, BlockBreakStages, BookScreen, Chat, CombatEventMessage,
+    CombatState, FromNet, FromNetMessage, InventoryMessage, InventoryState, LightningState,
+    PlayerStatus, ScoreboardMessage, ScoreboardState, ServerDifficultyState, SleepState,
+    SoundCategory, SoundEvent, SoundEventQueue, StatisticsState, TabListHeaderFooter, TitleMessage,
+    TitleOverlayState, WorldSpawnState, WorldTime,
 };
 use tracing::{debug, info};
 
-use crate::entities::{RemoteEntityEventQueue, RemoteEntityRegistry};
+use crate::entities::{RemoteEntityEventQueue, RemoteEntityName, RemoteEntityRegistry};
 use crate::movement_session::MovementSession;
 use crate::net::events::{NetEvent, NetEventQueue};
 use crate::sim::collision::WorldCollisionMap;
 use crate::sim::movement::WorldCollision;
-use crate::sim::{SimClock, SimReady, SimRenderState, SimState};
+use crate::sim::{
+    MountState, SimClock, SimReady, SimRenderState, SimState, SpectateCameraState,
+};
 use crate::sim_systems::PredictionHistory;
 use crate::timing::Timing;
 
@@ -37,13 +41,25 @@ pub(crate) struct MessageUiState<'w, 's> {
     _marker: std::marker::PhantomData<&'s ()>,
 }
 
+#[derive(SystemParam)]
+pub(crate) struct CombatParams<'w, 's> {
+    combat: ResMut<'w, CombatState>,
+    difficulty: ResMut<'w, ServerDifficultyState>,
+    entity_names: Query<'w, 's, &'static RemoteEntityName>,
+}
+
 #[derive(SystemParam)]
 pub(crate) struct GameplayState<'w, 's> {
     player_status: ResMut<'w, PlayerStatus>,
     world_time: ResMut<'w, WorldTime>,
+    world_spawn: ResMut<'w, WorldSpawnState>,
+    block_break_stages: ResMut<'w, BlockBreakStages>,
+    mount: ResMut<'w, MountState>,
+    spectate: ResMut<'w, SpectateCameraState>,
     title_overlay: ResMut<'w, TitleOverlayState>,
     tab_list_header_footer: ResMut<'w, TabListHeaderFooter>,
     scoreboard: ResMut<'w, ScoreboardState>,
+    statistics: ResMut<'w, StatisticsState>,
     sound_queue: ResMut<'w, SoundEventQueue>,
     sim_render: ResMut<'w, SimRenderState>,
     sim_clock: ResMut<'w, SimClock>,
@@ -62,6 +78,9 @@ pub fn handle_messages(
     remote_entity_registry: Res<RemoteEntityRegistry>,
     mut collision_map: ResMut<WorldCollisionMap>,
     mut game: GameplayState,
+    mut combat: CombatParams,
+    mut lightning: ResMut<LightningState>,
+    mut sleep: ResMut<SleepState>,
     sim_state: Res<SimState>,
 ) {
     let timer = Timing::start();
@@ -82,10 +101,19 @@ pub fn handle_messages(
                 game.world_time.world_age = 0;
                 game.world_time.time_of_day = 0;
                 game.world_time.last_sync_instant = None;
+                game.block_break_stages.clear();
+                *game.mount = MountState::default();
+                *game.spectate = SpectateCameraState::default();
+                *sleep = SleepState::default();
                 game.title_overlay.reset();
                 game.tab_list_header_footer.header.clear();
                 game.tab_list_header_footer.footer.clear();
                 game.scoreboard.reset();
+                game.statistics.reset();
+                combat.combat.reset();
+                *combat.difficulty = ServerDifficultyState::default();
+                *game.world_spawn = WorldSpawnState::default();
+                lightning.reset();
                 game.sim_clock.tick = 0;
                 game.sim_ready.0 = false;
                 game.history.0 = PredictionHistory::default().0;
@@ -97,10 +125,20 @@ pub fn handle_messages(
             FromNetMessage::Disconnected => {
                 *ui.app_state = AppState(ApplicationState::Disconnected);
                 ui.chat_autocomplete.clear();
+                ui.connect_ui.book = None;
+                game.block_break_stages.clear();
+                *game.mount = MountState::default();
+                *game.spectate = SpectateCameraState::default();
+                *sleep = SleepState::default();
                 game.title_overlay.reset();
                 game.tab_list_header_footer.header.clear();
                 game.tab_list_header_footer.footer.clear();
                 game.scoreboard.reset();
+                game.statistics.reset();
+                combat.combat.reset();
+                *combat.difficulty = ServerDifficultyState::default();
+                *game.world_spawn = WorldSpawnState::default();
+                lightning.reset();
                 game.sim_ready.0 = false;
                 game.sim_render.previous = sim_state.current;
                 movement_session.reset_all();
@@ -154,6 +192,10 @@ pub fn handle_messages(
                 movement_session.reset_all();
                 net_events.events.clear();
                 collision_map.clear();
+                game.block_break_stages.clear();
+                *game.mount = MountState::default();
+                *game.spectate = SpectateCameraState::default();
+                *sleep = SleepState::default();
                 chunk_updates.0.clear();
                 chunk_updates.0.push(WorldUpdate::Reset);
             }
@@ -180,6 +222,9 @@ pub fn handle_messages(
                     open_count: action.open_count,
                 });
             }
+            FromNetMessage::BlockBreakAnimation(anim) => {
+                game.block_break_stages.apply(anim, Instant::now());
+            }
             FromNetMessage::UpdateHealth {
                 health,
                 food,
@@ -193,12 +238,16 @@ pub fn handle_messages(
                 game.player_status.dead = health <= 0.0;
                 // Respawn transition: reset prediction and wait for authoritative position packet.
                 if was_dead && !game.player_status.dead {
+                    combat.combat.respawned();
                     game.sim_clock.tick = 0;
                     game.sim_ready.0 = false;
                     game.history.0 = PredictionHistory::default().0;
                     game.sim_render.previous = sim_state.current;
                 }
                 if !was_dead && health < previous_health {
+                    combat
+                        .combat
+                        .record_damage(previous_health - health, health, Instant::now());
                     game.sound_queue.push(SoundEvent::Ui {
                         event_id: "minecraft:game.player.hurt".to_string(),
                         volume: 1.0,
@@ -319,6 +368,11 @@ pub fn handle_messages(
                         recv_instant: Instant::now(),
                     });
                 }
+                if let rs_utils::NetEntityMessage::Destroy { entity_ids } = &event {
+                    for entity_id in entity_ids {
+                        game.block_break_stages.remove_entity(*entity_id);
+                    }
+                }
                 remote_entity_events.push(event);
             }
             FromNetMessage::UpdateExperience {
@@ -348,6 +402,79 @@ pub fn handle_messages(
                 if !can_fly {
                     game.player_status.flying = false;
                 }
+                if mode == 3 {
+                    // Spectators are always flying.
+                    game.player_status.flying = true;
+                } else {
+                    *game.spectate = SpectateCameraState::default();
+                }
+            }
+            FromNetMessage::Statistics(entries) => {
+                game.statistics.apply(entries);
+            }
+            FromNetMessage::OpenBook => {
+                // `NetHandlerPlayClient` only opens the screen for a written book in hand.
+                if let Some(book) =
+                    BookScreen::open_held(&ui.inventory_state).filter(|book| !book.editable)
+                {
+                    ui.connect_ui.book = Some(book);
+                }
+            }
+            FromNetMessage::CombatEvent(event) => match event {
+                CombatEventMessage::Enter => combat.combat.enter(Instant::now()),
+                CombatEventMessage::End {
+                    duration_ticks,
+                    opponent_id,
+                } => {
+                    let opponent = combat_entity_name(
+                        &remote_entity_registry,
+                        &combat.entity_names,
+                        opponent_id,
+                    );
+                    combat.combat.end(duration_ticks, opponent, Instant::now());
+                }
+                CombatEventMessage::EntityDead {
+                    player_id,
+                    killer_id,
+                    message,
+                } => {
+                    if remote_entity_registry
+                        .local_entity_id
+                        .is_some_and(|id| id != player_id)
+                    {
+                        continue;
+                    }
+                    let killer = combat_entity_name(
+                        &remote_entity_registry,
+                        &combat.entity_names,
+                        killer_id,
+                    );
+                    combat.combat.died(killer, message, Instant::now());
+                }
+            },
+            FromNetMessage::ServerDifficulty { difficulty, locked } => {
+                combat.difficulty.difficulty = Some(difficulty);
+                combat.difficulty.locked = locked;
+            }
+            FromNetMessage::Dimension { dimension } => {
+                game.world_spawn.dimension = dimension;
+                lightning.reset();
+            }
+            FromNetMessage::LightningStrike {
+                entity_id,
+                position,
+            } => {
+                for sound in lightning.strike(entity_id, position) {
+                    game.sound_queue.push(sound);
+                }
+            }
+            FromNetMessage::SpawnPosition { x, y, z } => {
+                game.world_spawn.position = Some((x, y, z));
+            }
+            FromNetMessage::Camera { target_id } => {
+                game.spectate.target_id =
+                    (remote_entity_registry.local_entity_id != Some(target_id)).then_some(target_id);
+                game.spectate.eye = None;
             }
             FromNetMessage::TimeUpdate {
                 world_age,
@@ -417,6 +544,14 @@ pub fn handle_messages(
                 );
             }
             FromNetMessage::Sound(event) => {
+                // The client plays a bolt's sounds itself, delayed by distance.
+                if let SoundEvent::World {
+                    event_id, position, ..
+                } = &event
+                    && lightning.is_strike_sound(event_id, *position)
+                {
+                    continue;
+                }
                 game.sound_queue.push(event);
             }
             FromNetMessage::Title(event) => match event {
@@ -496,6 +631,9 @@ pub fn handle_messages(
             _ => { /* Ignore other messages for now */ }
         }
     }
+    if !game.block_break_stages.is_empty() {
+        game.block_break_stages.expire(Instant::now());
+    }
     let _ = timer.ms();
 }
 
@@ -518,6 +656,8 @@ fn apply_inventory_message(
                 });
             }
             inventory_state.open_window = Some(open.clone());
+            inventory_state.window_properties.clear();
+            inventory_state.trade_offers.clear();
             inventory_state
                 .window_slots
                 .entry(open.id)
@@ -541,6 +681,8 @@ fn apply_inventory_message(
                     });
                 }
                 inventory_state.open_window = None;
+                inventory_state.window_properties.clear();
+                inventory_state.trade_offers.clear();
             }
         }
         InventoryMessage::WindowItems { id, items } => {
@@ -564,5 +706,28 @@ fn apply_inventory_message(
         InventoryMessage::SetCurrentHotbarSlot { slot } => {
             inventory_state.set_selected_hotbar_slot(slot);
         }
+        InventoryMessage::WindowProperty {
+            id,
+            property,
+            value,
+        } => {
+            inventory_state.set_window_property(id, property, value);
+        }
+        InventoryMessage::TradeList { id, offers } => {
+            inventory_state.set_trade_offers(id, offers);
+        }
+    }
+}
+
+/// Display name of a killer/opponent from `CombatEvent`; -1 means no entity was involved.
+fn combat_entity_name(
+    registry: &RemoteEntityRegistry,
+    names: &Query<&RemoteEntityName>,
+    entity_id: i32,
+) -> Option<String> {
+    if entity_id < 0 || registry.local_entity_id == Some(entity_id) {
+        return None;
     }
+    let entity = registry.by_server_id.get(&entity_id)?;
+    names.get(*entity).ok().map(|name| name.0.clone())
 }
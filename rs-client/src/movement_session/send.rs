@@ -7,8 +7,8 @@ use crate::movement_session::state::{
     MovementObservation, MovementPacketKind, MovementSession,
 };
 use crate::sim::collision::WorldCollisionMap;
-use crate::sim::{SimClock, SimReady, SimState};
-use crate::sim_systems::LatencyEstimate;
+use crate::sim::{MountState, SimClock, SimReady, SimState};
+use crate::sim_systems::{LatencyEstimate, PredictionHistory};
 use rs_utils::{AppState, ApplicationState, ToNet, ToNetMessage};
 use tracing::debug;
 
@@ -19,6 +19,8 @@ pub fn movement_session_send_system(
     sim_state: Res<SimState>,
     collision_map: Res<WorldCollisionMap>,
     to_net: Res<ToNet>,
+    mount: Res<MountState>,
+    history: Res<PredictionHistory>,
     mut latency: ResMut<LatencyEstimate>,
     mut session: ResMut<MovementSession>,
 ) {
@@ -38,6 +40,12 @@ pub fn movement_session_send_system(
     }
     pitch = pitch.clamp(-90.0, 90.0);
 
+    if mount.is_mounted() {
+        send_vehicle_input(&to_net, &history, session.movement_epoch, yaw, pitch);
+        latency.last_sent = Some(Instant::now());
+        return;
+    }
+
     let obs = MovementObservation {
         pos: sim_state.current.pos,
         yaw,
@@ -89,6 +97,36 @@ pub fn movement_session_send_system(
     latency.last_sent = Some(Instant::now());
 }
 
+/// Vanilla riders send a look packet plus `SteerVehicle` every tick; the vehicle's position
+/// comes back from the server, so no position packets are sent while mounted.
+fn send_vehicle_input(
+    to_net: &ToNet,
+    history: &PredictionHistory,
+    epoch: u64,
+    yaw: f32,
+    pitch: f32,
+) {
+    let input = history
+        .0
+        .latest_frame()
+        .map(|frame| frame.input)
+        .unwrap_or_default();
+    let sneak_scale = if input.sneak { 0.3 } else { 1.0 };
+    let _ = to_net.0.send(ToNetMessage::PlayerMoveLook {
+        epoch,
+        yaw,
+        pitch,
+        on_ground: false,
+    });
+    // Vanilla strafe is positive to the left; ours is positive to the right.
+    let _ = to_net.0.send(ToNetMessage::SteerVehicle {
+        sideways: -input.strafe * sneak_scale * 0.98,
+        forward: input.forward * sneak_scale * 0.98,
+        jump: input.jump,
+        unmount: input.sneak,
+    });
+}
+
 pub fn transaction_pacing_system(
     app_state: Res<AppState>,
     to_net: Res<ToNet>,
@@ -5,9 +5,10 @@ use std::sync::Mutex;
 
 use rs_ui::ConnectUiState;
 use rs_utils::{
-    AppState, ApplicationState, BreakIndicator, Chat, FromNet, InventoryState, PerfTimings,
-    PlayerStatus, ScoreboardState, TabListHeaderFooter, TitleOverlayState, ToNet, UiState,
-    WorldTime,
+    AppState, ApplicationState, BlockBreakStages, BreakIndicator, Chat, CombatState, FromNet,
+    InventoryState, ItemAnimationState, LightningState, PerfTimings, PlayerStatus, ScoreboardState,
+    ServerDifficultyState, SleepState, SpectatorMenuState, StatisticsState, TabListHeaderFooter,
+    TitleOverlayState, ToNet, UiState, WorldSpawnState, WorldTime,
 };
 
 use crate::entities;
@@ -76,7 +77,15 @@ impl Plugin for ClientCorePlugin {
             .insert_resource(TitleOverlayState::default())
             .insert_resource(TabListHeaderFooter::default())
             .insert_resource(ScoreboardState::default())
+            .insert_resource(StatisticsState::default())
+            .insert_resource(CombatState::default())
+            .insert_resource(ServerDifficultyState::default())
+            .insert_resource(WorldSpawnState::default())
+            .insert_resource(ItemAnimationState::default())
+            .insert_resource(LightningState::default())
+            .insert_resource(SleepState::default())
             .insert_resource(BreakIndicator::default())
+            .insert_resource(BlockBreakStages::default())
             .insert_resource(PerfTimings::default())
             .insert_resource(net::events::NetEventQueue::default())
             .insert_resource(entities::RemoteEntityEventQueue::default())
@@ -94,6 +103,8 @@ impl Plugin for ClientCorePlugin {
             .insert_resource(sim::DebugUiState::default())
             .insert_resource(sim::ZoomState::default())
             .insert_resource(sim::FreecamState::default())
+            .insert_resource(sim::MountState::default())
+            .insert_resource(sim::SpectateCameraState::default())
             .insert_resource(sim::CameraPerspectiveState::default())
             .insert_resource(sim::CameraPerspectiveAltHold::default())
             .insert_resource(sim::LocalArmSwing::default())
@@ -129,7 +140,16 @@ pub struct ClientInventoryPlugin;
 
 impl Plugin for ClientInventoryPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, inventory_systems::hotbar_input_system);
+        app.insert_resource(SpectatorMenuState::default())
+            .add_systems(
+                Update,
+                (
+                    inventory_systems::hotbar_input_system,
+                    inventory_systems::spectator_menu_input_system,
+                    inventory_systems::sync_open_horse_window_type
+                        .after(message_handler::handle_messages),
+                ),
+            );
     }
 }
 
@@ -166,7 +186,24 @@ impl Plugin for ClientEntityPlugin {
                 entities::animate_remote_biped_models.after(entities::smooth_remote_entity_motion),
                 entities::animate_remote_quadruped_models
                     .after(entities::smooth_remote_entity_motion),
+                entities::animate_remote_arthropod_models
+                    .after(entities::smooth_remote_entity_motion),
                 entities::billboard_item_sprites.after(entities::smooth_remote_item_entities),
+                entities::position_remote_passengers.after(entities::smooth_remote_entity_motion),
+                entities::pose_sleeping_remote_players
+                    .after(entities::position_remote_passengers),
+                entities::draw_entity_leashes.after(entities::position_remote_passengers),
+                entities::update_remote_projectiles.after(entities::smooth_remote_entity_motion),
+                (
+                    entities::draw_fishing_lines.after(entities::position_remote_passengers),
+                    entities::draw_projectile_trajectories,
+                )
+                    .after(entities::update_remote_projectiles),
+                entities::sync_lightning_bolts,
+                entities::update_spectate_camera_target
+                    .after(entities::position_remote_passengers)
+                    .after(entities::animate_remote_player_models)
+                    .before(sim_systems::apply_visual_transform_system),
             ),
         )
         .add_systems(
@@ -177,10 +214,33 @@ impl Plugin for ClientEntityPlugin {
                     .after(item_textures::item_texture_cache_tick),
                 entities::apply_entity_model_textures_system
                     .after(entity_model::entity_texture_cache_tick),
+                entities::sync_remote_entity_metadata
+                    .after(entities::apply_remote_entity_events)
+                    .before(entities::apply_entity_model_textures_system)
+                    .before(entities::update_remote_sheep_wool_system),
                 entities::update_remote_sheep_wool_system
                     .after(entity_model::entity_texture_cache_tick),
                 entities::reconcile_humanoid_armor_layers_system,
                 entities::apply_player_shadow_opacity_material_system,
+                entities::attach_remote_block_entity_meshes
+                    .after(entities::apply_remote_entity_events),
+                entities::update_remote_block_entities
+                    .after(entities::smooth_remote_entity_motion),
+                entities::update_remote_armor_stands
+                    .after(entities::sync_remote_entity_metadata),
+                entities::update_remote_item_frames
+                    .after(entities::apply_remote_entity_events)
+                    .before(entities::smooth_remote_entity_motion),
+                entities::apply_entity_layer_textures_system
+                    .after(entity_model::entity_texture_cache_tick),
+                entities::animate_remote_floating_mobs
+                    .after(entities::smooth_remote_entity_motion),
+                entities::update_remote_slimes.after(entities::sync_remote_entity_metadata),
+                entities::update_remote_guardians.after(entities::sync_remote_entity_metadata),
+                entities::draw_guardian_lasers
+                    .after(entities::update_remote_guardians)
+                    .after(entities::smooth_remote_entity_motion),
+                entities::update_horse_preview.after(entities::smooth_remote_entity_motion),
             ),
         )
         .add_systems(
@@ -205,6 +265,8 @@ impl Plugin for ClientEntityPlugin {
                     .after(entities::suppress_first_person_viewmodel_near_geometry_system),
                 entities::animate_local_player_model_system
                     .after(sim_systems::apply_visual_transform_system),
+                entities::pose_sleeping_local_player_model
+                    .after(entities::spawn_local_player_model_system),
             ),
         )
         .add_systems(EguiPrimaryContextPass, entities::draw_remote_entity_names);
@@ -246,6 +308,10 @@ impl Plugin for ClientSimPlugin {
                         .after(sim_systems::apply_visual_transform_system),
                     sim_systems::world_interaction_system
                         .after(sim_systems::apply_visual_transform_system),
+                    sim_systems::update_block_break_overlays
+                        .after(sim_systems::world_interaction_system),
+                    sim_systems::pick_block_system
+                        .after(sim_systems::apply_visual_transform_system),
                 ),
             )
             .add_systems(
@@ -255,6 +321,9 @@ impl Plugin for ClientSimPlugin {
                     movement_session::movement_session_send_system,
                     movement_session::transaction_pacing_system,
                     sim_systems::local_movement_sound_system,
+                    sim_systems::item_animation_tick_system,
+                    sim_systems::lightning_tick_system,
+                    sim_systems::sleep_tick_system,
                 )
                     .chain(),
             )
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::image::{ImageLoaderSettings, ImageSampler};
+use bevy::pbr::{NotShadowCaster, NotShadowReceiver};
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+
+use super::super::*;
+use super::world::target_block_boxes;
+use rs_utils::BlockBreakStages;
+
+const DESTROY_STAGE_COUNT: usize = 10;
+const CRACK_INFLATE: f32 = 0.002;
+
+#[derive(Component)]
+pub struct BlockBreakOverlay {
+    block: (i32, i32, i32),
+    block_state: u16,
+    stage: u8,
+}
+
+#[derive(Default)]
+pub struct DestroyStageMaterials(Option<[Handle<StandardMaterial>; DESTROY_STAGE_COUNT]>);
+
+impl DestroyStageMaterials {
+    fn get(
+        &mut self,
+        asset_server: &AssetServer,
+        materials: &mut Assets<StandardMaterial>,
+        stage: u8,
+    ) -> Handle<StandardMaterial> {
+        let handles = self.0.get_or_insert_with(|| {
+            std::array::from_fn(|idx| {
+                let texture = asset_server.load_with_settings(
+                    format!("texturepack/assets/minecraft/textures/blocks/destroy_stage_{idx}.png"),
+                    |settings: &mut ImageLoaderSettings| settings.sampler = ImageSampler::nearest(),
+                );
+                materials.add(StandardMaterial {
+                    base_color_texture: Some(texture),
+                    // Vanilla draws cracks with a DST_COLOR * SRC_COLOR blend.
+                    alpha_mode: AlphaMode::Multiply,
+                    unlit: true,
+                    depth_bias: 1.0,
+                    ..Default::default()
+                })
+            })
+        });
+        handles[(stage as usize).min(DESTROY_STAGE_COUNT - 1)].clone()
+    }
+}
+
+/// Where the overlays' stages come from, and the world they are drawn on.
+#[derive(SystemParam)]
+pub(crate) struct BreakOverlaySources<'w, 's> {
+    app_state: Res<'w, AppState>,
+    break_indicator: Res<'w, BreakIndicator>,
+    stages: Res<'w, BlockBreakStages>,
+    collision_map: Res<'w, WorldCollisionMap>,
+    _marker: std::marker::PhantomData<&'s ()>,
+}
+
+/// Draws `destroy_stage_N` overlays for every block being mined, whether the stage comes
+/// from the local break estimate or from the server's `BlockBreakAnimation` for other players.
+pub fn update_block_break_overlays(
+    mut commands: Commands,
+    sources: BreakOverlaySources,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut stage_materials: Local<DestroyStageMaterials>,
+    mut overlays: Query<(
+        Entity,
+        &mut BlockBreakOverlay,
+        &mut MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    let BreakOverlaySources {
+        app_state,
+        break_indicator,
+        stages,
+        collision_map,
+        ..
+    } = sources;
+    let mut desired = if matches!(app_state.0, ApplicationState::Connected) {
+        stages.blocks()
+    } else {
+        HashMap::new()
+    };
+    if let (Some(block), Some(stage)) = (
+        break_indicator.target_block,
+        break_indicator.destroy_stage(),
+    ) && matches!(app_state.0, ApplicationState::Connected)
+    {
+        let entry = desired.entry(block).or_insert(stage);
+        *entry = (*entry).max(stage);
+    }
+
+    for (entity, mut overlay, mut material) in &mut overlays {
+        let (x, y, z) = overlay.block;
+        let current_state = collision_map.block_at(x, y, z);
+        match desired.remove(&overlay.block) {
+            Some(stage) if current_state == overlay.block_state => {
+                if overlay.stage != stage {
+                    overlay.stage = stage;
+                    material.0 = stage_materials.get(&asset_server, &mut materials, stage);
+                }
+            }
+            Some(stage) => {
+                // The block changed shape under the crack; rebuild it below.
+                desired.insert(overlay.block, stage);
+                commands.entity(entity).despawn();
+            }
+            None => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+
+    for ((x, y, z), stage) in desired {
+        let block_state = collision_map.block_at(x, y, z);
+        if block_state_id(block_state) == 0 {
+            continue;
+        }
+        let world = WorldCollision::with_map(&collision_map);
+        let origin = Vec3::new(x as f32, y as f32, z as f32);
+        let boxes = target_block_boxes(&world, block_state, x, y, z);
+        if boxes.is_empty() {
+            continue;
+        }
+        commands.spawn((
+            Name::new("BlockBreakOverlay"),
+            Mesh3d(meshes.add(crack_mesh(&boxes, origin))),
+            MeshMaterial3d(stage_materials.get(&asset_server, &mut materials, stage)),
+            Transform::from_translation(origin),
+            NotShadowCaster,
+            NotShadowReceiver,
+            BlockBreakOverlay {
+                block: (x, y, z),
+                block_state,
+                stage,
+            },
+        ));
+    }
+}
+
+/// Builds a slightly inflated shell around the block's outline boxes. UVs come from the
+/// block-local position so partial blocks show the matching slice of the crack texture.
+fn crack_mesh(boxes: &[(Vec3, Vec3)], origin: Vec3) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    for (min, max) in boxes {
+        let min = *min - origin - Vec3::splat(CRACK_INFLATE);
+        let max = *max - origin + Vec3::splat(CRACK_INFLATE);
+        let faces: [([Vec3; 4], Vec3); 6] = [
+            (
+                [
+                    Vec3::new(max.x, min.y, max.z),
+                    Vec3::new(max.x, min.y, min.z),
+                    Vec3::new(max.x, max.y, min.z),
+                    Vec3::new(max.x, max.y, max.z),
+                ],
+                Vec3::X,
+            ),
+            (
+                [
+                    Vec3::new(min.x, min.y, min.z),
+                    Vec3::new(min.x, min.y, max.z),
+                    Vec3::new(min.x, max.y, max.z),
+                    Vec3::new(min.x, max.y, min.z),
+                ],
+                Vec3::NEG_X,
+            ),
+            (
+                [
+                    Vec3::new(min.x, max.y, max.z),
+                    Vec3::new(max.x, max.y, max.z),
+                    Vec3::new(max.x, max.y, min.z),
+                    Vec3::new(min.x, max.y, min.z),
+                ],
+                Vec3::Y,
+            ),
+            (
+                [
+                    Vec3::new(min.x, min.y, min.z),
+                    Vec3::new(max.x, min.y, min.z),
+                    Vec3::new(max.x, min.y, max.z),
+                    Vec3::new(min.x, min.y, max.z),
+                ],
+                Vec3::NEG_Y,
+            ),
+            (
+                [
+                    Vec3::new(min.x, min.y, max.z),
+                    Vec3::new(max.x, min.y, max.z),
+                    Vec3::new(max.x, max.y, max.z),
+                    Vec3::new(min.x, max.y, max.z),
+                ],
+                Vec3::Z,
+            ),
+            (
+                [
+                    Vec3::new(max.x, min.y, min.z),
+                    Vec3::new(min.x, min.y, min.z),
+                    Vec3::new(min.x, max.y, min.z),
+                    Vec3::new(max.x, max.y, min.z),
+                ],
+                Vec3::NEG_Z,
+            ),
+        ];
+        for (verts, normal) in faces {
+            let base = positions.len() as u32;
+            for v in verts {
+                let uv = if normal.x != 0.0 {
+                    [v.z, 1.0 - v.y]
+                } else if normal.y != 0.0 {
+                    [v.x, v.z]
+                } else {
+                    [v.x, 1.0 - v.y]
+                };
+                positions.push(v.to_array());
+                normals.push(normal.to_array());
+                uvs.push(uv);
+            }
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+    }
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
@@ -1,5 +1,5 @@
 use super::super::*;
-use super::world::{raycast_block, target_block_boxes};
+use super::world::{RemoteHitboxQuery, raycast_block, remote_entity_aabb, target_block_boxes};
 use crate::sim::movement::collision_parity_expected_box_count;
 use crate::sim_systems::{PerfMonitorSample, PerformanceMonitorState};
 use bevy::ecs::system::SystemParam;
@@ -18,6 +18,7 @@ pub(crate) struct DebugOverlayParams<'w, 's> {
     input: Res<'w, CurrentInput>,
     ui_state: Res<'w, UiState>,
     player_status: Res<'w, rs_utils::PlayerStatus>,
+    difficulty: Res<'w, rs_utils::ServerDifficultyState>,
     monitor: Res<'w, PerformanceMonitorState>,
     timings: ResMut<'w, PerfTimings>,
     _marker: std::marker::PhantomData<&'s ()>,
@@ -27,31 +28,42 @@ pub fn draw_entity_hitboxes_system(
     mut gizmos: Gizmos,
     settings: Res<EntityHitboxDebug>,
     app_state: Res<AppState>,
-    entities: Query<(&GlobalTransform, &RemoteEntity, &RemoteVisual)>,
+    entities: Query<RemoteHitboxQuery>,
+    motion_buffers: Query<&RemoteMotionBuffer>,
 ) {
     if !settings.enabled || !matches!(app_state.0, ApplicationState::Connected) {
         return;
     }
 
-    for (transform, remote, visual) in &entities {
-        let (half_w, height) = match remote.kind {
-            rs_utils::NetEntityKind::Player | rs_utils::NetEntityKind::Mob(_) => (0.34, 1.8),
-            rs_utils::NetEntityKind::Item => (0.22, 0.35),
-            rs_utils::NetEntityKind::ExperienceOrb => (0.18, 0.28),
-            rs_utils::NetEntityKind::Object(_) => (0.28, 0.56),
-        };
-        let feet = transform.translation() - Vec3::Y * visual.y_offset;
-        let min = Vec3::new(feet.x - half_w, feet.y, feet.z - half_w);
-        let max = Vec3::new(feet.x + half_w, feet.y + height, feet.z + half_w);
+    for (transform, remote, visual, hitbox, sleeping) in &entities {
+        let (min, max) = remote_entity_aabb(transform, remote, visual, hitbox, sleeping);
         draw_aabb_lines(&mut gizmos, min, max, Color::srgba(0.2, 1.0, 0.2, 1.0));
     }
+
+    // Buffered server positions in cyan, and where the entity coasts past the newest one in orange.
+    let snapshot_color = Color::srgba(0.2, 0.9, 1.0, 1.0);
+    for buffer in &motion_buffers {
+        let snapshots: Vec<Vec3> = buffer.snapshots().map(|s| s.translation).collect();
+        gizmos.linestrip(snapshots.iter().copied(), snapshot_color);
+        for point in snapshots {
+            draw_aabb_lines(
+                &mut gizmos,
+                point - Vec3::splat(0.05),
+                point + Vec3::splat(0.05),
+                snapshot_color,
+            );
+        }
+        gizmos.linestrip(
+            buffer.extrapolated_path(8),
+            Color::srgba(1.0, 0.6, 0.1, 1.0),
+        );
+    }
 }
 
 pub fn draw_chunk_debug_system(
     mut gizmos: Gizmos,
     render_debug: Res<RenderDebugSettings>,
     app_state: Res<AppState>,
-    break_indicator: Res<BreakIndicator>,
     freecam: Res<FreecamState>,
     player_status: Res<rs_utils::PlayerStatus>,
     collision_map: Res<WorldCollisionMap>,
@@ -106,19 +118,6 @@ pub fn draw_chunk_debug_system(
                 max += Vec3::splat(inflate);
                 draw_aabb_lines(&mut gizmos, min, max, Color::srgba(0.02, 0.02, 0.02, 1.0));
             }
-            if break_indicator.active {
-                let p = break_indicator.progress.clamp(0.0, 1.0);
-                let crack_color = Color::srgb(0.32 + 0.50 * p, 0.32 - 0.20 * p, 0.32 - 0.20 * p);
-                let crack_inflate = 0.008 + p * 0.010;
-                for (min, max) in boxes {
-                    draw_aabb_lines(
-                        &mut gizmos,
-                        min - Vec3::splat(crack_inflate),
-                        max + Vec3::splat(crack_inflate),
-                        crack_color,
-                    );
-                }
-            }
         }
     }
 }
@@ -338,6 +337,11 @@ pub fn debug_overlay_system(
                         ui.label(format!("pos: {:.3} {:.3} {:.3}", pos.x, pos.y, pos.z));
                         ui.label(format!("block: {} {} {}", block.x, block.y, block.z));
                         ui.label(format!("chunk: {} {}", chunk_x, chunk_z));
+                        ui.label(format!(
+                            "difficulty: {}{}",
+                            params.difficulty.label(),
+                            if params.difficulty.locked { " (locked)" } else { "" }
+                        ));
                     }
                     if params.render_debug.show_look_info {
                         let yaw_mc = (std::f32::consts::PI - params.input.0.yaw).to_degrees();
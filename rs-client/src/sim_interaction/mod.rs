@@ -1,5 +1,9 @@
+mod break_overlay;
 mod debug;
+mod pick_block;
 mod world;
 
+pub use break_overlay::update_block_break_overlays;
 pub use debug::{debug_overlay_system, draw_chunk_debug_system, draw_entity_hitboxes_system};
+pub use pick_block::pick_block_system;
 pub use world::world_interaction_system;
@@ -0,0 +1,79 @@
+use bevy::ecs::system::SystemParam;
+
+use super::super::*;
+use super::world::raycast_block;
+use rs_utils::pick_block_item;
+
+/// The middle click and the state deciding whether it may pick a block.
+#[derive(SystemParam)]
+pub struct PickBlockInput<'w, 's> {
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    app_state: Res<'w, AppState>,
+    ui_state: Res<'w, UiState>,
+    player_status: Res<'w, rs_utils::PlayerStatus>,
+    _marker: std::marker::PhantomData<&'s ()>,
+}
+
+/// Middle click on a block (`Minecraft#middleClickMouse`): selects the hotbar slot holding its
+/// item, and in creative pulls the item into the hotbar with `CreativeInventoryAction`.
+pub fn pick_block_system(
+    input: PickBlockInput,
+    to_net: Res<ToNet>,
+    mut inventory_state: ResMut<InventoryState>,
+    collision_map: Res<WorldCollisionMap>,
+    camera_query: Query<&GlobalTransform, With<PlayerCamera>>,
+) {
+    let PickBlockInput {
+        mouse,
+        app_state,
+        ui_state,
+        player_status,
+        ..
+    } = input;
+    if !matches!(app_state.0, ApplicationState::Connected)
+        || ui_state.chat_open
+        || ui_state.paused
+        || ui_state.inventory_open
+        || player_status.dead
+        || player_status.gamemode == 3
+        || !mouse.just_pressed(MouseButton::Middle)
+    {
+        return;
+    }
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let is_creative = player_status.gamemode == 1;
+    let reach = if is_creative {
+        CREATIVE_BLOCK_REACH
+    } else {
+        SURVIVAL_BLOCK_REACH
+    };
+    let Some(hit) = raycast_block(
+        &collision_map,
+        camera_transform.translation(),
+        *camera_transform.forward(),
+        reach,
+    ) else {
+        return;
+    };
+    let block_state = collision_map.block_at(hit.block.x, hit.block.y, hit.block.z);
+    let Some(item) = pick_block_item(block_state) else {
+        return;
+    };
+
+    let previous_slot = inventory_state.selected_hotbar_slot;
+    let Some(picked) = inventory_state.pick_block(&item, is_creative) else {
+        return;
+    };
+    if picked.hotbar_slot != previous_slot {
+        let _ = to_net.0.send(ToNetMessage::HeldItemChange {
+            slot: i16::from(picked.hotbar_slot),
+        });
+    }
+    for (slot, item) in picked.changed_slots {
+        let _ = to_net
+            .0
+            .send(ToNetMessage::CreativeInventoryAction { slot, item });
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::*;
 use rs_sound::{block_dig_sound, block_step_sound, emit_world_sound};
-use rs_utils::{SoundCategory, SoundEventQueue};
+use rs_ui::ConnectUiState;
+use rs_utils::{BookScreen, SoundCategory, SoundEventQueue};
 
 pub fn world_interaction_system(
     time: Res<Time>,
@@ -16,8 +17,9 @@ pub fn world_interaction_system(
     mut break_indicator: ResMut<BreakIndicator>,
     collision_map: Res<WorldCollisionMap>,
     camera_query: Query<&GlobalTransform, With<PlayerCamera>>,
-    remote_entities: Query<(&GlobalTransform, &RemoteEntity, &RemoteVisual)>,
+    remote_entities: Query<RemoteHitboxQuery>,
     mut mining: Local<MiningState>,
+    mut connect_ui: ResMut<ConnectUiState>,
 ) {
     if !matches!(app_state.0, ApplicationState::Connected)
         || ui_state.chat_open
@@ -154,6 +156,7 @@ pub fn world_interaction_system(
                 progress,
                 elapsed_secs: mining.elapsed_secs,
                 total_secs: mining.total_secs,
+                target_block: Some((hit.block.x, hit.block.y, hit.block.z)),
             };
 
             if !mining.finish_sent && progress >= 1.0 {
@@ -255,6 +258,11 @@ pub fn world_interaction_system(
         } else {
             let held_item = inventory_state.selected_hotbar_item();
             let _ = to_net.0.send(ToNetMessage::UseItem { held_item });
+            // `EntityPlayerSP#displayGUIBook` opens a book and quill without the server.
+            if let Some(book) = BookScreen::open_held(&inventory_state).filter(|book| book.editable)
+            {
+                connect_ui.book = Some(book);
+            }
         }
     }
 }
@@ -601,7 +609,7 @@ pub(super) fn target_block_boxes(
 }
 
 fn raycast_remote_entity(
-    remote_entities: &Query<(&GlobalTransform, &RemoteEntity, &RemoteVisual)>,
+    remote_entities: &Query<RemoteHitboxQuery>,
     origin: Vec3,
     direction: Vec3,
     max_distance: f32,
@@ -612,17 +620,8 @@ fn raycast_remote_entity(
     }
 
     let mut nearest: Option<EntityHit> = None;
-    for (transform, remote, visual) in remote_entities.iter() {
-        let (half_w, height) = match remote.kind {
-            rs_utils::NetEntityKind::Player | rs_utils::NetEntityKind::Mob(_) => (0.34, 1.8),
-            rs_utils::NetEntityKind::Item => (0.22, 0.35),
-            rs_utils::NetEntityKind::ExperienceOrb => (0.18, 0.28),
-            rs_utils::NetEntityKind::Object(_) => (0.28, 0.56),
-        };
-        // Remote entity transforms are rendered with a y-offset; derive collider base from that.
-        let feet = transform.translation() - Vec3::Y * visual.y_offset;
-        let min = Vec3::new(feet.x - half_w, feet.y, feet.z - half_w);
-        let max = Vec3::new(feet.x + half_w, feet.y + height, feet.z + half_w);
+    for (transform, remote, visual, hitbox, sleeping) in remote_entities.iter() {
+        let (min, max) = remote_entity_aabb(transform, remote, visual, hitbox, sleeping);
         let Some(distance) = ray_aabb_distance(origin, dir, min, max, max_distance) else {
             continue;
         };
@@ -641,6 +640,42 @@ fn raycast_remote_entity(
     nearest
 }
 
+pub(super) type RemoteHitboxQuery = (
+    &'static GlobalTransform,
+    &'static RemoteEntity,
+    &'static RemoteVisual,
+    Option<&'static RemoteHitbox>,
+    Option<&'static RemoteSleeping>,
+);
+
+/// World-space box used for picking remote entities; also drawn by the hitbox debug view.
+pub(super) fn remote_entity_aabb(
+    transform: &GlobalTransform,
+    remote: &RemoteEntity,
+    visual: &RemoteVisual,
+    hitbox: Option<&RemoteHitbox>,
+    sleeping: Option<&RemoteSleeping>,
+) -> (Vec3, Vec3) {
+    if let Some(sleeping) = sleeping {
+        return sleeping.hitbox();
+    }
+    if let Some(hitbox) = hitbox {
+        let center = transform.translation();
+        return (center - hitbox.half_extents, center + hitbox.half_extents);
+    }
+    let (half_w, height) = match remote.kind {
+        rs_utils::NetEntityKind::Player | rs_utils::NetEntityKind::Mob(_) => (0.34, 1.8),
+        rs_utils::NetEntityKind::Item => (0.22, 0.35),
+        rs_utils::NetEntityKind::ExperienceOrb => (0.18, 0.28),
+        rs_utils::NetEntityKind::Object(_) => (0.28, 0.56),
+    };
+    // Remote entity transforms are rendered with a y-offset; derive collider base from that.
+    let feet = transform.translation() - Vec3::Y * visual.y_offset;
+    let min = Vec3::new(feet.x - half_w, feet.y, feet.z - half_w);
+    let max = Vec3::new(feet.x + half_w, feet.y + height, feet.z + half_w);
+    (min, max)
+}
+
 fn ray_aabb_distance(origin: Vec3, dir: Vec3, min: Vec3, max: Vec3, max_dist: f32) -> Option<f32> {
     let mut t_min = 0.0f32;
     let mut t_max = max_dist;
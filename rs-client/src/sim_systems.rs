@@ -15,24 +15,26 @@ use rs_utils::{
 
 use crate::sim::collision::WorldCollisionMap;
 use crate::sim::movement::{
-    WorldCollision, debug_block_collision_boxes, effective_sprint, simulate_tick,
+    WorldCollision, debug_block_collision_boxes, effective_sprint, simulate_mounted_tick,
+    simulate_tick,
 };
 use crate::sim::predict::PredictionBuffer;
 use crate::sim::{
     CameraPerspectiveAltHold, CameraPerspectiveMode, CameraPerspectiveState, CurrentInput,
-    DebugStats, DebugUiState, FreecamState, LocalArmSwing, PredictedFrame, SimClock,
-    SimRenderState, SimState, VisualCorrectionOffset, ZoomState,
+    DebugStats, DebugUiState, FreecamState, LocalArmSwing, MountState, PredictedFrame, SimClock,
+    SimRenderState, SimState, SpectateCameraState, VisualCorrectionOffset, ZoomState,
 };
 use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use rs_render::{RenderDebugSettings, debug::RenderPerfStats};
 use rs_utils::{
-    BreakIndicator, EntityUseAction, InventoryState, PerfTimings, block_model_kind,
-    block_registry_key, block_state_id, block_state_meta,
+    BreakIndicator, EntityUseAction, InventoryState, PLAYER_SLEEP_EYE_HEIGHT, PerfTimings,
+    SleepState, bed_direction, block_model_kind, block_registry_key, block_state_id,
+    block_state_meta,
 };
 use sysinfo::{Pid, ProcessesToUpdate, System};
 
-use crate::entities::{ItemSpriteStack, RemoteVisual};
-use crate::entities::{RemoteEntity, RemoteEntityRegistry};
+use crate::entities::{ItemSpriteStack, RemoteHitbox, RemoteSleeping, RemoteVisual};
+use crate::entities::{RemoteEntity, RemoteEntityRegistry, RemoteMotionBuffer};
 use crate::item_textures::{ItemSpriteMesh, ItemTextureCache};
 use crate::movement_session::MovementSession;
 use crate::timing::Timing;
@@ -87,6 +89,8 @@ pub struct FixedSimParams<'w, 's> {
     pub to_net: Res<'w, ToNet>,
     pub remote_entities: Res<'w, RemoteEntityRegistry>,
     pub sim_ready: Res<'w, crate::sim::SimReady>,
+    pub mount: Res<'w, MountState>,
+    pub sleep: Res<'w, SleepState>,
     pub timings: ResMut<'w, PerfTimings>,
     pub _marker: std::marker::PhantomData<&'s ()>,
 }
@@ -192,6 +196,7 @@ pub fn input_collect_system(
     mut input: ResMut<CurrentInput>,
     perspective: Res<CameraPerspectiveState>,
     freecam: Res<FreecamState>,
+    spectate: Res<SpectateCameraState>,
     app_state: Res<AppState>,
     ui_state: Res<UiState>,
     player_status: Res<rs_utils::PlayerStatus>,
@@ -214,6 +219,17 @@ pub fn input_collect_system(
         look_delta += ev.delta;
     }
 
+    if spectate.is_active() {
+        // The view belongs to the spectated entity; sneaking is the only way back out.
+        input.0.forward = 0.0;
+        input.0.strafe = 0.0;
+        input.0.jump = false;
+        input.0.sprint = false;
+        input.0.sneak = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+        timings.input_collect_ms = timer.ms();
+        return;
+    }
+
     let sensitivity = 0.002;
     // Bevy uses right-handed yaw (positive rotates left), so invert mouse X for FPS feel.
     input.0.yaw -= look_delta.x * sensitivity;
@@ -352,6 +368,7 @@ pub fn local_held_item_view_system(
         || ui_state.paused
         || ui_state.inventory_open
         || player_status.dead
+        || player_status.gamemode == 3
         || !render_debug.render_held_items
         || render_debug.render_first_person_arms
         || !matches!(perspective.mode, CameraPerspectiveMode::FirstPerson)
@@ -433,7 +450,10 @@ pub fn local_held_item_view_system(
 }
 
 mod camera;
+mod item_animation;
+mod lightning;
 mod simulation;
+mod sleep;
 mod timing;
 
 pub use camera::{
@@ -441,10 +461,13 @@ pub use camera::{
     camera_perspective_toggle_system, debug_toggle_system, freecam_move_system,
     freecam_toggle_system,
 };
+pub use item_animation::item_animation_tick_system;
+pub use lightning::lightning_tick_system;
 pub use simulation::{
     fixed_sim_tick_system, local_arm_swing_tick_system, local_movement_sound_system,
     prune_collision_chunks_system, visual_smoothing_system,
 };
+pub use sleep::sleep_tick_system;
 pub use timing::{
     fixed_update_timing_end, fixed_update_timing_start, frame_timing_end, frame_timing_start,
     performance_monitor_sample_system, post_update_timing_end, post_update_timing_start,
@@ -455,6 +478,6 @@ pub use timing::{
 mod sim_interaction;
 
 pub use sim_interaction::{
-    debug_overlay_system, draw_chunk_debug_system, draw_entity_hitboxes_system,
-    world_interaction_system,
+    debug_overlay_system, draw_chunk_debug_system, draw_entity_hitboxes_system, pick_block_system,
+    update_block_break_overlays, world_interaction_system,
 };
@@ -187,6 +187,8 @@ pub fn apply_visual_transform_system(
     offset: Res<VisualCorrectionOffset>,
     collision_map: Res<WorldCollisionMap>,
     freecam: Res<FreecamState>,
+    spectate: Res<SpectateCameraState>,
+    sleep: Res<SleepState>,
     mut player_query: Query<(&mut Transform, &mut LookAngles), With<Player>>,
     mut camera_query: Query<&mut Transform, (With<PlayerCamera>, Without<Player>)>,
     mut eye_height: Local<f32>,
@@ -210,12 +212,24 @@ pub fn apply_visual_transform_system(
                 timings.apply_transform_ms = timer.ms();
                 return;
             }
-            let target_eye_height = if input.0.sneak {
+            if let Some(eye) = spectate.eye {
+                let inv_player_rot = player_transform.rotation.inverse();
+                let world_rot = Quat::from_axis_angle(Vec3::Y, spectate.yaw)
+                    * Quat::from_axis_angle(Vec3::X, spectate.pitch);
+                camera_transform.translation =
+                    inv_player_rot * (eye - player_transform.translation);
+                camera_transform.rotation = inv_player_rot * world_rot;
+                timings.apply_transform_ms = timer.ms();
+                return;
+            }
+            let target_eye_height = if sleep.is_sleeping() {
+                PLAYER_SLEEP_EYE_HEIGHT
+            } else if input.0.sneak {
                 EYE_HEIGHT_SNEAK
             } else {
                 EYE_HEIGHT_STAND
             };
-            if *eye_height <= 0.0 {
+            if *eye_height <= 0.0 || sleep.is_sleeping() {
                 *eye_height = target_eye_height;
             } else {
                 *eye_height += (target_eye_height - *eye_height) * 0.5;
@@ -225,6 +239,15 @@ pub fn apply_visual_transform_system(
             let base_eye_local = Vec3::new(0.0, *eye_height, 0.0);
 
             let (mut cam_local, cam_local_rot) = match perspective.mode {
+                // Sleepers look down the bed towards its foot, whatever the mouse says.
+                CameraPerspectiveMode::FirstPerson if sleep.is_sleeping() => {
+                    let toward_head = bed_direction(sleep.facing);
+                    let world_rot = Quat::from_rotation_y(toward_head.x.atan2(toward_head.z));
+                    (
+                        base_eye_local,
+                        player_transform.rotation.inverse() * world_rot,
+                    )
+                }
                 CameraPerspectiveMode::FirstPerson => (base_eye_local, pitch_rot),
                 CameraPerspectiveMode::ThirdPersonBack => {
                     let offset_local =
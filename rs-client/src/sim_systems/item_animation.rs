@@ -0,0 +1,31 @@
+use super::*;
+use rs_utils::{
+    ItemAnimationState, WorldSpawnState, WorldTime, celestial_angle, compass_target_angle,
+};
+
+/// Advances the compass needle and clock dial once per client tick, like vanilla's animated
+/// `TextureCompass`/`TextureClock` sprites.
+pub fn item_animation_tick_system(
+    sim_state: Res<SimState>,
+    world_spawn: Res<WorldSpawnState>,
+    world_time: Res<WorldTime>,
+    mut animation: ResMut<ItemAnimationState>,
+) {
+    let player = &sim_state.current;
+    let overworld = world_spawn.is_overworld();
+    let compass_target = world_spawn.position.filter(|_| overworld).map(|spawn| {
+        let yaw_degrees = f64::from((std::f32::consts::PI - player.yaw).to_degrees());
+        compass_target_angle(
+            spawn,
+            f64::from(player.pos.x),
+            f64::from(player.pos.z),
+            yaw_degrees,
+        )
+    });
+    let clock_target = overworld.then(|| {
+        celestial_angle(f64::from(
+            world_time.interpolated_time_of_day(Instant::now()),
+        ))
+    });
+    animation.tick(compass_target, clock_target);
+}
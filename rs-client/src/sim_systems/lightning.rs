@@ -0,0 +1,7 @@
+use super::*;
+use rs_utils::LightningState;
+
+/// Ages lightning bolts and the sky flash once per client tick, like `EntityLightningBolt`.
+pub fn lightning_tick_system(mut lightning: ResMut<LightningState>) {
+    lightning.tick();
+}
@@ -50,6 +50,8 @@ pub fn fixed_sim_tick_system(
     }
     let world = WorldCollision::with_map(&collision_map);
     let tick = sim_clock.tick;
+    let mounted = params.mount.is_mounted();
+    let sleeping_at = params.sleep.head_position();
     let mut input_snapshot = input.0;
     let boosted_flying_speed = effective_flying_speed(
         player_status.flying_speed,
@@ -70,8 +72,9 @@ pub fn fixed_sim_tick_system(
     if action_state.fly_toggle_timer > 0 {
         action_state.fly_toggle_timer = action_state.fly_toggle_timer.saturating_sub(1);
     }
-    if player_status.can_fly {
-        if jump_pressed {
+    let spectator = player_status.gamemode == 3;
+    if player_status.can_fly && !mounted {
+        if jump_pressed && !spectator {
             if action_state.fly_toggle_timer == 0 {
                 action_state.fly_toggle_timer = 7;
             } else {
@@ -99,6 +102,7 @@ pub fn fixed_sim_tick_system(
     input_snapshot.flying_speed = boosted_flying_speed;
     input_snapshot.speed_multiplier = (player_status.walking_speed / 0.1).max(0.0);
     input_snapshot.jump_boost_amplifier = player_status.jump_boost_amplifier;
+    input_snapshot.no_clip = spectator;
 
     let sprint_key_down = input_snapshot.sprint;
     let forward_strong = input_snapshot.forward >= SPRINT_FORWARD_THRESHOLD;
@@ -117,11 +121,19 @@ pub fn fixed_sim_tick_system(
     {
         sprinting_state = false;
     }
+    if mounted || sleeping_at.is_some() {
+        sprinting_state = false;
+    }
     input_snapshot.sprint = sprinting_state;
     action_state.sim_sprinting = sprinting_state;
 
     sim_render.previous = sim_state.current;
-    let next_state = if movement_session.consume_physics_hold() {
+    let next_state = if mounted {
+        simulate_mounted_tick(&sim_state.current, &input_snapshot, params.mount.seat_pos)
+    } else if sleeping_at.is_some() {
+        // A sleeper is pinned to the pillow just like a rider to its seat.
+        simulate_mounted_tick(&sim_state.current, &input_snapshot, sleeping_at)
+    } else if movement_session.consume_physics_hold() {
         let mut state = movement_session.last_authoritative_state;
         state.yaw = input_snapshot.yaw;
         state.pitch = input_snapshot.pitch;
@@ -138,7 +150,7 @@ pub fn fixed_sim_tick_system(
     });
 
     sim_state.current = next_state;
-    if player_status.flying && player_status.gamemode != 3 && sim_state.current.on_ground {
+    if player_status.flying && !spectator && sim_state.current.on_ground {
         player_status.flying = false;
         let _ = params.to_net.0.send(ToNetMessage::ClientAbilities {
             flags: client_abilities_flags(&player_status),
@@ -153,7 +165,9 @@ pub fn fixed_sim_tick_system(
         let current_sneak = input_snapshot.sneak;
         let current_sprint = effective_sprint(&input_snapshot);
 
-        if movement_session.correction_active() {
+        // Riding sends `SteerVehicle` instead; the sneak key means dismount there. Sleepers
+        // leave through the bed screen's button.
+        if movement_session.correction_active() || mounted || sleeping_at.is_some() {
             params.timings.fixed_tick_ms = timer.ms();
             return;
         }
@@ -0,0 +1,6 @@
+use super::*;
+
+/// Advances the bed fade once per client tick, like `EntityPlayer#sleepTimer`.
+pub fn sleep_tick_system(mut sleep: ResMut<SleepState>) {
+    sleep.tick();
+}
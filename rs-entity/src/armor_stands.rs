@@ -0,0 +1,118 @@
+use super::*;
+use crate::model::{
+    ARMOR_STAND_BASE, ARMOR_STAND_BODY, ARMOR_STAND_HEAD, ARMOR_STAND_LEFT_ARM,
+    ARMOR_STAND_LEFT_LEG, ARMOR_STAND_RIGHT_ARM, ARMOR_STAND_RIGHT_LEG, SpawnedModel,
+    part_angles_rotation,
+};
+use crate::specs::NAME_ABOVE_HITBOX;
+use rs_utils::MobMetadata;
+
+pub(crate) const ARMOR_STAND_TEXTURE_PATH: &str = "entity/armorstand/wood.png";
+/// `EntityArmorStand` hitbox height; small stands are half size and markers have none.
+const ARMOR_STAND_HEIGHT: f32 = 1.975;
+const SMALL_SCALE: f32 = 0.5;
+
+/// Part entities of an armor stand model, in `ARMOR_STAND_MODEL` order.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteArmorStand {
+    parts: Vec<Entity>,
+}
+
+/// Lets the stand wear armor and hold items through the biped mob rig.
+pub(crate) fn armor_stand_rig(spawned: &SpawnedModel) -> impl Bundle {
+    (
+        RemoteArmorStand {
+            parts: spawned.parts.clone(),
+        },
+        HumanoidRigParts {
+            kind: HumanoidRigKind::BipedMob,
+            model_root: spawned.root,
+            head: spawned.parts[ARMOR_STAND_HEAD],
+            body: spawned.parts[ARMOR_STAND_BODY],
+            arm_right: spawned.parts[ARMOR_STAND_RIGHT_ARM],
+            arm_left: spawned.parts[ARMOR_STAND_LEFT_ARM],
+            leg_right: spawned.parts[ARMOR_STAND_RIGHT_LEG],
+            leg_left: spawned.parts[ARMOR_STAND_LEFT_LEG],
+            render_layer: None,
+        },
+        HumanoidArmorState::default(),
+        HumanoidArmorLayerEntities::default(),
+    )
+}
+
+type ArmorStandQuery = (
+    &'static RemoteArmorStand,
+    Ref<'static, RemoteEntityMetadata>,
+    &'static mut Transform,
+    &'static mut RemoteVisual,
+);
+
+/// Poses armor stands from their metadata rotations and applies the size, arms, base plate
+/// and invisibility flags. Invisible stands hide only the stand itself; armor, held items
+/// and the name tag stay visible, which is how servers build holograms and NPCs.
+pub fn update_remote_armor_stands(
+    mut stands: Query<ArmorStandQuery>,
+    mut parts: Query<&mut Transform, Without<RemoteArmorStand>>,
+    children_query: Query<&Children>,
+    mut model_meshes: Query<&mut Visibility, With<EntityTexturePath>>,
+) {
+    for (stand, metadata, mut transform, mut visual) in &mut stands {
+        let MobMetadata::ArmorStand(pose) = metadata.0.mob else {
+            continue;
+        };
+        let rotations = [
+            (ARMOR_STAND_HEAD, pose.head),
+            (ARMOR_STAND_BODY, pose.body),
+            (ARMOR_STAND_RIGHT_ARM, pose.right_arm),
+            (ARMOR_STAND_LEFT_ARM, pose.left_arm),
+            (ARMOR_STAND_RIGHT_LEG, pose.right_leg),
+            (ARMOR_STAND_LEFT_LEG, pose.left_leg),
+        ]
+        .map(|(index, degrees)| (index, part_angles_rotation(degrees.map(f32::to_radians))));
+        // `ModelArmorStand` turns the base plate against the stand's yaw so it stays on the grid.
+        let base = (ARMOR_STAND_BASE, transform.rotation.inverse());
+        for (index, rotation) in rotations.into_iter().chain([base]) {
+            if let Ok(mut part) = parts.get_mut(stand.parts[index])
+                && part.rotation != rotation
+            {
+                part.rotation = rotation;
+            }
+        }
+
+        if !metadata.is_changed() {
+            continue;
+        }
+        let scale = if pose.small { SMALL_SCALE } else { 1.0 };
+        transform.scale = Vec3::splat(scale);
+        let height = if pose.marker {
+            0.0
+        } else {
+            ARMOR_STAND_HEIGHT * scale
+        };
+        visual.name_y_offset = height + NAME_ABOVE_HITBOX;
+
+        for (index, &part) in stand.parts.iter().enumerate() {
+            let shown = !metadata.0.invisible
+                && match index {
+                    ARMOR_STAND_RIGHT_ARM | ARMOR_STAND_LEFT_ARM => pose.has_arms,
+                    ARMOR_STAND_BASE => !pose.no_base_plate,
+                    _ => true,
+                };
+            let target = if shown {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            };
+            let Ok(children) = children_query.get(part) else {
+                continue;
+            };
+            for child in children.iter() {
+                if let Ok(mut visibility) = model_meshes.get_mut(child)
+                    && *visibility != target
+                {
+                    *visibility = target;
+                }
+            }
+        }
+    }
+}
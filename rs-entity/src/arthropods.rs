@@ -0,0 +1,201 @@
+use super::*;
+use crate::model::{
+    ENDERMITE_MODEL, ENDERMITE_SEGMENTS, SILVERFISH_MODEL, SILVERFISH_SEGMENTS, SPIDER_FIRST_LEG,
+    SPIDER_HEAD, SPIDER_LEG_COUNT, SPIDER_MODEL, part_angles_rotation,
+};
+use crate::objects::entity_model_material;
+use crate::specs::{ArthropodModelKind, mob_arthropod_model_kind};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_8, PI, TAU};
+
+const SPIDER_EYES_TEXTURE_PATH: &str = "entity/spider_eyes.png";
+const PX: f32 = 1.0 / 16.0;
+const TICKS_PER_SEC: f32 = 20.0;
+/// Fraction of the vanilla body-wave rate silverfish and endermites keep while standing still.
+const IDLE_WAVE_RATE: f32 = 0.35;
+
+/// Part entities of a spider, silverfish or endermite model, in model order.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteArthropodModel {
+    kind: ArthropodModelKind,
+    parts: Vec<Entity>,
+}
+
+/// Movement-driven animation state, like `RemoteQuadrupedAnimation`. `wave_ticks` is the
+/// `ageInTicks` the silverfish and endermite body waves are computed from.
+#[derive(Component, Debug, Clone, Default)]
+pub struct RemoteArthropodAnimation {
+    previous_pos: Option<Vec3>,
+    limb_swing: f32,
+    limb_swing_amount: f32,
+    wave_ticks: f32,
+}
+
+/// Spawns the arthropod model for `mob` under `root`, returning false for other mobs.
+pub(crate) fn spawn_arthropod_model(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_textures: &mut EntityTextureCache,
+    root: Entity,
+    mob: MobKind,
+) -> bool {
+    let (Some(kind), Some(texture_path)) = (mob_arthropod_model_kind(mob), mob_texture_path(mob))
+    else {
+        return false;
+    };
+    let material = entity_model_material(materials, entity_textures, texture_path);
+    let model = match kind {
+        ArthropodModelKind::Spider => &SPIDER_MODEL,
+        ArthropodModelKind::Silverfish => &SILVERFISH_MODEL,
+        ArthropodModelKind::Endermite => &ENDERMITE_MODEL,
+    };
+    let spawned = spawn_model(commands, meshes, material, model, texture_path);
+    commands.entity(root).add_child(spawned.root);
+
+    if kind == ArthropodModelKind::Spider {
+        // `LayerSpiderEyes` adds the eye glow over the head at full brightness.
+        let eyes_material = materials.add(StandardMaterial {
+            base_color: Color::WHITE,
+            base_color_texture: entity_textures.texture(SPIDER_EYES_TEXTURE_PATH),
+            alpha_mode: AlphaMode::Add,
+            unlit: true,
+            perceptual_roughness: 1.0,
+            metallic: 0.0,
+            ..Default::default()
+        });
+        // Only the head has eye pixels, so the layer skips the rest of the model.
+        let head = &SPIDER_MODEL.parts[SPIDER_HEAD];
+        let eyes = commands
+            .spawn((
+                Name::new("EntityModelMesh[spider_eyes]"),
+                Mesh3d(meshes.add(part_mesh(&SPIDER_MODEL, head))),
+                MeshMaterial3d(eyes_material),
+                Transform::IDENTITY,
+                GlobalTransform::default(),
+                Visibility::Inherited,
+                InheritedVisibility::default(),
+                ViewVisibility::default(),
+                EntityLayerTexturePath(SPIDER_EYES_TEXTURE_PATH),
+            ))
+            .id();
+        commands.entity(spawned.parts[SPIDER_HEAD]).add_child(eyes);
+    }
+
+    commands.entity(root).insert((
+        RemoteEntityTexture(texture_path),
+        RemoteArthropodModel {
+            kind,
+            parts: spawned.parts,
+        },
+        RemoteArthropodAnimation::default(),
+    ));
+    true
+}
+
+/// Walks spider legs with `ModelSpider`'s gait and sways silverfish and endermite segments
+/// with their vanilla body wave, which slows down while they stand still.
+pub fn animate_remote_arthropod_models(
+    time: Res<Time>,
+    mut roots: Query<(
+        &Transform,
+        &RemoteEntityLook,
+        &RemoteArthropodModel,
+        &mut RemoteArthropodAnimation,
+    )>,
+    mut part_transforms: Query<&mut Transform, Without<RemoteArthropodModel>>,
+) {
+    let dt = time.delta_secs().max(1e-4);
+    let pose_alpha = 1.0 - (-22.0 * dt).exp();
+    let swing_alpha = 1.0 - (-12.0 * dt).exp();
+
+    for (root_transform, look, model, mut anim) in &mut roots {
+        let pos = root_transform.translation;
+        let previous = anim.previous_pos.replace(pos).unwrap_or(pos);
+        let horizontal_delta = Vec2::new(pos.x - previous.x, pos.z - previous.z);
+        let speed = (horizontal_delta.length() / dt).min(10.0);
+
+        let target_limb_swing_amount = (speed / 4.0).clamp(0.0, 1.0);
+        anim.limb_swing_amount += (target_limb_swing_amount - anim.limb_swing_amount) * swing_alpha;
+        anim.limb_swing += speed * dt * 1.3;
+        anim.wave_ticks +=
+            dt * TICKS_PER_SEC * (IDLE_WAVE_RATE + (1.0 - IDLE_WAVE_RATE) * anim.limb_swing_amount);
+
+        match model.kind {
+            ArthropodModelKind::Spider => {
+                let head_pitch = look.pitch.clamp(-1.4, 1.4);
+                let head_yaw_delta = (look.head_yaw - look.yaw + PI).rem_euclid(TAU) - PI;
+                let head_target =
+                    Quat::from_rotation_y(head_yaw_delta) * Quat::from_rotation_x(-head_pitch);
+                if let Ok(mut t) = part_transforms.get_mut(model.parts[SPIDER_HEAD]) {
+                    t.rotation = t.rotation.slerp(head_target, pose_alpha);
+                }
+                for (pair, angles) in spider_leg_angles(anim.limb_swing, anim.limb_swing_amount)
+                    .into_iter()
+                    .enumerate()
+                {
+                    for (side, [y, z]) in angles.into_iter().enumerate() {
+                        let leg = model.parts[SPIDER_FIRST_LEG + pair * 2 + side];
+                        if let Ok(mut t) = part_transforms.get_mut(leg) {
+                            t.rotation = part_angles_rotation([0.0, y, z]);
+                        }
+                    }
+                }
+            }
+            ArthropodModelKind::Silverfish | ArthropodModelKind::Endermite => {
+                // `ModelEnderMite` runs the `ModelSilverfish` wave with less sway.
+                let (segments, yaw_scale, sway_scale) = match model.kind {
+                    ArthropodModelKind::Silverfish => (SILVERFISH_SEGMENTS, 0.05, 0.2),
+                    _ => (ENDERMITE_SEGMENTS, 0.01, 0.1),
+                };
+                for (index, &segment) in model.parts[..segments].iter().enumerate() {
+                    let phase = anim.wave_ticks * 0.9 + index as f32 * 0.15 * PI;
+                    let from_middle = (index as f32 - 2.0).abs();
+                    if let Ok(mut t) = part_transforms.get_mut(segment) {
+                        t.rotation = part_angles_rotation([
+                            0.0,
+                            phase.cos() * PI * yaw_scale * (1.0 + from_middle),
+                            0.0,
+                        ]);
+                        t.translation.x = phase.sin() * PI * sway_scale * from_middle * PX;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `ModelSpider` leg `rotateAngleY`/`rotateAngleZ` for each right/left pair, front to back:
+/// a splayed rest pose plus alternating forward swings and lifts while walking.
+fn spider_leg_angles(limb_swing: f32, amount: f32) -> [[[f32; 2]; 2]; SPIDER_LEG_COUNT / 2] {
+    let rest_yaw = [FRAC_PI_8 * 2.0, FRAC_PI_8, -FRAC_PI_8, -FRAC_PI_8 * 2.0];
+    let rest_roll = [FRAC_PI_4, FRAC_PI_4 * 0.74, FRAC_PI_4 * 0.74, FRAC_PI_4];
+    let offsets = [0.0, PI, FRAC_PI_2, PI * 1.5];
+    std::array::from_fn(|pair| {
+        let swing = -((limb_swing * 0.6662 * 2.0 + offsets[pair]).cos() * 0.4) * amount;
+        let lift = ((limb_swing * 0.6662 + offsets[pair]).sin() * 0.4).abs() * amount;
+        let yaw = rest_yaw[pair] + swing;
+        let roll = rest_roll[pair] - lift;
+        [[yaw, -roll], [-yaw, roll]]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spider_legs_rest_splayed_and_mirrored() {
+        let rest = spider_leg_angles(0.0, 0.0);
+        assert_eq!(rest[0], [[FRAC_PI_4, -FRAC_PI_4], [-FRAC_PI_4, FRAC_PI_4]]);
+        assert_eq!(rest[3], [[-FRAC_PI_4, -FRAC_PI_4], [FRAC_PI_4, FRAC_PI_4]]);
+        for [[right_yaw, right_roll], [left_yaw, left_roll]] in spider_leg_angles(3.7, 1.0) {
+            assert_eq!(right_yaw, -left_yaw);
+            assert_eq!(right_roll, -left_roll);
+        }
+        // Walking only ever lifts legs off the ground, never pushes them below it.
+        let walking = spider_leg_angles(1.2, 1.0);
+        for pair in 0..4 {
+            assert!(walking[pair][0][1] >= rest[pair][0][1]);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::f32::consts::PI;
+
+use super::*;
+use rs_sim::bed_facing;
+use rs_utils::{PLAYER_SLEEP_EYE_HEIGHT, SleepState, bed_direction, bed_head_position};
+
+/// Vanilla `EntityPlayer#getRenderOffset`: a sleeper's feet sit this far from its position.
+const SLEEPER_LENGTH: f32 = 1.8;
+
+/// A remote player lying in a bed; its server position is pinned to `head`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RemoteSleeping {
+    pub head: Vec3,
+    pub facing: u8,
+}
+
+impl RemoteSleeping {
+    /// Vanilla shrinks sleepers to a 0.2 cube around their pillow position.
+    pub fn hitbox(&self) -> (Vec3, Vec3) {
+        let center = self.head + Vec3::Y * 0.1;
+        (center - Vec3::splat(0.1), center + Vec3::splat(0.1))
+    }
+
+    pub fn name_position(&self) -> Vec3 {
+        self.head + Vec3::Y * (PLAYER_SLEEP_EYE_HEIGHT + 0.5)
+    }
+}
+
+/// Body orientation for a sleeper: on its back, head towards the headboard.
+fn sleeping_rotation(facing: u8) -> Quat {
+    let toward_head = bed_direction(facing);
+    Quat::from_mat3(&Mat3::from_cols(
+        toward_head.cross(Vec3::Y),
+        toward_head,
+        Vec3::Y,
+    ))
+}
+
+/// Feet position of a player model lying in the bed.
+fn sleeping_origin(head: Vec3, facing: u8) -> Vec3 {
+    head - bed_direction(facing) * SLEEPER_LENGTH
+}
+
+pub(crate) fn apply_use_bed(
+    commands: &mut Commands,
+    registry: &RemoteEntityRegistry,
+    sleep: &mut SleepState,
+    collision: &WorldCollisionMap,
+    entity_id: i32,
+    location: (i32, i32, i32),
+) {
+    let facing = bed_facing(collision, location).unwrap_or(0);
+    if registry.local_entity_id == Some(entity_id) {
+        sleep.enter(entity_id, location, facing);
+        return;
+    }
+    if let Some(&entity) = registry.by_server_id.get(&entity_id) {
+        commands.entity(entity).insert(RemoteSleeping {
+            head: bed_head_position(location, facing),
+            facing,
+        });
+    }
+}
+
+pub(crate) fn apply_leave_bed(
+    commands: &mut Commands,
+    registry: &RemoteEntityRegistry,
+    sleep: &mut SleepState,
+    entity_id: i32,
+) {
+    if registry.local_entity_id == Some(entity_id) {
+        sleep.wake();
+    } else if let Some(&entity) = registry.by_server_id.get(&entity_id) {
+        commands.entity(entity).remove::<RemoteSleeping>();
+    }
+}
+
+pub fn pose_sleeping_remote_players(
+    mut sleepers: Query<(&RemoteSleeping, &RemoteVisual, &mut Transform)>,
+) {
+    for (sleeping, visual, mut transform) in &mut sleepers {
+        transform.translation =
+            sleeping_origin(sleeping.head, sleeping.facing) + Vec3::Y * visual.y_offset;
+        transform.rotation = sleeping_rotation(sleeping.facing);
+    }
+}
+
+/// Lays the local player model in its bed; the model is a child of the yawed [`Player`] root.
+pub fn pose_sleeping_local_player_model(
+    sleep: Res<SleepState>,
+    player_query: Query<&Transform, (With<Player>, Without<LocalPlayerModel>)>,
+    mut model_query: Query<&mut Transform, With<LocalPlayerModel>>,
+) {
+    let Ok(player) = player_query.single() else {
+        return;
+    };
+    let Ok(mut model) = model_query.single_mut() else {
+        return;
+    };
+    let target = match sleep.head_position() {
+        Some(head) => {
+            let inv_player_rot = player.rotation.inverse();
+            Transform {
+                translation: inv_player_rot
+                    * (sleeping_origin(head, sleep.facing) - player.translation),
+                rotation: inv_player_rot * sleeping_rotation(sleep.facing),
+                ..default()
+            }
+        }
+        None => Transform::from_rotation(Quat::from_rotation_y(PI)),
+    };
+    model.set_if_neq(target);
+}
@@ -0,0 +1,159 @@
+use super::*;
+use rs_utils::ObjectKind;
+
+const TNT_BLOCK_ID: u16 = 46;
+/// `EntityTNTPrimed` starts with an 80 tick fuse; the server never syncs it.
+const TNT_FUSE_TICKS: f32 = 80.0;
+const TICKS_PER_SEC: f32 = 20.0;
+/// The flash overlay sits just outside the block so it doesn't z-fight with it.
+const TNT_FLASH_SIZE: f32 = 1.002;
+
+/// Falling blocks and primed TNT, drawn with the block mesher's geometry for their block.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteBlockEntity {
+    block: Entity,
+    /// White overlay `RenderTNTPrimed` blinks over the block while the fuse burns.
+    flash: Option<(Entity, Handle<StandardMaterial>)>,
+    spawned_secs: f64,
+}
+
+pub(crate) fn is_block_entity(kind: ObjectKind) -> bool {
+    matches!(kind, ObjectKind::FallingBlock | ObjectKind::PrimedTnt)
+}
+
+/// `EntityFallingBlock` spawn data: the block id in the low 12 bits and its meta above them.
+fn falling_block_state(data: i32) -> (u16, u8) {
+    ((data & 0xFFF) as u16, ((data >> 12) & 0xF) as u8)
+}
+
+type BlockEntitySpawnFilter = (
+    Without<RemoteBlockEntity>,
+    Or<(Added<RemoteEntity>, Added<RemoteObjectData>)>,
+);
+
+/// Builds the block mesh once the block is known: immediately for TNT, after the spawn data
+/// for falling blocks.
+pub fn attach_remote_block_entity_meshes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    chunk_assets: Res<ChunkRenderAssets>,
+    query: Query<(Entity, &RemoteEntity, Option<&RemoteObjectData>), BlockEntitySpawnFilter>,
+) {
+    for (root, remote, data) in &query {
+        let (block_id, meta) = match (remote.kind, data) {
+            (NetEntityKind::Object(ObjectKind::PrimedTnt), _) => (TNT_BLOCK_ID, 0),
+            (NetEntityKind::Object(ObjectKind::FallingBlock), Some(data)) => {
+                falling_block_state(data.0)
+            }
+            _ => continue,
+        };
+        let mut resolver = BlockModelResolver::new(default_model_roots());
+        let Some(quads) = block_item_display_quads(block_id, meta, &mut resolver) else {
+            warn!(
+                entity_id = remote.server_id,
+                block_id, meta, "no block model for falling block"
+            );
+            continue;
+        };
+        let (mesh, _) = build_block_display_mesh(&quads, &chunk_assets.texture_mapping);
+        // Block entities stand on their position; the display mesh is centred on the origin.
+        let block = commands
+            .spawn((
+                Name::new("RemoteBlockEntityMesh"),
+                Mesh3d(meshes.add(mesh)),
+                MeshMaterial3d::<ChunkAtlasMaterial>(chunk_assets.cutout_material.clone()),
+                Transform::from_translation(Vec3::Y * 0.5),
+                GlobalTransform::default(),
+                Visibility::Inherited,
+                InheritedVisibility::default(),
+                ViewVisibility::default(),
+            ))
+            .id();
+        commands.entity(root).add_child(block);
+
+        let flash = (block_id == TNT_BLOCK_ID).then(|| {
+            let material = materials.add(StandardMaterial {
+                base_color: Color::srgba(1.0, 1.0, 1.0, 0.0),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..Default::default()
+            });
+            let overlay = commands
+                .spawn((
+                    Name::new("RemoteTntFlash"),
+                    Mesh3d(meshes.add(Cuboid::from_length(TNT_FLASH_SIZE))),
+                    MeshMaterial3d(material.clone()),
+                    Transform::IDENTITY,
+                    GlobalTransform::default(),
+                    Visibility::Hidden,
+                    InheritedVisibility::default(),
+                    ViewVisibility::default(),
+                ))
+                .id();
+            commands.entity(block).add_child(overlay);
+            (overlay, material)
+        });
+        commands.entity(root).insert(RemoteBlockEntity {
+            block,
+            flash,
+            spawned_secs: time.elapsed_secs_f64(),
+        });
+    }
+}
+
+/// Stops extrapolated falls at the ground and animates the TNT fuse like `RenderTNTPrimed`:
+/// a white blink every 5 ticks that strengthens over time and a swell in the last 10 ticks.
+pub fn update_remote_block_entities(
+    time: Res<Time>,
+    collision_map: Res<WorldCollisionMap>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut roots: Query<(&RemoteBlockEntity, &mut Transform)>,
+    mut parts: Query<(&mut Transform, &mut Visibility), Without<RemoteBlockEntity>>,
+) {
+    let now_secs = time.elapsed_secs_f64();
+    for (block_entity, mut transform) in &mut roots {
+        let cell = transform.translation.floor().as_ivec3();
+        if is_solid(collision_map.block_at(cell.x, cell.y, cell.z)) {
+            transform.translation.y = cell.y as f32 + 1.0;
+        }
+
+        let Some((overlay, material)) = &block_entity.flash else {
+            continue;
+        };
+        let elapsed_ticks = (now_secs - block_entity.spawned_secs) as f32 * TICKS_PER_SEC;
+        let fuse = (TNT_FUSE_TICKS - elapsed_ticks).max(0.0);
+        if let Ok((mut block, _)) = parts.get_mut(block_entity.block) {
+            let swell = (1.0 - (fuse + 1.0) / 10.0).clamp(0.0, 1.0).powi(4);
+            block.scale = Vec3::splat(1.0 + swell * 0.3);
+        }
+        let flashing = (fuse as i32 / 5) % 2 == 0;
+        if let Ok((_, mut visibility)) = parts.get_mut(*overlay) {
+            let target = if flashing {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+            if *visibility != target {
+                *visibility = target;
+            }
+        }
+        if flashing && let Some(material) = materials.get_mut(material) {
+            let alpha = ((1.0 - (fuse + 1.0) / 100.0) * 0.8).clamp(0.0, 1.0);
+            material.base_color = Color::srgba(1.0, 1.0, 1.0, alpha);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_falling_block_data() {
+        // Red sand: sand (12) with meta 1.
+        assert_eq!(falling_block_state(12 | (1 << 12)), (12, 1));
+        assert_eq!(falling_block_state(13), (13, 0));
+    }
+}
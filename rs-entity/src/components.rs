@@ -59,7 +59,7 @@ pub struct RemoteDroppedItemCollect {
 #[derive(SystemParam)]
 pub struct RemoteEntityApplyParams<'w, 's> {
     pub(crate) transform_query: Query<'w, 's, &'static mut Transform>,
-    pub(crate) smoothing_query: Query<'w, 's, &'static mut RemoteMotionSmoothing>,
+    pub(crate) motion_buffer_query: Query<'w, 's, &'static mut RemoteMotionBuffer>,
     pub(crate) item_motion_query: Query<'w, 's, &'static mut RemoteDroppedItemMotion>,
     pub(crate) item_stack_query: Query<'w, 's, &'static RemoteItemStackState>,
     pub(crate) entity_query: Query<'w, 's, (&'static mut RemoteEntity, &'static mut RemoteEntityLook)>,
@@ -67,9 +67,12 @@ pub struct RemoteEntityApplyParams<'w, 's> {
     pub(crate) biped_anim_query: Query<'w, 's, &'static mut RemoteBipedAnimation>,
     pub(crate) name_query: Query<'w, 's, &'static mut RemoteEntityName>,
     pub(crate) visual_query: Query<'w, 's, &'static RemoteVisual>,
-    pub(crate) player_parts_query: Query<'w, 's, &'static RemotePlayerModelParts, With<RemotePlayer>>,
+    pub(crate) rig_parts_query: Query<'w, 's, &'static HumanoidRigParts>,
     pub(crate) held_item_query: Query<'w, 's, &'static RemoteHeldItem>,
     pub(crate) armor_state_query: Query<'w, 's, &'static mut HumanoidArmorState>,
+    pub(crate) mount: ResMut<'w, rs_sim::MountState>,
+    pub(crate) sleep: ResMut<'w, rs_utils::SleepState>,
+    pub(crate) collision: Res<'w, WorldCollisionMap>,
 }
 
 #[derive(Debug)]
@@ -143,6 +146,10 @@ pub struct RemoteEntityRegistry {
     pub player_skin_url_by_uuid: HashMap<rs_protocol::protocol::UUID, String>,
     pub player_skin_model_by_uuid: HashMap<rs_protocol::protocol::UUID, PlayerSkinModel>,
     pub pending_labels: HashMap<i32, String>,
+    /// Passenger server id -> vehicle server id, from `EntityAttach`.
+    pub vehicle_by_passenger: HashMap<i32, i32>,
+    /// Leashed entity server id -> leash holder server id.
+    pub leash_holder_by_entity: HashMap<i32, i32>,
 }
 
 #[derive(Component, Debug, Clone, Copy)]
@@ -198,12 +205,21 @@ pub struct RemoteVisual {
     pub name_y_offset: f32,
 }
 
+/// Explicit bounding box centered on the entity's translation, for entities whose box is not
+/// the usual feet-anchored square column (paintings).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RemoteHitbox {
+    pub half_extents: Vec3,
+}
+
 #[derive(Component, Debug, Clone, Copy)]
 pub struct RemoteHeldItem(pub Entity);
 
 #[derive(Component, Debug, Clone, Copy, Default)]
 pub struct RemotePoseState {
     pub sneaking: bool,
+    /// Seated on a vehicle (set from `EntityAttach`), which switches bipeds to the riding pose.
+    pub riding: bool,
 }
 
 #[derive(Component, Debug, Clone, Copy)]
@@ -297,13 +313,6 @@ pub struct RemoteSheepAppearance {
     pub sheared: bool,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
-pub struct RemoteMotionSmoothing {
-    pub target_translation: Vec3,
-    pub estimated_velocity: Vec3,
-    pub last_server_update_secs: f64,
-}
-
 pub(crate) const DROPPED_ITEM_GRAVITY: f32 = -0.04;
 pub(crate) const DROPPED_ITEM_DRAG_AIR: f32 = 0.98;
 pub(crate) const DROPPED_ITEM_DRAG_GROUND: f32 = 0.58;
@@ -313,13 +322,3 @@ pub(crate) const DROPPED_ITEM_COLLISION_RADIUS: f32 = 0.125;
 pub(crate) const DROPPED_ITEM_COLLISION_HEIGHT_OFFSET: f32 = DROPPED_ITEM_RENDER_Y_OFFSET;
 pub(crate) const DROPPED_ITEM_COLLECT_DURATION: f32 = 0.14;
 pub(crate) const DROPPED_ITEM_FALLBACK_COLLECT_HEIGHT: f32 = 0.6;
-
-impl RemoteMotionSmoothing {
-    pub(crate) fn new(target_translation: Vec3, now_secs: f64) -> Self {
-        Self {
-            target_translation,
-            estimated_velocity: Vec3::ZERO,
-            last_server_update_secs: now_secs,
-        }
-    }
-}
@@ -135,7 +135,11 @@ pub fn animate_remote_player_models(
     for (root_transform, look, pose, parts, skin_model, mut anim) in &mut roots {
         let pos = root_transform.translation;
         let horizontal_delta = Vec2::new(pos.x - anim.previous_pos.x, pos.z - anim.previous_pos.z);
-        let speed = (horizontal_delta.length() / dt).min(8.0);
+        let speed = if pose.riding {
+            0.0
+        } else {
+            (horizontal_delta.length() / dt).min(8.0)
+        };
         let stride = (speed / 4.0).clamp(0.0, 1.0);
         anim.walk_phase += speed * dt * 2.5;
         anim.swing_progress = (anim.swing_progress + dt * 3.6).min(1.0);
@@ -162,6 +166,7 @@ pub fn animate_remote_player_models(
         let leg_x = player_leg_pivot_x();
         let leg_y = player_leg_pivot_y_sneak(sneak_amount);
         let leg_z = player_leg_pivot_z_sneak(sneak_amount);
+        let ride_arm = if pose.riding { RIDING_ARM_PITCH } else { 0.0 };
 
         if let Ok(mut t) = part_transforms.get_mut(parts.head) {
             t.translation = Vec3::new(0.0, player_head_pivot_y_sneak(sneak_amount), 0.0);
@@ -175,19 +180,27 @@ pub fn animate_remote_player_models(
         }
         if let Ok(mut t) = part_transforms.get_mut(parts.arm_left) {
             t.translation = Vec3::new(-arm_x, player_arm_pivot_y(), 0.0);
-            t.rotation = Quat::from_rotation_x(swing + 0.4 * sneak_amount);
+            t.rotation = Quat::from_rotation_x(swing + 0.4 * sneak_amount + ride_arm);
         }
         if let Ok(mut t) = part_transforms.get_mut(parts.arm_right) {
             t.translation = Vec3::new(arm_x, player_arm_pivot_y(), 0.0);
-            t.rotation = Quat::from_rotation_x(-swing - arm_attack + 0.4 * sneak_amount);
+            t.rotation = Quat::from_rotation_x(-swing - arm_attack + 0.4 * sneak_amount + ride_arm);
         }
         if let Ok(mut t) = part_transforms.get_mut(parts.leg_left) {
             t.translation = Vec3::new(-leg_x, leg_y, leg_z);
-            t.rotation = Quat::from_rotation_x(-swing * (1.0 - 0.6 * sneak_amount));
+            t.rotation = if pose.riding {
+                riding_leg_rotation(false)
+            } else {
+                Quat::from_rotation_x(-swing * (1.0 - 0.6 * sneak_amount))
+            };
         }
         if let Ok(mut t) = part_transforms.get_mut(parts.leg_right) {
             t.translation = Vec3::new(leg_x, leg_y, leg_z);
-            t.rotation = Quat::from_rotation_x(swing * (1.0 - 0.6 * sneak_amount));
+            t.rotation = if pose.riding {
+                riding_leg_rotation(true)
+            } else {
+                Quat::from_rotation_x(swing * (1.0 - 0.6 * sneak_amount))
+            };
         }
     }
 }
@@ -272,6 +285,10 @@ pub fn animate_remote_biped_models(
             arm_r_x += 0.4;
             arm_l_x += 0.4;
         }
+        if pose.riding {
+            arm_r_x += RIDING_ARM_PITCH;
+            arm_l_x += RIDING_ARM_PITCH;
+        }
 
         // Pivots (vanilla model pixels; +Y down => bevy Y is negative).
         let (arm_y, leg_y, leg_z, head_y) = if is_sneak {
@@ -302,11 +319,19 @@ pub fn animate_remote_biped_models(
         }
         if let Ok(mut t) = part_transforms.get_mut(parts.leg_right) {
             t.translation = Vec3::new(-1.9 * px, -leg_y * px, leg_z * px);
-            t.rotation = Quat::from_rotation_x(right_leg_x);
+            t.rotation = if pose.riding {
+                riding_leg_rotation(true)
+            } else {
+                Quat::from_rotation_x(right_leg_x)
+            };
         }
         if let Ok(mut t) = part_transforms.get_mut(parts.leg_left) {
             t.translation = Vec3::new(1.9 * px, -leg_y * px, leg_z * px);
-            t.rotation = Quat::from_rotation_x(left_leg_x);
+            t.rotation = if pose.riding {
+                riding_leg_rotation(false)
+            } else {
+                Quat::from_rotation_x(left_leg_x)
+            };
         }
     }
 }
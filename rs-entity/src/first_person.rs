@@ -44,6 +44,8 @@ pub fn first_person_viewmodel_system(
         && !ui_state.paused
         && !ui_state.inventory_open
         && !player_status.dead
+        // Spectators have no hands.
+        && player_status.gamemode != 3
         && render_debug.render_held_items
         && render_debug.render_first_person_arms
         && held.is_some()
@@ -0,0 +1,166 @@
+use super::*;
+use crate::guardians::guardian_rig;
+use crate::model::{
+    BLAZE_FIRST_ROD, BLAZE_HEAD, BLAZE_MODEL, BLAZE_ROD_COUNT, GHAST_FIRST_TENTACLE, GHAST_MODEL,
+    GHAST_TENTACLE_COUNT, GUARDIAN_MODEL, SQUID_FIRST_TENTACLE, SQUID_MODEL, SQUID_TENTACLE_COUNT,
+    part_angles_rotation,
+};
+use crate::objects::entity_model_material;
+use crate::specs::{FloatingModelKind, mob_floating_model_kind};
+use std::f32::consts::{FRAC_PI_4, PI, TAU};
+
+const PX: f32 = 1.0 / 16.0;
+const TICKS_PER_SEC: f32 = 20.0;
+/// `EntitySquid.rotationVelocity` is rolled between 0.1 and 0.2 per tick; this is its middle.
+const SQUID_SWIM_RATE: f32 = 0.15;
+
+/// Ghast, blaze or squid model with the `ageInTicks` clock its idle animation runs on.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteFloatingMob {
+    kind: FloatingModelKind,
+    parts: Vec<Entity>,
+    age_ticks: f32,
+}
+
+/// Spawns the ghast, blaze, squid or guardian model for `mob` under `root`, returning false
+/// for other mobs.
+pub(crate) fn spawn_floating_mob_model(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_textures: &mut EntityTextureCache,
+    root: Entity,
+    mob: MobKind,
+) -> bool {
+    let (Some(kind), Some(texture_path)) = (mob_floating_model_kind(mob), mob_texture_path(mob))
+    else {
+        return false;
+    };
+    let material = entity_model_material(materials, entity_textures, texture_path);
+    let model = match kind {
+        FloatingModelKind::Ghast => &GHAST_MODEL,
+        FloatingModelKind::Blaze => &BLAZE_MODEL,
+        FloatingModelKind::Squid => &SQUID_MODEL,
+        FloatingModelKind::Guardian => &GUARDIAN_MODEL,
+    };
+    let spawned = spawn_model(commands, meshes, material, model, texture_path);
+    commands.entity(root).add_child(spawned.root);
+    commands
+        .entity(root)
+        .insert(RemoteEntityTexture(texture_path));
+    if kind == FloatingModelKind::Guardian {
+        commands.entity(root).insert(guardian_rig(spawned.parts));
+    } else {
+        commands.entity(root).insert(RemoteFloatingMob {
+            kind,
+            parts: spawned.parts,
+            age_ticks: 0.0,
+        });
+    }
+    true
+}
+
+/// Idle animations from `ModelGhast`, `ModelBlaze` and `ModelSquid`: dangling ghast
+/// tentacles, the three rings of blaze rods and the squid's tentacle strokes.
+pub fn animate_remote_floating_mobs(
+    time: Res<Time>,
+    mut mobs: Query<(&RemoteEntityLook, &mut RemoteFloatingMob)>,
+    mut parts: Query<&mut Transform, Without<RemoteFloatingMob>>,
+) {
+    let ticks = time.delta_secs() * TICKS_PER_SEC;
+    for (look, mut mob) in &mut mobs {
+        mob.age_ticks += ticks;
+        let age = mob.age_ticks;
+        match mob.kind {
+            FloatingModelKind::Ghast => {
+                for index in 0..GHAST_TENTACLE_COUNT {
+                    let swing = 0.2 * (age * 0.3 + index as f32).sin() + 0.4;
+                    if let Ok(mut tentacle) = parts.get_mut(mob.parts[GHAST_FIRST_TENTACLE + index])
+                    {
+                        tentacle.rotation = part_angles_rotation([swing, 0.0, 0.0]);
+                    }
+                }
+            }
+            FloatingModelKind::Blaze => {
+                let head_yaw = (look.head_yaw - look.yaw + PI).rem_euclid(TAU) - PI;
+                if let Ok(mut head) = parts.get_mut(mob.parts[BLAZE_HEAD]) {
+                    head.rotation =
+                        part_angles_rotation([look.pitch.clamp(-1.4, 1.4), head_yaw, 0.0]);
+                }
+                for index in 0..BLAZE_ROD_COUNT {
+                    if let Ok(mut rod) = parts.get_mut(mob.parts[BLAZE_FIRST_ROD + index]) {
+                        let [x, y, z] = blaze_rod_pivot(index, age);
+                        rod.translation = Vec3::new(x, -y, z) * PX;
+                    }
+                }
+            }
+            FloatingModelKind::Squid => {
+                let stroke = squid_tentacle_angle(age * SQUID_SWIM_RATE);
+                for index in 0..SQUID_TENTACLE_COUNT {
+                    let part = SQUID_FIRST_TENTACLE + index;
+                    if let Ok(mut tentacle) = parts.get_mut(mob.parts[part]) {
+                        let [_, yaw, _] = SQUID_MODEL.parts[part].rotation;
+                        tentacle.rotation = part_angles_rotation([stroke, yaw, 0.0]);
+                    }
+                }
+            }
+            FloatingModelKind::Guardian => {}
+        }
+    }
+}
+
+/// `ModelBlaze.setRotationAngles`: a ring of four rods per height, the top and bottom ones
+/// turning one way and the middle ring the other, each bobbing on its own phase.
+fn blaze_rod_pivot(index: usize, age: f32) -> [f32; 3] {
+    let ring = index / 4;
+    let (start, turn, radius) = match ring {
+        0 => (0.0, -0.1, 9.0),
+        1 => (FRAC_PI_4, 0.03, 7.0),
+        _ => (0.471_238_94, -0.05, 5.0),
+    };
+    // Vanilla steps one radian between rods rather than a quarter turn.
+    let angle = start + age * PI * turn + (index % 4) as f32;
+    let height = match ring {
+        0 => -2.0 + (((index * 2) as f32 + age) * 0.25).cos(),
+        1 => 2.0 + (((index * 2) as f32 + age) * 0.25).cos(),
+        _ => 11.0 + ((index as f32 * 1.5 + age) * 0.5).cos(),
+    };
+    [angle.cos() * radius, height, angle.sin() * radius]
+}
+
+/// `EntitySquid.tentacleAngle` over one stroke of `squidRotation`: the tentacles sweep out
+/// in the first half turn and hang straight for the rest.
+fn squid_tentacle_angle(rotation: f32) -> f32 {
+    let rotation = rotation.rem_euclid(TAU);
+    if rotation < PI {
+        let progress = rotation / PI;
+        (progress * progress * PI).sin() * PI * 0.25
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blaze_rods_form_three_rings() {
+        for (index, radius) in [(0, 9.0), (5, 7.0), (11, 5.0)] {
+            let [x, _, z] = blaze_rod_pivot(index, 17.0);
+            assert!((x.hypot(z) - radius).abs() < 1e-4);
+        }
+        assert!(blaze_rod_pivot(0, 0.0)[1] < blaze_rod_pivot(4, 0.0)[1]);
+        assert!(blaze_rod_pivot(4, 0.0)[1] < blaze_rod_pivot(8, 0.0)[1]);
+    }
+
+    #[test]
+    fn squid_tentacles_stroke_then_rest() {
+        assert_eq!(squid_tentacle_angle(0.0), 0.0);
+        assert!(squid_tentacle_angle(PI * 0.7) > 0.5);
+        assert_eq!(squid_tentacle_angle(PI * 1.5), 0.0);
+        assert!(
+            (squid_tentacle_angle(TAU + PI * 0.7) - squid_tentacle_angle(PI * 0.7)).abs() < 1e-4
+        );
+    }
+}
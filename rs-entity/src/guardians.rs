@@ -0,0 +1,192 @@
+use super::*;
+use crate::model::{
+    GUARDIAN_BODY, GUARDIAN_FIRST_SPIKE, GUARDIAN_MODEL, GUARDIAN_SPIKE_COUNT, GUARDIAN_TAIL,
+    part_angles_rotation,
+};
+use crate::specs::NAME_ABOVE_HITBOX;
+use rs_utils::MobMetadata;
+use std::f32::consts::{PI, TAU};
+
+const PX: f32 = 1.0 / 16.0;
+const TICKS_PER_SEC: f32 = 20.0;
+/// `EntityGuardian` hitbox height, and the elder guardian's render and hitbox scale.
+const GUARDIAN_HEIGHT: f32 = 0.85;
+const ELDER_SCALE: f32 = 2.35;
+/// Ticks the laser charges before it hits (`EntityGuardian.func_175464_ck`).
+const ATTACK_TICKS: f32 = 80.0;
+const ELDER_ATTACK_TICKS: f32 = 60.0;
+/// Spike pivots sit around this height in `ModelGuardian`.
+const SPIKE_CENTER_Y: f32 = 16.0;
+
+/// Guardian model state that `EntityGuardian` keeps on the client: how far the spikes are
+/// out, the tail's swim phase and speed, and when the current laser target was locked.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteGuardian {
+    parts: Vec<Entity>,
+    age_ticks: f32,
+    spikes_out: f32,
+    tail_phase: f32,
+    tail_speed: f32,
+    laser: Option<(i32, f64)>,
+}
+
+pub(crate) fn guardian_rig(parts: Vec<Entity>) -> RemoteGuardian {
+    RemoteGuardian {
+        parts,
+        age_ticks: 0.0,
+        spikes_out: 1.0,
+        tail_phase: 0.0,
+        tail_speed: 0.125,
+        laser: None,
+    }
+}
+
+type GuardianQuery = (
+    &'static RemoteEntityLook,
+    Ref<'static, RemoteEntityMetadata>,
+    &'static mut RemoteGuardian,
+    &'static mut Transform,
+    &'static mut RemoteVisual,
+);
+
+/// Animates guardians like `ModelGuardian`: the body follows the head look, spikes pull in
+/// while swimming and push out when idle, and the tail beats faster right after it starts
+/// moving. Elder guardians are scaled up from the metadata flag.
+pub fn update_remote_guardians(
+    time: Res<Time>,
+    mut guardians: Query<GuardianQuery>,
+    mut parts: Query<&mut Transform, Without<RemoteGuardian>>,
+) {
+    let ticks = time.delta_secs() * TICKS_PER_SEC;
+    let now_secs = time.elapsed_secs_f64();
+    for (look, metadata, mut guardian, mut transform, mut visual) in &mut guardians {
+        let MobMetadata::Guardian {
+            moving,
+            elder,
+            target,
+        } = metadata.0.mob
+        else {
+            continue;
+        };
+        if metadata.is_changed() {
+            let scale = if elder { ELDER_SCALE } else { 1.0 };
+            transform.scale = Vec3::splat(scale);
+            visual.name_y_offset = GUARDIAN_HEIGHT * scale + NAME_ABOVE_HITBOX;
+            guardian.laser = match guardian.laser {
+                _ if target == 0 => None,
+                Some(laser) if laser.0 == target => Some(laser),
+                _ => Some((target, now_secs)),
+            };
+        }
+
+        guardian.age_ticks += ticks;
+        if moving {
+            if guardian.tail_speed < 0.5 {
+                guardian.tail_speed = 4.0;
+            } else {
+                guardian.tail_speed += (0.5 - guardian.tail_speed) * ease(0.1, ticks);
+            }
+            guardian.spikes_out -= guardian.spikes_out * ease(0.25, ticks);
+        } else {
+            guardian.tail_speed += (0.125 - guardian.tail_speed) * ease(0.2, ticks);
+            guardian.spikes_out += (1.0 - guardian.spikes_out) * ease(0.06, ticks);
+        }
+        guardian.tail_phase = (guardian.tail_phase + guardian.tail_speed * ticks).rem_euclid(TAU);
+
+        let head_yaw = (look.head_yaw - look.yaw + PI).rem_euclid(TAU) - PI;
+        if let Ok(mut body) = parts.get_mut(guardian.parts[GUARDIAN_BODY]) {
+            body.rotation = part_angles_rotation([look.pitch.clamp(-1.4, 1.4), head_yaw, 0.0]);
+        }
+        let retract = (1.0 - guardian.spikes_out) * 0.55;
+        for index in 0..GUARDIAN_SPIKE_COUNT {
+            let part = GUARDIAN_FIRST_SPIKE + index;
+            let [x, y, z] = GUARDIAN_MODEL.parts[part].pivot;
+            let reach = 1.0 + (guardian.age_ticks * 1.5 + index as f32).cos() * 0.01 - retract;
+            if let Ok(mut spike) = parts.get_mut(guardian.parts[part]) {
+                let y = SPIKE_CENTER_Y + (y - SPIKE_CENTER_Y) * reach;
+                spike.translation = Vec3::new(x * reach, -y, z * reach) * PX;
+            }
+        }
+        let beat = guardian.tail_phase.sin() * PI;
+        for (segment, sway) in GUARDIAN_TAIL.into_iter().zip([0.05, 0.1, 0.15]) {
+            if let Ok(mut tail) = parts.get_mut(guardian.parts[segment]) {
+                tail.rotation = part_angles_rotation([0.0, beat * sway, 0.0]);
+            }
+        }
+    }
+}
+
+/// Per-frame share of a per-tick `value += (target - value) * rate` step.
+fn ease(rate: f32, ticks: f32) -> f32 {
+    1.0 - (1.0 - rate).powf(ticks)
+}
+
+/// Draws the guardian laser from its eye to the target in the metadata, shifting from purple
+/// to yellow as the attack charges.
+pub fn draw_guardian_lasers(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    registry: Res<RemoteEntityRegistry>,
+    guardians: Query<(
+        &RemoteGuardian,
+        &RemoteEntityMetadata,
+        &RemoteVisual,
+        &Transform,
+    )>,
+    targets: Query<(&RemoteVisual, &Transform)>,
+    local_player: Query<&Transform, With<Player>>,
+) {
+    for (guardian, metadata, visual, transform) in &guardians {
+        let Some((target_id, since)) = guardian.laser else {
+            continue;
+        };
+        let end = if registry.local_entity_id == Some(target_id) {
+            let Ok(player) = local_player.single() else {
+                continue;
+            };
+            player.translation + Vec3::Y * 0.9
+        } else {
+            let Some((target_visual, target_transform)) = registry
+                .by_server_id
+                .get(&target_id)
+                .and_then(|entity| targets.get(*entity).ok())
+            else {
+                continue;
+            };
+            target_transform.translation - Vec3::Y * target_visual.y_offset
+                + Vec3::Y * (target_visual.name_y_offset * 0.5)
+        };
+        // Guardians look out of their middle (`getEyeHeight` is half the height).
+        let eye = transform.translation - Vec3::Y * visual.y_offset
+            + Vec3::Y * ((visual.name_y_offset - NAME_ABOVE_HITBOX) * 0.5);
+        let elder = matches!(metadata.0.mob, MobMetadata::Guardian { elder: true, .. });
+        let duration = if elder {
+            ELDER_ATTACK_TICKS
+        } else {
+            ATTACK_TICKS
+        };
+        let elapsed = (time.elapsed_secs_f64() - since) as f32 * TICKS_PER_SEC;
+        gizmos.line(eye, end, laser_color((elapsed / duration).clamp(0.0, 1.0)));
+    }
+}
+
+/// Beam color for the attack progress; it warms up quadratically as in `RenderGuardian`.
+fn laser_color(progress: f32) -> Color {
+    let charge = progress * progress;
+    Color::srgb_u8(
+        (64.0 + charge * 191.0) as u8,
+        (32.0 + charge * 192.0) as u8,
+        (128.0 - charge * 64.0) as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn laser_charges_from_purple_to_yellow() {
+        assert_eq!(laser_color(0.0), Color::srgb_u8(64, 32, 128));
+        assert_eq!(laser_color(1.0), Color::srgb_u8(255, 224, 64));
+    }
+}
@@ -0,0 +1,151 @@
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::TextureUsages;
+use bevy_egui::EguiUserTextures;
+
+use super::*;
+use rs_render::HORSE_PREVIEW_RENDER_LAYER;
+use rs_utils::InventoryState;
+
+/// Side length of the square texture the horse window shows.
+const PREVIEW_TEXTURE_SIZE: u32 = 256;
+/// Where the preview camera sits, in the horse's own frame: beside and slightly in front of it.
+const PREVIEW_CAMERA_OFFSET: Vec3 = Vec3::new(3.0, 1.6, 2.0);
+/// Height above the horse's feet the camera looks at, roughly the middle of its body.
+const PREVIEW_FOCUS_HEIGHT: f32 = 0.9;
+
+/// Offscreen render of the horse whose inventory window is open.
+#[derive(Default)]
+pub struct HorsePreview {
+    image: Option<Handle<Image>>,
+    camera: Option<Entity>,
+    horse: Option<Entity>,
+}
+
+/// The remote entities the horse preview looks at and tags onto its render layer.
+#[derive(SystemParam)]
+pub struct HorsePreviewScene<'w, 's> {
+    registry: Res<'w, RemoteEntityRegistry>,
+    transforms: Query<'w, 's, &'static GlobalTransform>,
+    children: Query<'w, 's, &'static Children>,
+    mesh_layers: Query<'w, 's, Option<&'static RenderLayers>, With<Mesh3d>>,
+}
+
+impl HorsePreviewScene<'_, '_> {
+    /// Adds or removes the preview layer on every mesh of `horse`, keeping the layers they
+    /// already render on so the horse stays visible in the world.
+    fn set_preview_layer(&self, commands: &mut Commands, horse: Entity, shown: bool) {
+        for part in std::iter::once(horse).chain(self.children.iter_descendants(horse)) {
+            let Ok(layers) = self.mesh_layers.get(part) else {
+                continue;
+            };
+            let layers = layers.cloned().unwrap_or_default();
+            if layers.intersects(&RenderLayers::layer(HORSE_PREVIEW_RENDER_LAYER)) == shown {
+                continue;
+            }
+            let layers = if shown {
+                layers.with(HORSE_PREVIEW_RENDER_LAYER)
+            } else {
+                layers.without(HORSE_PREVIEW_RENDER_LAYER)
+            };
+            commands.entity(part).try_insert(layers);
+        }
+    }
+}
+
+/// Renders the horse of an open horse window into a texture for `GuiScreenHorseInventory`,
+/// like vanilla drawing the entity itself beside its saddle and armor slots.
+pub fn update_horse_preview(
+    mut commands: Commands,
+    inventory_state: Res<InventoryState>,
+    mut connect_ui: ResMut<ConnectUiState>,
+    mut images: ResMut<Assets<Image>>,
+    mut user_textures: ResMut<EguiUserTextures>,
+    mut preview: Local<HorsePreview>,
+    scene: HorsePreviewScene,
+) {
+    let horse = inventory_state
+        .open_window
+        .as_ref()
+        .and_then(|window| window.entity_id)
+        .and_then(|entity_id| scene.registry.by_server_id.get(&entity_id).copied());
+    if preview.horse != horse {
+        if let Some(previous) = preview.horse.take() {
+            scene.set_preview_layer(&mut commands, previous, false);
+        }
+        if let Some(camera) = preview.camera.take() {
+            commands.entity(camera).despawn();
+        }
+        connect_ui.horse_preview = None;
+    }
+    let Some(horse) = horse else {
+        return;
+    };
+    let Ok(horse_transform) = scene.transforms.get(horse) else {
+        return;
+    };
+    preview.horse = Some(horse);
+    // Tagged every frame so parts attached after the window opened are rendered too.
+    scene.set_preview_layer(&mut commands, horse, true);
+
+    let image = preview
+        .image
+        .get_or_insert_with(|| images.add(preview_target_image()))
+        .clone();
+    connect_ui.horse_preview = Some(user_textures.add_image(image.clone()));
+
+    let (_, rotation, translation) = horse_transform.to_scale_rotation_translation();
+    let camera_transform =
+        Transform::from_translation(translation + rotation * PREVIEW_CAMERA_OFFSET)
+            .looking_at(translation + Vec3::Y * PREVIEW_FOCUS_HEIGHT, Vec3::Y);
+    if let Some(camera) = preview.camera {
+        commands.entity(camera).insert(camera_transform);
+        return;
+    }
+    let camera = commands
+        .spawn((
+            Name::new("HorsePreviewCamera"),
+            Camera3d::default(),
+            Camera {
+                target: RenderTarget::Image(image.into()),
+                // Drawn before the main camera so the UI samples this frame's render.
+                order: -1,
+                clear_color: ClearColorConfig::Custom(Color::NONE),
+                ..default()
+            },
+            Tonemapping::None,
+            RenderLayers::layer(HORSE_PREVIEW_RENDER_LAYER),
+            camera_transform,
+        ))
+        .with_children(|camera| {
+            // The world's sun lives on the main layer, so the preview brings its own light.
+            camera.spawn((
+                DirectionalLight {
+                    illuminance: 8_000.0,
+                    ..default()
+                },
+                RenderLayers::layer(HORSE_PREVIEW_RENDER_LAYER),
+                Transform::from_rotation(Quat::from_euler(EulerRot::YXZ, 0.4, -0.5, 0.0)),
+            ));
+        })
+        .id();
+    preview.camera = Some(camera);
+}
+
+fn preview_target_image() -> Image {
+    let size = Extent3d {
+        width: PREVIEW_TEXTURE_SIZE,
+        height: PREVIEW_TEXTURE_SIZE,
+        depth_or_array_layers: 1,
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
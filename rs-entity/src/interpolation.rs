@@ -0,0 +1,295 @@
+use super::*;
+use rs_utils::ObjectKind;
+
+const TICK_SECS: f64 = 1.0 / 20.0;
+const MAX_SNAPSHOTS: usize = 32;
+/// Absolute moves farther than this snap instead of being interpolated.
+const TELEPORT_SNAP_DISTANCE_SQ: f32 = 64.0;
+/// Living entities are tracked every three ticks; a move after a longer pause starts its
+/// interpolation from a held copy of the old position this long before it.
+const MOVE_RESUME_SECS: f64 = 3.0 * TICK_SECS;
+
+/// How an entity keeps moving between server updates, using vanilla's per-tick constants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotionClass {
+    /// Arrows, thrown items, fireballs, fishing hooks and experience orbs.
+    Projectile {
+        gravity: f32,
+        drag: f32,
+    },
+    Item,
+    Tnt,
+    FallingBlock,
+    /// Players, mobs and vehicles, which steer themselves; velocity is held constant briefly.
+    Mob,
+    /// Paintings, item frames and other entities that never move on their own.
+    Fixed,
+}
+
+impl MotionClass {
+    pub fn for_kind(kind: NetEntityKind) -> Self {
+        let projectile = |gravity, drag| Self::Projectile { gravity, drag };
+        match kind {
+            NetEntityKind::Player | NetEntityKind::Mob(_) => Self::Mob,
+            NetEntityKind::Item => Self::Item,
+            NetEntityKind::ExperienceOrb => projectile(0.03, 0.98),
+            NetEntityKind::Object(object) => match object {
+                ObjectKind::Arrow | ObjectKind::SplashPotion => projectile(0.05, 0.99),
+                ObjectKind::Snowball | ObjectKind::Egg | ObjectKind::EnderPearl => {
+                    projectile(0.03, 0.99)
+                }
+                ObjectKind::ExpBottle => projectile(0.07, 0.99),
+                ObjectKind::FishingHook => projectile(0.04, 0.92),
+                // Fireballs, fireworks and eyes accelerate along their heading instead of falling.
+                ObjectKind::LargeFireball
+                | ObjectKind::SmallFireball
+                | ObjectKind::WitherSkull
+                | ObjectKind::Firework
+                | ObjectKind::EnderEye => projectile(0.0, 1.0),
+                ObjectKind::PrimedTnt => Self::Tnt,
+                ObjectKind::FallingBlock => Self::FallingBlock,
+                ObjectKind::Boat | ObjectKind::Minecart | ObjectKind::Unknown(_) => Self::Mob,
+                ObjectKind::ItemFrame
+                | ObjectKind::LeashKnot
+                | ObjectKind::Painting
+                | ObjectKind::ArmorStand
+                | ObjectKind::EndCrystal => Self::Fixed,
+            },
+        }
+    }
+
+    /// Downward acceleration and drag factor, both per tick.
+    pub fn physics(self) -> (f32, f32) {
+        match self {
+            Self::Projectile { gravity, drag } => (gravity, drag),
+            Self::Item => (-DROPPED_ITEM_GRAVITY, DROPPED_ITEM_DRAG_AIR),
+            Self::Tnt | Self::FallingBlock => (0.04, 0.98),
+            Self::Mob | Self::Fixed => (0.0, 1.0),
+        }
+    }
+
+    /// How far past the newest snapshot the entity is extrapolated. Projectiles, TNT and
+    /// falling blocks are only resynced every 10-20 ticks, so they coast for up to a second.
+    pub fn max_extrapolation_secs(self) -> f32 {
+        match self {
+            Self::Projectile { .. } | Self::Tnt | Self::FallingBlock => 1.0,
+            Self::Item => DROPPED_ITEM_EXTRAPOLATE_MAX,
+            Self::Mob => 0.15,
+            Self::Fixed => 0.0,
+        }
+    }
+
+    /// Advances position and velocity by `ticks` (fractional) of gravity and drag.
+    pub fn step(self, mut pos: Vec3, mut vel: Vec3, ticks: f32) -> (Vec3, Vec3) {
+        let (gravity, drag) = self.physics();
+        let mut remaining = ticks;
+        while remaining > 0.0 {
+            let step = remaining.min(1.0);
+            pos += vel * step;
+            vel *= drag.powf(step);
+            vel.y -= gravity * step;
+            remaining -= step;
+        }
+        (pos, vel)
+    }
+
+    fn simulates_velocity(self) -> bool {
+        !matches!(self, Self::Mob | Self::Fixed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionSnapshot {
+    pub time_secs: f64,
+    pub translation: Vec3,
+}
+
+/// Server positions of a remote entity, rendered `delay` seconds in the past so there is
+/// usually a pair of snapshots to interpolate between; past the newest one the entity is
+/// extrapolated with its velocity, gravity and drag.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteMotionBuffer {
+    snapshots: VecDeque<MotionSnapshot>,
+    /// Blocks per tick at `velocity_secs`.
+    velocity: Vec3,
+    velocity_secs: f64,
+    /// Whether `velocity` came from `EntityVelocity` rather than the last two snapshots.
+    server_velocity: bool,
+    class: MotionClass,
+}
+
+impl RemoteMotionBuffer {
+    pub(crate) fn new(kind: NetEntityKind, translation: Vec3, now_secs: f64) -> Self {
+        Self {
+            snapshots: VecDeque::from([MotionSnapshot {
+                time_secs: now_secs,
+                translation,
+            }]),
+            velocity: Vec3::ZERO,
+            velocity_secs: now_secs,
+            server_velocity: false,
+            class: MotionClass::for_kind(kind),
+        }
+    }
+
+    pub fn class(&self) -> MotionClass {
+        self.class
+    }
+
+    pub fn snapshots(&self) -> impl Iterator<Item = &MotionSnapshot> {
+        self.snapshots.iter()
+    }
+
+    /// Blocks per tick, from `EntityVelocity` or the last two snapshots.
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Latest server position; relative moves are applied on top of it.
+    pub fn latest(&self) -> Vec3 {
+        self.newest().translation
+    }
+
+    /// Relative move packets (`EntityRelativeMove`, `EntityLookAndRelativeMove`).
+    pub(crate) fn push_relative(&mut self, delta: Vec3, now_secs: f64) {
+        let next = self.latest() + delta;
+        self.push(next, now_secs);
+    }
+
+    /// `EntityTeleport`: an absolute position that is interpolated like a move when close, and
+    /// replaces the buffer when the entity jumped far enough that sliding there would look wrong.
+    pub(crate) fn teleport(&mut self, translation: Vec3, now_secs: f64) {
+        if self.latest().distance_squared(translation) > TELEPORT_SNAP_DISTANCE_SQ {
+            self.snapshots.clear();
+            self.snapshots.push_back(MotionSnapshot {
+                time_secs: now_secs,
+                translation,
+            });
+            self.velocity = Vec3::ZERO;
+            self.velocity_secs = now_secs;
+            self.server_velocity = false;
+        } else {
+            self.push(translation, now_secs);
+        }
+    }
+
+    /// `EntityVelocity`, in blocks per tick.
+    pub(crate) fn set_velocity(&mut self, velocity: Vec3, now_secs: f64) {
+        self.velocity = velocity;
+        self.velocity_secs = now_secs;
+        self.server_velocity = true;
+    }
+
+    fn push(&mut self, translation: Vec3, now_secs: f64) {
+        let newest = *self.newest();
+        if now_secs - newest.time_secs > MOVE_RESUME_SECS {
+            self.snapshots.push_back(MotionSnapshot {
+                time_secs: now_secs - MOVE_RESUME_SECS,
+                ..newest
+            });
+        }
+        let previous = *self.newest();
+        let ticks = ((now_secs - previous.time_secs) / TICK_SECS).max(1.0) as f32;
+        if self.server_velocity && self.class.simulates_velocity() {
+            // Keep simulating the last server velocity the way the vanilla client does.
+            let elapsed = ((now_secs - self.velocity_secs) / TICK_SECS) as f32;
+            self.velocity = self.class.step(Vec3::ZERO, self.velocity, elapsed).1;
+        } else {
+            self.velocity = (translation - previous.translation) / ticks;
+        }
+        self.velocity_secs = now_secs;
+        self.snapshots.push_back(MotionSnapshot {
+            time_secs: now_secs,
+            translation,
+        });
+        while self.snapshots.len() > MAX_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Drops snapshots the render time has passed, keeping the one it interpolates from.
+    pub(crate) fn prune(&mut self, now_secs: f64, delay_secs: f32) {
+        let render_secs = now_secs - f64::from(delay_secs);
+        while self.snapshots.len() > 1 && self.snapshots[1].time_secs <= render_secs {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Position to render at `now_secs`.
+    pub fn sample(&self, now_secs: f64, delay_secs: f32) -> Vec3 {
+        let render_secs = now_secs - f64::from(delay_secs);
+        let newest = self.newest();
+        if render_secs >= newest.time_secs {
+            return self.extrapolate((render_secs - newest.time_secs) as f32);
+        }
+        let next = self
+            .snapshots
+            .partition_point(|snapshot| snapshot.time_secs <= render_secs);
+        if next == 0 {
+            return self.snapshots[0].translation;
+        }
+        let (from, to) = (self.snapshots[next - 1], self.snapshots[next]);
+        let t = (render_secs - from.time_secs) / (to.time_secs - from.time_secs);
+        from.translation
+            .lerp(to.translation, t.clamp(0.0, 1.0) as f32)
+    }
+
+    /// Positions the entity would pass through while extrapolated past the newest snapshot.
+    pub fn extrapolated_path(&self, points: usize) -> Vec<Vec3> {
+        let max_secs = self.class.max_extrapolation_secs();
+        (0..=points)
+            .map(|i| self.extrapolate(max_secs * i as f32 / points.max(1) as f32))
+            .collect()
+    }
+
+    fn extrapolate(&self, elapsed_secs: f32) -> Vec3 {
+        let newest = self.newest();
+        let elapsed = elapsed_secs.min(self.class.max_extrapolation_secs());
+        // The velocity may have been set after the newest snapshot; never run it backwards.
+        let since_velocity = (self.velocity_secs - newest.time_secs).max(0.0) as f32;
+        let ticks = (elapsed - since_velocity).max(0.0) / TICK_SECS as f32;
+        self.class.step(newest.translation, self.velocity, ticks).0
+    }
+
+    fn newest(&self) -> &MotionSnapshot {
+        self.snapshots
+            .back()
+            .expect("motion buffer always keeps a snapshot")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARROW: NetEntityKind = NetEntityKind::Object(ObjectKind::Arrow);
+
+    #[test]
+    fn interpolates_between_snapshots_behind_the_delay() {
+        let mut buffer = RemoteMotionBuffer::new(NetEntityKind::Player, Vec3::ZERO, 0.0);
+        buffer.push_relative(Vec3::X, 0.05);
+        buffer.push_relative(Vec3::X, 0.10);
+        let pos = buffer.sample(0.175, 0.1);
+        assert!((pos.x - 1.5).abs() < 1e-4, "{pos:?}");
+    }
+
+    #[test]
+    fn far_teleport_snaps_and_near_teleport_interpolates() {
+        let mut buffer = RemoteMotionBuffer::new(NetEntityKind::Player, Vec3::ZERO, 0.0);
+        buffer.teleport(Vec3::new(2.0, 0.0, 0.0), 0.05);
+        assert_eq!(buffer.sample(0.05, 0.1), Vec3::ZERO);
+        buffer.teleport(Vec3::new(50.0, 0.0, 0.0), 0.10);
+        assert_eq!(buffer.sample(0.10, 0.1), Vec3::new(50.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn projectiles_fall_while_extrapolated() {
+        let mut buffer = RemoteMotionBuffer::new(ARROW, Vec3::ZERO, 0.0);
+        buffer.set_velocity(Vec3::new(1.0, 0.0, 0.0), 0.0);
+        let pos = buffer.sample(0.5, 0.0);
+        assert!(pos.x > 9.0 && pos.x < 10.0, "{pos:?}");
+        assert!(pos.y < -1.0, "{pos:?}");
+        let capped = buffer.sample(10.0, 0.0);
+        assert_eq!(capped, buffer.sample(1.0, 0.0));
+    }
+}
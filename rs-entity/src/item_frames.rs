@@ -0,0 +1,340 @@
+use super::*;
+use crate::item_textures::ItemSpriteMesh;
+use crate::model::EntityTexturePath;
+use crate::paintings::QuadBuilder;
+use crate::remote_apply::block_display_quads_for_stack;
+use rs_utils::{MobMetadata, item_frame_half_extents};
+
+const WOOD_TEXTURE_PATH: &str = "blocks/planks_birch.png";
+const BACKING_TEXTURE_PATH: &str = "blocks/itemframe_background.png";
+/// Stand-in for map contents, which we don't receive yet.
+const MAP_TEXTURE_PATH: &str = "map/map_background.png";
+const BLOCK_TEXTURE_SIZE: f32 = 16.0;
+const MAP_TEXTURE_SIZE: f32 = 128.0;
+const FILLED_MAP_ITEM_ID: i32 = 358;
+const PX: f32 = 1.0 / 16.0;
+/// The framed item sits on the frame's front face, nudged out so maps don't z-fight with it.
+const CONTENT_Z: f32 = -0.5 * PX - 0.002;
+/// `RenderItemFrame` halves the item; blocks are halved again by their fixed display scale.
+const SPRITE_SCALE: f32 = 0.5;
+const BLOCK_SCALE: f32 = 0.25;
+
+/// An item frame's meshes and the item currently shown in it. The frame faces local -Z, which
+/// is where the spawn yaw turns the entity's front.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteItemFrame {
+    wood: Entity,
+    backing: Entity,
+    content: Option<Entity>,
+    shown: Option<InventoryItemStack>,
+    /// Whether the frame uses the full-block map layout.
+    map: bool,
+}
+
+/// Spawns the empty frame; `update_remote_item_frames` fills it from metadata.
+pub(crate) fn spawn_item_frame(commands: &mut Commands, meshes: &mut Assets<Mesh>, root: Entity) {
+    let (wood_mesh, backing_mesh) = frame_meshes(false);
+    let mut spawn_part = |name: &'static str, mesh: Mesh, texture_path: &'static str| {
+        let part = commands
+            .spawn((
+                Name::new(name),
+                Mesh3d(meshes.add(mesh)),
+                MeshMaterial3d::<StandardMaterial>::default(),
+                Transform::IDENTITY,
+                GlobalTransform::default(),
+                Visibility::Inherited,
+                InheritedVisibility::default(),
+                ViewVisibility::default(),
+                EntityTexturePath(texture_path),
+            ))
+            .id();
+        commands.entity(root).add_child(part);
+        part
+    };
+    let wood = spawn_part("ItemFrameWood", wood_mesh, WOOD_TEXTURE_PATH);
+    let backing = spawn_part("ItemFrameBacking", backing_mesh, BACKING_TEXTURE_PATH);
+    commands.entity(root).insert(RemoteItemFrame {
+        wood,
+        backing,
+        content: None,
+        shown: None,
+        map: false,
+    });
+}
+
+#[derive(SystemParam)]
+pub struct ItemFrameContentAssets<'w> {
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+    item_textures: ResMut<'w, ItemTextureCache>,
+    sprite_mesh: Res<'w, ItemSpriteMesh>,
+    chunk_assets: Res<'w, ChunkRenderAssets>,
+}
+
+type ItemFrameQuery = (
+    Entity,
+    &'static RemoteEntity,
+    &'static mut RemoteItemFrame,
+    Ref<'static, RemoteEntityMetadata>,
+    Option<Ref<'static, RemoteObjectData>>,
+    &'static mut RemoteEntityLook,
+    &'static mut Transform,
+);
+
+/// Turns frames to the facing from their spawn data, gives them a hitbox for attacking and
+/// rotating, and shows the framed item with its rotation.
+pub fn update_remote_item_frames(
+    mut commands: Commands,
+    mut assets: ItemFrameContentAssets,
+    mut frames: Query<ItemFrameQuery>,
+    mut frame_meshes_query: Query<&mut Mesh3d>,
+) {
+    for (root, remote, mut frame, metadata, data, mut look, mut transform) in &mut frames {
+        if frame.is_added() || data.as_ref().is_some_and(|data| data.is_changed()) {
+            let facing = data.map_or(0, |data| (data.0 & 3) as u8);
+            look.yaw = std::f32::consts::PI - f32::from(facing) * std::f32::consts::FRAC_PI_2;
+            look.head_yaw = look.yaw;
+            transform.rotation = entity_root_rotation(remote.kind, look.yaw);
+            commands.entity(root).insert(RemoteHitbox {
+                half_extents: item_frame_half_extents(facing),
+            });
+        }
+        if !metadata.is_changed() {
+            continue;
+        }
+
+        let item = metadata.0.framed_item.as_ref();
+        let map = item.is_some_and(|stack| stack.item_id == FILLED_MAP_ITEM_ID);
+        if map != frame.map {
+            let (wood_mesh, backing_mesh) = frame_meshes(map);
+            if let Ok(mut mesh) = frame_meshes_query.get_mut(frame.wood) {
+                mesh.0 = assets.meshes.add(wood_mesh);
+            }
+            if let Ok(mut mesh) = frame_meshes_query.get_mut(frame.backing) {
+                mesh.0 = assets.meshes.add(backing_mesh);
+            }
+            frame.map = map;
+        }
+        if item != frame.shown.as_ref() {
+            if let Some(content) = frame.content.take() {
+                commands.entity(content).despawn();
+            }
+            frame.content = item.map(|stack| {
+                let content = spawn_frame_content(&mut commands, &mut assets, stack);
+                commands.entity(root).add_child(content);
+                content
+            });
+            frame.shown = item.cloned();
+        }
+        if let (Some(content), Some(stack)) = (frame.content, item) {
+            let rotation = match metadata.0.mob {
+                MobMetadata::ItemFrame { rotation } => rotation,
+                _ => 0,
+            };
+            commands
+                .entity(content)
+                .insert(content_transform(stack, rotation));
+        }
+    }
+}
+
+/// Maps fill the frame, blocks show as small 3D blocks and other items as flat sprites.
+fn spawn_frame_content(
+    commands: &mut Commands,
+    assets: &mut ItemFrameContentAssets,
+    stack: &InventoryItemStack,
+) -> Entity {
+    let visibility = (
+        GlobalTransform::default(),
+        Visibility::Inherited,
+        InheritedVisibility::default(),
+        ViewVisibility::default(),
+    );
+    if stack.item_id == FILLED_MAP_ITEM_ID {
+        return commands
+            .spawn((
+                Name::new("ItemFrameMap"),
+                Mesh3d(assets.meshes.add(map_mesh())),
+                MeshMaterial3d::<StandardMaterial>::default(),
+                visibility,
+                EntityTexturePath(MAP_TEXTURE_PATH),
+            ))
+            .id();
+    }
+    if let Some(quads) = block_display_quads_for_stack(stack) {
+        let (mesh, _) = build_block_display_mesh(&quads, &assets.chunk_assets.texture_mapping);
+        return commands
+            .spawn((
+                Name::new("ItemFrameBlock"),
+                Mesh3d(assets.meshes.add(mesh)),
+                MeshMaterial3d::<ChunkAtlasMaterial>(assets.chunk_assets.cutout_material.clone()),
+                visibility,
+            ))
+            .id();
+    }
+    assets.item_textures.request_stack(stack);
+    let material = assets
+        .item_textures
+        .material_for_stack(stack)
+        .unwrap_or_else(|| {
+            assets.materials.add(StandardMaterial {
+                base_color: Color::WHITE,
+                alpha_mode: AlphaMode::Mask(0.5),
+                cull_mode: None,
+                unlit: true,
+                perceptual_roughness: 1.0,
+                metallic: 0.0,
+                ..Default::default()
+            })
+        });
+    commands
+        .spawn((
+            Name::new("ItemFrameItem"),
+            Mesh3d(assets.sprite_mesh.0.clone()),
+            MeshMaterial3d(material),
+            visibility,
+            ItemSpriteStack(stack.clone()),
+        ))
+        .id()
+}
+
+/// `RenderItemFrame` turns items in eighths and maps in quarters, clockwise as seen from the
+/// front.
+fn content_transform(stack: &InventoryItemStack, rotation: u8) -> Transform {
+    let translation = Vec3::Z * CONTENT_Z;
+    if stack.item_id == FILLED_MAP_ITEM_ID {
+        let turn = f32::from(rotation % 4) * std::f32::consts::FRAC_PI_2;
+        return Transform::from_translation(translation).with_rotation(Quat::from_rotation_z(turn));
+    }
+    let turn = Quat::from_rotation_z(f32::from(rotation) * std::f32::consts::FRAC_PI_4);
+    if block_display_quads_for_stack(stack).is_some() {
+        return Transform {
+            translation,
+            rotation: turn,
+            scale: Vec3::splat(BLOCK_SCALE),
+        };
+    }
+    // The sprite quad faces +Z; flip it to face out of the frame.
+    Transform {
+        translation,
+        rotation: turn * Quat::from_rotation_y(std::f32::consts::PI),
+        scale: Vec3::splat(SPRITE_SCALE),
+    }
+}
+
+/// Wood border and backing as `item_frame.json` lays them out (or `item_frame_map.json`, which
+/// spans the whole block), in pixels around the frame center with the front at -Z.
+fn frame_meshes(map: bool) -> (Mesh, Mesh) {
+    let outer = if map { 8.0 } else { 6.0 };
+    let inner = outer - 1.0;
+    let mut wood = QuadBuilder::new(BLOCK_TEXTURE_SIZE);
+    for (min, max) in [
+        ([-outer, -outer], [outer, -inner]),
+        ([-outer, inner], [outer, outer]),
+        ([-outer, -inner], [-inner, inner]),
+        ([inner, -inner], [outer, inner]),
+    ] {
+        cuboid(
+            &mut wood,
+            Vec3::new(min[0], min[1], -0.5),
+            Vec3::new(max[0], max[1], 0.5),
+        );
+    }
+
+    // A half-pixel panel against the wall, textured on its front and back only.
+    let mut backing = QuadBuilder::new(BLOCK_TEXTURE_SIZE);
+    let (min, max) = (Vec3::new(-inner, -inner, 0.0), Vec3::new(inner, inner, 0.5));
+    front_face(&mut backing, min, max);
+    back_face(&mut backing, min, max);
+    (wood.build(), backing.build())
+}
+
+/// Box from `min` to `max` in pixels, each face textured with the part of the 16px texture
+/// it covers when projected onto it.
+fn cuboid(builder: &mut QuadBuilder, min: Vec3, max: Vec3) {
+    let p = |x: f32, y: f32, z: f32| [x * PX, y * PX, z * PX];
+    front_face(builder, min, max);
+    back_face(builder, min, max);
+    builder.quad(
+        [
+            p(max.x, min.y, max.z),
+            p(max.x, min.y, min.z),
+            p(max.x, max.y, min.z),
+            p(max.x, max.y, max.z),
+        ],
+        [1.0, 0.0, 0.0],
+        [8.0 - max.z, 8.0 - min.y, 8.0 - min.z, 8.0 - max.y],
+    );
+    builder.quad(
+        [
+            p(min.x, min.y, min.z),
+            p(min.x, min.y, max.z),
+            p(min.x, max.y, max.z),
+            p(min.x, max.y, min.z),
+        ],
+        [-1.0, 0.0, 0.0],
+        [8.0 + min.z, 8.0 - min.y, 8.0 + max.z, 8.0 - max.y],
+    );
+    builder.quad(
+        [
+            p(min.x, max.y, max.z),
+            p(max.x, max.y, max.z),
+            p(max.x, max.y, min.z),
+            p(min.x, max.y, min.z),
+        ],
+        [0.0, 1.0, 0.0],
+        [8.0 + min.x, 8.0 + max.z, 8.0 + max.x, 8.0 + min.z],
+    );
+    builder.quad(
+        [
+            p(min.x, min.y, min.z),
+            p(max.x, min.y, min.z),
+            p(max.x, min.y, max.z),
+            p(min.x, min.y, max.z),
+        ],
+        [0.0, -1.0, 0.0],
+        [8.0 + min.x, 8.0 + min.z, 8.0 + max.x, 8.0 + max.z],
+    );
+}
+
+fn front_face(builder: &mut QuadBuilder, min: Vec3, max: Vec3) {
+    builder.quad(
+        [
+            [max.x * PX, min.y * PX, min.z * PX],
+            [min.x * PX, min.y * PX, min.z * PX],
+            [min.x * PX, max.y * PX, min.z * PX],
+            [max.x * PX, max.y * PX, min.z * PX],
+        ],
+        [0.0, 0.0, -1.0],
+        [8.0 - max.x, 8.0 - min.y, 8.0 - min.x, 8.0 - max.y],
+    );
+}
+
+fn back_face(builder: &mut QuadBuilder, min: Vec3, max: Vec3) {
+    builder.quad(
+        [
+            [min.x * PX, min.y * PX, max.z * PX],
+            [max.x * PX, min.y * PX, max.z * PX],
+            [max.x * PX, max.y * PX, max.z * PX],
+            [min.x * PX, max.y * PX, max.z * PX],
+        ],
+        [0.0, 0.0, 1.0],
+        [8.0 + min.x, 8.0 - min.y, 8.0 + max.x, 8.0 - max.y],
+    );
+}
+
+/// One block square facing -Z, covering the whole frame like a vanilla map.
+fn map_mesh() -> Mesh {
+    let mut builder = QuadBuilder::new(MAP_TEXTURE_SIZE);
+    builder.quad(
+        [
+            [0.5, -0.5, 0.0],
+            [-0.5, -0.5, 0.0],
+            [-0.5, 0.5, 0.0],
+            [0.5, 0.5, 0.0],
+        ],
+        [0.0, 0.0, -1.0],
+        [0.0, MAP_TEXTURE_SIZE, MAP_TEXTURE_SIZE, 0.0],
+    );
+    builder.build()
+}
@@ -7,7 +7,9 @@ use bevy::prelude::*;
 use bevy::render::render_asset::RenderAssetUsages;
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use crossbeam::channel::{Receiver, Sender, unbounded};
-use rs_utils::{InventoryItemStack, item_texture_candidates, texturepack_textures_root};
+use rs_utils::{
+    InventoryItemStack, item_strip_frame, item_texture_candidates, texturepack_textures_root,
+};
 use tracing::{debug, warn};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -270,8 +272,14 @@ fn item_texture_worker(request_rx: Receiver<ItemTexKey>, result_tx: Sender<ItemT
                 .and_then(|bytes| image::load_from_memory(&bytes).ok())
             {
                 Some(img) => {
-                    let rgba = img.to_rgba8();
-                    let (w, h) = rgba.dimensions();
+                    let mut rgba = img.to_rgba8();
+                    let (w, mut h) = rgba.dimensions();
+                    // Animated strips (compass, clock, ...): keep one square frame.
+                    if h > w && h % w == 0 {
+                        let frame = item_strip_frame(key.item_id, key.damage, h / w);
+                        rgba = image::imageops::crop(&mut rgba, 0, frame * w, w, w).to_image();
+                        h = w;
+                    }
                     let _ = result_tx.send(ItemTexResult {
                         key,
                         rgba: rgba.into_raw(),
@@ -323,7 +331,7 @@ fn missing_texture_rgba() -> (u32, u32, Vec<u8>) {
     (w, h, out)
 }
 
-fn build_item_quad_mesh() -> Mesh {
+pub(crate) fn build_item_quad_mesh() -> Mesh {
     use bevy::render::mesh::Indices;
     use bevy::render::mesh::PrimitiveTopology;
 
@@ -2,18 +2,36 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::thread;
 
 mod armor;
+mod armor_stands;
+mod arthropods;
+mod beds;
+mod block_entities;
 mod components;
 mod entity_anim_spawn;
 mod first_person;
+mod floating_mobs;
+mod guardians;
+mod horse_preview;
+mod interpolation;
+mod item_frames;
 pub mod item_textures;
+mod lightning;
 mod local_player;
+mod metadata;
 mod motion;
 pub mod model;
+mod objects;
+mod paintings;
 mod player_mesh;
+mod projectiles;
 mod registry;
 mod remote_apply;
+mod remote_spawn;
 mod skins;
+mod slimes;
 mod specs;
+mod spectate;
+mod vehicles;
 
 use bevy::color::LinearRgba;
 use bevy::ecs::system::SystemParam;
@@ -34,9 +52,9 @@ use rs_render::{
 };
 use rs_sim::collision::{WorldCollisionMap, is_solid};
 use rs_utils::{
-    AppState, ApplicationState, InventoryItemStack, MobKind, NetEntityAnimation, NetEntityKind,
-    NetEntityMessage, PlayerSkinModel, UiState, block_registry_key, item_texture_candidates,
-    texturepack_textures_root,
+    AppState, ApplicationState, EntityMetadata, InventoryItemStack, ItemAnimationState, MobKind,
+    NetEntityAnimation, NetEntityKind, NetEntityMessage, PlayerSkinModel, UiState,
+    block_registry_key, item_texture_candidates, texturepack_textures_root,
 };
 use tracing::{debug, info, warn};
 
@@ -53,11 +71,14 @@ use crate::entity_anim_spawn::*;
 use crate::item_textures::{ItemSpriteMesh, ItemTextureCache};
 use crate::model::{
     BIPED_BODY, BIPED_HEAD, BIPED_LEFT_ARM, BIPED_LEFT_LEG, BIPED_MODEL_TEX32, BIPED_MODEL_TEX64,
-    BIPED_RIGHT_ARM, BIPED_RIGHT_LEG, COW_MODEL_TEX32, CREEPER_MODEL_TEX64, EntityTextureCache,
-    EntityTexturePath, PIG_MODEL_TEX32, QUADRUPED_BODY, QUADRUPED_HEAD, QUADRUPED_LEG_BACK_LEFT,
-    QUADRUPED_LEG_BACK_RIGHT, QUADRUPED_LEG_FRONT_LEFT, QUADRUPED_LEG_FRONT_RIGHT,
-    SHEEP_MODEL_TEX32, SHEEP_WOOL_MODEL_TEX32, part_mesh, spawn_model,
+    BIPED_RIGHT_ARM, BIPED_RIGHT_LEG, COW_MODEL_TEX32, CREEPER_MODEL_TEX64, EntityLayerTexturePath,
+    EntityTextureCache, EntityTexturePath, PIG_MODEL_TEX32, QUADRUPED_BODY, QUADRUPED_HEAD,
+    QUADRUPED_LEG_BACK_LEFT, QUADRUPED_LEG_BACK_RIGHT, QUADRUPED_LEG_FRONT_LEFT,
+    QUADRUPED_LEG_FRONT_RIGHT, SHEEP_MODEL_TEX32, SHEEP_WOOL_MODEL_TEX32, part_mesh, spawn_model,
 };
+pub use crate::objects::RemoteObjectData;
+use crate::objects::{object_uses_model, spawn_object_visual};
+use crate::paintings::spawn_painting;
 use crate::player_mesh::*;
 use crate::specs::{
     BipedModelKind, DROPPED_ITEM_RENDER_SCALE, DROPPED_ITEM_RENDER_Y_OFFSET, QuadrupedModelKind,
@@ -71,12 +92,38 @@ use rs_sim::{CameraPerspectiveMode, CameraPerspectiveState, FreecamState, LocalA
 use rs_ui::ConnectUiState;
 
 pub(crate) use components::{entity_root_translation, player_shadow_emissive_strength};
+pub use armor_stands::{RemoteArmorStand, update_remote_armor_stands};
+pub use arthropods::{
+    RemoteArthropodAnimation, RemoteArthropodModel, animate_remote_arthropod_models,
+};
+pub use block_entities::{
+    RemoteBlockEntity, attach_remote_block_entity_meshes, update_remote_block_entities,
+};
+pub use beds::{RemoteSleeping, pose_sleeping_local_player_model, pose_sleeping_remote_players};
+use beds::{apply_leave_bed, apply_use_bed};
 pub use components::*;
 pub use first_person::*;
+pub use floating_mobs::{RemoteFloatingMob, animate_remote_floating_mobs};
+pub use guardians::{RemoteGuardian, draw_guardian_lasers, update_remote_guardians};
+pub use horse_preview::{HorsePreview, HorsePreviewScene, update_horse_preview};
+pub use interpolation::{MotionClass, MotionSnapshot, RemoteMotionBuffer};
+pub use item_frames::{RemoteItemFrame, update_remote_item_frames};
+pub use lightning::{LightningBoltVisual, sync_lightning_bolts};
 pub use local_player::*;
+pub use metadata::{
+    RemoteEntityFire, RemoteEntityMetadata, RemoteEntityTexture, sync_remote_entity_metadata,
+};
 pub use motion::*;
+pub use projectiles::{
+    ProjectileVisual, RemoteProjectile, draw_fishing_lines, draw_projectile_trajectories,
+    projectile_visual, update_remote_projectiles,
+};
 pub use registry::*;
 pub use remote_apply::*;
 pub use skins::*;
+pub use slimes::{RemoteSlime, update_remote_slimes};
+pub use spectate::update_spectate_camera_target;
+pub use vehicles::{draw_entity_leashes, position_remote_passengers};
+use vehicles::{RIDING_ARM_PITCH, apply_entity_attach, forget_entity_links, riding_leg_rotation};
 
 const SHEEP_WOOL_TEXTURE_PATH: &str = "entity/sheep/sheep_fur.png";
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use bevy::pbr::NotShadowCaster;
+
+use super::*;
+use rs_utils::{BoltSegment, LightningState, lightning_bolt_segments};
+
+/// `RenderLightningBolt` draws every layer in this color with additive blending.
+const BOLT_COLOR: Color = Color::srgba(0.45, 0.45, 0.5, 0.3);
+
+#[derive(Component, Debug)]
+pub struct LightningBoltVisual {
+    entity_id: i32,
+    shape_seed: i64,
+}
+
+/// Mirrors [`LightningState`] bolts as meshes, rebuilding a bolt whenever it re-flashes with a
+/// new shape.
+pub fn sync_lightning_bolts(
+    mut commands: Commands,
+    lightning: Res<LightningState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material: Local<Option<Handle<StandardMaterial>>>,
+    mut visuals: Query<(Entity, &mut LightningBoltVisual, &mut Mesh3d)>,
+) {
+    if !lightning.is_changed() {
+        return;
+    }
+    let material = material
+        .get_or_insert_with(|| {
+            materials.add(StandardMaterial {
+                base_color: Color::WHITE,
+                unlit: true,
+                alpha_mode: AlphaMode::Add,
+                cull_mode: None,
+                ..Default::default()
+            })
+        })
+        .clone();
+
+    let mut shown = HashSet::new();
+    for (entity, mut visual, mut mesh) in &mut visuals {
+        let Some(bolt) = lightning
+            .bolts
+            .iter()
+            .find(|bolt| bolt.entity_id == visual.entity_id)
+        else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if bolt.shape_seed != visual.shape_seed {
+            visual.shape_seed = bolt.shape_seed;
+            mesh.0 = meshes.add(bolt_mesh(&lightning_bolt_segments(bolt.shape_seed)));
+        }
+        shown.insert(bolt.entity_id);
+    }
+
+    for bolt in lightning
+        .bolts
+        .iter()
+        .filter(|bolt| !shown.contains(&bolt.entity_id))
+    {
+        commands.spawn((
+            Name::new(format!("LightningBolt[{}]", bolt.entity_id)),
+            Mesh3d(meshes.add(bolt_mesh(&lightning_bolt_segments(bolt.shape_seed)))),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(bolt.position),
+            GlobalTransform::default(),
+            Visibility::Visible,
+            InheritedVisibility::default(),
+            ViewVisibility::default(),
+            NotShadowCaster,
+            LightningBoltVisual {
+                entity_id: bolt.entity_id,
+                shape_seed: bolt.shape_seed,
+            },
+        ));
+    }
+}
+
+/// Each segment is an open square tube; the four sides walk the same corner order as the
+/// vanilla triangle strip.
+fn bolt_mesh(segments: &[BoltSegment]) -> Mesh {
+    const CORNERS: [(f32, f32); 5] = [
+        (-1.0, -1.0),
+        (1.0, -1.0),
+        (1.0, 1.0),
+        (-1.0, 1.0),
+        (-1.0, -1.0),
+    ];
+    let color = BOLT_COLOR.to_linear().to_f32_array();
+    let mut positions = Vec::with_capacity(segments.len() * 16);
+    let mut normals = Vec::with_capacity(segments.len() * 16);
+    let mut indices = Vec::with_capacity(segments.len() * 24);
+    for segment in segments {
+        let corner = |center: Vec3, half_width: f32, (cx, cz): (f32, f32)| {
+            [
+                center.x + cx * half_width,
+                center.y,
+                center.z + cz * half_width,
+            ]
+        };
+        for side in CORNERS.windows(2) {
+            let (a, b) = (side[0], side[1]);
+            let normal = Vec3::new(a.0 + b.0, 0.0, a.1 + b.1).normalize_or_zero();
+            let base = positions.len() as u32;
+            positions.extend([
+                corner(segment.bottom, segment.bottom_half_width, a),
+                corner(segment.bottom, segment.bottom_half_width, b),
+                corner(segment.top, segment.top_half_width, b),
+                corner(segment.top, segment.top_half_width, a),
+            ]);
+            normals.extend([normal.to_array(); 4]);
+            indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+    }
+    let colors = vec![color; positions.len()];
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
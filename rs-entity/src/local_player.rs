@@ -339,6 +339,7 @@ pub fn animate_local_player_model_system(
     input: Res<rs_sim::CurrentInput>,
     sim_state: Res<rs_sim::SimState>,
     swing_state: Res<rs_sim::LocalArmSwing>,
+    mount: Res<rs_sim::MountState>,
     render_debug: Res<RenderDebugSettings>,
     mut roots: Query<
         (
@@ -369,7 +370,10 @@ pub fn animate_local_player_model_system(
     anim.walk_phase += speed * dt * 2.5;
 
     let swing = anim.walk_phase.sin() * 0.7 * stride;
-    let sneak_amount = if input.0.sneak { 1.0 } else { 0.0 };
+    let riding = mount.is_mounted();
+    // Sneak dismounts while riding, so it never shows as a crouch there.
+    let sneak_amount = if input.0.sneak && !riding { 1.0 } else { 0.0 };
+    let ride_arm = if riding { RIDING_ARM_PITCH } else { 0.0 };
     let arm_x = player_arm_pivot_x(skin_model.0);
     let leg_x = player_leg_pivot_x();
     let leg_y = player_leg_pivot_y_sneak(sneak_amount);
@@ -390,18 +394,26 @@ pub fn animate_local_player_model_system(
     }
     if let Ok(mut t) = part_transforms.get_mut(parts.arm_left) {
         t.translation = Vec3::new(-arm_x, player_arm_pivot_y(), 0.0);
-        t.rotation = Quat::from_rotation_x(swing + 0.4 * sneak_amount);
+        t.rotation = Quat::from_rotation_x(swing + 0.4 * sneak_amount + ride_arm);
     }
     if let Ok(mut t) = part_transforms.get_mut(parts.arm_right) {
         t.translation = Vec3::new(arm_x, player_arm_pivot_y(), 0.0);
-        t.rotation = Quat::from_rotation_x(-swing - arm_attack + 0.4 * sneak_amount);
+        t.rotation = Quat::from_rotation_x(-swing - arm_attack + 0.4 * sneak_amount + ride_arm);
     }
     if let Ok(mut t) = part_transforms.get_mut(parts.leg_left) {
         t.translation = Vec3::new(-leg_x, leg_y, leg_z);
-        t.rotation = Quat::from_rotation_x(-swing * (1.0 - 0.6 * sneak_amount));
+        t.rotation = if riding {
+            riding_leg_rotation(false)
+        } else {
+            Quat::from_rotation_x(-swing * (1.0 - 0.6 * sneak_amount))
+        };
     }
     if let Ok(mut t) = part_transforms.get_mut(parts.leg_right) {
         t.translation = Vec3::new(leg_x, leg_y, leg_z);
-        t.rotation = Quat::from_rotation_x(swing * (1.0 - 0.6 * sneak_amount));
+        t.rotation = if riding {
+            riding_leg_rotation(true)
+        } else {
+            Quat::from_rotation_x(swing * (1.0 - 0.6 * sneak_amount))
+        };
     }
 }
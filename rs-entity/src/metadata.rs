@@ -0,0 +1,235 @@
+use super::*;
+use rs_utils::{
+    EntityMetadata, HORSE_TYPE_DONKEY, HORSE_TYPE_MULE, HORSE_TYPE_SKELETON, HORSE_TYPE_ZOMBIE,
+    MobMetadata, ObjectKind,
+};
+
+/// First frame of the 16x512 `fire_layer_0` animation strip.
+const FIRE_TEXTURE_PATH: &str = "blocks/fire_layer_0.png";
+const FIRE_FRAME_V: f32 = 1.0 / 32.0;
+/// Share of the name tag height the flames cover, and their width relative to that height.
+const FIRE_HEIGHT_RATIO: f32 = 0.85;
+const FIRE_WIDTH_RATIO: f32 = 0.6;
+const CHILD_SCALE: f32 = 0.5;
+
+const HORSE_COAT_TEXTURES: [&str; 7] = [
+    "entity/horse/horse_white.png",
+    "entity/horse/horse_creamy.png",
+    "entity/horse/horse_chestnut.png",
+    "entity/horse/horse_brown.png",
+    "entity/horse/horse_black.png",
+    "entity/horse/horse_gray.png",
+    "entity/horse/horse_darkbrown.png",
+];
+const VILLAGER_PROFESSION_TEXTURES: [&str; 5] = [
+    "entity/villager/farmer.png",
+    "entity/villager/librarian.png",
+    "entity/villager/priest.png",
+    "entity/villager/smith.png",
+    "entity/villager/butcher.png",
+];
+
+/// Typed metadata of a remote entity; `sync_remote_entity_metadata` derives its visuals.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteEntityMetadata(pub EntityMetadata);
+
+/// Flame overlay shown while the entity's on-fire flag is set.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RemoteEntityFire(pub Entity);
+
+/// Texture the entity model meshes use, swapped when metadata selects a variant.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RemoteEntityTexture(pub &'static str);
+
+type MetadataSyncQuery = (
+    Entity,
+    &'static RemoteEntity,
+    &'static RemoteEntityMetadata,
+    &'static RemoteVisual,
+    &'static mut Transform,
+    &'static mut Visibility,
+    &'static mut RemotePoseState,
+    Option<&'static mut RemoteSheepAppearance>,
+    Option<&'static RemoteEntityFire>,
+    Option<&'static mut RemoteEntityTexture>,
+);
+
+/// Applies metadata changes to the entity's pose, visibility, size, fire overlay and textures.
+pub fn sync_remote_entity_metadata(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<MetadataSyncQuery, Changed<RemoteEntityMetadata>>,
+    children_query: Query<&Children>,
+    mut texture_paths: Query<&mut EntityTexturePath>,
+) {
+    for (
+        root,
+        remote,
+        metadata,
+        visual,
+        mut transform,
+        mut visibility,
+        mut pose,
+        sheep,
+        fire,
+        texture,
+    ) in &mut query
+    {
+        let metadata = &metadata.0;
+        pose.sneaking = metadata.sneaking;
+        if let (
+            Some(mut sheep),
+            MobMetadata::Sheep {
+                fleece_color,
+                sheared,
+            },
+        ) = (sheep, metadata.mob)
+        {
+            sheep.fleece_color = fleece_color;
+            sheep.sheared = sheared;
+        }
+        if remote.kind == NetEntityKind::Item {
+            continue;
+        }
+
+        // Invisible armor stands keep their equipment; `armor_stands` hides just the stand.
+        let hidden =
+            metadata.invisible && remote.kind != NetEntityKind::Object(ObjectKind::ArmorStand);
+        let target = if hidden {
+            Visibility::Hidden
+        } else {
+            Visibility::Visible
+        };
+        if *visibility != target {
+            *visibility = target;
+        }
+
+        let base_scale = visual_spec(remote.kind).scale;
+        if let NetEntityKind::Mob(mob) = remote.kind {
+            let size = if metadata.child { CHILD_SCALE } else { 1.0 };
+            transform.scale = base_scale * size;
+
+            if let Some(mut texture) = texture {
+                let path = metadata_texture_path(mob, metadata)
+                    .or_else(|| mob_texture_path(mob))
+                    .unwrap_or(texture.0);
+                if path != texture.0 {
+                    for mesh in children_query.iter_descendants(root) {
+                        if let Ok(mut mesh_path) = texture_paths.get_mut(mesh)
+                            && mesh_path.0 == texture.0
+                        {
+                            mesh_path.0 = path;
+                        }
+                    }
+                    texture.0 = path;
+                }
+            }
+        }
+
+        match (metadata.on_fire, fire) {
+            (true, None) => {
+                let overlay = commands
+                    .spawn((
+                        Name::new("RemoteEntityFire"),
+                        Mesh3d(meshes.add(fire_mesh())),
+                        MeshMaterial3d::<StandardMaterial>::default(),
+                        fire_transform(visual, base_scale),
+                        GlobalTransform::default(),
+                        Visibility::Inherited,
+                        InheritedVisibility::default(),
+                        ViewVisibility::default(),
+                        EntityTexturePath(FIRE_TEXTURE_PATH),
+                    ))
+                    .id();
+                commands.entity(root).add_child(overlay);
+                commands.entity(root).insert(RemoteEntityFire(overlay));
+            }
+            (false, Some(fire)) => {
+                commands.entity(fire.0).despawn();
+                commands.entity(root).remove::<RemoteEntityFire>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Texture variants picked by metadata; `None` keeps the mob's default texture.
+fn metadata_texture_path(mob: MobKind, metadata: &EntityMetadata) -> Option<&'static str> {
+    match (mob, metadata.mob) {
+        (MobKind::Skeleton, MobMetadata::Skeleton { wither: true }) => {
+            Some("entity/skeleton/wither_skeleton.png")
+        }
+        (MobKind::Zombie, MobMetadata::Zombie { villager: true, .. }) => {
+            Some("entity/zombie/zombie_villager.png")
+        }
+        (MobKind::Wolf, MobMetadata::Wolf { tamed: true, .. }) => Some("entity/wolf/wolf_tame.png"),
+        (MobKind::Wolf, MobMetadata::Wolf { angry: true, .. }) => {
+            Some("entity/wolf/wolf_angry.png")
+        }
+        (MobKind::Villager, MobMetadata::Villager { profession }) => usize::try_from(profession)
+            .ok()
+            .and_then(|profession| VILLAGER_PROFESSION_TEXTURES.get(profession))
+            .copied(),
+        (MobKind::Ghast, MobMetadata::Ghast { attacking: true }) => {
+            Some("entity/ghast/ghast_shooting.png")
+        }
+        (MobKind::Guardian, MobMetadata::Guardian { elder: true, .. }) => {
+            Some("entity/guardian_elder.png")
+        }
+        (MobKind::Horse, MobMetadata::Horse(horse)) => match horse.horse_type {
+            HORSE_TYPE_DONKEY => Some("entity/horse/donkey.png"),
+            HORSE_TYPE_MULE => Some("entity/horse/mule.png"),
+            HORSE_TYPE_ZOMBIE => Some("entity/horse/horse_zombie.png"),
+            HORSE_TYPE_SKELETON => Some("entity/horse/horse_skeleton.png"),
+            _ => HORSE_COAT_TEXTURES.get(usize::from(horse.color())).copied(),
+        },
+        _ => None,
+    }
+}
+
+/// Sizes the unit fire mesh from the feet up to just below the name tag, in the root's
+/// unscaled space so a child's smaller root scale shrinks the flames too.
+fn fire_transform(visual: &RemoteVisual, base_scale: Vec3) -> Transform {
+    let height = (visual.y_offset + visual.name_y_offset) * FIRE_HEIGHT_RATIO;
+    let width = height * FIRE_WIDTH_RATIO;
+    let bottom = -visual.y_offset;
+    Transform {
+        translation: Vec3::Y * (bottom + height * 0.5) / base_scale.y,
+        scale: Vec3::new(width, height, width) / base_scale,
+        ..Default::default()
+    }
+}
+
+/// Two crossed unit quads, each wound both ways so the flames show from every side.
+fn fire_mesh() -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    for (dx, dz) in [(0.5, 0.0), (0.0, 0.5)] {
+        let base = positions.len() as u32;
+        positions.extend([
+            [-dx, -0.5, -dz],
+            [dx, -0.5, dz],
+            [dx, 0.5, dz],
+            [-dx, 0.5, -dz],
+        ]);
+        uvs.extend([
+            [0.0, FIRE_FRAME_V],
+            [1.0, FIRE_FRAME_V],
+            [1.0, 0.0],
+            [0.0, 0.0],
+        ]);
+        indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        indices.extend([base, base + 2, base + 1, base, base + 3, base + 2]);
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
@@ -0,0 +1,101 @@
+use super::ModelDef;
+use crate::{cube, part};
+
+// Part indices for `ARMOR_STAND_MODEL`.
+pub const ARMOR_STAND_HEAD: usize = 0;
+pub const ARMOR_STAND_BODY: usize = 1;
+pub const ARMOR_STAND_RIGHT_ARM: usize = 2;
+pub const ARMOR_STAND_LEFT_ARM: usize = 3;
+pub const ARMOR_STAND_RIGHT_LEG: usize = 4;
+pub const ARMOR_STAND_LEFT_LEG: usize = 5;
+pub const ARMOR_STAND_BASE: usize = 9;
+
+/// Vanilla `ModelArmorStand` (64x64). The side sticks and waist follow the body rotation in
+/// vanilla, so they are children of the body here; the legs use the 11px pivot
+/// `setRotationAngles` moves them to.
+pub static ARMOR_STAND_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 64],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[
+        part! {
+            name: "head",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -7.0, -1.0), size: (2.0, 7.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "body",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 26), from: (-6.0, 0.0, -1.5), size: (12.0, 3.0, 3.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "right_arm",
+            parent: None,
+            pivot: (-5.0, 2.0, 0.0),
+            cubes: [
+                cube! { uv: (24, 0), from: (-2.0, -2.0, -1.0), size: (2.0, 12.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "left_arm",
+            parent: None,
+            pivot: (5.0, 2.0, 0.0),
+            cubes: [
+                cube! { uv: (32, 16), from: (0.0, -2.0, -1.0), size: (2.0, 12.0, 2.0), inflate: 0.0, mirror: true },
+            ],
+        },
+        part! {
+            name: "right_leg",
+            parent: None,
+            pivot: (-1.9, 11.0, 0.0),
+            cubes: [
+                cube! { uv: (8, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 11.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "left_leg",
+            parent: None,
+            pivot: (1.9, 11.0, 0.0),
+            cubes: [
+                cube! { uv: (40, 16), from: (-1.0, 0.0, -1.0), size: (2.0, 11.0, 2.0), inflate: 0.0, mirror: true },
+            ],
+        },
+        part! {
+            name: "right_side",
+            parent: Some(ARMOR_STAND_BODY),
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (16, 0), from: (-3.0, 3.0, -1.0), size: (2.0, 7.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "left_side",
+            parent: Some(ARMOR_STAND_BODY),
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (48, 16), from: (1.0, 3.0, -1.0), size: (2.0, 7.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "waist",
+            parent: Some(ARMOR_STAND_BODY),
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 48), from: (-4.0, 10.0, -1.0), size: (8.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "base",
+            parent: None,
+            pivot: (0.0, 12.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 32), from: (-6.0, 11.0, -6.0), size: (12.0, 1.0, 12.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
@@ -0,0 +1,237 @@
+use super::ModelDef;
+use crate::{cube, part};
+
+// Part indices for `SPIDER_MODEL`; the legs alternate right/left from front to back.
+pub const SPIDER_HEAD: usize = 0;
+pub const SPIDER_FIRST_LEG: usize = 3;
+pub const SPIDER_LEG_COUNT: usize = 8;
+
+/// Body segments of `SILVERFISH_MODEL` and `ENDERMITE_MODEL`, head first; the silverfish
+/// wing layers follow as children of the segments they sit on.
+pub const SILVERFISH_SEGMENTS: usize = 7;
+pub const ENDERMITE_SEGMENTS: usize = 4;
+
+/// Vanilla `ModelSpider` (64x32), shared by spiders and cave spiders.
+pub static SPIDER_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[
+        part! {
+            name: "head",
+            parent: None,
+            pivot: (0.0, 15.0, -3.0),
+            cubes: [
+                cube! { uv: (32, 4), from: (-4.0, -4.0, -8.0), size: (8.0, 8.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "neck",
+            parent: None,
+            pivot: (0.0, 15.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-3.0, -3.0, -3.0), size: (6.0, 6.0, 6.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "body",
+            parent: None,
+            pivot: (0.0, 15.0, 9.0),
+            cubes: [
+                cube! { uv: (0, 12), from: (-5.0, -4.0, -6.0), size: (10.0, 8.0, 12.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_1",
+            parent: None,
+            pivot: (-4.0, 15.0, 2.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-15.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_2",
+            parent: None,
+            pivot: (4.0, 15.0, 2.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-1.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_3",
+            parent: None,
+            pivot: (-4.0, 15.0, 1.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-15.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_4",
+            parent: None,
+            pivot: (4.0, 15.0, 1.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-1.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_5",
+            parent: None,
+            pivot: (-4.0, 15.0, 0.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-15.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_6",
+            parent: None,
+            pivot: (4.0, 15.0, 0.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-1.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_7",
+            parent: None,
+            pivot: (-4.0, 15.0, -1.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-15.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "leg_8",
+            parent: None,
+            pivot: (4.0, 15.0, -1.0),
+            cubes: [
+                cube! { uv: (18, 0), from: (-1.0, -1.0, -1.0), size: (16.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
+
+/// Vanilla `ModelSilverfish` (64x32). Segment sizes and placements are the ones its
+/// constructor computes; the wings copy a segment's sway in vanilla, so they are children.
+pub static SILVERFISH_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[
+        part! {
+            name: "segment_0",
+            parent: None,
+            pivot: (0.0, 22.0, -3.5),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.5, 0.0, -1.0), size: (3.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_1",
+            parent: None,
+            pivot: (0.0, 21.0, -1.5),
+            cubes: [
+                cube! { uv: (0, 4), from: (-2.0, 0.0, -1.0), size: (4.0, 3.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_2",
+            parent: None,
+            pivot: (0.0, 20.0, 1.0),
+            cubes: [
+                cube! { uv: (0, 9), from: (-3.0, 0.0, -1.5), size: (6.0, 4.0, 3.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_3",
+            parent: None,
+            pivot: (0.0, 21.0, 4.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (-1.5, 0.0, -1.5), size: (3.0, 3.0, 3.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_4",
+            parent: None,
+            pivot: (0.0, 22.0, 7.0),
+            cubes: [
+                cube! { uv: (0, 22), from: (-1.0, 0.0, -1.5), size: (2.0, 2.0, 3.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_5",
+            parent: None,
+            pivot: (0.0, 23.0, 9.5),
+            cubes: [
+                cube! { uv: (11, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 1.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_6",
+            parent: None,
+            pivot: (0.0, 23.0, 11.5),
+            cubes: [
+                cube! { uv: (13, 4), from: (-0.5, 0.0, -1.0), size: (1.0, 1.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "wing_0",
+            parent: Some(2),
+            pivot: (0.0, -4.0, 0.0),
+            cubes: [
+                cube! { uv: (20, 0), from: (-5.0, 0.0, -1.5), size: (10.0, 8.0, 3.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "wing_1",
+            parent: Some(4),
+            pivot: (0.0, -2.0, 0.0),
+            cubes: [
+                cube! { uv: (20, 11), from: (-3.0, 0.0, -1.5), size: (6.0, 4.0, 3.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "wing_2",
+            parent: Some(1),
+            pivot: (0.0, -2.0, 0.0),
+            cubes: [
+                cube! { uv: (20, 18), from: (-3.0, 0.0, -1.0), size: (6.0, 5.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
+
+/// Vanilla `ModelEnderMite` (64x32).
+pub static ENDERMITE_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[
+        part! {
+            name: "segment_0",
+            parent: None,
+            pivot: (0.0, 21.0, -3.5),
+            cubes: [
+                cube! { uv: (0, 0), from: (-2.0, 0.0, -1.0), size: (4.0, 3.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_1",
+            parent: None,
+            pivot: (0.0, 20.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 5), from: (-3.0, 0.0, -2.5), size: (6.0, 4.0, 5.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_2",
+            parent: None,
+            pivot: (0.0, 21.0, 3.0),
+            cubes: [
+                cube! { uv: (0, 14), from: (-1.5, 0.0, -0.5), size: (3.0, 3.0, 1.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "segment_3",
+            parent: None,
+            pivot: (0.0, 22.0, 4.0),
+            cubes: [
+                cube! { uv: (0, 18), from: (-0.5, 0.0, -0.5), size: (1.0, 2.0, 1.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
@@ -99,6 +99,7 @@ pub static BIPED_MODEL_TEX32: ModelDef = ModelDef {
             name: "headwear",
             parent: Some(BIPED_HEAD),
             pivot: [0.0, 0.0, 0.0],
+            rotation: [0.0, 0.0, 0.0],
             cubes: &[],
         },
         // Body
@@ -0,0 +1,471 @@
+use super::ModelDef;
+use crate::{cube, part};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+pub const GHAST_FIRST_TENTACLE: usize = 1;
+pub const GHAST_TENTACLE_COUNT: usize = 9;
+
+pub const BLAZE_HEAD: usize = 0;
+pub const BLAZE_FIRST_ROD: usize = 1;
+pub const BLAZE_ROD_COUNT: usize = 12;
+
+pub const SQUID_FIRST_TENTACLE: usize = 1;
+pub const SQUID_TENTACLE_COUNT: usize = 8;
+
+// Part indices for `GUARDIAN_MODEL`; the spikes, eye and tail are children of the body.
+pub const GUARDIAN_BODY: usize = 0;
+pub const GUARDIAN_FIRST_SPIKE: usize = 1;
+pub const GUARDIAN_SPIKE_COUNT: usize = 12;
+pub const GUARDIAN_TAIL: [usize; 3] = [14, 15, 16];
+
+/// Vanilla `ModelGhast` (64x32). Tentacle lengths are what its `Random(1660)` rolls; the
+/// root is lowered by the 0.6 block `render` translate.
+pub static GHAST_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 14.4, 0.0],
+    parts: &[
+        part! {
+            name: "body",
+            parent: None,
+            pivot: (0.0, 8.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-8.0, -8.0, -8.0), size: (16.0, 16.0, 16.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_0",
+            parent: None,
+            pivot: (-3.75, 15.0, -5.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 13.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_1",
+            parent: None,
+            pivot: (1.25, 15.0, -5.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 11.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_2",
+            parent: None,
+            pivot: (6.25, 15.0, -5.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_3",
+            parent: None,
+            pivot: (-6.25, 15.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_4",
+            parent: None,
+            pivot: (-1.25, 15.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 11.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_5",
+            parent: None,
+            pivot: (3.75, 15.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 10.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_6",
+            parent: None,
+            pivot: (-3.75, 15.0, 5.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 12.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_7",
+            parent: None,
+            pivot: (1.25, 15.0, 5.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_8",
+            parent: None,
+            pivot: (6.25, 15.0, 5.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 12.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
+
+/// Vanilla `ModelBlaze` (64x32). The rods orbit the head; their pivots are set every frame.
+pub static BLAZE_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[
+        part! {
+            name: "head",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-4.0, -4.0, -4.0), size: (8.0, 8.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_0",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_1",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_2",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_3",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_4",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_5",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_6",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_7",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_8",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_9",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_10",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "rod_11",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (0.0, 0.0, 0.0), size: (2.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
+
+/// Vanilla `ModelSquid` (64x32) with the tentacles in a ring under the body. The root is
+/// lowered by the 0.7 blocks `RenderSquid.rotateCorpse` translates the model down.
+pub static SQUID_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 12.8, 0.0],
+    parts: &[
+        part! {
+            name: "body",
+            parent: None,
+            pivot: (0.0, 8.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-6.0, -8.0, -6.0), size: (12.0, 16.0, 12.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_0",
+            parent: None,
+            pivot: (5.0, 15.0, 0.0),
+            rotation: (0.0, FRAC_PI_2, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_1",
+            parent: None,
+            pivot: (3.535_534, 15.0, 3.535_534),
+            rotation: (0.0, FRAC_PI_4, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_2",
+            parent: None,
+            pivot: (0.0, 15.0, 5.0),
+            rotation: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_3",
+            parent: None,
+            pivot: (-3.535_534, 15.0, 3.535_534),
+            rotation: (0.0, -FRAC_PI_4, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_4",
+            parent: None,
+            pivot: (-5.0, 15.0, 0.0),
+            rotation: (0.0, -FRAC_PI_2, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_5",
+            parent: None,
+            pivot: (-3.535_534, 15.0, -3.535_534),
+            rotation: (0.0, -FRAC_PI_4 * 3.0, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_6",
+            parent: None,
+            pivot: (0.0, 15.0, -5.0),
+            rotation: (0.0, -PI, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tentacle_7",
+            parent: None,
+            pivot: (3.535_534, 15.0, -3.535_534),
+            rotation: (0.0, -FRAC_PI_4 * 5.0, 0.0),
+            cubes: [
+                cube! { uv: (48, 0), from: (-1.0, 0.0, -1.0), size: (2.0, 18.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
+
+/// Vanilla `ModelGuardian` (64x64), shared by elder guardians. Spikes start extended; their
+/// pivots are moved in and out every frame.
+pub static GUARDIAN_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 64],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[
+        part! {
+            name: "body",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-6.0, 10.0, -8.0), size: (12.0, 12.0, 16.0), inflate: 0.0, mirror: false },
+                cube! { uv: (0, 28), from: (-8.0, 10.0, -6.0), size: (2.0, 12.0, 12.0), inflate: 0.0, mirror: false },
+                cube! { uv: (0, 28), from: (6.0, 10.0, -6.0), size: (2.0, 12.0, 12.0), inflate: 0.0, mirror: true },
+                cube! { uv: (16, 40), from: (-6.0, 8.0, -6.0), size: (12.0, 2.0, 12.0), inflate: 0.0, mirror: false },
+                cube! { uv: (16, 40), from: (-6.0, 22.0, -6.0), size: (12.0, 2.0, 12.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_0",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (0.0, 8.0, 8.0),
+            rotation: (PI * 1.75, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_1",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (0.0, 8.0, -8.0),
+            rotation: (PI * 0.25, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_2",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (8.0, 8.0, 0.0),
+            rotation: (0.0, 0.0, PI * 0.25),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_3",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (-8.0, 8.0, 0.0),
+            rotation: (0.0, 0.0, PI * 1.75),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_4",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (-8.0, 16.0, -8.0),
+            rotation: (PI * 0.5, PI * 0.25, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_5",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (8.0, 16.0, -8.0),
+            rotation: (PI * 0.5, PI * 1.75, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_6",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (8.0, 16.0, 8.0),
+            rotation: (PI * 0.5, PI * 1.25, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_7",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (-8.0, 16.0, 8.0),
+            rotation: (PI * 0.5, PI * 0.75, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_8",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (0.0, 24.0, 8.0),
+            rotation: (PI * 1.25, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_9",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (0.0, 24.0, -8.0),
+            rotation: (PI * 0.75, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_10",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (8.0, 24.0, 0.0),
+            rotation: (0.0, 0.0, PI * 0.75),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "spike_11",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (-8.0, 24.0, 0.0),
+            rotation: (0.0, 0.0, PI * 1.25),
+            cubes: [
+                cube! { uv: (0, 0), from: (-1.0, -4.5, -1.0), size: (2.0, 9.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "eye",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (0.0, 0.0, -8.25),
+            cubes: [
+                cube! { uv: (8, 0), from: (-1.0, 15.0, 0.0), size: (2.0, 2.0, 1.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tail_0",
+            parent: Some(GUARDIAN_BODY),
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (40, 0), from: (-2.0, 14.0, 7.0), size: (4.0, 4.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tail_1",
+            parent: Some(GUARDIAN_TAIL[0]),
+            pivot: (-1.5, 0.5, 14.0),
+            cubes: [
+                cube! { uv: (0, 54), from: (0.0, 14.0, 0.0), size: (3.0, 3.0, 7.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "tail_2",
+            parent: Some(GUARDIAN_TAIL[1]),
+            pivot: (0.5, 0.5, 6.0),
+            cubes: [
+                cube! { uv: (41, 32), from: (0.0, 14.0, 0.0), size: (2.0, 2.0, 6.0), inflate: 0.0, mirror: false },
+                cube! { uv: (25, 19), from: (1.0, 10.5, 3.0), size: (1.0, 9.0, 9.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
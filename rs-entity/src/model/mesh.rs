@@ -51,6 +51,16 @@ pub fn part_mesh_with_front_back_swap(
     mesh
 }
 
+fn part_rotation(part: &PartDef) -> Quat {
+    part_angles_rotation(part.rotation)
+}
+
+/// Vanilla `rotateAngleX/Y/Z` (radians) as a bevy rotation. Vanilla applies Z, Y, then X;
+/// X and Z flip sign because model +Y is down.
+pub fn part_angles_rotation([x, y, z]: [f32; 3]) -> Quat {
+    Quat::from_rotation_z(-z) * Quat::from_rotation_y(y) * Quat::from_rotation_x(-x)
+}
+
 pub fn spawn_model(
     commands: &mut Commands,
     meshes: &mut Assets<Mesh>,
@@ -81,7 +91,7 @@ pub fn spawn_model(
         let e = commands
             .spawn((
                 Name::new(format!("EntityModelPart[{}]", part.name)),
-                Transform::from_translation(pivot),
+                Transform::from_translation(pivot).with_rotation(part_rotation(part)),
                 GlobalTransform::default(),
                 Visibility::Visible,
                 InheritedVisibility::default(),
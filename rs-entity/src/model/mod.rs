@@ -6,20 +6,32 @@
 //! - Only textures are loaded at runtime (from the texture pack / skin URLs).
 
 mod armor;
+mod armor_stand;
+mod arthropods;
 mod biped;
 mod creeper;
+mod floating_mobs;
 mod mesh;
+mod projectiles;
 mod quadruped;
+mod slimes;
 mod textures;
 mod types;
+mod vehicles;
 
 pub use armor::*;
+pub use armor_stand::*;
+pub use arthropods::*;
 pub use biped::*;
 pub use creeper::*;
+pub use floating_mobs::*;
 pub use mesh::*;
+pub use projectiles::*;
 pub use quadruped::*;
+pub use slimes::*;
 pub use textures::*;
 pub use types::*;
+pub use vehicles::*;
 
 // Small DSL macros to make defining cuboid models less painful.
 // Intentionally kept as `macro_rules!` (no proc-macro / extra deps).
@@ -50,11 +62,27 @@ macro_rules! part {
         parent: $parent:expr,
         pivot: ($x:expr, $y:expr, $z:expr),
         cubes: [ $($cube:expr),* $(,)? ] $(,)?
+    ) => {
+        $crate::part! {
+            name: $name,
+            parent: $parent,
+            pivot: ($x, $y, $z),
+            rotation: (0.0, 0.0, 0.0),
+            cubes: [$($cube),*],
+        }
+    };
+    (
+        name: $name:expr,
+        parent: $parent:expr,
+        pivot: ($x:expr, $y:expr, $z:expr),
+        rotation: ($rx:expr, $ry:expr, $rz:expr),
+        cubes: [ $($cube:expr),* $(,)? ] $(,)?
     ) => {
         $crate::model::PartDef {
             name: $name,
             parent: $parent,
             pivot: [$x as f32, $y as f32, $z as f32],
+            rotation: [$rx as f32, $ry as f32, $rz as f32],
             cubes: &[$($cube),*],
         }
     };
@@ -0,0 +1,17 @@
+use super::ModelDef;
+use crate::{cube, part};
+
+/// Vanilla `ModelSkeletonHead(0, 35, 64, 64)` as `RenderWitherSkull` draws it: one 8px cube,
+/// centred on the entity.
+pub static WITHER_SKULL_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 64],
+    root_offset_px: [0.0, -4.0, 0.0],
+    parts: &[part! {
+        name: "head",
+        parent: None,
+        pivot: (0.0, 0.0, 0.0),
+        cubes: [
+            cube! { uv: (0, 35), from: (-4.0, -8.0, -4.0), size: (8.0, 8.0, 8.0), inflate: 0.0, mirror: false },
+        ],
+    }],
+};
@@ -0,0 +1,116 @@
+use super::ModelDef;
+use crate::{cube, part};
+
+/// Part index of the core in `MAGMA_CUBE_MODEL`; the eight slices come before it, top first.
+pub const MAGMA_CUBE_CORE: usize = 8;
+
+/// Vanilla `ModelSlime(16)` (64x32): the opaque inner body with its eyes and mouth.
+pub static SLIME_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[part! {
+        name: "inner",
+        parent: None,
+        pivot: (0.0, 0.0, 0.0),
+        cubes: [
+            cube! { uv: (0, 16), from: (-3.0, 17.0, -3.0), size: (6.0, 6.0, 6.0), inflate: 0.0, mirror: false },
+            cube! { uv: (32, 0), from: (-3.25, 18.0, -3.5), size: (2.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            cube! { uv: (32, 4), from: (1.25, 18.0, -3.5), size: (2.0, 2.0, 2.0), inflate: 0.0, mirror: false },
+            cube! { uv: (32, 8), from: (0.0, 21.0, -3.5), size: (1.0, 1.0, 1.0), inflate: 0.0, mirror: false },
+        ],
+    }],
+};
+
+/// Vanilla `ModelSlime(0)`: the translucent outer cube `LayerSlimeGel` draws over the body.
+pub static SLIME_GEL_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[part! {
+        name: "gel",
+        parent: None,
+        pivot: (0.0, 0.0, 0.0),
+        cubes: [
+            cube! { uv: (0, 0), from: (-4.0, 16.0, -4.0), size: (8.0, 8.0, 8.0), inflate: 0.0, mirror: false },
+        ],
+    }],
+};
+
+/// Vanilla `ModelMagmaCube` (64x32): eight one pixel slices around a small core.
+pub static MAGMA_CUBE_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 24.0, 0.0],
+    parts: &[
+        part! {
+            name: "slice_0",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-4.0, 16.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "slice_1",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 1), from: (-4.0, 17.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "slice_2",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (24, 10), from: (-4.0, 18.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "slice_3",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (24, 19), from: (-4.0, 19.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "slice_4",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 4), from: (-4.0, 20.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "slice_5",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 5), from: (-4.0, 21.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "slice_6",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 6), from: (-4.0, 22.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "slice_7",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 7), from: (-4.0, 23.0, -4.0), size: (8.0, 1.0, 8.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "core",
+            parent: None,
+            pivot: (0.0, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 16), from: (-2.0, 18.0, -2.0), size: (4.0, 4.0, 4.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
@@ -13,6 +13,11 @@ use tracing::warn;
 #[derive(Component, Debug, Clone, Copy)]
 pub struct EntityTexturePath(pub &'static str);
 
+/// Texture of a model layer drawn with its own material (additive spider eyes, translucent
+/// slime gel) instead of the shared cutout one; set once the texture has loaded.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EntityLayerTexturePath(pub &'static str);
+
 #[derive(Debug)]
 struct TextureResult {
     path: &'static str,
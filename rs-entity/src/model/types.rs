@@ -19,6 +19,8 @@ pub struct PartDef {
     pub parent: Option<usize>,
     /// Rotation point / pivot in model pixels (vanilla coordinates; +Y is down).
     pub pivot: [f32; 3],
+    /// Static rotation in radians (vanilla `rotateAngleX/Y/Z`), applied Z, then Y, then X.
+    pub rotation: [f32; 3],
     pub cubes: &'static [CubeDef],
 }
 
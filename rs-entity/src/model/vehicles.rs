@@ -0,0 +1,117 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use super::ModelDef;
+use crate::{cube, part};
+
+/// Vanilla `ModelBoat`: a flat bottom plus four side planks.
+pub static BOAT_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 4.0, 0.0],
+    parts: &[
+        part! {
+            name: "bottom",
+            parent: None,
+            pivot: (0.0, 4.0, 0.0),
+            rotation: (FRAC_PI_2, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 8), from: (-12.0, -8.0, -3.0), size: (24.0, 16.0, 4.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_left",
+            parent: None,
+            pivot: (-11.0, 4.0, 0.0),
+            rotation: (0.0, 3.0 * FRAC_PI_2, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-10.0, -7.0, -1.0), size: (20.0, 6.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_right",
+            parent: None,
+            pivot: (11.0, 4.0, 0.0),
+            rotation: (0.0, FRAC_PI_2, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-10.0, -7.0, -1.0), size: (20.0, 6.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_back",
+            parent: None,
+            pivot: (0.0, 4.0, -9.0),
+            rotation: (0.0, PI, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-10.0, -7.0, -1.0), size: (20.0, 6.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_front",
+            parent: None,
+            pivot: (0.0, 4.0, 9.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-10.0, -7.0, -1.0), size: (20.0, 6.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
+
+/// Vanilla `ModelMinecart`: bottom, four sides and the inner floor plate.
+pub static MINECART_MODEL: ModelDef = ModelDef {
+    tex_size: [64, 32],
+    root_offset_px: [0.0, 6.0, 0.0],
+    parts: &[
+        part! {
+            name: "bottom",
+            parent: None,
+            pivot: (0.0, 4.0, 0.0),
+            rotation: (FRAC_PI_2, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (0, 10), from: (-10.0, -8.0, -1.0), size: (20.0, 16.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_left",
+            parent: None,
+            pivot: (-9.0, 4.0, 0.0),
+            rotation: (0.0, 3.0 * FRAC_PI_2, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-8.0, -9.0, -1.0), size: (16.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_right",
+            parent: None,
+            pivot: (9.0, 4.0, 0.0),
+            rotation: (0.0, FRAC_PI_2, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-8.0, -9.0, -1.0), size: (16.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_back",
+            parent: None,
+            pivot: (0.0, 4.0, -7.0),
+            rotation: (0.0, PI, 0.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-8.0, -9.0, -1.0), size: (16.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "side_front",
+            parent: None,
+            pivot: (0.0, 4.0, 7.0),
+            cubes: [
+                cube! { uv: (0, 0), from: (-8.0, -9.0, -1.0), size: (16.0, 8.0, 2.0), inflate: 0.0, mirror: false },
+            ],
+        },
+        part! {
+            name: "floor",
+            parent: None,
+            pivot: (0.0, 4.0, 0.0),
+            rotation: (-FRAC_PI_2, 0.0, 0.0),
+            cubes: [
+                cube! { uv: (44, 10), from: (-9.0, -7.0, -1.0), size: (18.0, 14.0, 1.0), inflate: 0.0, mirror: false },
+            ],
+        },
+    ],
+};
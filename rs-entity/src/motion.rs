@@ -6,18 +6,6 @@ use crate::components::{
     DROPPED_ITEM_GRAVITY, DROPPED_ITEM_RESTITUTION,
 };
 
-pub(crate) fn update_motion_velocity(
-    smoothing: &mut RemoteMotionSmoothing,
-    previous: Vec3,
-    next: Vec3,
-    now_secs: f64,
-) {
-    let dt = (now_secs - smoothing.last_server_update_secs).max(1.0 / 120.0) as f32;
-    smoothing.estimated_velocity = (next - previous) / dt;
-    smoothing.target_translation = next;
-    smoothing.last_server_update_secs = now_secs;
-}
-
 pub(crate) fn update_item_motion_velocity(
     motion: &mut RemoteDroppedItemMotion,
     previous: Vec3,
@@ -129,29 +117,23 @@ fn advance_item_motion(
 
 pub fn smooth_remote_entity_motion(
     time: Res<Time>,
+    render_debug: Res<RenderDebugSettings>,
     mut query: Query<
         (
             &RemoteEntity,
             &RemoteEntityLook,
             &mut Transform,
-            &RemoteMotionSmoothing,
+            &mut RemoteMotionBuffer,
         ),
         With<RemoteEntity>,
     >,
 ) {
     let dt = time.delta_secs().max(1e-4);
     let now_secs = time.elapsed_secs_f64();
-    for (remote, look, mut transform, smoothing) in &mut query {
-        // Extrapolate a little bit from the last known velocity to hide packet spacing.
-        let extrapolate = ((now_secs - smoothing.last_server_update_secs) as f32).clamp(0.0, 0.1);
-        let desired_pos = smoothing.target_translation + smoothing.estimated_velocity * extrapolate;
-        let delta = desired_pos - transform.translation;
-        if delta.length_squared() > 64.0 {
-            transform.translation = desired_pos;
-        } else {
-            let pos_alpha = 1.0 - (-18.0 * dt).exp();
-            transform.translation += delta * pos_alpha;
-        }
+    let delay_secs = render_debug.entity_interpolation_delay_ms / 1000.0;
+    for (remote, look, mut transform, mut buffer) in &mut query {
+        buffer.prune(now_secs, delay_secs);
+        transform.translation = buffer.sample(now_secs, delay_secs);
 
         if remote.kind == NetEntityKind::Item {
             // Item sprites use a dedicated billboard/spin system; don't fight it here.
@@ -232,23 +214,26 @@ pub fn smooth_remote_item_entities(
     }
 }
 
+type RemoteNameQuery = (
+    &'static GlobalTransform,
+    &'static RemoteEntityName,
+    &'static RemoteVisual,
+    &'static RemoteEntity,
+    &'static ViewVisibility,
+    Option<&'static RemoteSleeping>,
+    Option<&'static RemoteEntityMetadata>,
+);
+
 pub fn draw_remote_entity_names(
     mut contexts: EguiContexts,
     camera_query: Query<(&Camera, &GlobalTransform), With<PlayerCamera>>,
-    names_query: Query<
-        (
-            &GlobalTransform,
-            &RemoteEntityName,
-            &RemoteVisual,
-            &RemoteEntity,
-            &ViewVisibility,
-        ),
-        With<RemoteEntity>,
-    >,
+    names_query: Query<RemoteNameQuery, With<RemoteEntity>>,
     collision_map: Res<WorldCollisionMap>,
     ui_state: Res<UiState>,
 ) {
     const NON_PLAYER_NAME_MAX_DISTANCE: f32 = 5.0;
+    /// `RenderLiving` stops drawing name tags past 64 blocks.
+    const CUSTOM_NAME_MAX_DISTANCE: f32 = 64.0;
     const PLAYER_NAME_ALPHA: u8 = 210;
     const NON_PLAYER_NAME_ALPHA: u8 = 120;
 
@@ -266,21 +251,36 @@ pub fn draw_remote_entity_names(
     ));
 
     let cam_pos = camera_transform.translation();
-    for (transform, name, visual, remote, view_visibility) in &names_query {
+    for (transform, name, visual, remote, view_visibility, sleeping, metadata) in &names_query {
+        let metadata = metadata.map(|metadata| &metadata.0);
+        let custom_name = metadata.and_then(EntityMetadata::plain_custom_name);
+        let is_player = remote.kind == NetEntityKind::Player;
+        if is_player && metadata.is_some_and(|metadata| metadata.invisible) {
+            continue;
+        }
         // Modeled entities already carry strong visual identity and their labels
-        // add unnecessary clutter.
-        if entity_kind_uses_model(remote.kind) && remote.kind != NetEntityKind::Player {
+        // add unnecessary clutter, unless they were given a custom name.
+        if entity_kind_uses_model(remote.kind) && !is_player && custom_name.is_none() {
             continue;
         }
+        // Always-shown custom names label invisible armor stands too, like hologram lines.
+        let always_shown =
+            custom_name.is_some() && metadata.is_some_and(|metadata| metadata.custom_name_visible);
 
-        let world_pos = transform.translation() + Vec3::Y * visual.name_y_offset;
-        let through_walls = remote.kind == NetEntityKind::Player;
-        if !through_walls && !view_visibility.get() {
+        let world_pos = sleeping.map_or_else(
+            || transform.translation() + Vec3::Y * visual.name_y_offset,
+            RemoteSleeping::name_position,
+        );
+        let through_walls = is_player;
+        if !through_walls && !always_shown && !view_visibility.get() {
             continue;
         }
-        if !through_walls
-            && transform.translation().distance(cam_pos) > NON_PLAYER_NAME_MAX_DISTANCE
-        {
+        let max_distance = if always_shown {
+            CUSTOM_NAME_MAX_DISTANCE
+        } else {
+            NON_PLAYER_NAME_MAX_DISTANCE
+        };
+        if !through_walls && transform.translation().distance(cam_pos) > max_distance {
             continue;
         }
         if !through_walls && line_of_sight_blocked(&collision_map, cam_pos, world_pos) {
@@ -293,7 +293,7 @@ pub fn draw_remote_entity_names(
         painter.text(
             pos,
             egui::Align2::CENTER_BOTTOM,
-            &name.0,
+            custom_name.as_deref().unwrap_or(&name.0),
             egui::TextStyle::Body.resolve(&ctx.style()),
             if through_walls {
                 egui::Color32::from_white_alpha(PLAYER_NAME_ALPHA)
@@ -308,21 +308,25 @@ fn entity_kind_uses_model(kind: NetEntityKind) -> bool {
     match kind {
         NetEntityKind::Player => true,
         NetEntityKind::Mob(mob) => mob_uses_entity_model(mob),
+        NetEntityKind::Object(obj) => object_uses_model(obj),
         _ => false,
     }
 }
 
 pub fn apply_item_sprite_textures_system(
     mut cache: ResMut<ItemTextureCache>,
+    animation: Res<ItemAnimationState>,
     mut query: Query<(&ItemSpriteStack, &mut MeshMaterial3d<StandardMaterial>)>,
 ) {
     for (stack, mut material) in &mut query {
-        cache.request_stack(&stack.0);
-        if let Some(handle) = cache.material_for_stack(&stack.0) {
+        let display = animation.display_stack(&stack.0);
+        let stack = display.as_ref().unwrap_or(&stack.0);
+        cache.request_stack(stack);
+        if let Some(handle) = cache.material_for_stack(stack) {
             if material.0 != handle {
                 material.0 = handle;
             }
-            crate::item_textures::log_item_texture_resolution(&mut cache, &stack.0);
+            crate::item_textures::log_item_texture_resolution(&mut cache, stack);
         }
     }
 }
@@ -341,6 +345,26 @@ pub fn apply_entity_model_textures_system(
     }
 }
 
+pub fn apply_entity_layer_textures_system(
+    mut cache: ResMut<EntityTextureCache>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(&EntityLayerTexturePath, &MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (path, material) in &query {
+        cache.request(path.0);
+        let Some(texture) = cache.texture(path.0) else {
+            continue;
+        };
+        if materials
+            .get(&material.0)
+            .is_some_and(|layer| layer.base_color_texture.as_ref() != Some(&texture))
+            && let Some(layer) = materials.get_mut(&material.0)
+        {
+            layer.base_color_texture = Some(texture);
+        }
+    }
+}
+
 pub fn update_remote_sheep_wool_system(
     mut cache: ResMut<EntityTextureCache>,
     mut materials: ResMut<Assets<StandardMaterial>>,
@@ -0,0 +1,114 @@
+use super::*;
+use crate::armor_stands::{ARMOR_STAND_TEXTURE_PATH, armor_stand_rig};
+use crate::arthropods::spawn_arthropod_model;
+use crate::block_entities::is_block_entity;
+use crate::floating_mobs::spawn_floating_mob_model;
+use crate::item_frames::spawn_item_frame;
+use crate::model::{ARMOR_STAND_MODEL, BOAT_MODEL, MINECART_MODEL, ModelDef};
+use crate::projectiles::{projectile_visual, spawn_projectile_visual};
+use crate::slimes::spawn_slime_model;
+use crate::specs::VisualSpec;
+use rs_utils::ObjectKind;
+
+/// Nonzero spawn data of an object entity, see `NetEntityMessage::ObjectData`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteObjectData(pub i32);
+
+/// The cached cutout material for an entity texture, or a magenta stand-in until it loads.
+pub(crate) fn entity_model_material(
+    materials: &mut Assets<StandardMaterial>,
+    entity_textures: &mut EntityTextureCache,
+    texture_path: &'static str,
+) -> Handle<StandardMaterial> {
+    entity_textures.request(texture_path);
+    entity_textures.material(texture_path).unwrap_or_else(|| {
+        materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.0, 1.0),
+            alpha_mode: AlphaMode::Mask(0.5),
+            unlit: true,
+            perceptual_roughness: 1.0,
+            metallic: 0.0,
+            ..Default::default()
+        })
+    })
+}
+
+/// Object entities drawn with a vanilla cuboid model instead of the placeholder sphere.
+pub(crate) fn object_model(kind: ObjectKind) -> Option<(&'static ModelDef, &'static str)> {
+    match kind {
+        ObjectKind::Boat => Some((&BOAT_MODEL, "entity/boat.png")),
+        ObjectKind::Minecart => Some((&MINECART_MODEL, "entity/minecart.png")),
+        ObjectKind::ArmorStand => Some((&ARMOR_STAND_MODEL, ARMOR_STAND_TEXTURE_PATH)),
+        _ => None,
+    }
+}
+
+pub(crate) fn object_uses_model(kind: ObjectKind) -> bool {
+    // Paintings and item frames build their own meshes (see `paintings`, `item_frames`),
+    // falling blocks and TNT use the block mesher (see `block_entities`).
+    object_model(kind).is_some()
+        || matches!(
+            kind,
+            ObjectKind::Painting
+                | ObjectKind::ItemFrame
+                | ObjectKind::FallingBlock
+                | ObjectKind::PrimedTnt
+        )
+        || projectile_visual(kind).is_some()
+}
+
+/// Attaches the visual for a non-living entity or a mob without a biped or quadruped rig to
+/// `root`: its vanilla model or projectile sprite when we have one, otherwise the colored
+/// placeholder mesh from its `VisualSpec`.
+pub(crate) fn spawn_object_visual(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_textures: &mut EntityTextureCache,
+    root: Entity,
+    kind: NetEntityKind,
+    spec: VisualSpec,
+) {
+    if kind == NetEntityKind::Object(ObjectKind::ItemFrame) {
+        spawn_item_frame(commands, meshes, root);
+        return;
+    }
+    if let NetEntityKind::Mob(mob) = kind
+        && (spawn_arthropod_model(commands, meshes, materials, entity_textures, root, mob)
+            || spawn_slime_model(commands, meshes, materials, entity_textures, root, mob)
+            || spawn_floating_mob_model(commands, meshes, materials, entity_textures, root, mob))
+    {
+        return;
+    }
+    if let NetEntityKind::Object(obj) = kind
+        && (is_block_entity(obj)
+            || spawn_projectile_visual(commands, meshes, materials, entity_textures, root, obj))
+    {
+        return;
+    }
+    if let NetEntityKind::Object(obj) = kind
+        && let Some((model, texture_path)) = object_model(obj)
+    {
+        let material = entity_model_material(materials, entity_textures, texture_path);
+        let spawned = spawn_model(commands, meshes, material, model, texture_path);
+        commands.entity(root).add_child(spawned.root);
+        if obj == ObjectKind::ArmorStand {
+            commands.entity(root).insert(armor_stand_rig(&spawned));
+        }
+        return;
+    }
+
+    let mesh = meshes.add(match spec.mesh {
+        VisualMesh::Capsule => Mesh::from(Capsule3d::default()),
+        VisualMesh::Sphere => Mesh::from(Sphere::default()),
+    });
+    let material = materials.add(StandardMaterial {
+        base_color: spec.color,
+        perceptual_roughness: 0.95,
+        metallic: 0.0,
+        ..Default::default()
+    });
+    commands
+        .entity(root)
+        .insert((Mesh3d(mesh), MeshMaterial3d(material)));
+}
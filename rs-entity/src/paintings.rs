@@ -0,0 +1,229 @@
+use super::*;
+use crate::model::EntityTexturePath;
+use rs_utils::{ObjectKind, PaintingArt, painting_half_extents};
+
+const PAINTING_TEXTURE_PATH: &str = "painting/paintings_kristoffer_zetterstrand.png";
+const ATLAS_SIZE: f32 = 256.0;
+/// `RenderPainting` takes the back and edges from the 16x16 tile at (192, 0); edges use a
+/// single texel row/column of it.
+const BACK_U: f32 = 192.0;
+const EDGE_TEXEL: f32 = 0.5;
+const PX: f32 = 1.0 / 16.0;
+
+/// Spawns (or replaces) a painting entity centered on `pos`, facing out of the wall along
+/// `facing`.
+pub(crate) fn spawn_painting(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_textures: &mut EntityTextureCache,
+    registry: &mut RemoteEntityRegistry,
+    (entity_id, art, pos, facing): (i32, PaintingArt, Vec3, u8),
+) {
+    if let Some(existing) = registry.by_server_id.remove(&entity_id) {
+        commands.entity(existing).despawn();
+    }
+    // Local +Z is the front; the protocol index counts quarter turns from south.
+    let yaw = -f32::from(facing & 3) * std::f32::consts::FRAC_PI_2;
+    let kind = NetEntityKind::Object(ObjectKind::Painting);
+
+    entity_textures.request(PAINTING_TEXTURE_PATH);
+    let material = entity_textures
+        .material(PAINTING_TEXTURE_PATH)
+        .unwrap_or_else(|| {
+            materials.add(StandardMaterial {
+                base_color: Color::srgb(0.55, 0.42, 0.30),
+                unlit: true,
+                perceptual_roughness: 1.0,
+                metallic: 0.0,
+                ..Default::default()
+            })
+        });
+    let mesh = commands
+        .spawn((
+            Name::new("PaintingMesh"),
+            Mesh3d(meshes.add(painting_mesh(&art))),
+            MeshMaterial3d(material),
+            Transform::IDENTITY,
+            GlobalTransform::default(),
+            Visibility::Inherited,
+            InheritedVisibility::default(),
+            ViewVisibility::default(),
+            EntityTexturePath(PAINTING_TEXTURE_PATH),
+        ))
+        .id();
+
+    let root = commands
+        .spawn((
+            Name::new(format!("RemoteEntity[{entity_id}]")),
+            Transform::from_translation(pos).with_rotation(Quat::from_rotation_y(yaw)),
+            GlobalTransform::default(),
+            Visibility::Visible,
+            InheritedVisibility::default(),
+            ViewVisibility::default(),
+            RemoteEntity {
+                server_id: entity_id,
+                kind,
+                on_ground: false,
+            },
+            RemoteEntityLook {
+                yaw,
+                pitch: 0.0,
+                head_yaw: yaw,
+            },
+            RemoteEntityName(kind_label(kind).to_string()),
+            RemoteVisual {
+                y_offset: 0.0,
+                name_y_offset: art.height as f32 / 32.0,
+            },
+            RemoteHitbox {
+                half_extents: painting_half_extents(&art, facing),
+            },
+        ))
+        .add_child(mesh)
+        .id();
+    registry.by_server_id.insert(entity_id, root);
+}
+
+/// One-pixel-thick board: the motif on the front, the tiled back texture behind it and edge
+/// strips around the border.
+fn painting_mesh(art: &PaintingArt) -> Mesh {
+    let half_w = art.width as f32 * PX / 2.0;
+    let half_h = art.height as f32 * PX / 2.0;
+    let half_d = PX / 2.0;
+    let mut builder = QuadBuilder::new(ATLAS_SIZE);
+
+    builder.quad(
+        [
+            [-half_w, -half_h, half_d],
+            [half_w, -half_h, half_d],
+            [half_w, half_h, half_d],
+            [-half_w, half_h, half_d],
+        ],
+        [0.0, 0.0, 1.0],
+        [
+            art.u as f32,
+            (art.v + art.height) as f32,
+            (art.u + art.width) as f32,
+            art.v as f32,
+        ],
+    );
+
+    let tiles_x = (art.width / 16) as usize;
+    let tiles_y = (art.height / 16) as usize;
+    for tx in 0..tiles_x {
+        for ty in 0..tiles_y {
+            let x0 = -half_w + tx as f32;
+            let y0 = -half_h + ty as f32;
+            let (x1, y1) = (x0 + 1.0, y0 + 1.0);
+            // Seen from behind, so the tile runs right-to-left.
+            builder.quad(
+                [
+                    [x1, y0, -half_d],
+                    [x0, y0, -half_d],
+                    [x0, y1, -half_d],
+                    [x1, y1, -half_d],
+                ],
+                [0.0, 0.0, -1.0],
+                [BACK_U, 16.0, BACK_U + 16.0, 0.0],
+            );
+            let row = [BACK_U, EDGE_TEXEL, BACK_U + 16.0, EDGE_TEXEL];
+            let column = [BACK_U + EDGE_TEXEL, 16.0, BACK_U + EDGE_TEXEL, 0.0];
+            if ty + 1 == tiles_y {
+                builder.quad(
+                    [
+                        [x0, y1, half_d],
+                        [x1, y1, half_d],
+                        [x1, y1, -half_d],
+                        [x0, y1, -half_d],
+                    ],
+                    [0.0, 1.0, 0.0],
+                    row,
+                );
+            }
+            if ty == 0 {
+                builder.quad(
+                    [
+                        [x0, y0, -half_d],
+                        [x1, y0, -half_d],
+                        [x1, y0, half_d],
+                        [x0, y0, half_d],
+                    ],
+                    [0.0, -1.0, 0.0],
+                    row,
+                );
+            }
+            if tx == 0 {
+                builder.quad(
+                    [
+                        [x0, y0, -half_d],
+                        [x0, y0, half_d],
+                        [x0, y1, half_d],
+                        [x0, y1, -half_d],
+                    ],
+                    [-1.0, 0.0, 0.0],
+                    column,
+                );
+            }
+            if tx + 1 == tiles_x {
+                builder.quad(
+                    [
+                        [x1, y0, half_d],
+                        [x1, y0, -half_d],
+                        [x1, y1, -half_d],
+                        [x1, y1, half_d],
+                    ],
+                    [1.0, 0.0, 0.0],
+                    column,
+                );
+            }
+        }
+    }
+    builder.build()
+}
+
+/// Textured quads on one square texture, for the hanging entities' hand-built meshes.
+pub(crate) struct QuadBuilder {
+    texture_size: f32,
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+impl QuadBuilder {
+    /// `texture_size` is the texture's width and height in pixels.
+    pub(crate) fn new(texture_size: f32) -> Self {
+        Self {
+            texture_size,
+            positions: Vec::new(),
+            normals: Vec::new(),
+            uvs: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+
+    /// Corners counter-clockwise from bottom-left as seen from the front; `uv` is
+    /// `[u_left, v_bottom, u_right, v_top]` in texture pixels.
+    pub(crate) fn quad(&mut self, corners: [[f32; 3]; 4], normal: [f32; 3], uv: [f32; 4]) {
+        let base = self.positions.len() as u32;
+        let [u0, v0, u1, v1] = uv.map(|c| c / self.texture_size);
+        self.positions.extend(corners);
+        self.normals.extend([normal; 4]);
+        self.uvs.extend([[u0, v0], [u1, v0], [u1, v1], [u0, v1]]);
+        self.indices
+            .extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    pub(crate) fn build(self) -> Mesh {
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs);
+        mesh.insert_indices(Indices::U32(self.indices));
+        mesh
+    }
+}
@@ -0,0 +1,387 @@
+use super::*;
+use crate::item_textures::build_item_quad_mesh;
+use crate::model::WITHER_SKULL_MODEL;
+use rs_utils::ObjectKind;
+
+const ARROW_TEXTURE_PATH: &str = "entity/arrow.png";
+const WITHER_SKULL_TEXTURE_PATH: &str = "entity/wither/wither.png";
+const BOBBER_TEXTURE_PATH: &str = "particle/particles.png";
+/// `RenderArrow` draws 16px quads scaled by 0.05625.
+const ARROW_SCALE: f32 = 0.05625;
+/// The arrow sticks once the block 7px ahead of its centre is solid.
+const ARROW_TIP_DISTANCE: f32 = 7.0 * ARROW_SCALE;
+const SPRITE_ITEM_ID_SNOWBALL: i32 = 332;
+const SPRITE_ITEM_ID_EGG: i32 = 344;
+const SPRITE_ITEM_ID_ENDER_PEARL: i32 = 368;
+const SPRITE_ITEM_ID_POTION: i32 = 373;
+const SPRITE_ITEM_ID_EYE_OF_ENDER: i32 = 381;
+const SPRITE_ITEM_ID_EXP_BOTTLE: i32 = 384;
+const SPRITE_ITEM_ID_FIRE_CHARGE: i32 = 385;
+/// Rod tip relative to the angler's feet, in their yaw frame (right, up, forward is -Z).
+const ROD_TIP_OFFSET: Vec3 = Vec3::new(0.35, 1.5, -0.8);
+const FISHING_LINE_SEGMENTS: usize = 16;
+const FISHING_LINE_COLOR: Color = Color::srgb(0.08, 0.08, 0.08);
+const TRAJECTORY_MAX_TICKS: usize = 100;
+const TRAJECTORY_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// How a projectile object is drawn instead of the placeholder sphere.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectileVisual {
+    /// `RenderArrow`'s crossed quads, pointed along the flight direction.
+    Arrow,
+    /// Camera-facing item icon (`RenderSnowball`, `RenderFireball`) at the given size in blocks.
+    Sprite { item_id: i32, scale: f32 },
+    /// Wither head cube, pointed along the flight direction.
+    Skull,
+    /// Camera-facing bobber from the particle sheet, tied to the angler's rod.
+    Bobber,
+}
+
+pub fn projectile_visual(kind: ObjectKind) -> Option<ProjectileVisual> {
+    let sprite = |item_id, scale| Some(ProjectileVisual::Sprite { item_id, scale });
+    match kind {
+        ObjectKind::Arrow => Some(ProjectileVisual::Arrow),
+        ObjectKind::Snowball => sprite(SPRITE_ITEM_ID_SNOWBALL, 0.5),
+        ObjectKind::Egg => sprite(SPRITE_ITEM_ID_EGG, 0.5),
+        ObjectKind::EnderPearl => sprite(SPRITE_ITEM_ID_ENDER_PEARL, 0.5),
+        ObjectKind::EnderEye => sprite(SPRITE_ITEM_ID_EYE_OF_ENDER, 0.5),
+        ObjectKind::SplashPotion => sprite(SPRITE_ITEM_ID_POTION, 0.5),
+        ObjectKind::ExpBottle => sprite(SPRITE_ITEM_ID_EXP_BOTTLE, 0.5),
+        ObjectKind::LargeFireball => sprite(SPRITE_ITEM_ID_FIRE_CHARGE, 2.0),
+        ObjectKind::SmallFireball => sprite(SPRITE_ITEM_ID_FIRE_CHARGE, 0.5),
+        ObjectKind::WitherSkull => Some(ProjectileVisual::Skull),
+        ObjectKind::FishingHook => Some(ProjectileVisual::Bobber),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StuckArrow {
+    translation: Vec3,
+    rotation: Quat,
+    /// Server position when it stuck; a new one frees the arrow again.
+    server_translation: Vec3,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct RemoteProjectile {
+    pub kind: ObjectKind,
+    pub visual: ProjectileVisual,
+    stuck: Option<StuckArrow>,
+}
+
+impl RemoteProjectile {
+    pub fn is_stuck(&self) -> bool {
+        self.stuck.is_some()
+    }
+}
+
+/// Spawns the projectile's model or sprite on `root`; `false` for non-projectile objects.
+pub(crate) fn spawn_projectile_visual(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_textures: &mut EntityTextureCache,
+    root: Entity,
+    kind: ObjectKind,
+) -> bool {
+    let Some(visual) = projectile_visual(kind) else {
+        return false;
+    };
+    commands.entity(root).insert(RemoteProjectile {
+        kind,
+        visual,
+        stuck: None,
+    });
+    match visual {
+        ProjectileVisual::Arrow => {
+            entity_textures.request(ARROW_TEXTURE_PATH);
+            let arrow = commands
+                .spawn((
+                    Name::new("RemoteArrow"),
+                    Mesh3d(meshes.add(arrow_mesh())),
+                    MeshMaterial3d::<StandardMaterial>::default(),
+                    Transform::IDENTITY,
+                    GlobalTransform::default(),
+                    Visibility::Inherited,
+                    InheritedVisibility::default(),
+                    ViewVisibility::default(),
+                    EntityTexturePath(ARROW_TEXTURE_PATH),
+                ))
+                .id();
+            commands.entity(root).add_child(arrow);
+        }
+        ProjectileVisual::Sprite { item_id, .. } => {
+            let material = materials.add(StandardMaterial {
+                base_color: Color::WHITE,
+                alpha_mode: AlphaMode::Mask(0.5),
+                cull_mode: None,
+                unlit: true,
+                perceptual_roughness: 1.0,
+                metallic: 0.0,
+                ..Default::default()
+            });
+            commands.entity(root).insert((
+                Mesh3d(meshes.add(build_item_quad_mesh())),
+                MeshMaterial3d(material),
+                ItemSpriteStack(InventoryItemStack {
+                    item_id,
+                    count: 1,
+                    damage: 0,
+                    meta: Default::default(),
+                }),
+            ));
+        }
+        ProjectileVisual::Skull => {
+            entity_textures.request(WITHER_SKULL_TEXTURE_PATH);
+            let material = entity_textures
+                .material(WITHER_SKULL_TEXTURE_PATH)
+                .unwrap_or_default();
+            let spawned = spawn_model(
+                commands,
+                meshes,
+                material,
+                &WITHER_SKULL_MODEL,
+                WITHER_SKULL_TEXTURE_PATH,
+            );
+            commands.entity(root).add_child(spawned.root);
+        }
+        ProjectileVisual::Bobber => {
+            entity_textures.request(BOBBER_TEXTURE_PATH);
+            commands.entity(root).insert((
+                Mesh3d(meshes.add(bobber_mesh())),
+                MeshMaterial3d::<StandardMaterial>::default(),
+                EntityTexturePath(BOBBER_TEXTURE_PATH),
+            ));
+        }
+    }
+    true
+}
+
+type ProjectileQuery = (
+    &'static RemoteEntityLook,
+    &'static RemoteMotionBuffer,
+    &'static mut RemoteProjectile,
+    &'static mut Transform,
+    Option<&'static mut ItemSpriteStack>,
+    Option<&'static RemoteObjectData>,
+);
+
+/// Points arrows and skulls along their flight, turns sprites to the camera, and pins arrows
+/// that hit a block until the server moves them again.
+pub fn update_remote_projectiles(
+    collision_map: Res<WorldCollisionMap>,
+    camera: Query<&GlobalTransform, With<PlayerCamera>>,
+    mut projectiles: Query<ProjectileQuery>,
+) {
+    let camera_pos = camera.single().ok().map(GlobalTransform::translation);
+    for (look, buffer, mut projectile, mut transform, sprite, data) in &mut projectiles {
+        match projectile.visual {
+            ProjectileVisual::Sprite { scale, .. } => {
+                transform.scale = Vec3::splat(scale);
+                // Splash potions carry their potion damage as spawn data.
+                if let (Some(mut sprite), Some(data)) = (sprite, data)
+                    && projectile.kind == ObjectKind::SplashPotion
+                    && i32::from(sprite.0.damage) != data.0
+                {
+                    sprite.0.damage = data.0 as i16;
+                }
+                face_camera(&mut transform, camera_pos);
+            }
+            ProjectileVisual::Bobber => {
+                transform.scale = Vec3::splat(0.5);
+                face_camera(&mut transform, camera_pos);
+            }
+            ProjectileVisual::Arrow | ProjectileVisual::Skull => {
+                if let Some(stuck) = projectile.stuck {
+                    if buffer.latest().distance_squared(stuck.server_translation) < 1e-4 {
+                        transform.translation = stuck.translation;
+                        transform.rotation = stuck.rotation;
+                        continue;
+                    }
+                    projectile.stuck = None;
+                }
+                let velocity = buffer.velocity();
+                let heading = if velocity.length_squared() > 1e-6 {
+                    velocity
+                } else {
+                    // Arrows and skulls store yaw/pitch from their motion with both signs
+                    // flipped relative to living entities.
+                    Quat::from_euler(EulerRot::YXZ, -look.yaw, -look.pitch, 0.0) * Vec3::NEG_Z
+                };
+                transform.rotation = Transform::IDENTITY.looking_to(heading, Vec3::Y).rotation;
+                if projectile.visual != ProjectileVisual::Arrow {
+                    continue;
+                }
+                let tip = transform.translation + transform.forward() * ARROW_TIP_DISTANCE;
+                let cell = tip.floor().as_ivec3();
+                if is_solid(collision_map.block_at(cell.x, cell.y, cell.z)) {
+                    projectile.stuck = Some(StuckArrow {
+                        translation: transform.translation,
+                        rotation: transform.rotation,
+                        server_translation: buffer.latest(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn face_camera(transform: &mut Transform, camera_pos: Option<Vec3>) {
+    let Some(camera_pos) = camera_pos else {
+        return;
+    };
+    let away = transform.translation - camera_pos;
+    if away.length_squared() > 1e-6 {
+        transform.rotation = Transform::IDENTITY.looking_to(away, Vec3::Y).rotation;
+    }
+}
+
+/// Draws the line from each fishing hook to its angler's rod tip, sagging like `RenderFish`.
+pub fn draw_fishing_lines(
+    mut gizmos: Gizmos,
+    registry: Res<RemoteEntityRegistry>,
+    hooks: Query<(&RemoteProjectile, &RemoteObjectData, &Transform)>,
+    anglers: Query<(&RemoteEntityLook, &RemoteVisual, &Transform)>,
+    local_player: Query<(&Transform, &LookAngles), With<Player>>,
+) {
+    for (hook, &RemoteObjectData(angler_id), hook_transform) in &hooks {
+        if hook.visual != ProjectileVisual::Bobber {
+            continue;
+        }
+        let rod_tip = if registry.local_entity_id == Some(angler_id) {
+            let Ok((player, look)) = local_player.single() else {
+                continue;
+            };
+            player.translation + Quat::from_rotation_y(look.yaw) * ROD_TIP_OFFSET
+        } else {
+            let Some((look, visual, transform)) = registry
+                .by_server_id
+                .get(&angler_id)
+                .and_then(|entity| anglers.get(*entity).ok())
+            else {
+                continue;
+            };
+            let feet = transform.translation - Vec3::Y * visual.y_offset;
+            feet + Quat::from_rotation_y(look.yaw) * ROD_TIP_OFFSET
+        };
+        let bobber = hook_transform.translation;
+        let sag = (rod_tip.distance(bobber) * 0.05).min(0.8);
+        gizmos.linestrip(
+            (0..=FISHING_LINE_SEGMENTS).map(|i| {
+                let t = i as f32 / FISHING_LINE_SEGMENTS as f32;
+                rod_tip.lerp(bobber, t) - Vec3::Y * (sag * 4.0 * t * (1.0 - t))
+            }),
+            FISHING_LINE_COLOR,
+        );
+    }
+}
+
+/// Debug view: the path each in-flight projectile would take under its gravity and drag,
+/// up to the first solid block.
+pub fn draw_projectile_trajectories(
+    mut gizmos: Gizmos,
+    render_debug: Res<RenderDebugSettings>,
+    collision_map: Res<WorldCollisionMap>,
+    projectiles: Query<(&RemoteProjectile, &RemoteMotionBuffer, &Transform)>,
+) {
+    if !render_debug.show_projectile_trajectories {
+        return;
+    }
+    for (projectile, buffer, transform) in &projectiles {
+        if projectile.is_stuck() || buffer.velocity().length_squared() < 1e-6 {
+            continue;
+        }
+        let class = buffer.class();
+        let (mut pos, mut vel) = (transform.translation, buffer.velocity());
+        let mut points = vec![pos];
+        for _ in 0..TRAJECTORY_MAX_TICKS {
+            (pos, vel) = class.step(pos, vel, 1.0);
+            points.push(pos);
+            let cell = pos.floor().as_ivec3();
+            if is_solid(collision_map.block_at(cell.x, cell.y, cell.z)) {
+                break;
+            }
+        }
+        gizmos.linestrip(points, TRAJECTORY_COLOR);
+    }
+}
+
+/// `RenderArrow`'s geometry with the tip towards -Z: two shaft quads crossed at 45 degrees
+/// around the shaft plus the square fletching end, each wound both ways.
+fn arrow_mesh() -> Mesh {
+    const SHAFT_V: f32 = 5.0 / 32.0;
+    const BACK_U: f32 = 5.0 / 32.0;
+    const BACK_V: f32 = 10.0 / 32.0;
+    let half = 8.0 * ARROW_SCALE;
+    let width = 2.0 * ARROW_SCALE;
+    let mut quads: Vec<([Vec3; 4], [[f32; 2]; 4])> = Vec::new();
+    for side in [Vec3::new(1.0, 1.0, 0.0), Vec3::new(1.0, -1.0, 0.0)] {
+        let side = side.normalize() * width;
+        quads.push((
+            [
+                Vec3::Z * half - side,
+                Vec3::NEG_Z * half - side,
+                Vec3::NEG_Z * half + side,
+                Vec3::Z * half + side,
+            ],
+            [[0.0, 0.0], [0.5, 0.0], [0.5, SHAFT_V], [0.0, SHAFT_V]],
+        ));
+    }
+    let back = Vec3::Z * 7.0 * ARROW_SCALE;
+    quads.push((
+        [
+            back + Vec3::new(-width, -width, 0.0),
+            back + Vec3::new(width, -width, 0.0),
+            back + Vec3::new(width, width, 0.0),
+            back + Vec3::new(-width, width, 0.0),
+        ],
+        [
+            [0.0, SHAFT_V],
+            [BACK_U, SHAFT_V],
+            [BACK_U, BACK_V],
+            [0.0, BACK_V],
+        ],
+    ));
+    double_sided_quads_mesh(&quads)
+}
+
+/// `RenderFish` draws the 8x8 bobber at (8, 16) of the 128x128 particle sheet.
+fn bobber_mesh() -> Mesh {
+    const U0: f32 = 8.0 / 128.0;
+    const U1: f32 = 16.0 / 128.0;
+    const V0: f32 = 16.0 / 128.0;
+    const V1: f32 = 24.0 / 128.0;
+    double_sided_quads_mesh(&[(
+        [
+            Vec3::new(-0.5, -0.5, 0.0),
+            Vec3::new(0.5, -0.5, 0.0),
+            Vec3::new(0.5, 0.5, 0.0),
+            Vec3::new(-0.5, 0.5, 0.0),
+        ],
+        [[U0, V1], [U1, V1], [U1, V0], [U0, V0]],
+    )])
+}
+
+fn double_sided_quads_mesh(quads: &[([Vec3; 4], [[f32; 2]; 4])]) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    for (corners, quad_uvs) in quads {
+        let base = positions.len() as u32;
+        positions.extend(corners.map(|corner| corner.to_array()));
+        uvs.extend(quad_uvs);
+        indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        indices.extend([base, base + 2, base + 1, base, base + 3, base + 2]);
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
@@ -23,4 +23,6 @@ pub fn remote_entity_connection_sync(
     registry.player_skin_url_by_uuid.clear();
     registry.player_skin_model_by_uuid.clear();
     registry.pending_labels.clear();
+    registry.vehicle_by_passenger.clear();
+    registry.leash_holder_by_entity.clear();
 }
@@ -1,5 +1,7 @@
 use super::*;
-use crate::motion::{update_item_motion_velocity, update_motion_velocity};
+use crate::motion::update_item_motion_velocity;
+use crate::remote_spawn::{RemoteSpawnAssets, spawn_remote_entity};
+use rs_utils::ObjectKind;
 
 fn has_explicit_item_texture(stack: &InventoryItemStack) -> bool {
     item_texture_candidates(stack.item_id, stack.damage)
@@ -8,7 +10,7 @@ fn has_explicit_item_texture(stack: &InventoryItemStack) -> bool {
         .any(|rel| texturepack_textures_root().join(rel).is_file())
 }
 
-fn block_display_quads_for_stack(stack: &InventoryItemStack) -> Option<Vec<IconQuad>> {
+pub(crate) fn block_display_quads_for_stack(stack: &InventoryItemStack) -> Option<Vec<IconQuad>> {
     let block_id = u16::try_from(stack.item_id).ok()?;
     block_registry_key(block_id)?;
     if has_explicit_item_texture(stack) {
@@ -79,333 +81,20 @@ pub fn apply_remote_entity_events(
                 registry.player_name_by_uuid.remove(&uuid);
                 registry.player_skin_model_by_uuid.remove(&uuid);
             }
-            NetEntityMessage::Spawn {
-                entity_id,
-                uuid,
-                kind,
-                pos,
-                yaw,
-                pitch,
-                on_ground,
-            } => {
-                if registry.local_entity_id == Some(entity_id) {
-                    continue;
-                }
-
-                if let Some(existing) = registry.by_server_id.remove(&entity_id) {
-                    commands.entity(existing).despawn_recursive();
-                    registry
-                        .player_entity_by_uuid
-                        .retain(|_, id| *id != entity_id);
-                }
-
-                let spec = visual_spec(kind);
-                let visual = visual_for_kind(kind);
-                let player_skin = if kind == NetEntityKind::Player {
-                    let url = uuid
-                        .as_ref()
-                        .and_then(|id| registry.player_skin_url_by_uuid.get(id));
-                    if let Some(url) = url {
-                        skin_downloader.request(url.clone());
-                        skin_downloader.skin_handle(url)
-                    } else {
-                        if let Some(id) = uuid.as_ref() {
-                            warn!("ENTITY player spawn without known skin url uuid={:?}", id);
-                        }
-                        None
-                    }
-                } else {
-                    None
-                };
-                let player_skin_model = uuid
-                    .as_ref()
-                    .and_then(|id| registry.player_skin_model_by_uuid.get(id))
-                    .copied()
-                    .unwrap_or(PlayerSkinModel::Classic);
-                let display_name = if kind == NetEntityKind::Player {
-                    uuid.as_ref()
-                        .and_then(|id| registry.player_name_by_uuid.get(id))
-                        .cloned()
-                        .unwrap_or_else(|| format!("Player {}", entity_id))
-                } else {
-                    registry
-                        .pending_labels
-                        .remove(&entity_id)
-                        .unwrap_or_else(|| kind_label(kind).to_string())
-                };
-
-                let biped_mob = match kind {
-                    NetEntityKind::Mob(m) if mob_uses_biped_model(m) => Some(m),
-                    _ => None,
-                };
-                let quadruped_mob = match kind {
-                    NetEntityKind::Mob(m) if mob_uses_quadruped_model(m) => Some(m),
-                    _ => None,
-                };
-                let uses_model_mesh = biped_mob.is_some() || quadruped_mob.is_some();
-                let root_translation = entity_root_translation(kind, pos, visual.y_offset);
-
-                let spawn_cmd = commands.spawn((
-                    Name::new(format!("RemoteEntity[{entity_id}]")),
-                    Transform {
-                        translation: root_translation,
-                        rotation: entity_root_rotation(kind, yaw),
-                        scale: if uses_model_mesh {
-                            match kind {
-                                NetEntityKind::Mob(mob) => mob_model_scale(mob),
-                                _ => Vec3::ONE,
-                            }
-                        } else {
-                            spec.scale
-                        },
-                    },
-                    GlobalTransform::default(),
-                    Visibility::Visible,
-                    InheritedVisibility::default(),
-                    ViewVisibility::default(),
-                    RemoteEntity {
-                        server_id: entity_id,
-                        kind,
-                        on_ground: on_ground.unwrap_or(false),
-                    },
-                    RemoteEntityLook {
-                        yaw,
-                        pitch,
-                        head_yaw: yaw,
-                    },
-                    RemoteEntityName(display_name),
-                    visual,
-                    RemotePoseState::default(),
-                ));
-                let root = spawn_cmd.id();
-
-                if kind == NetEntityKind::Player {
-                    commands
-                        .entity(root)
-                        .insert(RemoteMotionSmoothing::new(root_translation, now_secs));
-                    let (parts, material_handles) = spawn_remote_player_model(
-                        &mut commands,
-                        &mut meshes,
-                        &mut materials,
-                        player_skin,
-                        player_skin_model,
-                        &texture_debug,
-                    );
-                    commands.entity(root).add_child(parts.head);
-                    commands.entity(root).add_child(parts.body);
-                    commands.entity(root).add_child(parts.arm_left);
-                    commands.entity(root).add_child(parts.arm_right);
-                    commands.entity(root).add_child(parts.leg_left);
-                    commands.entity(root).add_child(parts.leg_right);
-                    commands.entity(root).insert((
-                        RemotePlayer,
-                        parts,
-                        HumanoidRigParts {
-                            kind: HumanoidRigKind::Player,
-                            model_root: root,
-                            head: parts.head,
-                            body: parts.body,
-                            arm_right: parts.arm_right,
-                            arm_left: parts.arm_left,
-                            leg_right: parts.leg_right,
-                            leg_left: parts.leg_left,
-                            render_layer: None,
-                        },
-                        HumanoidArmorState::default(),
-                        HumanoidArmorLayerEntities::default(),
-                        RemotePlayerSkinMaterials(material_handles),
-                        RemotePlayerAnimation {
-                            previous_pos: pos,
-                            walk_phase: 0.0,
-                            swing_progress: 1.0,
-                            hurt_progress: 1.0,
-                        },
-                        RemotePlayerSkinModel(player_skin_model),
-                    ));
-                } else {
-                    if kind == NetEntityKind::Item {
-                        // Dropped item sprite (texture applied once metadata arrives).
-                        let material = materials.add(StandardMaterial {
-                            base_color: Color::WHITE,
-                            alpha_mode: AlphaMode::Mask(0.5),
-                            cull_mode: None,
-                            unlit: true,
-                            perceptual_roughness: 1.0,
-                            metallic: 0.0,
-                            ..Default::default()
-                        });
-                        debug!(entity_id, pos = ?pos, "spawned dropped item placeholder awaiting metadata");
-                        commands.entity(root).insert((
-                            Mesh3d(item_sprite_mesh.0.clone()),
-                            MeshMaterial3d(material),
-                            RemoteItemSprite,
-                            ItemSpin::default(),
-                            RemoteDroppedItemMotion::new(root_translation, now_secs),
-                            Visibility::Hidden,
-                        ));
-                    } else if let Some(mob) = biped_mob {
-                        commands
-                            .entity(root)
-                            .insert(RemoteMotionSmoothing::new(root_translation, now_secs));
-                        let Some(texture_path) = mob_texture_path(mob) else {
-                            // Shouldn't happen since `biped_mob` is gated above.
-                            continue;
-                        };
-                        entity_textures.request(texture_path);
-                        let material =
-                            entity_textures.material(texture_path).unwrap_or_else(|| {
-                                materials.add(StandardMaterial {
-                                    base_color: Color::srgb(1.0, 0.0, 1.0),
-                                    alpha_mode: AlphaMode::Mask(0.5),
-                                    unlit: true,
-                                    perceptual_roughness: 1.0,
-                                    metallic: 0.0,
-                                    ..Default::default()
-                                })
-                            });
-
-                        let spawned = spawn_model(
-                            &mut commands,
-                            &mut meshes,
-                            material,
-                            mob_biped_model(mob),
-                            texture_path,
-                        );
-                        commands.entity(root).add_child(spawned.root);
-                        commands.entity(root).insert((
-                            RemoteBipedModelParts {
-                                model_root: spawned.root,
-                                head: spawned.parts[BIPED_HEAD],
-                                body: spawned.parts[BIPED_BODY],
-                                arm_right: spawned.parts[BIPED_RIGHT_ARM],
-                                arm_left: spawned.parts[BIPED_LEFT_ARM],
-                                leg_right: spawned.parts[BIPED_RIGHT_LEG],
-                                leg_left: spawned.parts[BIPED_LEFT_LEG],
-                            },
-                            HumanoidRigParts {
-                                kind: HumanoidRigKind::BipedMob,
-                                model_root: spawned.root,
-                                head: spawned.parts[BIPED_HEAD],
-                                body: spawned.parts[BIPED_BODY],
-                                arm_right: spawned.parts[BIPED_RIGHT_ARM],
-                                arm_left: spawned.parts[BIPED_LEFT_ARM],
-                                leg_right: spawned.parts[BIPED_RIGHT_LEG],
-                                leg_left: spawned.parts[BIPED_LEFT_LEG],
-                                render_layer: None,
-                            },
-                            HumanoidArmorState::default(),
-                            HumanoidArmorLayerEntities::default(),
-                            RemoteBipedAnimation {
-                                previous_pos: pos,
-                                limb_swing: 0.0,
-                                limb_swing_amount: 0.0,
-                                swing_progress: 1.0,
-                            },
-                        ));
-                    } else if let Some(mob) = quadruped_mob {
-                        commands
-                            .entity(root)
-                            .insert(RemoteMotionSmoothing::new(root_translation, now_secs));
-                        let Some(texture_path) = mob_texture_path(mob) else {
-                            // Shouldn't happen since `quadruped_mob` is gated above.
-                            continue;
-                        };
-                        entity_textures.request(texture_path);
-                        let material =
-                            entity_textures.material(texture_path).unwrap_or_else(|| {
-                                materials.add(StandardMaterial {
-                                    base_color: Color::srgb(1.0, 0.0, 1.0),
-                                    alpha_mode: AlphaMode::Mask(0.5),
-                                    unlit: true,
-                                    perceptual_roughness: 1.0,
-                                    metallic: 0.0,
-                                    ..Default::default()
-                                })
-                            });
-
-                        let spawned = spawn_model(
-                            &mut commands,
-                            &mut meshes,
-                            material,
-                            mob_quadruped_model(mob),
-                            texture_path,
-                        );
-                        commands.entity(root).add_child(spawned.root);
-                        commands.entity(root).insert((
-                            RemoteQuadrupedModelParts {
-                                model_root: spawned.root,
-                                head: spawned.parts[QUADRUPED_HEAD],
-                                body: spawned.parts[QUADRUPED_BODY],
-                                leg_front_right: spawned.parts[QUADRUPED_LEG_FRONT_RIGHT],
-                                leg_front_left: spawned.parts[QUADRUPED_LEG_FRONT_LEFT],
-                                leg_back_right: spawned.parts[QUADRUPED_LEG_BACK_RIGHT],
-                                leg_back_left: spawned.parts[QUADRUPED_LEG_BACK_LEFT],
-                            },
-                            RemoteQuadrupedAnimation {
-                                previous_pos: pos,
-                                limb_swing: 0.0,
-                                limb_swing_amount: 0.0,
-                            },
-                            mob_quadruped_anim_tuning(mob),
-                        ));
-                        if mob == MobKind::Sheep {
-                            let wool_material = materials.add(StandardMaterial {
-                                base_color: Color::WHITE,
-                                alpha_mode: AlphaMode::Mask(0.5),
-                                unlit: true,
-                                perceptual_roughness: 1.0,
-                                metallic: 0.0,
-                                ..Default::default()
-                            });
-                            entity_textures.request(SHEEP_WOOL_TEXTURE_PATH);
-                            let wool_mesh_entities = spawn_sheep_wool_layer(
-                                &mut commands,
-                                &mut meshes,
-                                spawned.parts,
-                                wool_material.clone(),
-                            );
-                            commands.entity(root).insert((
-                                RemoteSheepWoolLayer {
-                                    mesh_entities: wool_mesh_entities,
-                                    material: wool_material,
-                                },
-                                // Vanilla initializes sheep metadata byte (index 16) to 0:
-                                // white fleece and not sheared.
-                                RemoteSheepAppearance {
-                                    fleece_color: 0,
-                                    sheared: false,
-                                },
-                            ));
-                        }
-                    } else {
-                        commands
-                            .entity(root)
-                            .insert(RemoteMotionSmoothing::new(root_translation, now_secs));
-                        let mesh = meshes.add(match spec.mesh {
-                            VisualMesh::Capsule => Mesh::from(Capsule3d::default()),
-                            VisualMesh::Sphere => Mesh::from(Sphere::default()),
-                        });
-                        let material = materials.add(StandardMaterial {
-                            base_color: spec.color,
-                            perceptual_roughness: 0.95,
-                            metallic: 0.0,
-                            ..Default::default()
-                        });
-                        commands
-                            .entity(root)
-                            .insert((Mesh3d(mesh), MeshMaterial3d(material)));
-                    }
-                }
-
-                if let Some(uuid) = uuid {
-                    registry
-                        .player_entity_by_uuid
-                        .insert(uuid.clone(), entity_id);
-                    commands.entity(root).insert(RemoteEntityUuid(uuid));
-                }
-
-                registry.by_server_id.insert(entity_id, root);
-            }
+            spawn @ NetEntityMessage::Spawn { .. } => spawn_remote_entity(
+                &mut commands,
+                &mut registry,
+                RemoteSpawnAssets {
+                    meshes: &mut meshes,
+                    materials: &mut materials,
+                    entity_textures: &mut entity_textures,
+                    skin_downloader: &mut skin_downloader,
+                    item_sprite_mesh: &item_sprite_mesh,
+                    texture_debug: &texture_debug,
+                },
+                spawn,
+                now_secs,
+            ),
             NetEntityMessage::SetLabel { entity_id, label } => {
                 if let Some(entity) = registry.by_server_id.get(&entity_id).copied() {
                     if let Ok(mut name_comp) = params.name_query.get_mut(entity) {
@@ -461,22 +150,14 @@ pub fn apply_remote_entity_events(
                     }
                 }
             }
-            NetEntityMessage::SheepAppearance {
-                entity_id,
-                fleece_color,
-                sheared,
-            } => {
-                let Some(entity) = registry.by_server_id.get(&entity_id).copied() else {
-                    continue;
-                };
-                if let Ok((remote, _look)) = params.entity_query.get_mut(entity)
-                    && remote.kind == NetEntityKind::Mob(MobKind::Sheep)
+            NetEntityMessage::Metadata { entity_id, values } => {
+                // Spawn packets carry metadata too, so the entity may only exist as commands yet.
+                if let Some(entity) = registry.by_server_id.get(&entity_id).copied()
                     && let Ok(mut commands_entity) = commands.get_entity(entity)
                 {
-                    commands_entity.insert(RemoteSheepAppearance {
-                        fleece_color,
-                        sheared,
-                    });
+                    commands_entity
+                        .entry::<RemoteEntityMetadata>()
+                        .and_modify(move |mut metadata| metadata.0.apply(&values));
                 }
             }
             NetEntityMessage::MoveDelta {
@@ -489,10 +170,8 @@ pub fn apply_remote_entity_events(
                         let previous = item_motion.authoritative_translation;
                         let next = previous + delta;
                         update_item_motion_velocity(&mut item_motion, previous, next, now_secs);
-                    } else if let Ok(mut smoothing) = params.smoothing_query.get_mut(entity) {
-                        let previous = smoothing.target_translation;
-                        let next = previous + delta;
-                        update_motion_velocity(&mut smoothing, previous, next, now_secs);
+                    } else if let Ok(mut buffer) = params.motion_buffer_query.get_mut(entity) {
+                        buffer.push_relative(delta, now_secs);
                     } else if let Ok(mut transform) = params.transform_query.get_mut(entity) {
                         transform.translation += delta;
                     }
@@ -561,15 +240,8 @@ pub fn apply_remote_entity_events(
                                 transform.translation = target;
                                 item_motion.render_translation = target;
                             }
-                        } else if let Ok(mut smoothing) = params.smoothing_query.get_mut(entity) {
-                            let previous = smoothing.target_translation;
-                            update_motion_velocity(&mut smoothing, previous, target, now_secs);
-                            // Big teleports should still snap to avoid long catch-up.
-                            if let Ok(mut transform) = params.transform_query.get_mut(entity)
-                                && transform.translation.distance_squared(target) > 64.0
-                            {
-                                transform.translation = target;
-                            }
+                        } else if let Ok(mut buffer) = params.motion_buffer_query.get_mut(entity) {
+                            buffer.teleport(target, now_secs);
                         } else if let Ok(mut transform) = params.transform_query.get_mut(entity) {
                             transform.translation = target;
                         }
@@ -590,23 +262,26 @@ pub fn apply_remote_entity_events(
                 entity_id,
                 velocity,
             } => {
-                if let Some(entity) = registry.by_server_id.get(&entity_id).copied()
-                    && let Ok(mut item_motion) = params.item_motion_query.get_mut(entity)
-                {
+                let Some(entity) = registry.by_server_id.get(&entity_id).copied() else {
+                    continue;
+                };
+                if let Ok(mut item_motion) = params.item_motion_query.get_mut(entity) {
                     debug!(entity_id, velocity = ?velocity, "received dropped item velocity");
                     item_motion.estimated_velocity = velocity;
                     item_motion.ground_contact = false;
                     item_motion.last_server_update_secs = now_secs;
+                } else if let Ok(mut commands_entity) = commands.get_entity(entity) {
+                    // Queued so the spawn velocity reaches a buffer inserted this frame.
+                    commands_entity
+                        .entry::<RemoteMotionBuffer>()
+                        .and_modify(move |mut buffer| buffer.set_velocity(velocity, now_secs));
                 }
             }
-            NetEntityMessage::Pose {
-                entity_id,
-                sneaking,
-            } => {
+            NetEntityMessage::ObjectData { entity_id, data } => {
                 if let Some(entity) = registry.by_server_id.get(&entity_id).copied()
                     && let Ok(mut commands_entity) = commands.get_entity(entity)
                 {
-                    commands_entity.insert(RemotePoseState { sneaking });
+                    commands_entity.insert(RemoteObjectData(data));
                 }
             }
             NetEntityMessage::Equipment {
@@ -638,10 +313,13 @@ pub fn apply_remote_entity_events(
                 let Ok((remote, _look)) = params.entity_query.get_mut(root) else {
                     continue;
                 };
-                if remote.kind != NetEntityKind::Player {
+                if !matches!(
+                    remote.kind,
+                    NetEntityKind::Player | NetEntityKind::Object(ObjectKind::ArmorStand)
+                ) {
                     continue;
                 }
-                let Ok(parts) = params.player_parts_query.get(root) else {
+                let Ok(parts) = params.rig_parts_query.get(root) else {
                     continue;
                 };
 
@@ -716,6 +394,9 @@ pub fn apply_remote_entity_events(
                 entity_id,
                 animation,
             } => {
+                if animation == NetEntityAnimation::LeaveBed {
+                    apply_leave_bed(&mut commands, &registry, &mut params.sleep, entity_id);
+                }
                 if let Some(entity) = registry.by_server_id.get(&entity_id).copied() {
                     if let Ok(mut anim) = params.player_anim_query.get_mut(entity) {
                         match animation {
@@ -752,9 +433,48 @@ pub fn apply_remote_entity_events(
                     });
                 }
             }
+            NetEntityMessage::Attach {
+                entity_id,
+                vehicle_id,
+                leash,
+            } => {
+                apply_entity_attach(
+                    &mut registry,
+                    &mut params.mount,
+                    entity_id,
+                    vehicle_id,
+                    leash,
+                );
+            }
+            NetEntityMessage::UseBed {
+                entity_id,
+                location,
+            } => apply_use_bed(
+                &mut commands,
+                &registry,
+                &mut params.sleep,
+                &params.collision,
+                entity_id,
+                location,
+            ),
+            NetEntityMessage::SpawnPainting {
+                entity_id,
+                art,
+                pos,
+                facing,
+                ..
+            } => spawn_painting(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &mut entity_textures,
+                &mut registry,
+                (entity_id, art, pos, facing),
+            ),
             NetEntityMessage::Destroy { entity_ids } => {
                 for entity_id in entity_ids {
                     registry.pending_labels.remove(&entity_id);
+                    forget_entity_links(&mut registry, &mut params.mount, entity_id);
                     if let Some(entity) = registry.by_server_id.remove(&entity_id) {
                         commands.entity(entity).despawn_recursive();
                     }
@@ -0,0 +1,346 @@
+use super::*;
+
+/// Assets and caches the model of a newly spawned remote entity is built from.
+pub(crate) struct RemoteSpawnAssets<'a> {
+    pub(crate) meshes: &'a mut Assets<Mesh>,
+    pub(crate) materials: &'a mut Assets<StandardMaterial>,
+    pub(crate) entity_textures: &'a mut EntityTextureCache,
+    pub(crate) skin_downloader: &'a mut RemoteSkinDownloader,
+    pub(crate) item_sprite_mesh: &'a ItemSpriteMesh,
+    pub(crate) texture_debug: &'a PlayerTextureDebugSettings,
+}
+
+/// Spawns the root and visual of a `NetEntityMessage::Spawn`, replacing any entity that had
+/// the same server id. Other messages are ignored.
+pub(crate) fn spawn_remote_entity(
+    commands: &mut Commands,
+    registry: &mut RemoteEntityRegistry,
+    assets: RemoteSpawnAssets,
+    event: NetEntityMessage,
+    now_secs: f64,
+) {
+    let NetEntityMessage::Spawn {
+        entity_id,
+        uuid,
+        kind,
+        pos,
+        yaw,
+        pitch,
+        on_ground,
+    } = event
+    else {
+        return;
+    };
+    let RemoteSpawnAssets {
+        meshes,
+        materials,
+        entity_textures,
+        skin_downloader,
+        item_sprite_mesh,
+        texture_debug,
+    } = assets;
+
+    if registry.local_entity_id == Some(entity_id) {
+        return;
+    }
+
+    if let Some(existing) = registry.by_server_id.remove(&entity_id) {
+        commands.entity(existing).despawn_recursive();
+        registry
+            .player_entity_by_uuid
+            .retain(|_, id| *id != entity_id);
+    }
+
+    let spec = visual_spec(kind);
+    let visual = visual_for_kind(kind);
+    let player_skin = if kind == NetEntityKind::Player {
+        let url = uuid
+            .as_ref()
+            .and_then(|id| registry.player_skin_url_by_uuid.get(id));
+        if let Some(url) = url {
+            skin_downloader.request(url.clone());
+            skin_downloader.skin_handle(url)
+        } else {
+            if let Some(id) = uuid.as_ref() {
+                warn!("ENTITY player spawn without known skin url uuid={:?}", id);
+            }
+            None
+        }
+    } else {
+        None
+    };
+    let player_skin_model = uuid
+        .as_ref()
+        .and_then(|id| registry.player_skin_model_by_uuid.get(id))
+        .copied()
+        .unwrap_or(PlayerSkinModel::Classic);
+    let display_name = if kind == NetEntityKind::Player {
+        uuid.as_ref()
+            .and_then(|id| registry.player_name_by_uuid.get(id))
+            .cloned()
+            .unwrap_or_else(|| format!("Player {}", entity_id))
+    } else {
+        registry
+            .pending_labels
+            .remove(&entity_id)
+            .unwrap_or_else(|| kind_label(kind).to_string())
+    };
+
+    let biped_mob = match kind {
+        NetEntityKind::Mob(m) if mob_uses_biped_model(m) => Some(m),
+        _ => None,
+    };
+    let quadruped_mob = match kind {
+        NetEntityKind::Mob(m) if mob_uses_quadruped_model(m) => Some(m),
+        _ => None,
+    };
+    let uses_model_mesh = biped_mob.is_some() || quadruped_mob.is_some();
+    let root_translation = entity_root_translation(kind, pos, visual.y_offset);
+
+    let spawn_cmd = commands.spawn((
+        Name::new(format!("RemoteEntity[{entity_id}]")),
+        Transform {
+            translation: root_translation,
+            rotation: entity_root_rotation(kind, yaw),
+            scale: if uses_model_mesh {
+                match kind {
+                    NetEntityKind::Mob(mob) => mob_model_scale(mob),
+                    _ => Vec3::ONE,
+                }
+            } else {
+                spec.scale
+            },
+        },
+        GlobalTransform::default(),
+        Visibility::Visible,
+        InheritedVisibility::default(),
+        ViewVisibility::default(),
+        RemoteEntity {
+            server_id: entity_id,
+            kind,
+            on_ground: on_ground.unwrap_or(false),
+        },
+        RemoteEntityLook {
+            yaw,
+            pitch,
+            head_yaw: yaw,
+        },
+        RemoteEntityName(display_name),
+        visual,
+        RemotePoseState::default(),
+        RemoteEntityMetadata(EntityMetadata::new(kind)),
+    ));
+    let root = spawn_cmd.id();
+    if kind != NetEntityKind::Item {
+        commands
+            .entity(root)
+            .insert(RemoteMotionBuffer::new(kind, root_translation, now_secs));
+    }
+
+    if kind == NetEntityKind::Player {
+        let (parts, material_handles) = spawn_remote_player_model(
+            commands,
+            meshes,
+            materials,
+            player_skin,
+            player_skin_model,
+            texture_debug,
+        );
+        commands.entity(root).add_child(parts.head);
+        commands.entity(root).add_child(parts.body);
+        commands.entity(root).add_child(parts.arm_left);
+        commands.entity(root).add_child(parts.arm_right);
+        commands.entity(root).add_child(parts.leg_left);
+        commands.entity(root).add_child(parts.leg_right);
+        commands.entity(root).insert((
+            RemotePlayer,
+            parts,
+            HumanoidRigParts {
+                kind: HumanoidRigKind::Player,
+                model_root: root,
+                head: parts.head,
+                body: parts.body,
+                arm_right: parts.arm_right,
+                arm_left: parts.arm_left,
+                leg_right: parts.leg_right,
+                leg_left: parts.leg_left,
+                render_layer: None,
+            },
+            HumanoidArmorState::default(),
+            HumanoidArmorLayerEntities::default(),
+            RemotePlayerSkinMaterials(material_handles),
+            RemotePlayerAnimation {
+                previous_pos: pos,
+                walk_phase: 0.0,
+                swing_progress: 1.0,
+                hurt_progress: 1.0,
+            },
+            RemotePlayerSkinModel(player_skin_model),
+        ));
+    } else {
+        if kind == NetEntityKind::Item {
+            // Dropped item sprite (texture applied once metadata arrives).
+            let material = materials.add(StandardMaterial {
+                base_color: Color::WHITE,
+                alpha_mode: AlphaMode::Mask(0.5),
+                cull_mode: None,
+                unlit: true,
+                perceptual_roughness: 1.0,
+                metallic: 0.0,
+                ..Default::default()
+            });
+            debug!(entity_id, pos = ?pos, "spawned dropped item placeholder awaiting metadata");
+            commands.entity(root).insert((
+                Mesh3d(item_sprite_mesh.0.clone()),
+                MeshMaterial3d(material),
+                RemoteItemSprite,
+                ItemSpin::default(),
+                RemoteDroppedItemMotion::new(root_translation, now_secs),
+                Visibility::Hidden,
+            ));
+        } else if let Some(mob) = biped_mob {
+            let Some(texture_path) = mob_texture_path(mob) else {
+                // Shouldn't happen since `biped_mob` is gated above.
+                return;
+            };
+            entity_textures.request(texture_path);
+            let material = entity_textures.material(texture_path).unwrap_or_else(|| {
+                materials.add(StandardMaterial {
+                    base_color: Color::srgb(1.0, 0.0, 1.0),
+                    alpha_mode: AlphaMode::Mask(0.5),
+                    unlit: true,
+                    perceptual_roughness: 1.0,
+                    metallic: 0.0,
+                    ..Default::default()
+                })
+            });
+
+            let spawned = spawn_model(
+                commands,
+                meshes,
+                material,
+                mob_biped_model(mob),
+                texture_path,
+            );
+            commands.entity(root).add_child(spawned.root);
+            commands.entity(root).insert((
+                RemoteEntityTexture(texture_path),
+                RemoteBipedModelParts {
+                    model_root: spawned.root,
+                    head: spawned.parts[BIPED_HEAD],
+                    body: spawned.parts[BIPED_BODY],
+                    arm_right: spawned.parts[BIPED_RIGHT_ARM],
+                    arm_left: spawned.parts[BIPED_LEFT_ARM],
+                    leg_right: spawned.parts[BIPED_RIGHT_LEG],
+                    leg_left: spawned.parts[BIPED_LEFT_LEG],
+                },
+                HumanoidRigParts {
+                    kind: HumanoidRigKind::BipedMob,
+                    model_root: spawned.root,
+                    head: spawned.parts[BIPED_HEAD],
+                    body: spawned.parts[BIPED_BODY],
+                    arm_right: spawned.parts[BIPED_RIGHT_ARM],
+                    arm_left: spawned.parts[BIPED_LEFT_ARM],
+                    leg_right: spawned.parts[BIPED_RIGHT_LEG],
+                    leg_left: spawned.parts[BIPED_LEFT_LEG],
+                    render_layer: None,
+                },
+                HumanoidArmorState::default(),
+                HumanoidArmorLayerEntities::default(),
+                RemoteBipedAnimation {
+                    previous_pos: pos,
+                    limb_swing: 0.0,
+                    limb_swing_amount: 0.0,
+                    swing_progress: 1.0,
+                },
+            ));
+        } else if let Some(mob) = quadruped_mob {
+            let Some(texture_path) = mob_texture_path(mob) else {
+                // Shouldn't happen since `quadruped_mob` is gated above.
+                return;
+            };
+            entity_textures.request(texture_path);
+            let material = entity_textures.material(texture_path).unwrap_or_else(|| {
+                materials.add(StandardMaterial {
+                    base_color: Color::srgb(1.0, 0.0, 1.0),
+                    alpha_mode: AlphaMode::Mask(0.5),
+                    unlit: true,
+                    perceptual_roughness: 1.0,
+                    metallic: 0.0,
+                    ..Default::default()
+                })
+            });
+
+            let spawned = spawn_model(
+                commands,
+                meshes,
+                material,
+                mob_quadruped_model(mob),
+                texture_path,
+            );
+            commands.entity(root).add_child(spawned.root);
+            commands.entity(root).insert((
+                RemoteEntityTexture(texture_path),
+                RemoteQuadrupedModelParts {
+                    model_root: spawned.root,
+                    head: spawned.parts[QUADRUPED_HEAD],
+                    body: spawned.parts[QUADRUPED_BODY],
+                    leg_front_right: spawned.parts[QUADRUPED_LEG_FRONT_RIGHT],
+                    leg_front_left: spawned.parts[QUADRUPED_LEG_FRONT_LEFT],
+                    leg_back_right: spawned.parts[QUADRUPED_LEG_BACK_RIGHT],
+                    leg_back_left: spawned.parts[QUADRUPED_LEG_BACK_LEFT],
+                },
+                RemoteQuadrupedAnimation {
+                    previous_pos: pos,
+                    limb_swing: 0.0,
+                    limb_swing_amount: 0.0,
+                },
+                mob_quadruped_anim_tuning(mob),
+            ));
+            if mob == MobKind::Sheep {
+                let wool_material = materials.add(StandardMaterial {
+                    base_color: Color::WHITE,
+                    alpha_mode: AlphaMode::Mask(0.5),
+                    unlit: true,
+                    perceptual_roughness: 1.0,
+                    metallic: 0.0,
+                    ..Default::default()
+                });
+                entity_textures.request(SHEEP_WOOL_TEXTURE_PATH);
+                let wool_mesh_entities =
+                    spawn_sheep_wool_layer(commands, meshes, spawned.parts, wool_material.clone());
+                commands.entity(root).insert((
+                    RemoteSheepWoolLayer {
+                        mesh_entities: wool_mesh_entities,
+                        material: wool_material,
+                    },
+                    // Vanilla initializes sheep metadata byte (index 16) to 0:
+                    // white fleece and not sheared.
+                    RemoteSheepAppearance {
+                        fleece_color: 0,
+                        sheared: false,
+                    },
+                ));
+            }
+        } else {
+            spawn_object_visual(
+                commands,
+                meshes,
+                materials,
+                entity_textures,
+                root,
+                kind,
+                spec,
+            );
+        }
+    }
+
+    if let Some(uuid) = uuid {
+        registry
+            .player_entity_by_uuid
+            .insert(uuid.clone(), entity_id);
+        commands.entity(root).insert(RemoteEntityUuid(uuid));
+    }
+
+    registry.by_server_id.insert(entity_id, root);
+}
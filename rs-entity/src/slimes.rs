@@ -0,0 +1,141 @@
+use super::*;
+use crate::model::{MAGMA_CUBE_CORE, MAGMA_CUBE_MODEL, SLIME_GEL_MODEL, SLIME_MODEL};
+use crate::objects::entity_model_material;
+use crate::specs::{CubeModelKind, NAME_ABOVE_HITBOX, mob_cube_model_kind};
+use rs_utils::MobMetadata;
+
+const PX: f32 = 1.0 / 16.0;
+const TICKS_PER_SEC: f32 = 20.0;
+/// `EntitySlime` hitbox height per size step.
+const SLIME_HEIGHT: f32 = 0.51;
+/// `squishAmount` right after landing and jumping.
+const LANDING_SQUISH: f32 = -0.5;
+const JUMPING_SQUISH: f32 = 1.0;
+
+/// Slime or magma cube model with the squish state `EntitySlime` keeps on the client.
+#[derive(Component, Debug, Clone)]
+pub struct RemoteSlime {
+    kind: CubeModelKind,
+    parts: Vec<Entity>,
+    /// `squishAmount`: kicked on landing and jumping, then decays every tick.
+    squish_amount: f32,
+    /// `squishFactor`, which eases toward `squish_amount` and drives the render scale.
+    squish_factor: f32,
+    was_on_ground: bool,
+}
+
+/// Spawns the slime or magma cube model for `mob` under `root`, returning false for other
+/// mobs. Slimes also get `LayerSlimeGel`'s translucent outer cube.
+pub(crate) fn spawn_slime_model(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    entity_textures: &mut EntityTextureCache,
+    root: Entity,
+    mob: MobKind,
+) -> bool {
+    let (Some(kind), Some(texture_path)) = (mob_cube_model_kind(mob), mob_texture_path(mob)) else {
+        return false;
+    };
+    let material = entity_model_material(materials, entity_textures, texture_path);
+    let model = match kind {
+        CubeModelKind::Slime => &SLIME_MODEL,
+        CubeModelKind::MagmaCube => &MAGMA_CUBE_MODEL,
+    };
+    let spawned = spawn_model(commands, meshes, material, model, texture_path);
+    commands.entity(root).add_child(spawned.root);
+
+    if kind == CubeModelKind::Slime {
+        let gel_material = materials.add(StandardMaterial {
+            base_color: Color::WHITE,
+            base_color_texture: entity_textures.texture(texture_path),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            perceptual_roughness: 1.0,
+            metallic: 0.0,
+            ..Default::default()
+        });
+        let gel = commands
+            .spawn((
+                Name::new("EntityModelMesh[slime_gel]"),
+                Mesh3d(meshes.add(part_mesh(&SLIME_GEL_MODEL, &SLIME_GEL_MODEL.parts[0]))),
+                MeshMaterial3d(gel_material),
+                Transform::IDENTITY,
+                GlobalTransform::default(),
+                Visibility::Inherited,
+                InheritedVisibility::default(),
+                ViewVisibility::default(),
+                EntityLayerTexturePath(texture_path),
+            ))
+            .id();
+        commands.entity(spawned.root).add_child(gel);
+    }
+
+    commands.entity(root).insert((
+        RemoteEntityTexture(texture_path),
+        RemoteSlime {
+            kind,
+            parts: spawned.parts,
+            squish_amount: 0.0,
+            squish_factor: 0.0,
+            was_on_ground: true,
+        },
+    ));
+    true
+}
+
+/// Sizes slimes and magma cubes from their metadata and squashes them like `RenderSlime`:
+/// flat on landing, stretched when jumping. Magma cube slices also spread apart mid-jump.
+pub fn update_remote_slimes(
+    time: Res<Time>,
+    mut slimes: Query<(
+        &RemoteEntity,
+        &RemoteEntityMetadata,
+        &mut RemoteSlime,
+        &mut Transform,
+        &mut RemoteVisual,
+    )>,
+    mut parts: Query<&mut Transform, Without<RemoteSlime>>,
+) {
+    let ticks = time.delta_secs() * TICKS_PER_SEC;
+    for (remote, metadata, mut slime, mut transform, mut visual) in &mut slimes {
+        let size = match metadata.0.mob {
+            MobMetadata::Slime { size } => f32::from(size.max(1)),
+            _ => 1.0,
+        };
+
+        if remote.on_ground && !slime.was_on_ground {
+            slime.squish_amount = LANDING_SQUISH;
+        } else if !remote.on_ground && slime.was_on_ground {
+            slime.squish_amount = JUMPING_SQUISH;
+        }
+        slime.was_on_ground = remote.on_ground;
+        // Magma cubes recover slower (`EntityMagmaCube.alterSquishAmount`).
+        let decay: f32 = match slime.kind {
+            CubeModelKind::Slime => 0.6,
+            CubeModelKind::MagmaCube => 0.9,
+        };
+        slime.squish_factor +=
+            (slime.squish_amount - slime.squish_factor) * (1.0 - 0.5f32.powf(ticks));
+        slime.squish_amount *= decay.powf(ticks);
+
+        let squish = slime.squish_factor / (size * 0.5 + 1.0);
+        let spread = 1.0 / (squish + 1.0);
+        let base_scale = visual_spec(remote.kind).scale;
+        transform.scale = base_scale * Vec3::new(spread * size, size / spread, spread * size);
+        let name_y_offset = SLIME_HEIGHT * size + NAME_ABOVE_HITBOX;
+        if visual.name_y_offset != name_y_offset {
+            visual.name_y_offset = name_y_offset;
+        }
+
+        if slime.kind == CubeModelKind::MagmaCube {
+            // `ModelMagmaCube.setLivingAnimations`: slices move apart only while stretched.
+            let stretch = slime.squish_factor.max(0.0);
+            for (index, &slice) in slime.parts[..MAGMA_CUBE_CORE].iter().enumerate() {
+                if let Ok(mut part) = parts.get_mut(slice) {
+                    part.translation.y = (4.0 - index as f32) * stretch * 1.7 * PX;
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use bevy::prelude::{Color, Vec3};
 use rs_utils::{MobKind, NetEntityKind, ObjectKind};
 
 use crate::RemoteQuadrupedAnimTuning;
+use crate::objects::object_uses_model;
 
 const PLAYER_SCALE: Vec3 = Vec3::ONE;
 const PLAYER_Y_OFFSET: f32 = 0.0;
@@ -46,8 +47,7 @@ struct MobSpec {
     kind: MobKind,
     label: &'static str,
     color: [f32; 3],
-    uses_biped_model: bool,
-    uses_quadruped_model: bool,
+    model: MobModelFamily,
     scale: Vec3,
     name_y_offset: f32,
     texture_path: Option<&'static str>,
@@ -63,6 +63,17 @@ struct ObjectSpec {
     color: [f32; 3],
 }
 
+/// Which model a mob is drawn with; `Placeholder` mobs get a colored capsule.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MobModelFamily {
+    Placeholder,
+    Biped,
+    Quadruped,
+    Arthropod(ArthropodModelKind),
+    Cube(CubeModelKind),
+    Floating(FloatingModelKind),
+}
+
 #[derive(Clone, Copy)]
 pub(crate) enum BipedModelKind {
     Tex32,
@@ -77,6 +88,27 @@ pub(crate) enum QuadrupedModelKind {
     CreeperTex64,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum ArthropodModelKind {
+    Spider,
+    Silverfish,
+    Endermite,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum CubeModelKind {
+    Slime,
+    MagmaCube,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum FloatingModelKind {
+    Ghast,
+    Blaze,
+    Squid,
+    Guardian,
+}
+
 const DEFAULT_QUAD_TUNING: RemoteQuadrupedAnimTuning = RemoteQuadrupedAnimTuning {
     body_pitch: -std::f32::consts::FRAC_PI_2,
     leg_swing_scale: 1.0,
@@ -87,8 +119,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Creeper,
         label: "Creeper",
         color: [0.10, 0.78, 0.12],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::ONE,
         name_y_offset: 1.8,
         texture_path: Some("entity/creeper/creeper.png"),
@@ -103,8 +134,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Skeleton,
         label: "Skeleton",
         color: [0.86, 0.86, 0.86],
-        uses_biped_model: true,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Biped,
         scale: Vec3::ONE,
         name_y_offset: 2.05,
         texture_path: Some("entity/skeleton/skeleton.png"),
@@ -116,11 +146,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Spider,
         label: "Spider",
         color: [0.22, 0.22, 0.22],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Arthropod(ArthropodModelKind::Spider),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        name_y_offset: 1.15,
+        texture_path: Some("entity/spider/spider.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -129,8 +158,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Giant,
         label: "Giant",
         color: [0.72, 0.35, 0.85],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Placeholder,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: None,
@@ -142,8 +170,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Zombie,
         label: "Zombie",
         color: [0.25, 0.73, 0.25],
-        uses_biped_model: true,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Biped,
         scale: Vec3::ONE,
         name_y_offset: 2.05,
         texture_path: Some("entity/zombie/zombie.png"),
@@ -155,11 +182,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Slime,
         label: "Slime",
         color: [0.72, 0.35, 0.85],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Cube(CubeModelKind::Slime),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        name_y_offset: 0.76,
+        texture_path: Some("entity/slime/slime.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -168,11 +194,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Ghast,
         label: "Ghast",
         color: [0.92, 0.45, 0.12],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
-        scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        model: MobModelFamily::Floating(FloatingModelKind::Ghast),
+        scale: Vec3::splat(4.5),
+        name_y_offset: 4.25,
+        texture_path: Some("entity/ghast/ghast.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -181,8 +206,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::PigZombie,
         label: "Zombie Pigman",
         color: [0.25, 0.73, 0.25],
-        uses_biped_model: true,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Biped,
         scale: Vec3::ONE,
         name_y_offset: 2.05,
         texture_path: Some("entity/zombie_pigman.png"),
@@ -194,8 +218,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Enderman,
         label: "Enderman",
         color: [0.20, 0.10, 0.28],
-        uses_biped_model: true,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Biped,
         scale: Vec3::new(1.06, 1.38, 1.06),
         name_y_offset: 2.65,
         texture_path: Some("entity/enderman/enderman.png"),
@@ -207,11 +230,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::CaveSpider,
         label: "Cave Spider",
         color: [0.22, 0.22, 0.22],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
-        scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        model: MobModelFamily::Arthropod(ArthropodModelKind::Spider),
+        scale: Vec3::splat(0.7),
+        name_y_offset: 0.75,
+        texture_path: Some("entity/spider/cave_spider.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -220,11 +242,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Silverfish,
         label: "Silverfish",
         color: [0.72, 0.35, 0.85],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Arthropod(ArthropodModelKind::Silverfish),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        name_y_offset: 0.55,
+        texture_path: Some("entity/silverfish.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -233,11 +254,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Blaze,
         label: "Blaze",
         color: [0.92, 0.45, 0.12],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Floating(FloatingModelKind::Blaze),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        name_y_offset: 2.05,
+        texture_path: Some("entity/blaze.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -246,11 +266,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::MagmaCube,
         label: "Magma Cube",
         color: [0.92, 0.45, 0.12],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Cube(CubeModelKind::MagmaCube),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        name_y_offset: 0.76,
+        texture_path: Some("entity/slime/magmacube.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -259,8 +278,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::EnderDragon,
         label: "Ender Dragon",
         color: [0.72, 0.35, 0.85],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Placeholder,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: None,
@@ -272,8 +290,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Wither,
         label: "Wither",
         color: [0.86, 0.86, 0.86],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Placeholder,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: None,
@@ -285,8 +302,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Bat,
         label: "Bat",
         color: [0.72, 0.35, 0.85],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Placeholder,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: None,
@@ -298,8 +314,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Witch,
         label: "Witch",
         color: [0.72, 0.35, 0.85],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Placeholder,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: None,
@@ -311,11 +326,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Endermite,
         label: "Endermite",
         color: [0.22, 0.22, 0.22],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Arthropod(ArthropodModelKind::Endermite),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        name_y_offset: 0.55,
+        texture_path: Some("entity/endermite.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -324,11 +338,10 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Guardian,
         label: "Guardian",
         color: [0.72, 0.35, 0.85],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Floating(FloatingModelKind::Guardian),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
-        texture_path: None,
+        name_y_offset: 1.1,
+        texture_path: Some("entity/guardian.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
         quadruped_model: QuadrupedModelKind::PigTex32,
@@ -337,8 +350,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Pig,
         label: "Pig",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: Some("entity/pig/pig.png"),
@@ -350,8 +362,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Sheep,
         label: "Sheep",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: Some("entity/sheep/sheep.png"),
@@ -363,8 +374,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Cow,
         label: "Cow",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::ONE,
         name_y_offset: 1.8,
         texture_path: Some("entity/cow/cow.png"),
@@ -376,8 +386,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Chicken,
         label: "Chicken",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::splat(0.62),
         name_y_offset: 1.2,
         texture_path: Some("entity/chicken.png"),
@@ -392,10 +401,9 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Squid,
         label: "Squid",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Floating(FloatingModelKind::Squid),
         scale: Vec3::ONE,
-        name_y_offset: 1.6,
+        name_y_offset: 1.2,
         texture_path: Some("entity/squid.png"),
         quadruped_tuning: DEFAULT_QUAD_TUNING,
         biped_model: BipedModelKind::Tex32,
@@ -405,8 +413,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Wolf,
         label: "Wolf",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::splat(0.78),
         name_y_offset: 1.6,
         texture_path: Some("entity/wolf/wolf.png"),
@@ -418,8 +425,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Mooshroom,
         label: "Mooshroom",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::ONE,
         name_y_offset: 1.8,
         texture_path: Some("entity/cow/mooshroom.png"),
@@ -431,8 +437,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::SnowGolem,
         label: "Snow Golem",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Placeholder,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: Some("entity/snow_golem.png"),
@@ -444,8 +449,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Ocelot,
         label: "Ocelot",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::splat(0.78),
         name_y_offset: 1.6,
         texture_path: Some("entity/cat/ocelot.png"),
@@ -457,8 +461,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::IronGolem,
         label: "Iron Golem",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Placeholder,
         scale: Vec3::ONE,
         name_y_offset: 1.6,
         texture_path: Some("entity/iron_golem.png"),
@@ -470,8 +473,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Horse,
         label: "Horse",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::splat(1.24),
         name_y_offset: 2.0,
         texture_path: Some("entity/horse/horse_white.png"),
@@ -486,8 +488,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Rabbit,
         label: "Rabbit",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: false,
-        uses_quadruped_model: true,
+        model: MobModelFamily::Quadruped,
         scale: Vec3::splat(0.52),
         name_y_offset: 1.2,
         texture_path: Some("entity/rabbit/brown.png"),
@@ -502,8 +503,7 @@ const MOB_SPECS: &[MobSpec] = &[
         kind: MobKind::Villager,
         label: "Villager",
         color: [0.30, 0.55, 0.88],
-        uses_biped_model: true,
-        uses_quadruped_model: false,
+        model: MobModelFamily::Biped,
         scale: Vec3::new(0.96, 0.98, 0.96),
         name_y_offset: 2.05,
         texture_path: Some("entity/villager/villager.png"),
@@ -614,6 +614,11 @@ const OBJECT_SPECS: &[ObjectSpec] = &[
         label: "Falling Block",
         color: [0.45, 0.74, 0.88],
     },
+    ObjectSpec {
+        kind: ObjectKind::Painting,
+        label: "Painting",
+        color: [0.55, 0.42, 0.30],
+    },
 ];
 
 fn mob_spec(kind: MobKind) -> Option<&'static MobSpec> {
@@ -668,10 +673,11 @@ pub(crate) fn visual_spec(kind: NetEntityKind) -> VisualSpec {
         },
         NetEntityKind::Object(obj) => {
             let color = object_color(obj);
+            let modeled = object_uses_model(obj);
             VisualSpec {
                 mesh: VisualMesh::Sphere,
-                scale: OBJECT_SCALE,
-                y_offset: OBJECT_Y_OFFSET,
+                scale: if modeled { Vec3::ONE } else { OBJECT_SCALE },
+                y_offset: if modeled { 0.0 } else { OBJECT_Y_OFFSET },
                 name_y_offset: OBJECT_NAME_Y_OFFSET,
                 color,
             }
@@ -712,21 +718,45 @@ pub(crate) fn object_label(kind: ObjectKind) -> &'static str {
 }
 
 pub(crate) fn mob_uses_biped_model(mob: MobKind) -> bool {
-    mob_spec(mob).is_some_and(|s| s.uses_biped_model)
+    mob_spec(mob).is_some_and(|s| s.model == MobModelFamily::Biped)
 }
 
 pub(crate) fn mob_uses_quadruped_model(mob: MobKind) -> bool {
-    mob_spec(mob).is_some_and(|s| s.uses_quadruped_model)
+    mob_spec(mob).is_some_and(|s| s.model == MobModelFamily::Quadruped)
+}
+
+pub(crate) fn mob_arthropod_model_kind(mob: MobKind) -> Option<ArthropodModelKind> {
+    match mob_spec(mob)?.model {
+        MobModelFamily::Arthropod(kind) => Some(kind),
+        _ => None,
+    }
+}
+
+pub(crate) fn mob_cube_model_kind(mob: MobKind) -> Option<CubeModelKind> {
+    match mob_spec(mob)?.model {
+        MobModelFamily::Cube(kind) => Some(kind),
+        _ => None,
+    }
+}
+
+pub(crate) fn mob_floating_model_kind(mob: MobKind) -> Option<FloatingModelKind> {
+    match mob_spec(mob)?.model {
+        MobModelFamily::Floating(kind) => Some(kind),
+        _ => None,
+    }
 }
 
 pub(crate) fn mob_uses_entity_model(mob: MobKind) -> bool {
-    mob_uses_biped_model(mob) || mob_uses_quadruped_model(mob)
+    mob_spec(mob).is_some_and(|s| s.model != MobModelFamily::Placeholder)
 }
 
 pub(crate) fn mob_model_scale(mob: MobKind) -> Vec3 {
     mob_spec(mob).map_or(Vec3::ONE, |s| s.scale)
 }
 
+/// Gap between the hitbox top and the name tag, as for players (2.05 over 1.8 blocks).
+pub(crate) const NAME_ABOVE_HITBOX: f32 = 0.25;
+
 pub(crate) fn mob_model_name_y_offset(mob: MobKind) -> f32 {
     mob_spec(mob).map_or(1.6, |s| s.name_y_offset)
 }
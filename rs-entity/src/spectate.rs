@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use super::*;
+use rs_sim::SpectateCameraState;
+
+const PLAYER_EYE_HEIGHT: f32 = 1.62;
+/// Vanilla `Entity#getEyeHeight` defaults to 85% of the entity height.
+const MOB_EYE_HEIGHT_FRACTION: f32 = 0.85;
+
+fn eye_height(kind: NetEntityKind, visual: &RemoteVisual) -> f32 {
+    match kind {
+        NetEntityKind::Player => PLAYER_EYE_HEIGHT,
+        _ => visual.name_y_offset * MOB_EYE_HEIGHT_FRACTION,
+    }
+}
+
+/// Follows the entity the server attached the camera to: publishes its eye position and look
+/// for the camera and hides its model so the view is not blocked by the target's own head.
+pub fn update_spectate_camera_target(
+    registry: Res<RemoteEntityRegistry>,
+    mut spectate: ResMut<SpectateCameraState>,
+    targets: Query<(&RemoteEntity, &RemoteEntityLook, &RemoteVisual, &Transform)>,
+    children_query: Query<&Children>,
+    mut vis_query: Query<&mut Visibility>,
+    mut hidden: Local<Option<(Entity, HashMap<Entity, Visibility>)>>,
+) {
+    let target = spectate
+        .target_id
+        .and_then(|id| registry.by_server_id.get(&id).copied());
+
+    if hidden
+        .as_ref()
+        .is_some_and(|(root, _)| Some(*root) != target)
+        && let Some((_, saved)) = hidden.take()
+    {
+        for (entity, visibility) in saved {
+            if let Ok(mut v) = vis_query.get_mut(entity) {
+                *v = visibility;
+            }
+        }
+    }
+
+    let Some(target) = target else {
+        if spectate.eye.is_some() {
+            spectate.eye = None;
+        }
+        return;
+    };
+    let Ok((remote, look, visual, transform)) = targets.get(target) else {
+        spectate.eye = None;
+        return;
+    };
+    let feet = transform.translation - Vec3::Y * visual.y_offset;
+    spectate.eye = Some(feet + Vec3::Y * eye_height(remote.kind, visual));
+    spectate.yaw = look.head_yaw;
+    spectate.pitch = look.pitch;
+
+    // Other systems may flip descendants back to visible, so re-hide the subtree every frame.
+    let (_, saved) = hidden.get_or_insert_with(|| (target, HashMap::new()));
+    let mut stack = vec![target];
+    while let Some(e) = stack.pop() {
+        if let Ok(mut v) = vis_query.get_mut(e) {
+            saved.entry(e).or_insert(*v);
+            if *v != Visibility::Hidden {
+                *v = Visibility::Hidden;
+            }
+        }
+        if let Ok(children) = children_query.get(e) {
+            stack.extend(children.iter());
+        }
+    }
+}
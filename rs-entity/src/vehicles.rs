@@ -0,0 +1,216 @@
+use std::f32::consts::PI;
+
+use super::*;
+use rs_sim::MountState;
+use rs_utils::ObjectKind;
+
+/// Vanilla `EntityPlayer#getYOffset`: riding players sit slightly below the seat.
+const PLAYER_RIDING_Y_OFFSET: f32 = -0.35;
+const BOAT_SEAT_FORWARD: f32 = 0.4;
+const LEASH_SEGMENTS: usize = 24;
+const LEASH_COLOR: Color = Color::srgb(0.40, 0.28, 0.16);
+
+/// Vanilla `ModelBiped` riding pose: arms tilt forward, legs stick straight out.
+pub(crate) const RIDING_ARM_PITCH: f32 = -PI / 5.0;
+
+pub(crate) fn riding_leg_rotation(right: bool) -> Quat {
+    let yaw = if right { PI / 10.0 } else { -PI / 10.0 };
+    Quat::from_rotation_y(yaw) * Quat::from_rotation_x(-2.0 * PI / 5.0)
+}
+
+pub(crate) fn apply_entity_attach(
+    registry: &mut RemoteEntityRegistry,
+    mount: &mut MountState,
+    entity_id: i32,
+    vehicle_id: Option<i32>,
+    leash: bool,
+) {
+    if leash {
+        match vehicle_id {
+            Some(holder_id) => {
+                registry.leash_holder_by_entity.insert(entity_id, holder_id);
+            }
+            None => {
+                registry.leash_holder_by_entity.remove(&entity_id);
+            }
+        }
+        return;
+    }
+
+    match vehicle_id {
+        Some(vehicle_id) => {
+            // 1.8 vehicles carry a single passenger.
+            registry
+                .vehicle_by_passenger
+                .retain(|passenger, vehicle| *vehicle != vehicle_id || *passenger == entity_id);
+            registry.vehicle_by_passenger.insert(entity_id, vehicle_id);
+        }
+        None => {
+            registry.vehicle_by_passenger.remove(&entity_id);
+        }
+    }
+    if registry.local_entity_id == Some(entity_id) {
+        mount.vehicle_id = vehicle_id;
+        mount.seat_pos = None;
+    } else if mount.vehicle_id.is_some() && mount.vehicle_id == vehicle_id {
+        *mount = MountState::default();
+    }
+}
+
+/// Drops passenger and leash links that involve a destroyed entity.
+pub(crate) fn forget_entity_links(
+    registry: &mut RemoteEntityRegistry,
+    mount: &mut MountState,
+    entity_id: i32,
+) {
+    registry.vehicle_by_passenger.remove(&entity_id);
+    registry
+        .vehicle_by_passenger
+        .retain(|_, vehicle| *vehicle != entity_id);
+    registry.leash_holder_by_entity.remove(&entity_id);
+    registry
+        .leash_holder_by_entity
+        .retain(|_, holder| *holder != entity_id);
+    if mount.vehicle_id == Some(entity_id) {
+        *mount = MountState::default();
+    }
+}
+
+/// Passenger feet position relative to the vehicle's feet (vanilla `getMountedYOffset`,
+/// plus the boat's off-center seat).
+fn seat_offset(vehicle: NetEntityKind, vehicle_yaw: f32) -> Vec3 {
+    match vehicle {
+        NetEntityKind::Object(ObjectKind::Boat) => {
+            // Client yaw is `PI - vanilla yaw`, so vanilla's (cos, sin) becomes (-cos, sin).
+            Vec3::new(
+                -vehicle_yaw.cos() * BOAT_SEAT_FORWARD,
+                -0.3,
+                vehicle_yaw.sin() * BOAT_SEAT_FORWARD,
+            )
+        }
+        NetEntityKind::Object(ObjectKind::Minecart) => Vec3::ZERO,
+        NetEntityKind::Mob(MobKind::Pig) => Vec3::Y * 0.675,
+        NetEntityKind::Mob(MobKind::Horse) => Vec3::Y * 1.2,
+        NetEntityKind::Mob(MobKind::Spider | MobKind::CaveSpider) => Vec3::Y * 0.45,
+        NetEntityKind::Mob(_) | NetEntityKind::Player => Vec3::Y * 1.35,
+        _ => Vec3::ZERO,
+    }
+}
+
+fn passenger_y_offset(passenger: NetEntityKind) -> f32 {
+    if passenger == NetEntityKind::Player {
+        PLAYER_RIDING_Y_OFFSET
+    } else {
+        0.0
+    }
+}
+
+fn vehicle_seat(
+    vehicles: &Query<(
+        &RemoteEntity,
+        &RemoteEntityLook,
+        &RemoteVisual,
+        &mut Transform,
+    )>,
+    registry: &RemoteEntityRegistry,
+    vehicle_id: i32,
+) -> Option<Vec3> {
+    let vehicle_entity = registry.by_server_id.get(&vehicle_id)?;
+    let (vehicle, look, visual, transform) = vehicles.get(*vehicle_entity).ok()?;
+    let feet = transform.translation - Vec3::Y * visual.y_offset;
+    Some(feet + seat_offset(vehicle.kind, look.yaw))
+}
+
+/// Pins passengers to their vehicle's seat after motion smoothing, keeps the riding pose in
+/// sync with the registry and publishes the local player's seat to the simulation.
+pub fn position_remote_passengers(
+    registry: Res<RemoteEntityRegistry>,
+    mut mount: ResMut<MountState>,
+    mut entities: Query<(
+        &RemoteEntity,
+        &RemoteEntityLook,
+        &RemoteVisual,
+        &mut Transform,
+    )>,
+    mut poses: Query<(&RemoteEntity, &mut RemotePoseState)>,
+) {
+    for (remote, mut pose) in &mut poses {
+        let riding = registry
+            .vehicle_by_passenger
+            .contains_key(&remote.server_id);
+        if pose.riding != riding {
+            pose.riding = riding;
+        }
+    }
+
+    if let Some(vehicle_id) = mount.vehicle_id {
+        let seat = vehicle_seat(&entities, &registry, vehicle_id)
+            .map(|seat| seat + Vec3::Y * PLAYER_RIDING_Y_OFFSET);
+        if mount.seat_pos != seat {
+            mount.seat_pos = seat;
+        }
+    }
+
+    for (&passenger_id, &vehicle_id) in &registry.vehicle_by_passenger {
+        let Some(&passenger_entity) = registry.by_server_id.get(&passenger_id) else {
+            continue;
+        };
+        let Some(seat) = vehicle_seat(&entities, &registry, vehicle_id) else {
+            continue;
+        };
+        let Ok((remote, _, visual, mut transform)) = entities.get_mut(passenger_entity) else {
+            continue;
+        };
+        transform.translation =
+            seat + Vec3::Y * (visual.y_offset + passenger_y_offset(remote.kind));
+    }
+}
+
+/// Draws a sagging rope between leashed entities and their holder (a knot or a player).
+pub fn draw_entity_leashes(
+    mut gizmos: Gizmos,
+    registry: Res<RemoteEntityRegistry>,
+    entities: Query<(&RemoteEntity, &RemoteVisual, &Transform)>,
+    local_player: Query<&Transform, With<Player>>,
+) {
+    for (&entity_id, &holder_id) in &registry.leash_holder_by_entity {
+        let Some((_, visual, transform)) = registry
+            .by_server_id
+            .get(&entity_id)
+            .and_then(|entity| entities.get(*entity).ok())
+        else {
+            continue;
+        };
+        let start = transform.translation - Vec3::Y * visual.y_offset
+            + Vec3::Y * (visual.name_y_offset * 0.5);
+        let end = if registry.local_entity_id == Some(holder_id) {
+            let Ok(player) = local_player.single() else {
+                continue;
+            };
+            player.translation + Vec3::Y * 1.2
+        } else {
+            let Some((holder, holder_visual, holder_transform)) = registry
+                .by_server_id
+                .get(&holder_id)
+                .and_then(|entity| entities.get(*entity).ok())
+            else {
+                continue;
+            };
+            let feet = holder_transform.translation - Vec3::Y * holder_visual.y_offset;
+            if matches!(holder.kind, NetEntityKind::Object(ObjectKind::LeashKnot)) {
+                holder_transform.translation
+            } else {
+                feet + Vec3::Y * 1.2
+            }
+        };
+
+        let sag = (start.distance(end) * 0.08).min(0.6);
+        gizmos.linestrip(
+            (0..=LEASH_SEGMENTS).map(|i| {
+                let t = i as f32 / LEASH_SEGMENTS as f32;
+                start.lerp(end, t) - Vec3::Y * (sag * 4.0 * t * (1.0 - t))
+            }),
+            LEASH_COLOR,
+        );
+    }
+}
@@ -1,5 +1,65 @@
 use super::*;
 
+fn send_spawn_painting(
+    to_main: &crossbeam::channel::Sender<FromNetMessage>,
+    entity_id: i32,
+    uuid: Option<rs_protocol::protocol::UUID>,
+    art: Option<PaintingArt>,
+    location: &rs_protocol::shared::Position,
+    facing: u8,
+) {
+    let Some(art) = art else {
+        warn!(entity_id, "SpawnPainting with unknown motive");
+        return;
+    };
+    let location = (location.x, location.y, location.z);
+    let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::SpawnPainting {
+        entity_id,
+        uuid,
+        art,
+        pos: painting_center(&art, location, facing),
+        facing,
+    }));
+}
+
+/// Item frames are spawned at the block they hang in with their facing as the object data;
+/// place them against the wall like `EntityItemFrame` does.
+fn object_spawn_position(
+    kind: NetEntityKind,
+    pos: bevy::prelude::Vec3,
+    data: i32,
+) -> bevy::prelude::Vec3 {
+    if kind != NetEntityKind::Object(ObjectKind::ItemFrame) {
+        return pos;
+    }
+    let block = pos.floor().as_ivec3();
+    item_frame_center((block.x, block.y, block.z), (data & 3) as u8)
+}
+
+/// Vanilla only writes the spawn velocity when the object data is nonzero.
+fn send_object_data(
+    to_main: &crossbeam::channel::Sender<FromNetMessage>,
+    entity_id: i32,
+    data: i32,
+    velocity: [i16; 3],
+) {
+    if data == 0 {
+        return;
+    }
+    let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::ObjectData {
+        entity_id,
+        data,
+    }));
+    let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Velocity {
+        entity_id,
+        velocity: bevy::prelude::Vec3::new(
+            f32::from(velocity[0]) / 8000.0,
+            f32::from(velocity[1]) / 8000.0,
+            f32::from(velocity[2]) / 8000.0,
+        ),
+    }));
+}
+
 fn apply_attribute_modifiers(base: f64, modifiers: &[rs_protocol::protocol::packet::PropertyModifier]) -> f64 {
     let mut value = base;
     for modifier in modifiers.iter().filter(|modifier| modifier.operation == 0) {
@@ -106,38 +166,47 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
             None,
             Some(position.on_ground),
         ),
-        Packet::SpawnPlayer_i32_HeldItem(sp) => send_spawn_player(
-            to_main,
-            sp.entity_id.0,
-            Some(sp.uuid),
-            bevy::prelude::Vec3::new(
-                f64::from(sp.x) as f32,
-                f64::from(sp.y) as f32,
-                f64::from(sp.z) as f32,
-            ),
-            sp.yaw,
-            sp.pitch,
-        ),
-        Packet::SpawnPlayer_i32(sp) => send_spawn_player(
-            to_main,
-            sp.entity_id.0,
-            Some(sp.uuid),
-            bevy::prelude::Vec3::new(
-                f64::from(sp.x) as f32,
-                f64::from(sp.y) as f32,
-                f64::from(sp.z) as f32,
-            ),
-            sp.yaw,
-            sp.pitch,
-        ),
-        Packet::SpawnPlayer_f64(sp) => send_spawn_player(
-            to_main,
-            sp.entity_id.0,
-            Some(sp.uuid),
-            bevy::prelude::Vec3::new(sp.x as f32, sp.y as f32, sp.z as f32),
-            sp.yaw,
-            sp.pitch,
-        ),
+        Packet::SpawnPlayer_i32_HeldItem(sp) => {
+            send_spawn_player(
+                to_main,
+                sp.entity_id.0,
+                Some(sp.uuid),
+                bevy::prelude::Vec3::new(
+                    f64::from(sp.x) as f32,
+                    f64::from(sp.y) as f32,
+                    f64::from(sp.z) as f32,
+                ),
+                sp.yaw,
+                sp.pitch,
+            );
+            metadata::handle_entity_metadata(sp.entity_id.0, &sp.metadata, to_main);
+        }
+        Packet::SpawnPlayer_i32(sp) => {
+            send_spawn_player(
+                to_main,
+                sp.entity_id.0,
+                Some(sp.uuid),
+                bevy::prelude::Vec3::new(
+                    f64::from(sp.x) as f32,
+                    f64::from(sp.y) as f32,
+                    f64::from(sp.z) as f32,
+                ),
+                sp.yaw,
+                sp.pitch,
+            );
+            metadata::handle_entity_metadata(sp.entity_id.0, &sp.metadata, to_main);
+        }
+        Packet::SpawnPlayer_f64(sp) => {
+            send_spawn_player(
+                to_main,
+                sp.entity_id.0,
+                Some(sp.uuid),
+                bevy::prelude::Vec3::new(sp.x as f32, sp.y as f32, sp.z as f32),
+                sp.yaw,
+                sp.pitch,
+            );
+            metadata::handle_entity_metadata(sp.entity_id.0, &sp.metadata, to_main);
+        }
         Packet::SpawnPlayer_f64_NoMeta(sp) => send_spawn_player(
             to_main,
             sp.entity_id.0,
@@ -181,10 +250,10 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
             }
         }
         Packet::EntityMetadata(em) => {
-            handle_entity_metadata(em.entity_id.0, &em.metadata, to_main);
+            metadata::handle_entity_metadata(em.entity_id.0, &em.metadata, to_main);
         }
         Packet::EntityMetadata_i32(em) => {
-            handle_entity_metadata(em.entity_id, &em.metadata, to_main);
+            metadata::handle_entity_metadata(em.entity_id, &em.metadata, to_main);
         }
         Packet::Animation(anim) => {
             let animation = match anim.animation_id {
@@ -212,15 +281,25 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 entity_id: so.entity_id.0,
                 uuid: None,
                 kind: object_type_to_kind(so.ty),
-                pos: bevy::prelude::Vec3::new(
-                    f64::from(so.x) as f32,
-                    f64::from(so.y) as f32,
-                    f64::from(so.z) as f32,
+                pos: object_spawn_position(
+                    object_type_to_kind(so.ty),
+                    bevy::prelude::Vec3::new(
+                        f64::from(so.x) as f32,
+                        f64::from(so.y) as f32,
+                        f64::from(so.z) as f32,
+                    ),
+                    so.data,
                 ),
                 yaw: server_yaw_to_client_yaw(angle_i8_to_degrees(so.yaw)),
                 pitch: server_pitch_to_client_pitch(angle_i8_to_degrees(so.pitch)),
                 on_ground: None,
             }));
+            send_object_data(
+                to_main,
+                so.entity_id.0,
+                so.data,
+                [so.velocity_x, so.velocity_y, so.velocity_z],
+            );
         }
         Packet::SpawnObject_i32(so) => {
             if object_type_to_kind(so.ty) == NetEntityKind::Item {
@@ -230,15 +309,62 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 entity_id: so.entity_id.0,
                 uuid: Some(so.uuid),
                 kind: object_type_to_kind(so.ty),
-                pos: bevy::prelude::Vec3::new(
-                    f64::from(so.x) as f32,
-                    f64::from(so.y) as f32,
-                    f64::from(so.z) as f32,
+                pos: object_spawn_position(
+                    object_type_to_kind(so.ty),
+                    bevy::prelude::Vec3::new(
+                        f64::from(so.x) as f32,
+                        f64::from(so.y) as f32,
+                        f64::from(so.z) as f32,
+                    ),
+                    so.data,
                 ),
                 yaw: server_yaw_to_client_yaw(angle_i8_to_degrees(so.yaw)),
                 pitch: server_pitch_to_client_pitch(angle_i8_to_degrees(so.pitch)),
                 on_ground: None,
             }));
+            send_object_data(
+                to_main,
+                so.entity_id.0,
+                so.data,
+                [so.velocity_x, so.velocity_y, so.velocity_z],
+            );
+        }
+        Packet::SpawnPainting_NoUUID(sp) => {
+            let art = painting_art(&sp.title);
+            send_spawn_painting(
+                to_main,
+                sp.entity_id.0,
+                None,
+                art,
+                &sp.location,
+                sp.direction,
+            );
+        }
+        Packet::SpawnPainting_String(sp) => {
+            let art = painting_art(&sp.title);
+            let uuid = Some(sp.uuid);
+            send_spawn_painting(
+                to_main,
+                sp.entity_id.0,
+                uuid,
+                art,
+                &sp.location,
+                sp.direction,
+            );
+        }
+        Packet::SpawnPainting_VarInt(sp) => {
+            let art = usize::try_from(sp.motive.0)
+                .ok()
+                .and_then(|idx| PAINTING_ARTS.get(idx).copied());
+            let uuid = Some(sp.uuid);
+            send_spawn_painting(
+                to_main,
+                sp.entity_id.0,
+                uuid,
+                art,
+                &sp.location,
+                sp.direction,
+            );
         }
         Packet::SpawnExperienceOrb_i32(xp) => {
             let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Spawn {
@@ -269,6 +395,7 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 pitch: server_pitch_to_client_pitch(angle_i8_to_degrees(sm.pitch)),
                 on_ground: None,
             }));
+            metadata::handle_entity_metadata(sm.entity_id.0, &sm.metadata, to_main);
         }
         Packet::SpawnMob_u8_i32(sm) => {
             let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Spawn {
@@ -284,6 +411,7 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 pitch: server_pitch_to_client_pitch(angle_i8_to_degrees(sm.pitch)),
                 on_ground: None,
             }));
+            metadata::handle_entity_metadata(sm.entity_id.0, &sm.metadata, to_main);
         }
         Packet::SpawnMob_u8(sm) => {
             let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Spawn {
@@ -295,6 +423,7 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 pitch: server_pitch_to_client_pitch(angle_i8_to_degrees(sm.pitch)),
                 on_ground: None,
             }));
+            metadata::handle_entity_metadata(sm.entity_id.0, &sm.metadata, to_main);
         }
         Packet::EntityHeadLook(ehl) => {
             let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::HeadLook {
@@ -451,6 +580,32 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 entity_ids: ed.entity_ids.data,
             }));
         }
+        Packet::EntityAttach(attach) => {
+            let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Attach {
+                entity_id: attach.entity_id,
+                vehicle_id: (attach.vehicle >= 0).then_some(attach.vehicle),
+                leash: false,
+            }));
+        }
+        Packet::EntityAttach_leashed(attach) => {
+            let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Attach {
+                entity_id: attach.entity_id,
+                vehicle_id: (attach.vehicle >= 0).then_some(attach.vehicle),
+                leash: attach.leash,
+            }));
+        }
+        Packet::EntityUsedBed(bed) => {
+            let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::UseBed {
+                entity_id: bed.entity_id.0,
+                location: (bed.location.x, bed.location.y, bed.location.z),
+            }));
+        }
+        Packet::EntityUsedBed_i32(bed) => {
+            let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::UseBed {
+                entity_id: bed.entity_id,
+                location: (bed.x, i32::from(bed.y), bed.z),
+            }));
+        }
         Packet::EntityStatus(es) => {
             if es.entity_status == 2 {
                 let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Animation {
@@ -3,12 +3,15 @@ use super::*;
 pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<FromNetMessage>) {
     match pkt {
         Packet::WindowOpen(open) => {
+            let entity_id = (open.ty == "EntityHorse").then_some(open.entity_id);
             let _ = to_main.send(FromNetMessage::Inventory(InventoryMessage::WindowOpen(
                 InventoryWindowInfo {
                     id: open.id,
                     kind: open.ty,
                     title: open.title.to_string(),
                     slot_count: open.slot_count,
+                    entity_id,
+                    horse_type: None,
                 },
             )));
         }
@@ -19,6 +22,8 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                     kind: format!("type_{}", open.ty),
                     title: open.title.to_string(),
                     slot_count: open.slot_count,
+                    entity_id: (open.ty == 11).then_some(open.entity_id),
+                    horse_type: None,
                 },
             )));
         }
@@ -30,6 +35,8 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                         kind: format!("type_{}", open.ty.0),
                         title: open.title.to_string(),
                         slot_count: 0,
+                        entity_id: None,
+                        horse_type: None,
                     },
                 )));
             }
@@ -41,6 +48,8 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                     kind: "EntityHorse".to_string(),
                     title: "Horse".to_string(),
                     slot_count: open.number_of_slots.0.clamp(0, u8::MAX as i32) as u8,
+                    entity_id: Some(open.entity_id),
+                    horse_type: None,
                 },
             )));
         }
@@ -68,6 +77,15 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 item: protocol_stack_to_inventory_item(slot.item),
             }));
         }
+        Packet::WindowProperty(property) => {
+            let _ = to_main.send(FromNetMessage::Inventory(
+                InventoryMessage::WindowProperty {
+                    id: property.id,
+                    property: property.property,
+                    value: property.value,
+                },
+            ));
+        }
         Packet::ConfirmTransaction(tx) => {
             let _ = to_main.send(FromNetMessage::Inventory(
                 InventoryMessage::ConfirmTransaction {
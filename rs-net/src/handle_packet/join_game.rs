@@ -20,7 +20,15 @@ pub(super) fn handle_packet(
                 None,
                 requested_view_distance,
             );
-            send_join_game(to_main, conn, jg.entity_id, jg.gamemode, requested_view_distance);
+            send_join_game(
+                to_main,
+                conn,
+                jg.entity_id,
+                jg.gamemode,
+                i32::from(jg.dimension),
+                Some(jg.difficulty),
+                requested_view_distance,
+            );
         }
         Packet::JoinGame_i8_NoDebug(jg) => {
             log_join_game(
@@ -35,7 +43,15 @@ pub(super) fn handle_packet(
                 None,
                 requested_view_distance,
             );
-            send_join_game(to_main, conn, jg.entity_id, jg.gamemode, requested_view_distance);
+            send_join_game(
+                to_main,
+                conn,
+                jg.entity_id,
+                jg.gamemode,
+                i32::from(jg.dimension),
+                Some(jg.difficulty),
+                requested_view_distance,
+            );
         }
         Packet::JoinGame_i32(jg) => {
             log_join_game(
@@ -50,7 +66,15 @@ pub(super) fn handle_packet(
                 None,
                 requested_view_distance,
             );
-            send_join_game(to_main, conn, jg.entity_id, jg.gamemode, requested_view_distance);
+            send_join_game(
+                to_main,
+                conn,
+                jg.entity_id,
+                jg.gamemode,
+                jg.dimension,
+                Some(jg.difficulty),
+                requested_view_distance,
+            );
         }
         Packet::JoinGame_i32_ViewDistance(jg) => {
             log_join_game(
@@ -65,7 +89,15 @@ pub(super) fn handle_packet(
                 None,
                 requested_view_distance,
             );
-            send_join_game(to_main, conn, jg.entity_id, jg.gamemode, requested_view_distance);
+            send_join_game(
+                to_main,
+                conn,
+                jg.entity_id,
+                jg.gamemode,
+                jg.dimension,
+                None,
+                requested_view_distance,
+            );
         }
         Packet::KeepAliveClientbound_VarInt(ka) => {
             conn.write_packet(
@@ -113,6 +145,72 @@ pub(super) fn handle_packet(
                 }
             }
         }
+        Packet::Camera(camera) => {
+            let _ = to_main.send(FromNetMessage::Camera {
+                target_id: camera.target_id.0,
+            });
+        }
+        Packet::ServerDifficulty(sd) => {
+            let _ = to_main.send(FromNetMessage::ServerDifficulty {
+                difficulty: sd.difficulty,
+                locked: false,
+            });
+        }
+        Packet::ServerDifficulty_Locked(sd) => {
+            let _ = to_main.send(FromNetMessage::ServerDifficulty {
+                difficulty: sd.difficulty,
+                locked: sd.locked,
+            });
+        }
+        Packet::CombatEvent(combat) => {
+            let event = match combat.event.0 {
+                0 => Some(CombatEventMessage::Enter),
+                1 => Some(CombatEventMessage::End {
+                    duration_ticks: combat.direction.map_or(0, |duration| duration.0),
+                    opponent_id: combat.entity_id.unwrap_or(-1),
+                }),
+                2 => Some(CombatEventMessage::EntityDead {
+                    player_id: combat.player_id.map_or(-1, |id| id.0),
+                    killer_id: combat.entity_id.unwrap_or(-1),
+                    message: combat
+                        .message
+                        .as_ref()
+                        .map(component_to_legacy)
+                        .unwrap_or_default(),
+                }),
+                other => {
+                    debug!(event = other, "Unknown CombatEvent");
+                    None
+                }
+            };
+            if let Some(event) = event {
+                let _ = to_main.send(FromNetMessage::CombatEvent(event));
+            }
+        }
+        Packet::Statistics(stats) => {
+            let _ = to_main.send(FromNetMessage::Statistics(
+                stats
+                    .statistices
+                    .data
+                    .into_iter()
+                    .map(|stat| (stat.name, stat.value.0))
+                    .collect(),
+            ));
+        }
+        Packet::SpawnPosition(spawn) => {
+            let _ = to_main.send(FromNetMessage::SpawnPosition {
+                x: spawn.location.x,
+                y: spawn.location.y,
+                z: spawn.location.z,
+            });
+        }
+        Packet::SpawnPosition_i32(spawn) => {
+            let _ = to_main.send(FromNetMessage::SpawnPosition {
+                x: spawn.x,
+                y: spawn.y,
+                z: spawn.z,
+            });
+        }
         Packet::TimeUpdate(time_update) => {
             let _ = to_main.send(FromNetMessage::TimeUpdate {
                 world_age: time_update.world_age,
@@ -125,6 +223,13 @@ pub(super) fn handle_packet(
             let _ = to_main.send(FromNetMessage::GameMode {
                 gamemode: respawn.gamemode,
             });
+            let _ = to_main.send(FromNetMessage::Dimension {
+                dimension: respawn.dimension,
+            });
+            let _ = to_main.send(FromNetMessage::ServerDifficulty {
+                difficulty: respawn.difficulty,
+                locked: false,
+            });
         }
         Packet::Respawn_HashedSeed(respawn) => {
             info!(
@@ -138,6 +243,9 @@ pub(super) fn handle_packet(
             let _ = to_main.send(FromNetMessage::GameMode {
                 gamemode: respawn.gamemode,
             });
+            let _ = to_main.send(FromNetMessage::Dimension {
+                dimension: respawn.dimension,
+            });
         }
         Packet::Respawn_NBT(respawn) => {
             info!(
@@ -163,6 +271,12 @@ pub(super) fn handle_packet(
             let _ = to_main.send(FromNetMessage::GameMode {
                 gamemode: respawn.gamemode,
             });
+            let dimension = match respawn.dimension.as_str() {
+                "minecraft:the_nether" => -1,
+                "minecraft:the_end" => 1,
+                _ => 0,
+            };
+            let _ = to_main.send(FromNetMessage::Dimension { dimension });
         }
         Packet::UpdateViewDistance(update) => {
             info!(
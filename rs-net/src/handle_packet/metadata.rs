@@ -0,0 +1,77 @@
+use rs_protocol::types::Value as MetadataValue;
+use rs_utils::EntityMetadataValue;
+
+use super::*;
+
+pub(super) fn handle_entity_metadata(
+    entity_id: i32,
+    metadata: &rs_protocol::types::Metadata,
+    to_main: &crossbeam::channel::Sender<FromNetMessage>,
+) {
+    let mut values: Vec<(u8, EntityMetadataValue)> = metadata
+        .iter()
+        .filter_map(|(index, value)| Some((index as u8, metadata_value(value)?)))
+        .collect();
+    values.sort_by_key(|(index, _)| *index);
+    let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Metadata {
+        entity_id,
+        values,
+    }));
+
+    if let Some(MetadataValue::OptionalItemStack(stack_opt)) = metadata.get_raw(10) {
+        let stack_converted = protocol_stack_to_inventory_item(stack_opt.clone());
+        debug!(
+            entity_id,
+            has_stack = stack_converted.is_some(),
+            item_id = stack_converted.as_ref().map(|s| s.item_id),
+            damage = stack_converted.as_ref().map(|s| s.damage),
+            count = stack_converted.as_ref().map(|s| s.count),
+            "entity metadata updated item stack slot"
+        );
+
+        let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::SetItemStack {
+            entity_id,
+            stack: stack_converted,
+        }));
+
+        if let Some(stack) = stack_opt.as_ref() {
+            let label = item_stack_label(stack);
+            let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::SetLabel {
+                entity_id,
+                label,
+            }));
+        }
+    }
+}
+
+/// The 1.8 metadata types; protocol's `Rotation` holds type 6 (a block position) and `Vector`
+/// type 7 (pitch, yaw, roll).
+fn metadata_value(value: &MetadataValue) -> Option<EntityMetadataValue> {
+    Some(match value {
+        MetadataValue::Byte(value) => EntityMetadataValue::Byte(*value),
+        MetadataValue::Short(value) => EntityMetadataValue::Short(*value),
+        MetadataValue::Int(value) => EntityMetadataValue::Int(*value),
+        MetadataValue::Float(value) => EntityMetadataValue::Float(*value),
+        MetadataValue::String(value) => EntityMetadataValue::String(value.clone()),
+        MetadataValue::OptionalItemStack(stack) => {
+            EntityMetadataValue::Item(protocol_stack_to_inventory_item(stack.clone()))
+        }
+        MetadataValue::Rotation(position) => EntityMetadataValue::Position(*position),
+        MetadataValue::Vector(rotation) => EntityMetadataValue::Rotation(*rotation),
+        _ => return None,
+    })
+}
+
+fn item_stack_label(stack: &rs_protocol::item::Stack) -> String {
+    let name = stack
+        .meta
+        .display_name()
+        .map(|c| c.to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| item_name(stack.id as i32).to_string());
+    if stack.count > 1 {
+        format!("{name} x{}", stack.count)
+    } else {
+        name
+    }
+}
@@ -1,15 +1,15 @@
 use base64::Engine;
 use rs_protocol::format::Component;
-use rs_protocol::format::ComponentType;
-use rs_protocol::format::color::Color;
 use rs_protocol::protocol::{Conn, packet::Packet};
-use rs_protocol::types::Value as MetadataValue;
 use rs_utils::world::ChestAction;
 use rs_utils::{
-    BlockUpdate, FromNetMessage, InventoryEnchantment, InventoryItemMeta, InventoryItemStack,
-    InventoryMessage, InventoryWindowInfo, MobKind, NetEntityAnimation, NetEntityKind,
-    NetEntityMessage, ObjectKind, PlayerPosition, PlayerSkinModel, ScoreboardMessage,
-    SoundCategory, SoundEvent, TitleMessage, item_name,
+    BlockBreakAnimation, BlockUpdate, CombatEventMessage, FromNetMessage, InventoryBookMeta,
+    InventoryEnchantment, InventoryItemMeta, InventoryItemStack, InventoryMessage,
+    InventoryWindowInfo, LIGHTNING_GLOBAL_ENTITY_TYPE, MobKind, NetEntityAnimation, NetEntityKind,
+    NetEntityMessage, ObjectKind, PAINTING_ARTS, PaintingArt, PlayerPosition, PlayerSkinModel,
+    RawItemTag, ScoreboardMessage, SoundCategory, SoundEvent, TitleMessage, WRITABLE_BOOK_ITEM_ID,
+    WRITTEN_BOOK_ITEM_ID, component_to_legacy, item_frame_center, item_name, painting_art,
+    painting_center,
 };
 use tracing::{debug, info, warn};
 
@@ -20,6 +20,8 @@ mod chat;
 mod entities;
 mod inventory;
 mod join_game;
+mod metadata;
+mod plugin_message;
 mod scoreboard;
 mod title;
 mod world;
@@ -29,6 +31,8 @@ fn send_join_game(
     conn: &mut Conn,
     entity_id: i32,
     gamemode: u8,
+    dimension: i32,
+    difficulty: Option<u8>,
     requested_view_distance: u8,
 ) {
     if let Err(err) = rs_protocol::protocol::packet::send_client_settings(
@@ -49,6 +53,13 @@ fn send_join_game(
         entity_id,
     }));
     let _ = to_main.send(FromNetMessage::GameMode { gamemode });
+    let _ = to_main.send(FromNetMessage::Dimension { dimension });
+    if let Some(difficulty) = difficulty {
+        let _ = to_main.send(FromNetMessage::ServerDifficulty {
+            difficulty,
+            locked: false,
+        });
+    }
 }
 
 fn log_join_game(
@@ -146,7 +157,14 @@ pub fn handle_packet(
         | Packet::SetExperience(_)
         | Packet::SetExperience_i16(_)
         | Packet::ChangeGameState(_)
+        | Packet::Camera(_)
+        | Packet::Statistics(_)
+        | Packet::CombatEvent(_)
+        | Packet::ServerDifficulty(_)
+        | Packet::ServerDifficulty_Locked(_)
         | Packet::TimeUpdate(_)
+        | Packet::SpawnPosition(_)
+        | Packet::SpawnPosition_i32(_)
         | Packet::Respawn_Gamemode(_)
         | Packet::Respawn_HashedSeed(_)
         | Packet::Respawn_NBT(_)
@@ -166,7 +184,11 @@ pub fn handle_packet(
         | Packet::MultiBlockChange_u16(_)
         | Packet::UpdateBlockEntity(_)
         | Packet::BlockAction(_)
-        | Packet::BlockAction_u16(_) => world::handle_packet(pkt, to_main),
+        | Packet::BlockAction_u16(_)
+        | Packet::BlockBreakAnimation(_)
+        | Packet::BlockBreakAnimation_i32(_)
+        | Packet::SpawnGlobalEntity(_)
+        | Packet::SpawnGlobalEntity_i32(_) => world::handle_packet(pkt, to_main),
         Packet::TeleportPlayer_NoConfirm(_)
         | Packet::TeleportPlayer_OnGround(_)
         | Packet::PlayerPosition(_)
@@ -186,6 +208,9 @@ pub fn handle_packet(
         | Packet::SpawnObject_i32_NoUUID(_)
         | Packet::SpawnObject_i32(_)
         | Packet::SpawnExperienceOrb_i32(_)
+        | Packet::SpawnPainting_NoUUID(_)
+        | Packet::SpawnPainting_String(_)
+        | Packet::SpawnPainting_VarInt(_)
         | Packet::SpawnMob_u8_i32_NoUUID(_)
         | Packet::SpawnMob_u8_i32(_)
         | Packet::SpawnMob_u8(_)
@@ -205,6 +230,10 @@ pub fn handle_packet(
         | Packet::EntityLook_i32_NoGround(_)
         | Packet::EntityDestroy(_)
         | Packet::EntityDestroy_u8(_)
+        | Packet::EntityAttach(_)
+        | Packet::EntityAttach_leashed(_)
+        | Packet::EntityUsedBed(_)
+        | Packet::EntityUsedBed_i32(_)
         | Packet::EntityStatus(_)
         | Packet::CollectItem_nocount(_)
         | Packet::CollectItem_nocount_i32(_)
@@ -233,8 +262,12 @@ pub fn handle_packet(
         | Packet::WindowClose(_)
         | Packet::WindowItems(_)
         | Packet::WindowSetSlot(_)
+        | Packet::WindowProperty(_)
         | Packet::ConfirmTransaction(_)
         | Packet::SetCurrentHotbarSlot(_) => inventory::handle_packet(pkt, to_main),
+        Packet::PluginMessageClientbound(_) | Packet::PluginMessageClientbound_i16(_) => {
+            plugin_message::handle_packet(pkt, to_main)
+        }
         Packet::Title(_) | Packet::Title_notext(_) | Packet::Title_notext_component(_) => {
             title::handle_packet(pkt, to_main)
         }
@@ -393,132 +426,22 @@ fn protocol_stack_meta_to_inventory_meta(stack: &rs_protocol::item::Stack) -> In
         enchantments,
         repair_cost: stack.meta.repair_cost(),
         unbreakable: stack.meta.unbreakable(),
+        book: protocol_book_meta(stack),
+        raw_tag: stack.meta.tag().cloned().map(|tag| RawItemTag(Box::new(tag))),
     }
 }
 
-fn handle_entity_metadata(
-    entity_id: i32,
-    metadata: &rs_protocol::types::Metadata,
-    to_main: &crossbeam::channel::Sender<FromNetMessage>,
-) {
-    if let Some(MetadataValue::Byte(flags)) = metadata.get_raw(0) {
-        let sneaking = (*flags & 0x02) != 0;
-        let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::Pose {
-            entity_id,
-            sneaking,
-        }));
-    }
-
-    if let Some(MetadataValue::OptionalItemStack(stack_opt)) = metadata.get_raw(10) {
-        let stack_converted = protocol_stack_to_inventory_item(stack_opt.clone());
-        debug!(
-            entity_id,
-            has_stack = stack_converted.is_some(),
-            item_id = stack_converted.as_ref().map(|s| s.item_id),
-            damage = stack_converted.as_ref().map(|s| s.damage),
-            count = stack_converted.as_ref().map(|s| s.count),
-            "entity metadata updated item stack slot"
-        );
-
-        let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::SetItemStack {
-            entity_id,
-            stack: stack_converted,
-        }));
-
-        if let Some(stack) = stack_opt.as_ref() {
-            let label = item_stack_label(stack);
-            let _ = to_main.send(FromNetMessage::NetEntity(NetEntityMessage::SetLabel {
-                entity_id,
-                label,
-            }));
-        }
-    }
-
-    if let Some(MetadataValue::Byte(sheep_flags)) = metadata.get_raw(16) {
-        let fleece_color = (*sheep_flags & 0x0F) as u8;
-        let sheared = (*sheep_flags & 0x10) != 0;
-        let _ = to_main.send(FromNetMessage::NetEntity(
-            NetEntityMessage::SheepAppearance {
-                entity_id,
-                fleece_color,
-                sheared,
-            },
-        ));
-    }
-}
-
-fn component_to_legacy(component: &Component) -> String {
-    let mut out = String::new();
-    for part in &component.list {
-        let modifier = part.get_modifier();
-        if let Some(code) = legacy_color_code(modifier.color) {
-            out.push('§');
-            out.push(code);
-        }
-        if modifier.bold {
-            out.push_str("§l");
-        }
-        if modifier.italic {
-            out.push_str("§o");
-        }
-        if modifier.underlined {
-            out.push_str("§n");
-        }
-        if modifier.strikethrough {
-            out.push_str("§m");
-        }
-        if modifier.obfuscated {
-            out.push_str("§k");
-        }
-        out.push_str(match part {
-            ComponentType::Text { text, .. } => text,
-            ComponentType::Hover { text, .. } => text,
-            ComponentType::Click { text, .. } => text,
-            ComponentType::ClickAndHover { text, .. } => text,
-        });
-    }
-    if out.is_empty() {
-        component.to_string()
-    } else {
-        out
-    }
-}
-
-fn legacy_color_code(color: Color) -> Option<char> {
-    match color {
-        Color::Black => Some('0'),
-        Color::DarkBlue => Some('1'),
-        Color::DarkGreen => Some('2'),
-        Color::DarkAqua => Some('3'),
-        Color::DarkRed => Some('4'),
-        Color::DarkPurple => Some('5'),
-        Color::Gold => Some('6'),
-        Color::Gray => Some('7'),
-        Color::DarkGray => Some('8'),
-        Color::Blue => Some('9'),
-        Color::Green => Some('a'),
-        Color::Aqua => Some('b'),
-        Color::Red => Some('c'),
-        Color::LightPurple => Some('d'),
-        Color::Yellow => Some('e'),
-        Color::White => Some('f'),
-        Color::Reset => Some('r'),
-        Color::RGB(_) | Color::None => None,
-    }
-}
-
-fn item_stack_label(stack: &rs_protocol::item::Stack) -> String {
-    let name = stack
-        .meta
-        .display_name()
-        .map(|c| c.to_string())
-        .filter(|s| !s.is_empty())
-        .unwrap_or_else(|| item_name(stack.id as i32).to_string());
-    if stack.count > 1 {
-        format!("{name} x{}", stack.count)
-    } else {
-        name
-    }
+/// Written book pages stay JSON text components; the book screen flattens them for display.
+fn protocol_book_meta(stack: &rs_protocol::item::Stack) -> Option<InventoryBookMeta> {
+    matches!(
+        stack.id as i32,
+        WRITABLE_BOOK_ITEM_ID | WRITTEN_BOOK_ITEM_ID
+    )
+    .then(|| InventoryBookMeta {
+        title: stack.meta.book_title(),
+        author: stack.meta.book_author(),
+        pages: stack.meta.pages(),
+    })
 }
 
 fn extract_skin_info_from_player_properties(
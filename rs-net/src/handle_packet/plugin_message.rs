@@ -0,0 +1,70 @@
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+use rs_protocol::protocol::Serializable;
+use rs_utils::TradeOffer;
+
+use super::*;
+
+pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<FromNetMessage>) {
+    let (channel, data) = match pkt {
+        Packet::PluginMessageClientbound(message) => (message.channel, message.data),
+        Packet::PluginMessageClientbound_i16(message) => (message.channel, message.data.data),
+        _ => return,
+    };
+    match channel.as_str() {
+        "MC|TrList" => match read_trade_list(&data) {
+            Ok((id, offers)) => {
+                let _ = to_main.send(FromNetMessage::Inventory(InventoryMessage::TradeList {
+                    id,
+                    offers,
+                }));
+            }
+            Err(err) => warn!("Malformed MC|TrList payload: {err:?}"),
+        },
+        "MC|BOpen" => {
+            let _ = to_main.send(FromNetMessage::OpenBook);
+        }
+        _ => debug!("Ignoring plugin message on {channel}"),
+    }
+}
+
+/// Window id followed by `MerchantRecipeList#writeToBuf`.
+fn read_trade_list(data: &[u8]) -> Result<(i32, Vec<TradeOffer>), rs_protocol::protocol::Error> {
+    let mut buf = Cursor::new(data);
+    let id = buf.read_i32::<BigEndian>()?;
+    let count = buf.read_u8()?;
+    let mut offers = Vec::with_capacity(usize::from(count));
+    for _ in 0..count {
+        let input = read_trade_stack(&mut buf)?;
+        let output = read_trade_stack(&mut buf)?;
+        let second_input = if buf.read_u8()? != 0 {
+            read_trade_stack(&mut buf)?
+        } else {
+            None
+        };
+        let disabled = buf.read_u8()? != 0;
+        let uses = buf.read_i32::<BigEndian>()?;
+        let max_uses = buf.read_i32::<BigEndian>()?;
+        let (Some(input), Some(output)) = (input, output) else {
+            continue;
+        };
+        offers.push(TradeOffer {
+            input,
+            second_input,
+            output,
+            disabled,
+            uses,
+            max_uses,
+        });
+    }
+    Ok((id, offers))
+}
+
+fn read_trade_stack(
+    buf: &mut Cursor<&[u8]>,
+) -> Result<Option<InventoryItemStack>, rs_protocol::protocol::Error> {
+    Ok(protocol_stack_to_inventory_item(Serializable::read_from(
+        buf,
+    )?))
+}
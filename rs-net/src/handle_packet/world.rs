@@ -166,7 +166,57 @@ pub(super) fn handle_packet(pkt: Packet, to_main: &crossbeam::channel::Sender<Fr
                 }));
             }
         }
+        Packet::BlockBreakAnimation(anim) => {
+            let _ = to_main.send(FromNetMessage::BlockBreakAnimation(BlockBreakAnimation {
+                entity_id: anim.entity_id.0,
+                x: anim.location.x,
+                y: anim.location.y,
+                z: anim.location.z,
+                stage: anim.stage,
+            }));
+        }
+        Packet::BlockBreakAnimation_i32(anim) => {
+            let _ = to_main.send(FromNetMessage::BlockBreakAnimation(BlockBreakAnimation {
+                entity_id: anim.entity_id.0,
+                x: anim.x,
+                y: anim.y,
+                z: anim.z,
+                stage: anim.stage,
+            }));
+        }
         Packet::UpdateBlockEntity(_ube) => {}
+        Packet::SpawnGlobalEntity(global) => send_global_entity(
+            to_main,
+            global.entity_id.0,
+            global.ty,
+            bevy::prelude::Vec3::new(global.x as f32, global.y as f32, global.z as f32),
+        ),
+        Packet::SpawnGlobalEntity_i32(global) => send_global_entity(
+            to_main,
+            global.entity_id.0,
+            global.ty,
+            bevy::prelude::Vec3::new(
+                f64::from(global.x) as f32,
+                f64::from(global.y) as f32,
+                f64::from(global.z) as f32,
+            ),
+        ),
         _ => {}
     }
 }
+
+fn send_global_entity(
+    to_main: &crossbeam::channel::Sender<FromNetMessage>,
+    entity_id: i32,
+    ty: u8,
+    position: bevy::prelude::Vec3,
+) {
+    if ty != LIGHTNING_GLOBAL_ENTITY_TYPE {
+        debug!("ignoring global entity {entity_id} of unknown type {ty}");
+        return;
+    }
+    let _ = to_main.send(FromNetMessage::LightningStrike {
+        entity_id,
+        position,
+    });
+}
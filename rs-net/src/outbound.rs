@@ -1,5 +1,13 @@
-use rs_protocol::protocol::Conn;
-use rs_utils::{EntityUseAction, InventoryItemStack, ToNetMessage};
+use std::collections::HashMap;
+
+use rs_protocol::item::ItemMeta;
+use rs_protocol::nbt::{NamedTag, Tag};
+use rs_protocol::protocol::{Conn, Serializable};
+use rs_utils::creative::ENCHANTED_BOOK_ITEM_ID;
+use rs_utils::{
+    EntityUseAction, InventoryItemMeta, InventoryItemStack, RawItemTag, ToNetMessage,
+    WRITTEN_BOOK_ITEM_ID,
+};
 use tracing::{info, warn};
 
 pub(super) fn send_session_message(conn: &mut Conn, msg: ToNetMessage) {
@@ -92,6 +100,12 @@ pub(super) fn send_session_message(conn: &mut Conn, msg: ToNetMessage) {
                 rs_protocol::protocol::packet::ClientStatus::PerformRespawn,
             );
         }
+        ToNetMessage::RequestStats => {
+            let _ = rs_protocol::protocol::packet::send_client_status(
+                conn,
+                rs_protocol::protocol::packet::ClientStatus::RequestStats,
+            );
+        }
         ToNetMessage::PlayerAction {
             entity_id,
             action_id,
@@ -104,6 +118,32 @@ pub(super) fn send_session_message(conn: &mut Conn, msg: ToNetMessage) {
                 },
             );
         }
+        ToNetMessage::SteerVehicle {
+            sideways,
+            forward,
+            jump,
+            unmount,
+        } => {
+            let mut flags = 0u8;
+            if jump {
+                flags |= 0x01;
+            }
+            if unmount {
+                flags |= 0x02;
+            }
+            let _ = conn.write_packet(
+                rs_protocol::protocol::packet::play::serverbound::SteerVehicle {
+                    sideways,
+                    forward,
+                    flags,
+                },
+            );
+        }
+        ToNetMessage::SpectateTeleport { target } => {
+            let _ = conn.write_packet(
+                rs_protocol::protocol::packet::play::serverbound::SpectateTeleport { target },
+            );
+        }
         ToNetMessage::ClientAbilities {
             flags,
             flying_speed,
@@ -178,6 +218,37 @@ pub(super) fn send_session_message(conn: &mut Conn, msg: ToNetMessage) {
             let _ = conn
                 .write_packet(rs_protocol::protocol::packet::play::serverbound::CloseWindow { id });
         }
+        ToNetMessage::EnchantItem { id, enchantment } => {
+            let _ = conn.write_packet(
+                rs_protocol::protocol::packet::play::serverbound::EnchantItem { id, enchantment },
+            );
+        }
+        ToNetMessage::RenameItem { name } => {
+            let mut data = Vec::new();
+            let _ = name.write_to(&mut data);
+            send_plugin_message(conn, "MC|ItemName", data);
+        }
+        ToNetMessage::CreativeInventoryAction { slot, item } => {
+            let _ = conn.write_packet(
+                rs_protocol::protocol::packet::play::serverbound::CreativeInventoryAction {
+                    slot,
+                    clicked_item: item.map(to_protocol_stack),
+                },
+            );
+        }
+        ToNetMessage::SelectTrade { index } => {
+            let mut data = Vec::new();
+            let _ = index.write_to(&mut data);
+            send_plugin_message(conn, "MC|TrSel", data);
+        }
+        ToNetMessage::ConfirmBeacon { primary, secondary } => {
+            let mut data = Vec::new();
+            let _ = primary.write_to(&mut data);
+            let _ = secondary.write_to(&mut data);
+            send_plugin_message(conn, "MC|Beacon", data);
+        }
+        ToNetMessage::EditBook { book } => send_book(conn, "MC|BEdit", book),
+        ToNetMessage::SignBook { book } => send_book(conn, "MC|BSign", book),
         ToNetMessage::DigStart { x, y, z, face } => {
             let _ = conn.write_packet(
                 rs_protocol::protocol::packet::play::serverbound::PlayerDigging_u8 {
@@ -250,6 +321,23 @@ pub(super) fn send_session_message(conn: &mut Conn, msg: ToNetMessage) {
     }
 }
 
+/// Vanilla's own `MC|` channels, as `C17PacketCustomPayload` sends them.
+fn send_plugin_message(conn: &mut Conn, channel: &str, data: Vec<u8>) {
+    let _ = conn.write_packet(
+        rs_protocol::protocol::packet::play::serverbound::PluginMessageServerbound {
+            channel: channel.to_string(),
+            data,
+        },
+    );
+}
+
+/// `GuiScreenBook#sendBookToServer`: the whole book stack as the payload.
+fn send_book(conn: &mut Conn, channel: &str, book: InventoryItemStack) {
+    let mut data = Vec::new();
+    let _ = Some(to_protocol_stack(book)).write_to(&mut data);
+    send_plugin_message(conn, channel, data);
+}
+
 fn sanitize_outgoing_chat(input: &str) -> String {
     let filtered: String = input
         .chars()
@@ -267,5 +355,76 @@ fn to_protocol_stack(item: InventoryItemStack) -> rs_protocol::item::Stack {
     stack.id = item.item_id as isize;
     stack.count = item.count as isize;
     stack.damage = Some(item.damage as isize);
+    stack.meta = ItemMeta::from_tag(match item.meta.raw_tag {
+        Some(RawItemTag(tag)) => Some(*tag),
+        None => inventory_meta_to_nbt(item.item_id, &item.meta),
+    });
     stack
 }
+
+/// Builds a tag for stacks the client made itself, such as signed books and creative tab
+/// items; server stacks go back with their own tag untouched.
+fn inventory_meta_to_nbt(item_id: i32, meta: &InventoryItemMeta) -> Option<NamedTag> {
+    let mut display = HashMap::new();
+    if let Some(name) = &meta.display_name {
+        display.insert("Name".to_string(), Tag::String(name.clone()));
+    }
+    if !meta.lore.is_empty() {
+        let lore = meta.lore.iter().cloned().map(Tag::String).collect();
+        display.insert("Lore".to_string(), Tag::List(lore));
+    }
+    if let Some(color) = meta.display_color {
+        display.insert("color".to_string(), Tag::Int(color as i32));
+    }
+
+    let mut tag = HashMap::new();
+    if !display.is_empty() {
+        tag.insert("display".to_string(), Tag::Compound(display));
+    }
+    if !meta.enchantments.is_empty() {
+        let key = if item_id == ENCHANTED_BOOK_ITEM_ID {
+            "StoredEnchantments"
+        } else {
+            "ench"
+        };
+        let enchantments = meta
+            .enchantments
+            .iter()
+            .map(|ench| {
+                Tag::Compound(HashMap::from([
+                    ("id".to_string(), Tag::Short(ench.id)),
+                    ("lvl".to_string(), Tag::Short(ench.level)),
+                ]))
+            })
+            .collect();
+        tag.insert(key.to_string(), Tag::List(enchantments));
+    }
+    if let Some(repair_cost) = meta.repair_cost {
+        tag.insert("RepairCost".to_string(), Tag::Int(repair_cost));
+    }
+    if meta.unbreakable {
+        tag.insert("Unbreakable".to_string(), Tag::Byte(1));
+    }
+    if let Some(book) = &meta.book {
+        // Signed pages are text components, like `GuiScreenBook` writes them.
+        let pages = book
+            .pages
+            .iter()
+            .map(|page| {
+                Tag::String(if item_id == WRITTEN_BOOK_ITEM_ID {
+                    serde_json::json!({ "text": page }).to_string()
+                } else {
+                    page.clone()
+                })
+            })
+            .collect();
+        tag.insert("pages".to_string(), Tag::List(pages));
+        if let Some(title) = &book.title {
+            tag.insert("title".to_string(), Tag::String(title.clone()));
+        }
+        if let Some(author) = &book.author {
+            tag.insert("author".to_string(), Tag::String(author.clone()));
+        }
+    }
+    (!tag.is_empty()).then(|| NamedTag(String::new(), Tag::Compound(tag)))
+}
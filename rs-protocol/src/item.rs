@@ -123,6 +123,14 @@ impl Serializable for Option<Stack> {
 pub struct ItemMeta(Option<NamedTag>);
 
 impl ItemMeta {
+    pub fn from_tag(tag: Option<NamedTag>) -> Self {
+        Self(tag)
+    }
+
+    pub fn tag(&self) -> Option<&NamedTag> {
+        self.0.as_ref()
+    }
+
     fn display(&self) -> Option<&HashMap<String, Tag>> {
         match self.0.as_ref() {
             Some(tag) => tag
@@ -242,6 +250,39 @@ impl ItemMeta {
             .unwrap_or(0)
             != 0
     }
+
+    fn tag_string(&self, key: &str) -> Option<String> {
+        self.0
+            .as_ref()
+            .and_then(|tag| tag.1.as_compound())
+            .and_then(|comp| comp.get(key))
+            .and_then(|val| val.as_str())
+            .map(str::to_string)
+    }
+
+    pub fn book_title(&self) -> Option<String> {
+        self.tag_string("title")
+    }
+
+    pub fn book_author(&self) -> Option<String> {
+        self.tag_string("author")
+    }
+
+    /// Raw page strings: JSON components for written books, plain text for a book and quill.
+    pub fn pages(&self) -> Vec<String> {
+        self.0
+            .as_ref()
+            .and_then(|tag| tag.1.as_compound())
+            .and_then(|comp| comp.get("pages"))
+            .and_then(|pages| pages.as_list())
+            .map(|pages| {
+                pages
+                    .iter()
+                    .filter_map(|page| page.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
 
 pub struct Enchantment {
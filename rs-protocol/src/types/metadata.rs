@@ -62,6 +62,10 @@ impl Metadata {
         self.map.get(&index)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = (i32, &Value)> {
+        self.map.iter().map(|(index, value)| (*index, value))
+    }
+
     fn put_raw<T: MetaValue>(&mut self, index: i32, val: T) {
         self.map.insert(index, val.wrap());
     }
@@ -13,6 +13,7 @@ use crate::reflection::{
 };
 
 const EYE_HEIGHT: f32 = 1.62;
+pub(crate) const SKYBOX_BRIGHTNESS: f32 = 1000.0;
 
 pub fn spawn_player(
     mut commands: Commands,
@@ -52,7 +53,7 @@ pub fn spawn_player(
                 Msaa::Off,
                 Skybox {
                     image: skybox_handle,
-                    brightness: 1000.0,
+                    brightness: SKYBOX_BRIGHTNESS,
                     ..Default::default()
                 },
                 Projection::Perspective(PerspectiveProjection {
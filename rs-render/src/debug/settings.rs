@@ -167,6 +167,7 @@ pub struct RenderDebugSettings {
     pub frustum_fov_debug: bool,
     pub frustum_fov_deg: f32,
     pub show_chunk_borders: bool,
+    pub show_projectile_trajectories: bool,
     pub show_coordinates: bool,
     pub show_look_info: bool,
     pub show_look_ray: bool,
@@ -174,6 +175,8 @@ pub struct RenderDebugSettings {
     pub render_held_items: bool,
     pub render_first_person_arms: bool,
     pub render_self_model: bool,
+    /// How far behind the newest server position remote entities are drawn.
+    pub entity_interpolation_delay_ms: f32,
     pub shading_model: ShadingModel,
     pub shader_quality_mode: u8,
     pub enable_pbr_terrain_lighting: bool,
@@ -273,12 +276,14 @@ impl Default for RenderDebugSettings {
             frustum_fov_debug: false,
             frustum_fov_deg: 110.0,
             show_chunk_borders: false,
+            show_projectile_trajectories: false,
             show_coordinates: false,
             show_look_info: false,
             show_look_ray: false,
             show_target_block_outline: true,
             render_held_items: true,
             render_first_person_arms: true,
+            entity_interpolation_delay_ms: 100.0,
             render_self_model: true,
             shading_model: ShadingModel::VanillaLighting,
             shader_quality_mode: 2,
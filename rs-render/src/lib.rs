@@ -41,6 +41,7 @@ pub const CHUNK_OPAQUE_RENDER_LAYER: usize = reflection::CHUNK_OPAQUE_RENDER_LAY
 pub const CHUNK_CUTOUT_RENDER_LAYER: usize = reflection::CHUNK_CUTOUT_RENDER_LAYER;
 pub const CHUNK_TRANSPARENT_RENDER_LAYER: usize = reflection::CHUNK_TRANSPARENT_RENDER_LAYER;
 pub const LOCAL_PLAYER_RENDER_LAYER: usize = reflection::LOCAL_PLAYER_RENDER_LAYER;
+pub const HORSE_PREVIEW_RENDER_LAYER: usize = reflection::HORSE_PREVIEW_RENDER_LAYER;
 
 pub struct RenderPlugin;
 
@@ -76,6 +77,7 @@ impl Plugin for RenderPlugin {
                 lighting::apply_lighting_quality.after(debug::apply_render_debug_settings),
                 lighting::update_water_animation.after(lighting::apply_lighting_quality),
                 lighting::update_material_debug_stats.after(lighting::update_water_animation),
+                lighting::apply_lightning_flash.after(lighting::update_water_animation),
                 lighting::apply_antialiasing.after(debug::apply_render_debug_settings),
                 lighting::apply_ssao_quality.after(lighting::apply_lighting_quality),
                 lighting::apply_depth_prepass_for_ssr.after(lighting::apply_lighting_quality),
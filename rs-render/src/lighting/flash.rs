@@ -0,0 +1,42 @@
+use bevy::core_pipeline::Skybox;
+use bevy::prelude::*;
+
+use crate::camera::SKYBOX_BRIGHTNESS;
+use crate::chunk::{ChunkAtlasMaterial, ChunkRenderAssets};
+use rs_utils::LightningState;
+
+/// Vanilla blends the sky 45% toward pale blue while a bolt is lit.
+const SKY_FLASH: f32 = 0.45;
+const AMBIENT_FLASH: f32 = 0.6;
+
+/// Brightens sky, terrain and entities while lightning is lit. Runs after the per-frame
+/// uniform rebuild, so the flash is layered on top and never sticks.
+pub fn apply_lightning_flash(
+    lightning: Res<LightningState>,
+    assets: Res<ChunkRenderAssets>,
+    mut materials: ResMut<Assets<ChunkAtlasMaterial>>,
+    mut skyboxes: Query<&mut Skybox>,
+    mut ambient: ResMut<AmbientLight>,
+) {
+    let flash = lightning.flash();
+    for handle in [
+        &assets.opaque_material,
+        &assets.cutout_material,
+        &assets.cutout_culled_material,
+        &assets.transparent_material,
+    ] {
+        if let Some(mat) = materials.get_mut(handle) {
+            mat.extension.lighting.debug_flags.w = flash;
+        }
+    }
+
+    let sky_brightness = SKYBOX_BRIGHTNESS * (1.0 + flash * SKY_FLASH);
+    for mut skybox in &mut skyboxes {
+        if skybox.brightness != sky_brightness {
+            skybox.brightness = sky_brightness;
+        }
+    }
+    if flash > 0.0 {
+        ambient.brightness += flash * AMBIENT_FLASH;
+    }
+}
@@ -1,8 +1,10 @@
+mod flash;
 mod materials;
 mod postprocess;
 mod presets;
 mod uniforms;
 
+pub use flash::apply_lightning_flash;
 pub use materials::{
     apply_lighting_quality, update_material_debug_stats, update_water_animation,
 };
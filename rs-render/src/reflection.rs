@@ -3,5 +3,6 @@ pub const CHUNK_OPAQUE_RENDER_LAYER: usize = 1;
 pub const CHUNK_CUTOUT_RENDER_LAYER: usize = 2;
 pub const CHUNK_TRANSPARENT_RENDER_LAYER: usize = 3;
 pub const LOCAL_PLAYER_RENDER_LAYER: usize = 4;
+pub const HORSE_PREVIEW_RENDER_LAYER: usize = 5;
 
 pub const DEFAULT_WATER_PLANE_Y: f32 = 62.0;
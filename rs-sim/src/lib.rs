@@ -1,4 +1,5 @@
 use bevy::prelude::{Resource, Vec3};
+use rs_utils::{BED_BLOCK_ID, block_state_id, block_state_meta};
 use std::collections::VecDeque;
 
 pub mod collision;
@@ -181,6 +182,42 @@ impl Default for FreecamState {
     }
 }
 
+/// Vehicle the local player is riding, as announced by `EntityAttach`.
+#[derive(Debug, Default, Resource, Clone, Copy)]
+pub struct MountState {
+    pub vehicle_id: Option<i32>,
+    /// Passenger feet position on the vehicle, refreshed from the vehicle's transform.
+    pub seat_pos: Option<Vec3>,
+}
+
+impl MountState {
+    pub fn is_mounted(&self) -> bool {
+        self.vehicle_id.is_some()
+    }
+}
+
+/// Entity the server attached the view to with a `Camera` packet (spectator mode).
+#[derive(Debug, Default, Resource, Clone, Copy)]
+pub struct SpectateCameraState {
+    pub target_id: Option<i32>,
+    /// Eye position and look of the target, refreshed from its transform every frame.
+    pub eye: Option<Vec3>,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl SpectateCameraState {
+    pub fn is_active(&self) -> bool {
+        self.target_id.is_some()
+    }
+}
+
+/// Facing of the bed block at `bed`, if it is loaded and still a bed.
+pub fn bed_facing(world: &collision::WorldCollisionMap, bed: (i32, i32, i32)) -> Option<u8> {
+    let state = world.block_at(bed.0, bed.1, bed.2);
+    (block_state_id(state) == BED_BLOCK_ID).then(|| block_state_meta(state) & 3)
+}
+
 #[derive(Debug, Resource)]
 pub struct DebugUiState {
     pub open: bool,
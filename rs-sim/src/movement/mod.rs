@@ -4,7 +4,7 @@ mod simulate;
 mod world;
 
 pub use block_shapes::{collision_parity_expected_box_count, debug_block_collision_boxes};
-pub use simulate::{effective_sprint, simulate_tick};
+pub use simulate::{effective_sprint, simulate_mounted_tick, simulate_tick};
 pub use world::WorldCollision;
 
 pub(crate) const PLAYER_HALF_WIDTH: f32 = 0.3;
@@ -24,7 +24,7 @@ pub fn simulate_tick(
     if !input.jump {
         state.jump_ticks = 0;
     }
-    if !world.has_chunk_at_pos(state.pos) {
+    if input.no_clip || !world.has_chunk_at_pos(state.pos) {
         return simulate_tick_unloaded_chunk(state, input);
     }
     let sprinting = effective_sprint(input);
@@ -32,28 +32,14 @@ pub fn simulate_tick(
     let in_water = world.is_player_in_water(state.pos);
 
     if flying {
-        let fly_speed = input.flying_speed.max(0.0);
-        let fly_move_speed = fly_speed * if sprinting { FLY_SPRINT_MULT } else { 1.0 };
-
-        let wish = damped_move_input(input.strafe, input.forward, false);
-        move_flying(&mut state.vel, wish.x, wish.z, fly_move_speed, state.yaw);
-
-        if input.sneak {
-            state.vel.y -= fly_speed * FLY_VERTICAL_ACCEL_MULT;
-        }
-        if input.jump {
-            state.vel.y += fly_speed * FLY_VERTICAL_ACCEL_MULT;
-        }
-
+        accelerate_flying(&mut state, input, sprinting);
         let (pos, vel, on_ground, collided_horizontally) =
             world.resolve(state.pos, state.vel, state.on_ground);
         state.pos = pos;
         state.vel = vel;
         state.on_ground = on_ground;
         state.collided_horizontally = collided_horizontally;
-        state.vel.x *= FLY_HORIZONTAL_DAMPING;
-        state.vel.z *= FLY_HORIZONTAL_DAMPING;
-        state.vel.y *= FLY_VERTICAL_DAMPING;
+        damp_flying(&mut state);
         return state;
     }
 
@@ -147,31 +133,34 @@ pub fn simulate_tick(
     state
 }
 
+/// While riding, the vehicle owns the player's position; only the look direction is simulated.
+pub fn simulate_mounted_tick(
+    prev: &PlayerSimState,
+    input: &InputState,
+    seat: Option<Vec3>,
+) -> PlayerSimState {
+    let mut state = *prev;
+    state.yaw = input.yaw;
+    state.pitch = input.pitch;
+    state.pos = seat.unwrap_or(prev.pos);
+    state.vel = Vec3::ZERO;
+    state.on_ground = false;
+    state.collided_horizontally = false;
+    state.jump_ticks = 0;
+    state
+}
+
+/// Also used for spectators, who move without any block collision.
 fn simulate_tick_unloaded_chunk(mut state: PlayerSimState, input: &InputState) -> PlayerSimState {
     let sprinting = effective_sprint(input);
-    let flying = input.can_fly && input.flying;
+    let flying = input.no_clip || (input.can_fly && input.flying);
 
     if flying {
-        let fly_speed = input.flying_speed.max(0.0);
-        let fly_move_speed = fly_speed * if sprinting { FLY_SPRINT_MULT } else { 1.0 };
-
-        let wish = damped_move_input(input.strafe, input.forward, false);
-
-        move_flying(&mut state.vel, wish.x, wish.z, fly_move_speed, state.yaw);
-
-        if input.sneak {
-            state.vel.y -= fly_speed * FLY_VERTICAL_ACCEL_MULT;
-        }
-        if input.jump {
-            state.vel.y += fly_speed * FLY_VERTICAL_ACCEL_MULT;
-        }
-
+        accelerate_flying(&mut state, input, sprinting);
         state.pos += state.vel;
         state.on_ground = false;
         state.collided_horizontally = false;
-        state.vel.x *= FLY_HORIZONTAL_DAMPING;
-        state.vel.z *= FLY_HORIZONTAL_DAMPING;
-        state.vel.y *= FLY_VERTICAL_DAMPING;
+        damp_flying(&mut state);
         return state;
     }
 
@@ -213,6 +202,27 @@ fn simulate_tick_unloaded_chunk(mut state: PlayerSimState, input: &InputState) -
     state
 }
 
+fn accelerate_flying(state: &mut PlayerSimState, input: &InputState, sprinting: bool) {
+    let fly_speed = input.flying_speed.max(0.0);
+    let fly_move_speed = fly_speed * if sprinting { FLY_SPRINT_MULT } else { 1.0 };
+
+    let wish = damped_move_input(input.strafe, input.forward, false);
+    move_flying(&mut state.vel, wish.x, wish.z, fly_move_speed, state.yaw);
+
+    if input.sneak {
+        state.vel.y -= fly_speed * FLY_VERTICAL_ACCEL_MULT;
+    }
+    if input.jump {
+        state.vel.y += fly_speed * FLY_VERTICAL_ACCEL_MULT;
+    }
+}
+
+fn damp_flying(state: &mut PlayerSimState) {
+    state.vel.x *= FLY_HORIZONTAL_DAMPING;
+    state.vel.z *= FLY_HORIZONTAL_DAMPING;
+    state.vel.y *= FLY_VERTICAL_DAMPING;
+}
+
 fn move_flying(vel: &mut Vec3, strafe: f32, forward: f32, friction: f32, yaw: f32) {
     let f = strafe * strafe + forward * forward;
     if f < 1.0e-4 {
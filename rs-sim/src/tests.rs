@@ -1,13 +1,14 @@
+use super::bed_facing;
 use super::collision::WorldCollisionMap;
 use super::movement::{
     WorldCollision, collision_parity_expected_box_count, debug_block_collision_boxes,
-    effective_sprint, simulate_tick,
+    effective_sprint, simulate_mounted_tick, simulate_tick,
 };
 use super::predict::PredictionBuffer;
 use super::reconcile::reconcile;
 use super::types::{InputState, PlayerSimState, PredictedFrame};
 use bevy::prelude::Vec3;
-use rs_utils::BlockUpdate;
+use rs_utils::{BlockUpdate, SleepState, bed_head_position};
 
 fn input_sequence(len: usize) -> Vec<InputState> {
     let mut inputs = Vec::with_capacity(len);
@@ -23,6 +24,7 @@ fn input_sequence(len: usize) -> Vec<InputState> {
             flying_speed: 0.05,
             speed_multiplier: 1.0,
             jump_boost_amplifier: None,
+            no_clip: false,
             yaw: (i as f32 * 0.01) % 6.28,
             pitch: (i as f32 * 0.005) % 1.5,
         });
@@ -131,6 +133,65 @@ fn sprint_requires_strong_forward_input() {
     assert!(effective_sprint(&input));
 }
 
+#[test]
+fn mounted_tick_follows_seat_and_ignores_walking_input() {
+    let prev = PlayerSimState {
+        pos: Vec3::new(3.0, 64.0, 3.0),
+        vel: Vec3::new(0.2, -0.5, 0.1),
+        on_ground: true,
+        ..Default::default()
+    };
+    let input = InputState {
+        forward: 1.0,
+        jump: true,
+        yaw: 1.25,
+        pitch: -0.3,
+        ..Default::default()
+    };
+    let seat = Vec3::new(10.0, 62.5, -4.0);
+    let state = simulate_mounted_tick(&prev, &input, Some(seat));
+    assert_eq!(state.pos, seat);
+    assert_eq!(state.vel, Vec3::ZERO);
+    assert!(!state.on_ground);
+    assert_eq!(state.yaw, 1.25);
+    assert_eq!(state.pitch, -0.3);
+
+    let state = simulate_mounted_tick(&prev, &input, None);
+    assert_eq!(state.pos, prev.pos);
+}
+
+#[test]
+fn no_clip_flight_passes_through_blocks() {
+    let mut map = WorldCollisionMap::default();
+    lay_floor(&mut map, -1, 1, -1, 1, 0);
+    let world = WorldCollision::with_map(&map);
+    let start = PlayerSimState {
+        pos: Vec3::new(0.0, 1.0, 0.0),
+        on_ground: true,
+        ..Default::default()
+    };
+    let mut input = InputState {
+        sneak: true,
+        can_fly: true,
+        flying: true,
+        ..Default::default()
+    };
+
+    let mut solid = start;
+    for _ in 0..10 {
+        solid = simulate_tick(&solid, &input, &world);
+    }
+    assert_eq!(solid.pos.y, 1.0);
+
+    input.no_clip = true;
+    let mut spectator = start;
+    for _ in 0..10 {
+        spectator = simulate_tick(&spectator, &input, &world);
+    }
+    assert!(spectator.pos.y < 0.0, "pos.y={}", spectator.pos.y);
+    assert!(!spectator.on_ground);
+}
+
 #[test]
 fn sprint_jump_takeoff_matches_vanilla_reference() {
     let mut map = WorldCollisionMap::default();
@@ -400,6 +461,7 @@ fn diagonal_stair_sprint_regression_stays_stable() {
         flying_speed: 0.05,
         speed_multiplier: 1.0,
         jump_boost_amplifier: None,
+        no_clip: false,
         yaw: -std::f32::consts::FRAC_PI_2,
         pitch: 0.0,
     };
@@ -449,6 +511,7 @@ fn sprint_jump_into_stair_preserves_forward_progress() {
         flying_speed: 0.05,
         speed_multiplier: 1.0,
         jump_boost_amplifier: None,
+        no_clip: false,
         yaw: -std::f32::consts::FRAC_PI_2,
         pitch: 0.0,
     };
@@ -505,6 +568,7 @@ fn closed_door_and_gate_block_forward_motion() {
         flying_speed: 0.05,
         speed_multiplier: 1.0,
         jump_boost_amplifier: None,
+        no_clip: false,
         yaw: -std::f32::consts::FRAC_PI_2,
         pitch: 0.0,
     };
@@ -724,6 +788,7 @@ fn open_fence_gate_allows_forward_motion() {
         flying_speed: 0.05,
         speed_multiplier: 1.0,
         jump_boost_amplifier: None,
+        no_clip: false,
         yaw: std::f32::consts::PI,
         pitch: 0.0,
     };
@@ -767,6 +832,7 @@ fn open_door_allows_forward_motion() {
         flying_speed: 0.05,
         speed_multiplier: 1.0,
         jump_boost_amplifier: None,
+        no_clip: false,
         yaw: -std::f32::consts::FRAC_PI_2,
         pitch: 0.0,
     };
@@ -805,6 +871,7 @@ fn missing_chunk_does_not_hard_stop_player_motion() {
         flying_speed: 0.05,
         speed_multiplier: 1.0,
         jump_boost_amplifier: None,
+        no_clip: false,
         yaw: 0.0,
         pitch: 0.0,
     };
@@ -848,3 +915,38 @@ fn stepping_off_edge_clears_grounded_state_without_downward_motion() {
     assert!(!on_ground, "walking off support should clear grounded state");
     assert!(!collided_horizontally);
 }
+
+#[test]
+fn sleeping_fades_in_and_out_around_a_bed() {
+    let mut map = WorldCollisionMap::default();
+    // Bed head half (bit 3) pointing north.
+    map.apply_block_update(BlockUpdate {
+        x: 4,
+        y: 64,
+        z: -2,
+        block_id: block_state(26, 0x8 | 2),
+    });
+    assert_eq!(bed_facing(&map, (4, 64, -2)), Some(2));
+    assert_eq!(bed_facing(&map, (5, 64, -2)), None);
+    assert_eq!(
+        bed_head_position((4, 64, -2), 2),
+        Vec3::new(4.5, 64.6875, -2.0 + 0.5 - 0.4)
+    );
+
+    let mut sleep = SleepState::default();
+    sleep.enter(7, (4, 64, -2), 2);
+    assert!(sleep.is_sleeping());
+    for _ in 0..150 {
+        sleep.tick();
+    }
+    assert!((sleep.fade_alpha() - 220.0 / 255.0).abs() < 1e-6);
+
+    sleep.wake();
+    assert!(!sleep.is_sleeping());
+    sleep.tick();
+    assert!(sleep.fade_alpha() < 220.0 / 255.0);
+    for _ in 0..10 {
+        sleep.tick();
+    }
+    assert_eq!(sleep.fade_alpha(), 0.0);
+}
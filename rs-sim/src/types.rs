@@ -12,6 +12,8 @@ pub struct InputState {
     pub flying_speed: f32,
     pub speed_multiplier: f32,
     pub jump_boost_amplifier: Option<u8>,
+    /// Spectator mode: fly straight through blocks.
+    pub no_clip: bool,
     pub yaw: f32,
     pub pitch: f32,
 }
@@ -29,6 +31,7 @@ impl Default for InputState {
             flying_speed: 0.05,
             speed_multiplier: 1.0,
             jump_boost_amplifier: None,
+            no_clip: false,
             yaw: 0.0,
             pitch: 0.0,
         }
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use rs_render::Player;
+use rs_utils::{SoundEvent, SoundEventQueue, SoundStopScope};
+
+/// Blocks per second, taking a block as one metre.
+const SPEED_OF_SOUND: f32 = 343.0;
+const MAX_DELAY_SECS: f32 = 8.0;
+/// Anything closer than a client tick away plays right away.
+const MIN_DELAY_SECS: f32 = 0.05;
+
+#[derive(Resource, Default)]
+pub(crate) struct DelayedSounds {
+    pending: Vec<(f64, SoundEvent)>,
+}
+
+/// Holds back `distance_delay` world sounds (thunder) until sound from the source would have
+/// reached the player, then hands them back to the queue.
+pub(crate) fn delay_distant_sounds(
+    time: Res<Time>,
+    mut queue: ResMut<SoundEventQueue>,
+    mut delayed: ResMut<DelayedSounds>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+) {
+    let now = time.elapsed_secs_f64();
+    let listener = player_query.iter().next().map(GlobalTransform::translation);
+    for mut event in queue.drain() {
+        match &mut event {
+            SoundEvent::World {
+                position,
+                distance_delay,
+                ..
+            } if *distance_delay => {
+                *distance_delay = false;
+                let delay = listener
+                    .map(|listener| listener.distance(*position) / SPEED_OF_SOUND)
+                    .unwrap_or(0.0)
+                    .min(MAX_DELAY_SECS);
+                if delay >= MIN_DELAY_SECS {
+                    delayed.pending.push((now + f64::from(delay), event));
+                    continue;
+                }
+            }
+            SoundEvent::Stop { scope } => {
+                let scope = *scope;
+                delayed.pending.retain(|(_, pending)| match scope {
+                    SoundStopScope::All => false,
+                    SoundStopScope::Category(category) => !matches!(
+                        pending,
+                        SoundEvent::World {
+                            category_override: Some(pending_category),
+                            ..
+                        } if *pending_category == category
+                    ),
+                });
+            }
+            _ => {}
+        }
+        queue.push(event);
+    }
+
+    let mut index = 0;
+    while index < delayed.pending.len() {
+        if delayed.pending[index].0 <= now {
+            let (_, event) = delayed.pending.swap_remove(index);
+            queue.push(event);
+        } else {
+            index += 1;
+        }
+    }
+}
@@ -2,6 +2,7 @@ use bevy::audio::{DefaultSpatialScale, SpatialScale};
 use bevy::prelude::*;
 use rs_utils::{SoundEventQueue, SoundSettings};
 
+mod delay;
 mod events;
 mod mappings;
 mod runtime;
@@ -20,12 +21,14 @@ impl Plugin for SoundPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SoundSettings>()
             .init_resource::<SoundEventQueue>()
+            .init_resource::<delay::DelayedSounds>()
             .insert_resource(DefaultSpatialScale(SpatialScale::new(0.2)))
             .add_systems(Startup, runtime::setup_sound_runtime)
             .add_systems(
                 Update,
                 (
                     runtime::ensure_spatial_listener,
+                    delay::delay_distant_sounds.before(runtime::drain_sound_events),
                     runtime::drain_sound_events,
                     runtime::sync_playing_sound_volumes,
                 ),
@@ -0,0 +1,71 @@
+use super::*;
+use crate::container_ui::{ContainerSlots, horizontal_arrow};
+use rs_utils::{
+    ANVIL_LEFT_SLOT, ANVIL_MAX_NAME_LENGTH, ANVIL_OUTPUT_SLOT, ANVIL_RIGHT_SLOT, AnvilCost,
+    anvil_cost, anvil_display_name, anvil_rename_payload,
+};
+
+const COST_AFFORDABLE: egui::Color32 = egui::Color32::from_rgb(0x80, 0xFF, 0x20);
+const COST_UNAFFORDABLE: egui::Color32 = egui::Color32::from_rgb(0xFF, 0x60, 0x60);
+
+/// The rename field, reset whenever the left input changes like `GuiRepair#sendSlotContents`.
+#[derive(Debug, Default)]
+pub struct AnvilUiState {
+    name: String,
+    input: Option<InventoryItemStack>,
+}
+
+/// Two inputs and the output in a row, the rename field above and the level cost below.
+pub(crate) fn draw_anvil(ui: &mut egui::Ui, slots: &mut ContainerSlots) {
+    let input = slots.item(ANVIL_LEFT_SLOT);
+    if input != slots.state.anvil.input {
+        slots.state.anvil.name = input.as_ref().map(anvil_display_name).unwrap_or_default();
+        slots.state.anvil.input = input.clone();
+        send_rename(slots, input.as_ref());
+    }
+
+    let response = ui.add_enabled(
+        input.is_some(),
+        egui::TextEdit::singleline(&mut slots.state.anvil.name)
+            .char_limit(ANVIL_MAX_NAME_LENGTH)
+            .desired_width(3.0 * INVENTORY_SLOT_SIZE + 2.0 * INVENTORY_SLOT_SPACING),
+    );
+    if response.changed() {
+        send_rename(slots, input.as_ref());
+    }
+    ui.add_space(4.0);
+
+    let has_output = slots.item(ANVIL_OUTPUT_SLOT).is_some();
+    egui::Grid::new(format!("anvil_{}", slots.window_id))
+        .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+        .show(ui, |ui| {
+            slots.slot(ui, ANVIL_LEFT_SLOT);
+            ui.label("+");
+            slots.slot(ui, ANVIL_RIGHT_SLOT);
+            horizontal_arrow(ui, if has_output { 1.0 } else { 0.0 });
+            slots.slot(ui, ANVIL_OUTPUT_SLOT);
+            ui.end_row();
+        });
+
+    // Each prior anvil use doubles the next one's base cost.
+    for (label, slot) in [("Left", ANVIL_LEFT_SLOT), ("Right", ANVIL_RIGHT_SLOT)] {
+        if let Some(repair_cost) = slots.item(slot).and_then(|stack| stack.meta.repair_cost) {
+            ui.label(egui::RichText::new(format!("{label} prior work: {repair_cost}")).small());
+        }
+    }
+
+    let creative = slots.player.gamemode == 1;
+    let cost = anvil_cost(slots.properties(), has_output, slots.player.level, creative);
+    let (text, color) = match cost {
+        AnvilCost::Hidden => return,
+        AnvilCost::Affordable(cost) => (format!("Enchantment Cost: {cost}"), COST_AFFORDABLE),
+        AnvilCost::Unaffordable(cost) => (format!("Enchantment Cost: {cost}"), COST_UNAFFORDABLE),
+        AnvilCost::TooExpensive => ("Too Expensive!".to_string(), COST_UNAFFORDABLE),
+    };
+    ui.label(egui::RichText::new(text).color(color));
+}
+
+fn send_rename(slots: &ContainerSlots, input: Option<&InventoryItemStack>) {
+    let name = anvil_rename_payload(&slots.state.anvil.name, input);
+    let _ = slots.to_net.0.send(ToNetMessage::RenameItem { name });
+}
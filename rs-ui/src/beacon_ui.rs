@@ -0,0 +1,88 @@
+use super::*;
+use crate::container_ui::ContainerSlots;
+use rs_utils::{
+    BEACON_PAYMENT_SLOT, BEACON_PRIMARY_TIERS, BeaconState, beacon_power_name, is_beacon_payment,
+};
+
+const BEACON_POWER_WIDTH: f32 = 110.0;
+
+/// Primary powers by pyramid tier, the secondary row and the payment slot (`GuiBeacon`).
+pub(crate) fn draw_beacon(ui: &mut egui::Ui, slots: &mut ContainerSlots) {
+    let beacon = BeaconState::from_properties(slots.properties());
+    ui.horizontal_top(|ui| {
+        ui.vertical(|ui| {
+            ui.label("Primary Power");
+            for tier in 0..BEACON_PRIMARY_TIERS {
+                power_row(ui, slots, &beacon, tier, "");
+            }
+        });
+        ui.add_space(12.0);
+        ui.vertical(|ui| {
+            ui.label("Secondary Power");
+            power_row(ui, slots, &beacon, BEACON_PRIMARY_TIERS, " II");
+        });
+    });
+    ui.add_space(6.0);
+
+    ui.horizontal(|ui| {
+        slots.slot(ui, BEACON_PAYMENT_SLOT);
+        ui.label(
+            egui::RichText::new("Emerald, Diamond, Gold Ingot or Iron Ingot")
+                .small()
+                .color(egui::Color32::from_gray(170)),
+        );
+    });
+    let has_payment = slots
+        .item(BEACON_PAYMENT_SLOT)
+        .is_some_and(|stack| is_beacon_payment(stack.item_id));
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(beacon.can_confirm(has_payment), egui::Button::new("Done"))
+            .clicked()
+        {
+            let _ = slots.to_net.0.send(ToNetMessage::ConfirmBeacon {
+                primary: beacon.primary,
+                secondary: beacon.secondary,
+            });
+            slots.state.close_inventory = true;
+        }
+        if ui.button("Cancel").clicked() {
+            slots.state.close_inventory = true;
+        }
+    });
+}
+
+/// One tier of power buttons. Picking one only changes the local property, like vanilla; the
+/// choice reaches the server with `MC|Beacon`.
+fn power_row(
+    ui: &mut egui::Ui,
+    slots: &mut ContainerSlots,
+    beacon: &BeaconState,
+    tier: usize,
+    secondary_suffix: &str,
+) {
+    ui.horizontal(|ui| {
+        for effect_id in beacon.tier_powers(tier) {
+            // Only the primary power repeated in the secondary row is a level II upgrade.
+            let suffix = if effect_id == beacon.primary {
+                secondary_suffix
+            } else {
+                ""
+            };
+            let selected = beacon.is_selected(tier, effect_id);
+            let button = egui::Button::new(format!("{}{suffix}", beacon_power_name(effect_id)))
+                .selected(selected)
+                .min_size(egui::vec2(BEACON_POWER_WIDTH, 0.0));
+            let response = ui.add_enabled(beacon.tier_unlocked(tier), button);
+            if response.hovered() {
+                slots.hovered_any_slot = true;
+            }
+            if response.clicked() && !selected {
+                slots
+                    .inventory_state
+                    .window_properties
+                    .insert(BeaconState::selection_property(tier), effect_id as i16);
+            }
+        }
+    });
+}
@@ -0,0 +1,150 @@
+use super::*;
+use crate::overlays::draw_legacy_text;
+use rs_utils::{BOOK_PAGE_MAX_CHARS, BOOK_TITLE_MAX_CHARS, BookScreen};
+
+const BOOK_PAGE_WIDTH: f32 = 260.0;
+const BOOK_PAGE_ROWS: usize = 14;
+const BOOK_FONT_SIZE: f32 = 15.0;
+
+/// `GuiScreenBook`, drawn in place of the inventory window while a book is open.
+pub(crate) fn draw_book_screen(
+    ctx: &egui::Context,
+    to_net: &ToNet,
+    state: &mut ConnectUiState,
+    inventory_state: &mut InventoryState,
+) {
+    let Some(book) = state.book.as_mut() else {
+        return;
+    };
+    let title = if book.editable {
+        "Book and Quill"
+    } else {
+        "Written Book"
+    };
+    let mut open = true;
+    let mut done = false;
+    egui::Window::new(title)
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::new(0.0, -20.0))
+        .show(ctx, |ui| {
+            ui.set_width(BOOK_PAGE_WIDTH);
+            done = if book.signing {
+                draw_signing_page(ui, to_net, book, &state.username, inventory_state)
+            } else {
+                draw_pages(ui, to_net, book, inventory_state)
+            };
+        });
+    if !open || done {
+        state.book = None;
+    }
+}
+
+/// The current page with paging buttons; returns true once "Done" closes the book.
+fn draw_pages(
+    ui: &mut egui::Ui,
+    to_net: &ToNet,
+    book: &mut BookScreen,
+    inventory_state: &mut InventoryState,
+) -> bool {
+    ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+        ui.label(
+            egui::RichText::new(format!("Page {} of {}", book.page + 1, book.pages.len()))
+                .small()
+                .color(egui::Color32::from_gray(170)),
+        );
+    });
+    if book.editable {
+        let response = ui.add(
+            egui::TextEdit::multiline(&mut book.pages[book.page])
+                .char_limit(BOOK_PAGE_MAX_CHARS)
+                .desired_rows(BOOK_PAGE_ROWS)
+                .desired_width(f32::INFINITY),
+        );
+        if response.changed() {
+            book.modified = true;
+        }
+    } else {
+        egui::ScrollArea::vertical()
+            .max_height(BOOK_PAGE_ROWS as f32 * BOOK_FONT_SIZE * 1.25)
+            .show(ui, |ui| {
+                draw_legacy_text(ui, &book.pages[book.page], false, BOOK_FONT_SIZE);
+            });
+    }
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(book.can_go_back(), egui::Button::new("<"))
+            .clicked()
+        {
+            book.previous_page();
+        }
+        if ui
+            .add_enabled(book.can_go_forward(), egui::Button::new(">"))
+            .clicked()
+        {
+            book.next_page();
+        }
+    });
+    ui.separator();
+
+    let mut done = false;
+    ui.horizontal(|ui| {
+        if book.editable && ui.button("Sign").clicked() {
+            book.signing = true;
+        }
+        if ui.button("Done").clicked() {
+            if book.editable && book.modified {
+                let edited = book.edited_book();
+                inventory_state.set_held_book(book.hotbar_slot, edited.clone());
+                let _ = to_net.0.send(ToNetMessage::EditBook { book: edited });
+            }
+            done = true;
+        }
+    });
+    done
+}
+
+/// Title entry for signing; returns true once the signed book is sent.
+fn draw_signing_page(
+    ui: &mut egui::Ui,
+    to_net: &ToNet,
+    book: &mut BookScreen,
+    author: &str,
+    inventory_state: &mut InventoryState,
+) -> bool {
+    ui.label("Enter Book Title:");
+    ui.add(
+        egui::TextEdit::singleline(&mut book.title)
+            .char_limit(BOOK_TITLE_MAX_CHARS)
+            .desired_width(f32::INFINITY),
+    );
+    ui.label(
+        egui::RichText::new(format!("by {author}"))
+            .small()
+            .color(egui::Color32::from_gray(170)),
+    );
+    ui.add_space(6.0);
+    ui.label(
+        egui::RichText::new("Note! When you sign the book, it will no longer be editable.").small(),
+    );
+    ui.separator();
+
+    let mut done = false;
+    ui.horizontal(|ui| {
+        let can_sign = !book.title.trim().is_empty();
+        if ui
+            .add_enabled(can_sign, egui::Button::new("Sign and Close"))
+            .clicked()
+        {
+            let signed = book.signed_book(author);
+            inventory_state.set_held_book(book.hotbar_slot, signed.clone());
+            let _ = to_net.0.send(ToNetMessage::SignBook { book: signed });
+            done = true;
+        }
+        if ui.button("Cancel").clicked() {
+            book.signing = false;
+        }
+    });
+    done
+}
@@ -1,6 +1,7 @@
 use super::*;
+use crate::book_ui::draw_book_screen;
 use crate::debug_items::{build_debug_item_list, draw_debug_item_browser};
-use crate::hud::draw_hotbar_ui;
+use crate::hud::{draw_death_screen, draw_hotbar_ui, draw_sleep_overlay, draw_spectator_menu};
 use crate::inventory_interaction::{close_open_window_if_needed, draw_inventory_cursor_item};
 use crate::inventory_ui::draw_inventory_grid;
 use crate::item_icons::ItemIconCache;
@@ -13,6 +14,7 @@ use crate::overlays::{
     draw_tab_list_overlay, draw_title_overlay, handle_chat_tab_complete,
 };
 use crate::state::{ChatAutocompleteState, ConnectUiState};
+use crate::stats_ui::{draw_statistics_screen, open_statistics_screen};
 
 pub struct UiPlugin;
 
@@ -34,6 +36,12 @@ struct HudParams<'w, 's> {
     tab_list_header_footer: Res<'w, TabListHeaderFooter>,
     scoreboard: Res<'w, ScoreboardState>,
     break_indicator: Res<'w, BreakIndicator>,
+    spectator_menu: Res<'w, SpectatorMenuState>,
+    statistics: Res<'w, StatisticsState>,
+    combat: Res<'w, CombatState>,
+    difficulty: Res<'w, ServerDifficultyState>,
+    item_animation: Res<'w, ItemAnimationState>,
+    sleep: Res<'w, SleepState>,
     _marker: std::marker::PhantomData<&'s ()>,
 }
 
@@ -62,6 +70,7 @@ fn connect_ui(
     let tab_list_header_footer = &hud.tab_list_header_footer;
     let scoreboard = &hud.scoreboard;
     let break_indicator = &hud.break_indicator;
+    item_icons.set_animation(*hud.item_animation);
 
     for ev in window_events.read() {
         if ev.focused {
@@ -116,10 +125,11 @@ fn connect_ui(
     let ctx = contexts.ctx_mut().unwrap();
 
     if matches!(app_state.0, ApplicationState::Connected)
-        && inventory_state
-            .open_window
-            .as_ref()
-            .is_some_and(|window| window.id != 0)
+        && (state.book.is_some()
+            || inventory_state
+                .open_window
+                .as_ref()
+                .is_some_and(|window| window.id != 0))
     {
         ui_state.inventory_open = true;
         ui_state.chat_open = false;
@@ -131,6 +141,9 @@ fn connect_ui(
     } else if keys.just_pressed(KeyCode::Escape) && ui_state.inventory_open {
         close_open_window_if_needed(&to_net, &mut inventory_state);
         ui_state.inventory_open = false;
+        state.book = None;
+    } else if keys.just_pressed(KeyCode::Escape) && state.statistics.open {
+        state.statistics.open = false;
     } else if keys.just_pressed(KeyCode::Escape) {
         ui_state.paused = !ui_state.paused;
     } else if keys.just_pressed(KeyCode::KeyE)
@@ -143,6 +156,7 @@ fn connect_ui(
         if ui_state.inventory_open {
             close_open_window_if_needed(&to_net, &mut inventory_state);
             ui_state.inventory_open = false;
+            state.book = None;
         } else {
             ui_state.inventory_open = true;
         }
@@ -480,21 +494,21 @@ fn connect_ui(
         }
     }
 
+    if matches!(app_state.0, ApplicationState::Connected) && !player_status.dead {
+        draw_sleep_overlay(ctx, &hud.sleep, &to_net);
+    }
     if matches!(app_state.0, ApplicationState::Connected) && player_status.dead {
-        egui::Window::new("You Died")
-            .collapsible(false)
-            .resizable(false)
-            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
-            .show(ctx, |ui| {
-                ui.heading("You Died");
-                ui.add_space(8.0);
-                if ui.button("Respawn").clicked() {
-                    let _ = to_net.0.send(ToNetMessage::Respawn);
-                }
-            });
+        draw_death_screen(ctx, &hud.combat, &to_net, state.chat_font_size);
     }
 
-    if matches!(app_state.0, ApplicationState::Connected) && ui_state.paused && !player_status.dead
+    if !ui_state.paused || !matches!(app_state.0, ApplicationState::Connected) {
+        state.statistics.open = false;
+    }
+    if state.statistics.open {
+        draw_statistics_screen(ctx, &mut state.statistics, &hud.statistics);
+    } else if matches!(app_state.0, ApplicationState::Connected)
+        && ui_state.paused
+        && !player_status.dead
     {
         egui::Window::new("Paused")
             .collapsible(false)
@@ -502,6 +516,12 @@ fn connect_ui(
             .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
             .show(ctx, |ui| {
                 ui.heading("Game Paused");
+                let difficulty = if hud.difficulty.locked {
+                    format!("Difficulty: {} (locked)", hud.difficulty.label())
+                } else {
+                    format!("Difficulty: {}", hud.difficulty.label())
+                };
+                ui.label(difficulty);
                 ui.add_space(8.0);
                 let mut primary_window = window_query.single_mut().ok();
                 let options_changed = render_settings_panel(
@@ -528,6 +548,9 @@ fn connect_ui(
                     }
                 }
                 ui.add_space(8.0);
+                if ui.button("Statistics").clicked() {
+                    open_statistics_screen(&mut state.statistics, &hud.statistics, &to_net);
+                }
                 if ui.button("Controls (todo)").clicked() {}
                 if ui.button("Disconnect").clicked() {
                     if state.options_dirty {
@@ -562,14 +585,21 @@ fn connect_ui(
     }
 
     if matches!(app_state.0, ApplicationState::Connected) && !player_status.dead {
-        draw_hotbar_ui(ctx, &inventory_state, &player_status, &mut item_icons);
+        if player_status.gamemode != 3 {
+            draw_hotbar_ui(ctx, &inventory_state, &player_status, &mut item_icons);
+        } else if hud.spectator_menu.open {
+            draw_spectator_menu(ctx, &hud.spectator_menu);
+        }
     }
 
     if state.debug_items_open {
         draw_debug_item_browser(ctx, &mut state, &mut item_icons, &to_net);
     }
 
-    if matches!(app_state.0, ApplicationState::Connected)
+    if state.book.is_some() && ui_state.inventory_open && !ui_state.paused {
+        draw_book_screen(ctx, &to_net, &mut state, &mut inventory_state);
+        ui_state.inventory_open = state.book.is_some();
+    } else if matches!(app_state.0, ApplicationState::Connected)
         && ui_state.inventory_open
         && !ui_state.paused
         && !player_status.dead
@@ -595,9 +625,10 @@ fn connect_ui(
                 &mut state,
                 &mut inventory_state,
                 &mut item_icons,
+                player_status,
             );
         });
-        if !open {
+        if !open || std::mem::take(&mut state.close_inventory) {
             close_open_window_if_needed(&to_net, &mut inventory_state);
             ui_state.inventory_open = false;
         }
@@ -0,0 +1,255 @@
+use super::*;
+use crate::anvil_ui::draw_anvil;
+use crate::beacon_ui::draw_beacon;
+use crate::enchanting_ui::draw_enchanting_table;
+use crate::horse_ui::draw_horse;
+use crate::inventory_interaction::handle_inventory_slot_interaction;
+use crate::inventory_ui::draw_slot;
+use crate::item_icons::ItemIconCache;
+use crate::trading_ui::draw_villager;
+use rs_utils::{
+    CRAFTING_RESULT_SLOT, CraftingLayout, WindowKind, brewing_progress, furnace_progress,
+};
+
+const PROGRESS_TRACK: egui::Color32 = egui::Color32::from_gray(60);
+const FURNACE_FLAME: egui::Color32 = egui::Color32::from_rgb(255, 140, 20);
+const BREWING_BUBBLES: egui::Color32 = egui::Color32::from_rgb(150, 200, 255);
+
+/// Everything needed to draw and click the open window's own slots.
+pub(crate) struct ContainerSlots<'a> {
+    pub(crate) ctx: &'a egui::Context,
+    pub(crate) to_net: &'a ToNet,
+    pub(crate) keys: &'a ButtonInput<KeyCode>,
+    pub(crate) state: &'a mut ConnectUiState,
+    pub(crate) inventory_state: &'a mut InventoryState,
+    pub(crate) item_icons: &'a mut ItemIconCache,
+    pub(crate) player: &'a PlayerStatus,
+    pub(crate) window_id: u8,
+    pub(crate) unique_slots: usize,
+    pub(crate) hovered_item: Option<InventoryItemStack>,
+    pub(crate) hovered_any_slot: bool,
+}
+
+impl ContainerSlots<'_> {
+    pub(crate) fn slot(&mut self, ui: &mut egui::Ui, slot: usize) {
+        // The player window (id 0) has no container half; all of its slots are addressable.
+        if self.window_id != 0 && slot >= self.unique_slots {
+            empty_cell(ui);
+            return;
+        }
+        let item = self.item(slot);
+        let response = draw_slot(
+            self.ctx,
+            self.item_icons,
+            ui,
+            item.as_ref(),
+            false,
+            INVENTORY_SLOT_SIZE,
+            true,
+        );
+        if response.hovered() {
+            self.hovered_any_slot = true;
+            self.hovered_item = item;
+        }
+        handle_inventory_slot_interaction(
+            self.ctx,
+            response,
+            self.window_id,
+            self.unique_slots,
+            slot as i16,
+            self.keys,
+            self.to_net,
+            self.state,
+            self.inventory_state,
+        );
+    }
+
+    pub(crate) fn item(&self, slot: usize) -> Option<InventoryItemStack> {
+        let slots = if self.window_id == 0 {
+            Some(&self.inventory_state.player_slots)
+        } else {
+            self.inventory_state.window_slots.get(&self.window_id)
+        };
+        slots.and_then(|slots| slots.get(slot)).cloned().flatten()
+    }
+
+    pub(crate) fn properties(&self) -> &HashMap<i16, i16> {
+        &self.inventory_state.window_properties
+    }
+}
+
+/// Container slots laid out like the vanilla GUI for `kind`, or as a plain grid.
+pub(crate) fn draw_container_slots(
+    ui: &mut egui::Ui,
+    slots: &mut ContainerSlots,
+    window: &InventoryWindowInfo,
+) {
+    let kind = WindowKind::from_window_type(&window.kind);
+    match kind {
+        WindowKind::Furnace => draw_furnace(ui, slots),
+        WindowKind::BrewingStand => draw_brewing_stand(ui, slots),
+        WindowKind::EnchantingTable => draw_enchanting_table(ui, slots),
+        WindowKind::Anvil => draw_anvil(ui, slots),
+        WindowKind::Villager => draw_villager(ui, slots),
+        WindowKind::Beacon => draw_beacon(ui, slots),
+        WindowKind::Horse => draw_horse(ui, slots, window),
+        WindowKind::CraftingTable => draw_crafting_grid(ui, slots, CraftingLayout { width: 3 }),
+        _ => {
+            let cols = container_layout_columns(kind, &window.kind, slots.unique_slots);
+            slot_grid(ui, slots, "container_grid", cols, 0..slots.unique_slots);
+        }
+    }
+}
+
+fn slot_grid(
+    ui: &mut egui::Ui,
+    slots: &mut ContainerSlots,
+    id: &str,
+    cols: usize,
+    range: std::ops::Range<usize>,
+) {
+    if range.is_empty() {
+        return;
+    }
+    let rows = range.len().div_ceil(cols);
+    egui::Grid::new(format!("{id}_{}", slots.window_id))
+        .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+        .show(ui, |ui| {
+            for row in 0..rows {
+                for col in 0..cols {
+                    let slot = range.start + row * cols + col;
+                    if slot < range.end {
+                        slots.slot(ui, slot);
+                    } else {
+                        empty_cell(ui);
+                    }
+                }
+                ui.end_row();
+            }
+        });
+}
+
+/// Crafting grid with an arrow to the output slot, filled while a result is on offer.
+pub(crate) fn draw_crafting_grid(
+    ui: &mut egui::Ui,
+    slots: &mut ContainerSlots,
+    layout: CraftingLayout,
+) {
+    let crafted = slots.item(CRAFTING_RESULT_SLOT).is_some();
+    egui::Grid::new(format!("crafting_{}", slots.window_id))
+        .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+        .show(ui, |ui| {
+            let middle_row = layout.width / 2;
+            for row in 0..layout.width {
+                for col in 0..layout.width {
+                    slots.slot(ui, layout.grid().start + row * layout.width + col);
+                }
+                if row == middle_row {
+                    horizontal_arrow(ui, if crafted { 1.0 } else { 0.0 });
+                    slots.slot(ui, CRAFTING_RESULT_SLOT);
+                }
+                ui.end_row();
+            }
+        });
+}
+
+/// Input over flame over fuel, with the cook arrow pointing at the output (`GuiFurnace`).
+fn draw_furnace(ui: &mut egui::Ui, slots: &mut ContainerSlots) {
+    let progress = furnace_progress(slots.properties());
+    egui::Grid::new(format!("furnace_{}", slots.window_id))
+        .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+        .show(ui, |ui| {
+            slots.slot(ui, 0);
+            ui.end_row();
+            vertical_fill(ui, progress.burn, FURNACE_FLAME);
+            horizontal_arrow(ui, progress.cook);
+            slots.slot(ui, 2);
+            ui.end_row();
+            slots.slot(ui, 1);
+            ui.end_row();
+        });
+}
+
+/// Ingredient between the bubbles and the brew arrow, bottles underneath (`GuiBrewingStand`).
+fn draw_brewing_stand(ui: &mut egui::Ui, slots: &mut ContainerSlots) {
+    let progress = brewing_progress(slots.properties());
+    egui::Grid::new(format!("brewing_{}", slots.window_id))
+        .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+        .show(ui, |ui| {
+            vertical_fill(ui, progress.bubbles, BREWING_BUBBLES);
+            slots.slot(ui, 3);
+            vertical_arrow(ui, progress.arrow);
+            ui.end_row();
+            for bottle in 0..3 {
+                slots.slot(ui, bottle);
+            }
+            ui.end_row();
+        });
+}
+
+fn empty_cell(ui: &mut egui::Ui) -> egui::Rect {
+    ui.allocate_exact_size(egui::Vec2::splat(INVENTORY_SLOT_SIZE), egui::Sense::hover())
+        .0
+}
+
+/// A narrow gauge filling from the bottom, used for the furnace flame and brewing bubbles.
+fn vertical_fill(ui: &mut egui::Ui, fraction: f32, color: egui::Color32) {
+    let cell = empty_cell(ui);
+    let gauge = egui::Rect::from_center_size(cell.center(), egui::vec2(10.0, cell.height()));
+    ui.painter().rect_filled(gauge, 2.0, PROGRESS_TRACK);
+    if fraction > 0.0 {
+        let mut filled = gauge;
+        filled.set_top(gauge.bottom() - gauge.height() * fraction.clamp(0.0, 1.0));
+        ui.painter().rect_filled(filled, 2.0, color);
+    }
+}
+
+pub(crate) fn horizontal_arrow(ui: &mut egui::Ui, fraction: f32) -> egui::Rect {
+    let cell = empty_cell(ui);
+    let shaft = egui::Rect::from_center_size(cell.center(), egui::vec2(cell.width(), 8.0));
+    progress_shaft(ui, shaft, fraction, false);
+    cell
+}
+
+fn vertical_arrow(ui: &mut egui::Ui, fraction: f32) {
+    let cell = empty_cell(ui);
+    let shaft = egui::Rect::from_center_size(cell.center(), egui::vec2(8.0, cell.height()));
+    progress_shaft(ui, shaft, fraction, true);
+}
+
+fn progress_shaft(ui: &mut egui::Ui, shaft: egui::Rect, fraction: f32, vertical: bool) {
+    ui.painter().rect_filled(shaft, 2.0, PROGRESS_TRACK);
+    let fraction = fraction.clamp(0.0, 1.0);
+    if fraction > 0.0 {
+        let mut filled = shaft;
+        if vertical {
+            filled.set_bottom(shaft.top() + shaft.height() * fraction);
+        } else {
+            filled.set_right(shaft.left() + shaft.width() * fraction);
+        }
+        ui.painter().rect_filled(filled, 2.0, egui::Color32::WHITE);
+    }
+}
+
+fn container_layout_columns(kind: WindowKind, window_type: &str, unique_slots: usize) -> usize {
+    match kind {
+        WindowKind::Furnace | WindowKind::Anvil | WindowKind::Dispenser => return 3,
+        WindowKind::Hopper => return 5,
+        WindowKind::BrewingStand => return 4,
+        WindowKind::EnchantingTable => return 2,
+        WindowKind::Chest => return 9,
+        _ => {}
+    }
+    if window_type.to_ascii_lowercase().contains("chest") {
+        return 9;
+    }
+    if unique_slots.is_multiple_of(9) {
+        9
+    } else if unique_slots.is_multiple_of(5) {
+        5
+    } else if unique_slots.is_multiple_of(3) {
+        3
+    } else {
+        unique_slots.clamp(1, 9)
+    }
+}
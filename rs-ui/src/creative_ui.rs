@@ -0,0 +1,369 @@
+use super::*;
+use crate::inventory_ui::{draw_inventory_item_tooltip, draw_slot};
+use crate::item_icons::ItemIconCache;
+use rs_utils::{
+    CREATIVE_DROP_SLOT, CreativeTab, apply_creative_tab_click, creative_full_stack, creative_search,
+};
+
+/// `GuiContainerCreative` shows five rows of a tab at a time.
+const CREATIVE_VISIBLE_ROWS: f32 = 5.0;
+const CREATIVE_COLUMNS: usize = 9;
+const OUTSIDE_SLOT: i16 = -999;
+const ARMOR_SLOTS: std::ops::Range<usize> = 5..9;
+const MAIN_SLOTS: std::ops::Range<usize> = 9..36;
+const HOTBAR_SLOTS: std::ops::Range<usize> = 36..45;
+const TRASH_COLOR: egui::Color32 = egui::Color32::from_rgb(0xC0, 0x40, 0x40);
+
+/// Selected tab, search text and the tab's item list, rebuilt only when either changes.
+#[derive(Debug, Default)]
+pub struct CreativeUiState {
+    tab: CreativeTab,
+    search: String,
+    items: Option<(CreativeTab, String, Vec<InventoryItemStack>)>,
+    /// A tab item is being dragged towards the hotbar.
+    dragging: bool,
+}
+
+impl CreativeUiState {
+    fn items(&mut self) -> &[InventoryItemStack] {
+        let stale = self
+            .items
+            .as_ref()
+            .is_none_or(|(tab, search, _)| *tab != self.tab || *search != self.search);
+        if stale {
+            let items = match self.tab {
+                CreativeTab::Search => creative_search(&CreativeTab::Search.items(), &self.search),
+                tab => tab.items(),
+            };
+            self.items = Some((self.tab, self.search.clone(), items));
+        }
+        self.items.as_ref().map_or(&[], |(_, _, items)| items)
+    }
+}
+
+/// Everything a creative screen click needs; every change goes out as `CreativeInventoryAction`.
+struct CreativeScreen<'a> {
+    ctx: &'a egui::Context,
+    to_net: &'a ToNet,
+    keys: &'a ButtonInput<KeyCode>,
+    inventory_state: &'a mut InventoryState,
+    item_icons: &'a mut ItemIconCache,
+    hovered_item: Option<InventoryItemStack>,
+    hovered_any_slot: bool,
+    dragging: bool,
+}
+
+impl CreativeScreen<'_> {
+    fn send(&self, changes: Vec<(i16, Option<InventoryItemStack>)>) {
+        for (slot, item) in changes {
+            let _ = self
+                .to_net
+                .0
+                .send(ToNetMessage::CreativeInventoryAction { slot, item });
+        }
+    }
+
+    fn hovered_number_key(&self) -> Option<u8> {
+        const DIGITS: [KeyCode; 9] = [
+            KeyCode::Digit1,
+            KeyCode::Digit2,
+            KeyCode::Digit3,
+            KeyCode::Digit4,
+            KeyCode::Digit5,
+            KeyCode::Digit6,
+            KeyCode::Digit7,
+            KeyCode::Digit8,
+            KeyCode::Digit9,
+        ];
+        DIGITS
+            .iter()
+            .position(|&key| self.keys.just_pressed(key))
+            .map(|index| index as u8)
+    }
+
+    fn shift(&self) -> bool {
+        self.keys.pressed(KeyCode::ShiftLeft) || self.keys.pressed(KeyCode::ShiftRight)
+    }
+
+    fn ctrl(&self) -> bool {
+        self.keys.pressed(KeyCode::ControlLeft) || self.keys.pressed(KeyCode::ControlRight)
+    }
+
+    /// One of the player's own slots, clicked with the usual inventory rules.
+    fn player_slot(&mut self, ui: &mut egui::Ui, slot: usize) {
+        let item = self
+            .inventory_state
+            .player_slots
+            .get(slot)
+            .cloned()
+            .flatten();
+        let response = draw_slot(
+            self.ctx,
+            self.item_icons,
+            ui,
+            item.as_ref(),
+            false,
+            INVENTORY_SLOT_SIZE,
+            true,
+        );
+        let released_here = self.dragging
+            && ui.rect_contains_pointer(response.rect)
+            && self.ctx.input(|i| i.pointer.primary_released());
+        if response.hovered() || released_here {
+            self.hovered_any_slot = true;
+            self.hovered_item = item;
+        }
+        let slot = slot as i16;
+        let click = if released_here {
+            Some((0, 0))
+        } else if !response.hovered() {
+            None
+        } else if let Some(key) = self.hovered_number_key() {
+            Some((key, 2))
+        } else if self.keys.just_pressed(KeyCode::KeyQ) {
+            Some((u8::from(self.ctrl()), 4))
+        } else if response.clicked_by(egui::PointerButton::Primary) {
+            Some((0, u8::from(self.shift())))
+        } else if response.clicked_by(egui::PointerButton::Secondary) {
+            Some((1, u8::from(self.shift())))
+        } else {
+            None
+        };
+        if let Some((button, mode)) = click {
+            let changes = self
+                .inventory_state
+                .apply_creative_click(slot, button, mode);
+            self.send(changes);
+        }
+    }
+
+    /// An item of the selected tab: an endless source of copies.
+    fn tab_item(&mut self, ui: &mut egui::Ui, item: &InventoryItemStack) {
+        let response = draw_slot(
+            self.ctx,
+            self.item_icons,
+            ui,
+            Some(item),
+            false,
+            INVENTORY_SLOT_SIZE,
+            true,
+        )
+        .interact(egui::Sense::click_and_drag());
+        if !response.hovered() && !response.drag_started() {
+            return;
+        }
+        self.hovered_any_slot = true;
+        self.hovered_item = Some(item.clone());
+
+        if let Some(key) = self.hovered_number_key() {
+            let change = self.inventory_state.set_creative_hotbar(key, item);
+            self.send(change.into_iter().collect());
+            return;
+        }
+        if self.keys.just_pressed(KeyCode::KeyQ) {
+            let dropped = if self.ctrl() {
+                creative_full_stack(item)
+            } else {
+                item.clone()
+            };
+            self.send(vec![(CREATIVE_DROP_SLOT, Some(dropped))]);
+            return;
+        }
+        let shift = self.shift();
+        let cursor = &mut self.inventory_state.cursor_item;
+        if response.drag_started_by(egui::PointerButton::Primary) {
+            if cursor.is_none() {
+                apply_creative_tab_click(cursor, item, 0, shift);
+            }
+            self.dragging = cursor.is_some();
+        } else if response.clicked_by(egui::PointerButton::Middle) {
+            if cursor.is_none() {
+                *cursor = Some(creative_full_stack(item));
+            }
+        } else if response.clicked_by(egui::PointerButton::Primary) {
+            apply_creative_tab_click(cursor, item, 0, shift);
+        } else if response.clicked_by(egui::PointerButton::Secondary) {
+            apply_creative_tab_click(cursor, item, 1, shift);
+        }
+    }
+
+    /// `GuiContainerCreative`'s destroy slot: deletes the cursor stack, or with shift the
+    /// whole inventory.
+    fn trash_slot(&mut self, ui: &mut egui::Ui) {
+        let response = draw_slot(
+            self.ctx,
+            self.item_icons,
+            ui,
+            None,
+            false,
+            INVENTORY_SLOT_SIZE,
+            true,
+        );
+        ui.painter().text(
+            response.rect.center(),
+            egui::Align2::CENTER_CENTER,
+            "X",
+            egui::FontId::proportional(18.0),
+            TRASH_COLOR,
+        );
+        let response = response.on_hover_text("Destroy Item");
+        if response.hovered() {
+            self.hovered_any_slot = true;
+        }
+        if !response.clicked() {
+            return;
+        }
+        if self.shift() {
+            let changes = self.inventory_state.clear_creative_inventory();
+            self.send(changes);
+        } else {
+            self.inventory_state.cursor_item = None;
+        }
+    }
+}
+
+/// The creative inventory: tab rows, the selected tab's items (or the survival inventory) and the
+/// hotbar.
+pub(crate) fn draw_creative_inventory(
+    ctx: &egui::Context,
+    ui: &mut egui::Ui,
+    to_net: &ToNet,
+    keys: &ButtonInput<KeyCode>,
+    state: &mut ConnectUiState,
+    inventory_state: &mut InventoryState,
+    item_icons: &mut ItemIconCache,
+) {
+    let creative = &mut state.creative;
+    let mut screen = CreativeScreen {
+        ctx,
+        to_net,
+        keys,
+        inventory_state,
+        item_icons,
+        hovered_item: None,
+        hovered_any_slot: false,
+        dragging: creative.dragging,
+    };
+
+    for row in CreativeTab::ALL.chunks(6) {
+        ui.horizontal(|ui| {
+            for &tab in row {
+                let icon = tab.icon();
+                let response = draw_slot(
+                    ctx,
+                    screen.item_icons,
+                    ui,
+                    Some(&icon),
+                    tab == creative.tab,
+                    INVENTORY_SLOT_SIZE,
+                    true,
+                )
+                .on_hover_text(tab.label());
+                if response.hovered() {
+                    screen.hovered_any_slot = true;
+                }
+                if response.clicked() {
+                    creative.tab = tab;
+                }
+            }
+        });
+    }
+    ui.add_space(4.0);
+    ui.label(creative.tab.label());
+    ui.add_space(4.0);
+
+    if creative.tab == CreativeTab::Inventory {
+        draw_survival_tab(ui, &mut screen);
+    } else {
+        if creative.tab == CreativeTab::Search {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut creative.search)
+                    .hint_text("Search...")
+                    .desired_width(CREATIVE_COLUMNS as f32 * INVENTORY_SLOT_SIZE),
+            );
+            if response.hovered() {
+                screen.hovered_any_slot = true;
+            }
+            ui.add_space(4.0);
+        }
+        let row_height = INVENTORY_SLOT_SIZE + INVENTORY_SLOT_SPACING;
+        let items = creative.items().to_vec();
+        let area = egui::ScrollArea::vertical()
+            .id_salt(creative.tab.label())
+            .max_height(CREATIVE_VISIBLE_ROWS * row_height)
+            .show_rows(
+                ui,
+                row_height,
+                items.len().div_ceil(CREATIVE_COLUMNS),
+                |ui, rows| {
+                    for row in rows {
+                        ui.horizontal(|ui| {
+                            ui.spacing_mut().item_spacing.x = INVENTORY_SLOT_SPACING;
+                            let start = row * CREATIVE_COLUMNS;
+                            let end = (start + CREATIVE_COLUMNS).min(items.len());
+                            for item in &items[start..end] {
+                                screen.tab_item(ui, item);
+                            }
+                        });
+                    }
+                },
+            );
+        if ui.rect_contains_pointer(area.inner_rect) {
+            screen.hovered_any_slot = true;
+        }
+    }
+
+    ui.add_space(8.0);
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = INVENTORY_SLOT_SPACING;
+        for slot in HOTBAR_SLOTS {
+            screen.player_slot(ui, slot);
+        }
+        if creative.tab == CreativeTab::Inventory {
+            ui.add_space(8.0);
+            screen.trash_slot(ui);
+        }
+    });
+
+    let clicked_primary = ui.input(|i| i.pointer.button_clicked(egui::PointerButton::Primary));
+    let clicked_secondary = ui.input(|i| i.pointer.button_clicked(egui::PointerButton::Secondary));
+    if !screen.hovered_any_slot
+        && ui.rect_contains_pointer(ui.max_rect())
+        && (clicked_primary || clicked_secondary)
+    {
+        let button = u8::from(clicked_secondary);
+        let changes = screen
+            .inventory_state
+            .apply_creative_click(OUTSIDE_SLOT, button, 0);
+        screen.send(changes);
+    }
+    if ctx.input(|i| !i.pointer.primary_down()) {
+        screen.dragging = false;
+    }
+    creative.dragging = screen.dragging;
+
+    if let Some(stack) = screen.hovered_item.as_ref() {
+        draw_inventory_item_tooltip(ctx, stack);
+    }
+}
+
+/// Armor and main inventory, clicked like the survival screen but reported slot by slot.
+fn draw_survival_tab(ui: &mut egui::Ui, screen: &mut CreativeScreen) {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = INVENTORY_SLOT_SPACING;
+        for slot in ARMOR_SLOTS {
+            screen.player_slot(ui, slot);
+        }
+    });
+    ui.add_space(8.0);
+    egui::Grid::new("creative_main_grid")
+        .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+        .show(ui, |ui| {
+            for row in MAIN_SLOTS.step_by(CREATIVE_COLUMNS) {
+                for slot in row..row + CREATIVE_COLUMNS {
+                    screen.player_slot(ui, slot);
+                }
+                ui.end_row();
+            }
+        });
+}
@@ -0,0 +1,233 @@
+use super::*;
+use crate::container_ui::ContainerSlots;
+use crate::item_icons::{load_color_image, texturepack_textures_root};
+use crate::tooltips::{enchantment_name, format_enchantment_level};
+use rs_utils::{
+    ENCHANT_ITEM_SLOT, ENCHANT_LAPIS_SLOT, EnchantOption, LAPIS_DAMAGE, LAPIS_ITEM_ID,
+    enchant_glyph_lines, enchant_options,
+};
+
+const OPTION_SIZE: egui::Vec2 = egui::vec2(240.0, 36.0);
+const GLYPH_HEIGHT: f32 = 12.0;
+/// `GuiEnchantment` text colors for hovered, available and unavailable rows.
+const GLYPH_HOVERED: egui::Color32 = egui::Color32::from_rgb(0xFF, 0xFF, 0x80);
+const GLYPH_AVAILABLE: egui::Color32 = egui::Color32::from_rgb(0x68, 0x5E, 0x4A);
+const GLYPH_UNAVAILABLE: egui::Color32 = egui::Color32::from_rgb(0x34, 0x2F, 0x25);
+const COST_AVAILABLE: egui::Color32 = egui::Color32::from_rgb(0x80, 0xFF, 0x20);
+const COST_UNAVAILABLE: egui::Color32 = egui::Color32::from_rgb(0x40, 0x7F, 0x10);
+const OPTION_BACKGROUND: egui::Color32 = egui::Color32::from_rgb(0xC6, 0xB8, 0x9A);
+const OPTION_DISABLED: egui::Color32 = egui::Color32::from_rgb(0x8B, 0x83, 0x6E);
+
+/// `font/ascii_sga.png`: a 16x16 sheet of glyphs indexed by character code.
+pub(crate) struct GalacticFont {
+    texture: egui::TextureHandle,
+    /// Advance of each glyph in 8-pixel font units, measured like `FontRenderer#readFontTexture`.
+    widths: [u8; 256],
+}
+
+impl GalacticFont {
+    pub(crate) fn load(ctx: &egui::Context) -> Option<Self> {
+        let path = texturepack_textures_root().join("font/ascii_sga.png");
+        let image = load_color_image(&path, |_| 0)?;
+        let cell = image.width() / 16;
+        if cell == 0 {
+            return None;
+        }
+        let widths = std::array::from_fn(|code| {
+            if code == usize::from(b' ') {
+                return 4;
+            }
+            let (cell_x, cell_y) = (code % 16 * cell, code / 16 * cell);
+            let filled = (0..cell).rev().find(|&column| {
+                (0..cell).any(|row| image[(cell_x + column, cell_y + row)].a() > 0)
+            });
+            filled.map_or(0, |column| {
+                ((column + 1) as f32 * 8.0 / cell as f32 + 0.5) as u8 + 1
+            })
+        });
+        let texture = ctx.load_texture("font_ascii_sga", image, egui::TextureOptions::NEAREST);
+        Some(Self { texture, widths })
+    }
+
+    /// Paints `text` from `pos` until `max_width`, returning how far it got.
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        pos: egui::Pos2,
+        text: &str,
+        color: egui::Color32,
+        max_width: f32,
+    ) -> f32 {
+        let scale = GLYPH_HEIGHT / 8.0;
+        let mut x = 0.0;
+        for code in text.bytes() {
+            let advance = f32::from(self.widths[usize::from(code)]) * scale;
+            if x + advance > max_width {
+                break;
+            }
+            let uv_min = egui::pos2(f32::from(code % 16) / 16.0, f32::from(code / 16) / 16.0);
+            painter.image(
+                self.texture.id(),
+                egui::Rect::from_min_size(
+                    pos + egui::vec2(x, 0.0),
+                    egui::Vec2::splat(GLYPH_HEIGHT),
+                ),
+                egui::Rect::from_min_size(uv_min, egui::Vec2::splat(1.0 / 16.0)),
+                color,
+            );
+            x += advance;
+        }
+        x
+    }
+}
+
+/// Item and lapis slots beside the three enchantment buttons (`GuiEnchantment`).
+pub(crate) fn draw_enchanting_table(ui: &mut egui::Ui, slots: &mut ContainerSlots) {
+    let options = enchant_options(slots.properties());
+    let lines = enchant_glyph_lines(slots.properties());
+    let lapis = slots
+        .item(ENCHANT_LAPIS_SLOT)
+        .filter(|stack| stack.item_id == LAPIS_ITEM_ID && stack.damage == LAPIS_DAMAGE)
+        .map_or(0, |stack| stack.count);
+    ui.horizontal(|ui| {
+        egui::Grid::new(format!("enchanting_{}", slots.window_id))
+            .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+            .show(ui, |ui| {
+                slots.slot(ui, ENCHANT_ITEM_SLOT);
+                slots.slot(ui, ENCHANT_LAPIS_SLOT);
+                ui.end_row();
+            });
+        ui.add_space(8.0);
+        ui.vertical(|ui| {
+            for (index, option) in options.iter().enumerate() {
+                enchant_option_row(ui, slots, index, option, &lines[index], lapis);
+            }
+        });
+    });
+}
+
+fn enchant_option_row(
+    ui: &mut egui::Ui,
+    slots: &mut ContainerSlots,
+    index: usize,
+    option: &EnchantOption,
+    glyphs: &str,
+    lapis: u8,
+) {
+    let creative = slots.player.gamemode == 1;
+    let affordable = slots.item(ENCHANT_ITEM_SLOT).is_some()
+        && option.is_affordable(index, lapis, slots.player.level, creative);
+    let (rect, response) = ui.allocate_exact_size(OPTION_SIZE, egui::Sense::click());
+    let painter = ui.painter_at(rect);
+    let hovered = affordable && response.hovered();
+    let background = match (option.cost > 0 && affordable, hovered) {
+        (true, true) => OPTION_BACKGROUND.gamma_multiply(1.15),
+        (true, false) => OPTION_BACKGROUND,
+        (false, _) => OPTION_DISABLED,
+    };
+    painter.rect_filled(rect, 2.0, background);
+    if option.cost == 0 {
+        return;
+    }
+
+    let tier = (index + 1).to_string();
+    painter.text(
+        rect.left_center() + egui::vec2(10.0, 0.0),
+        egui::Align2::CENTER_CENTER,
+        tier,
+        egui::FontId::proportional(12.0),
+        if affordable {
+            COST_AVAILABLE
+        } else {
+            COST_UNAVAILABLE
+        },
+    );
+    let cost = option.cost.to_string();
+    let cost_galley = painter.layout_no_wrap(
+        cost,
+        egui::FontId::proportional(14.0),
+        if affordable {
+            COST_AVAILABLE
+        } else {
+            COST_UNAVAILABLE
+        },
+    );
+    let cost_pos = rect.right_center() - egui::vec2(cost_galley.size().x + 6.0, 8.0);
+    let glyph_color = match (affordable, hovered) {
+        (true, true) => GLYPH_HOVERED,
+        (true, false) => GLYPH_AVAILABLE,
+        (false, _) => GLYPH_UNAVAILABLE,
+    };
+    let glyph_origin = rect.left_top() + egui::vec2(22.0, 4.0);
+    let glyph_width = cost_pos.x - glyph_origin.x - 4.0;
+    match slots.item_icons.galactic_font(slots.ctx) {
+        Some(font) => {
+            font.paint(&painter, glyph_origin, glyphs, glyph_color, glyph_width);
+        }
+        None => {
+            painter.text(
+                glyph_origin,
+                egui::Align2::LEFT_TOP,
+                glyphs,
+                egui::FontId::proportional(11.0),
+                glyph_color,
+            );
+        }
+    }
+    painter.galley(cost_pos, cost_galley, egui::Color32::WHITE);
+
+    let level = slots.player.level;
+    let response = response
+        .on_hover_ui(|ui| enchant_option_tooltip(ui, index, option, lapis, level, creative));
+    if affordable && response.clicked() {
+        let _ = slots.to_net.0.send(ToNetMessage::EnchantItem {
+            id: slots.window_id,
+            enchantment: index as u8,
+        });
+    }
+}
+
+/// The clue and requirement lines `GuiEnchantment` shows over a button.
+fn enchant_option_tooltip(
+    ui: &mut egui::Ui,
+    index: usize,
+    option: &EnchantOption,
+    lapis: u8,
+    level: i32,
+    creative: bool,
+) {
+    if let Some((id, hint_level)) = option.hint {
+        ui.label(
+            egui::RichText::new(format!(
+                "{} {} . . . ?",
+                enchantment_name(id),
+                format_enchantment_level(hint_level)
+            ))
+            .italics()
+            .color(egui::Color32::WHITE),
+        );
+    }
+    if creative {
+        return;
+    }
+    if level < option.cost {
+        ui.label(
+            egui::RichText::new(format!("Level Requirement: {}", option.cost))
+                .color(egui::Color32::from_rgb(0xFF, 0x55, 0x55)),
+        );
+        return;
+    }
+    let tier = index + 1;
+    let lapis_color = if usize::from(lapis) >= tier {
+        egui::Color32::from_gray(0xAA)
+    } else {
+        egui::Color32::from_rgb(0xFF, 0x55, 0x55)
+    };
+    ui.label(egui::RichText::new(format!("{tier} Lapis Lazuli")).color(lapis_color));
+    let levels = if tier == 1 {
+        "1 Enchantment Level".to_string()
+    } else {
+        format!("{tier} Enchantment Levels")
+    };
+    ui.label(egui::RichText::new(levels).color(egui::Color32::from_gray(0xAA)));
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::container_ui::ContainerSlots;
+use rs_utils::{
+    HORSE_ARMOR_SLOT, HORSE_CHEST_COLUMNS, HORSE_CHEST_FIRST_SLOT, HORSE_SADDLE_SLOT,
+    horse_can_wear_armor, horse_has_chest,
+};
+
+/// Horse preview, saddle and armor slots, and the donkey or mule chest (`GuiScreenHorseInventory`).
+pub(crate) fn draw_horse(
+    ui: &mut egui::Ui,
+    slots: &mut ContainerSlots,
+    window: &InventoryWindowInfo,
+) {
+    let chested = horse_has_chest(slots.unique_slots);
+    let wears_armor = horse_can_wear_armor(window.horse_type, slots.unique_slots);
+    ui.horizontal_top(|ui| {
+        horse_preview(ui, slots, &window.title);
+        ui.add_space(INVENTORY_SLOT_SPACING);
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = INVENTORY_SLOT_SPACING;
+            slots.slot(ui, HORSE_SADDLE_SLOT);
+            // Donkeys, mules and undead horses cannot wear armor, so vanilla hides the slot.
+            if wears_armor {
+                slots.slot(ui, HORSE_ARMOR_SLOT);
+            }
+        });
+        if chested {
+            ui.add_space(INVENTORY_SLOT_SPACING);
+            egui::Grid::new(format!("horse_chest_{}", slots.window_id))
+                .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+                .show(ui, |ui| {
+                    let chest = HORSE_CHEST_FIRST_SLOT..slots.unique_slots;
+                    for row in chest.clone().step_by(HORSE_CHEST_COLUMNS) {
+                        for slot in row..(row + HORSE_CHEST_COLUMNS).min(chest.end) {
+                            slots.slot(ui, slot);
+                        }
+                        ui.end_row();
+                    }
+                });
+        }
+    });
+}
+
+/// The horse as rendered offscreen by `rs-entity`, with the saddle and armor it wears.
+fn horse_preview(ui: &mut egui::Ui, slots: &mut ContainerSlots, title: &str) {
+    ui.vertical(|ui| {
+        let preview_size = 2.0 * INVENTORY_SLOT_SIZE + INVENTORY_SLOT_SPACING;
+        match slots.state.horse_preview {
+            Some(texture) => {
+                ui.image(egui::load::SizedTexture::new(
+                    texture,
+                    egui::Vec2::splat(preview_size),
+                ));
+            }
+            // The horse is not rendered until its entity has spawned.
+            None => {
+                ui.allocate_exact_size(egui::Vec2::splat(preview_size), egui::Sense::hover());
+            }
+        }
+        let worn: Vec<String> = [HORSE_SADDLE_SLOT, HORSE_ARMOR_SLOT]
+            .into_iter()
+            .filter_map(|slot| slots.item(slot))
+            .map(|stack| item_name(stack.item_id).to_string())
+            .collect();
+        ui.label(egui::RichText::new(title).small().strong());
+        if !worn.is_empty() {
+            ui.label(egui::RichText::new(worn.join(", ")).small());
+        }
+    });
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::item_icons::ItemIconCache;
 use crate::inventory_ui::draw_slot;
+use crate::overlays::{alpha_to_u8, draw_chat_message};
 
 pub(crate) fn draw_hotbar_ui(
     ctx: &egui::Context,
@@ -98,6 +99,180 @@ pub(crate) fn draw_hotbar_ui(
         });
 }
 
+/// Death screen with the server's death message, the killer and the recent combat log.
+pub(crate) fn draw_death_screen(
+    ctx: &egui::Context,
+    combat: &CombatState,
+    to_net: &ToNet,
+    font_size: f32,
+) {
+    egui::Window::new("You Died")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.heading("You Died");
+            if let Some(death) = &combat.death {
+                if !death.message.is_empty() {
+                    draw_chat_message(ui, &death.message, font_size);
+                }
+                if let Some(killer) = &death.killer {
+                    ui.label(format!("Killed by {killer}"));
+                }
+            }
+            ui.add_space(8.0);
+            if ui.button("Respawn").clicked() {
+                let _ = to_net.0.send(ToNetMessage::Respawn);
+            }
+            if !combat.timeline.is_empty() {
+                ui.add_space(4.0);
+                egui::CollapsingHeader::new("Combat log").show(ui, |ui| {
+                    draw_combat_timeline(ui, combat);
+                });
+            }
+        });
+}
+
+/// Darkens the screen while in bed (`GuiIngame` sleep fade) and offers the vanilla
+/// "Leave Bed" button.
+pub(crate) fn draw_sleep_overlay(ctx: &egui::Context, sleep: &SleepState, to_net: &ToNet) {
+    let alpha = sleep.fade_alpha();
+    if alpha > 0.0 {
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Background,
+            egui::Id::new("sleep_fade"),
+        ));
+        painter.rect_filled(
+            ctx.screen_rect(),
+            0.0,
+            egui::Color32::from_rgba_unmultiplied(16, 16, 32, alpha_to_u8(alpha * 255.0)),
+        );
+    }
+    if !sleep.is_sleeping() {
+        return;
+    }
+    egui::Area::new(egui::Id::new("leave_bed"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::Vec2::new(0.0, -72.0))
+        .show(ctx, |ui| {
+            if ui.button("Leave Bed").clicked() {
+                let _ = to_net.0.send(ToNetMessage::PlayerAction {
+                    entity_id: sleep.entity_id,
+                    action_id: PLAYER_ACTION_LEAVE_BED,
+                });
+            }
+        });
+}
+
+fn draw_combat_timeline(ui: &mut egui::Ui, combat: &CombatState) {
+    let Some(last) = combat.timeline.back() else {
+        return;
+    };
+    if let Some(source) = &combat.last_damage_source {
+        ui.label(format!("Last damage source: {source}"));
+    }
+    if let Some(ticks) = combat.last_combat_duration_ticks {
+        ui.label(format!("Last fight: {:.1}s", ticks as f32 / 20.0));
+    }
+    ui.separator();
+    for entry in combat.timeline.iter().rev() {
+        let ago = last.at.saturating_duration_since(entry.at).as_secs_f32();
+        let text = match &entry.event {
+            CombatTimelineEvent::Entered => "Entered combat".to_string(),
+            CombatTimelineEvent::Damaged { amount, health } => {
+                format!("Took {amount:.1} damage ({health:.1} health left)")
+            }
+            CombatTimelineEvent::Ended {
+                duration_ticks,
+                opponent,
+            } => match opponent {
+                Some(opponent) => format!(
+                    "Combat with {opponent} ended after {:.1}s",
+                    *duration_ticks as f32 / 20.0
+                ),
+                None => format!("Combat ended after {:.1}s", *duration_ticks as f32 / 20.0),
+            },
+            CombatTimelineEvent::Died { killer, .. } => match killer {
+                Some(killer) => format!("Killed by {killer}"),
+                None => "Died".to_string(),
+            },
+        };
+        ui.label(format!("-{ago:.1}s  {text}"));
+    }
+}
+
+/// Spectator replacement for the hotbar: one slot per player, key 9 turns the page.
+pub(crate) fn draw_spectator_menu(ctx: &egui::Context, menu: &SpectatorMenuState) {
+    let entries = menu.page_entries();
+    let has_more_pages = menu.page_count() > 1;
+
+    egui::Area::new(egui::Id::new("spectator_menu_overlay"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::Vec2::new(0.0, -12.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::NONE
+                .fill(egui::Color32::from_black_alpha(170))
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_gray(64)))
+                .inner_margin(egui::Margin::same(6))
+                .show(ui, |ui| {
+                    let title = if entries.is_empty() {
+                        "No players to teleport to".to_string()
+                    } else if has_more_pages {
+                        format!(
+                            "Teleport to player ({}/{})",
+                            menu.page + 1,
+                            menu.page_count()
+                        )
+                    } else {
+                        "Teleport to player".to_string()
+                    };
+                    ui.label(egui::RichText::new(title).color(egui::Color32::WHITE));
+                    ui.add_space(4.0);
+                    egui::Grid::new("spectator_menu_grid")
+                        .spacing(egui::Vec2::new(
+                            INVENTORY_SLOT_SPACING,
+                            INVENTORY_SLOT_SPACING,
+                        ))
+                        .show(ui, |ui| {
+                            for (index, entry) in entries.iter().enumerate() {
+                                draw_spectator_menu_slot(ui, index + 1, &entry.name);
+                            }
+                            let last = if has_more_pages { "Next page" } else { "Close" };
+                            draw_spectator_menu_slot(ui, SPECTATOR_MENU_PAGE_SIZE + 1, last);
+                            ui.end_row();
+                        });
+                });
+        });
+}
+
+fn draw_spectator_menu_slot(ui: &mut egui::Ui, key: usize, label: &str) {
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(INVENTORY_SLOT_SIZE * 1.6, INVENTORY_SLOT_SIZE),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter();
+    painter.rect(
+        rect,
+        2.0,
+        egui::Color32::from_gray(28),
+        egui::Stroke::new(1.0, egui::Color32::from_gray(92)),
+        egui::StrokeKind::Inside,
+    );
+    painter.text(
+        rect.left_top() + egui::vec2(3.0, 2.0),
+        egui::Align2::LEFT_TOP,
+        key.to_string(),
+        egui::FontId::proportional(10.0),
+        egui::Color32::from_gray(170),
+    );
+    painter.text(
+        rect.center() + egui::vec2(0.0, 4.0),
+        egui::Align2::CENTER_CENTER,
+        label,
+        egui::FontId::proportional(10.0),
+        egui::Color32::WHITE,
+    );
+}
+
 fn draw_stat_bar(painter: &egui::Painter, rect: egui::Rect, progress: f32, fill: egui::Color32) {
     let stroke = egui::Stroke::new(1.0, egui::Color32::from_gray(92));
     painter.rect(
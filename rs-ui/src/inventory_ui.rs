@@ -1,10 +1,14 @@
 use super::*;
+use crate::container_ui::{ContainerSlots, draw_container_slots, draw_crafting_grid};
+use crate::creative_ui::draw_creative_inventory;
 use crate::inventory_interaction::{
     finish_inventory_drag_if_released, handle_inventory_slot_interaction, send_inventory_click,
 };
 use crate::item_icons::ItemIconCache;
 use crate::tooltips::{draw_item_tooltip, item_short_label};
+use rs_utils::CraftingLayout;
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn draw_inventory_grid(
     ctx: &egui::Context,
     ui: &mut egui::Ui,
@@ -13,6 +17,7 @@ pub(crate) fn draw_inventory_grid(
     state: &mut ConnectUiState,
     inventory_state: &mut InventoryState,
     item_icons: &mut ItemIconCache,
+    player: &PlayerStatus,
 ) {
     if let Some(window) = inventory_state
         .open_window
@@ -27,16 +32,32 @@ pub(crate) fn draw_inventory_grid(
             state,
             inventory_state,
             item_icons,
+            player,
             &window,
         );
         finish_inventory_drag_if_released(ctx, to_net, state, inventory_state);
         return;
     }
 
-    draw_player_inventory_grid(ctx, ui, to_net, keys, state, inventory_state, item_icons);
+    if player.gamemode == 1 {
+        draw_creative_inventory(ctx, ui, to_net, keys, state, inventory_state, item_icons);
+        return;
+    }
+
+    draw_player_inventory_grid(
+        ctx,
+        ui,
+        to_net,
+        keys,
+        state,
+        inventory_state,
+        item_icons,
+        player,
+    );
     finish_inventory_drag_if_released(ctx, to_net, state, inventory_state);
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn draw_player_inventory_grid(
     ctx: &egui::Context,
     ui: &mut egui::Ui,
@@ -45,6 +66,7 @@ pub(crate) fn draw_player_inventory_grid(
     state: &mut ConnectUiState,
     inventory_state: &mut InventoryState,
     item_icons: &mut ItemIconCache,
+    player: &PlayerStatus,
 ) {
     ui.label("Survival Inventory");
     ui.add_space(4.0);
@@ -89,6 +111,28 @@ pub(crate) fn draw_player_inventory_grid(
             ui.end_row();
         });
 
+    ui.add_space(8.0);
+    ui.label("Crafting");
+    ui.add_space(4.0);
+    let mut crafting = ContainerSlots {
+        ctx,
+        to_net,
+        keys,
+        state,
+        inventory_state,
+        item_icons,
+        player,
+        window_id: 0,
+        unique_slots: 0,
+        hovered_item: None,
+        hovered_any_slot: false,
+    };
+    draw_crafting_grid(ui, &mut crafting, CraftingLayout { width: 2 });
+    if crafting.hovered_any_slot {
+        hovered_any_slot = true;
+        hovered_item = crafting.hovered_item;
+    }
+
     ui.add_space(8.0);
     egui::Grid::new("inventory_main_grid")
         .spacing(egui::Vec2::new(
@@ -193,73 +237,29 @@ pub(crate) fn draw_container_inventory_grid(
     state: &mut ConnectUiState,
     inventory_state: &mut InventoryState,
     item_icons: &mut ItemIconCache,
+    player: &PlayerStatus,
     window: &InventoryWindowInfo,
 ) {
     let unique_slots = container_unique_slot_count(inventory_state, window);
-    let cols = container_layout_columns(&window.kind, unique_slots);
-    let rows = if unique_slots == 0 {
-        0
-    } else {
-        unique_slots.div_ceil(cols)
-    };
 
     ui.label(format!("{} ({})", window.title, window.kind));
     ui.add_space(4.0);
-    let mut hovered_any_slot = false;
-    let mut hovered_item: Option<InventoryItemStack> = None;
-
-    if rows > 0 {
-        egui::Grid::new(format!("container_grid_{}", window.id))
-            .spacing(egui::Vec2::new(
-                INVENTORY_SLOT_SPACING,
-                INVENTORY_SLOT_SPACING,
-            ))
-            .show(ui, |ui| {
-                for row in 0..rows {
-                    for col in 0..cols {
-                        let slot = row * cols + col;
-                        if slot >= unique_slots {
-                            ui.allocate_exact_size(
-                                egui::Vec2::splat(INVENTORY_SLOT_SIZE),
-                                egui::Sense::hover(),
-                            );
-                            continue;
-                        }
-                        let item = inventory_state
-                            .window_slots
-                            .get(&window.id)
-                            .and_then(|slots| slots.get(slot))
-                            .cloned()
-                            .flatten();
-                        let response = draw_slot(
-                            ctx,
-                            item_icons,
-                            ui,
-                            item.as_ref(),
-                            false,
-                            INVENTORY_SLOT_SIZE,
-                            true,
-                        );
-                        if response.hovered() {
-                            hovered_any_slot = true;
-                            hovered_item = item;
-                        }
-                        handle_inventory_slot_interaction(
-                            ctx,
-                            response,
-                            window.id,
-                            unique_slots,
-                            slot as i16,
-                            keys,
-                            to_net,
-                            state,
-                            inventory_state,
-                        );
-                    }
-                    ui.end_row();
-                }
-            });
-    }
+    let mut container = ContainerSlots {
+        ctx,
+        to_net,
+        keys,
+        state,
+        inventory_state,
+        item_icons,
+        player,
+        window_id: window.id,
+        unique_slots,
+        hovered_item: None,
+        hovered_any_slot: false,
+    };
+    draw_container_slots(ui, &mut container, window);
+    let mut hovered_any_slot = container.hovered_any_slot;
+    let mut hovered_item = container.hovered_item;
 
     ui.add_space(8.0);
     ui.label("Inventory");
@@ -388,50 +388,6 @@ fn container_unique_slot_count(
     }
 }
 
-fn container_layout_columns(kind: &str, unique_slots: usize) -> usize {
-    let normalized = kind.to_ascii_lowercase();
-    if normalized.contains("furnace") || normalized.contains("anvil") {
-        return 3;
-    }
-    if normalized.contains("hopper") {
-        return 5;
-    }
-    if normalized.contains("beacon") {
-        return 1;
-    }
-    if normalized.contains("brewing") {
-        return 4;
-    }
-    if normalized.contains("enchant") {
-        return 2;
-    }
-    if normalized.contains("dispenser")
-        || normalized.contains("dropper")
-        || normalized.contains("chest")
-        || normalized.contains("container")
-        || normalized == "type_0"
-        || normalized == "type_3"
-        || normalized == "type_10"
-    {
-        return 9;
-    }
-    if normalized == "type_2" || normalized == "type_8" {
-        return 3;
-    }
-    if normalized == "type_9" {
-        return 5;
-    }
-    if unique_slots.is_multiple_of(9) {
-        9
-    } else if unique_slots.is_multiple_of(5) {
-        5
-    } else if unique_slots.is_multiple_of(3) {
-        3
-    } else {
-        unique_slots.clamp(1, 9)
-    }
-}
-
 fn container_player_slot_item(
     inventory_state: &InventoryState,
     window_id: u8,
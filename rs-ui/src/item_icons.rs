@@ -1,4 +1,5 @@
 use super::*;
+use crate::enchanting_ui::GalacticFont;
 
 #[derive(Resource)]
 pub struct ItemIconCache {
@@ -8,6 +9,8 @@ pub struct ItemIconCache {
     block_texture_images: HashMap<String, Option<egui::ColorImage>>,
     logged_stone_fallback: HashSet<(i32, i16)>,
     logged_model_fallback: HashSet<(i32, i16)>,
+    animation: ItemAnimationState,
+    galactic_font: Option<Option<GalacticFont>>,
 }
 
 impl Default for ItemIconCache {
@@ -19,15 +22,40 @@ impl Default for ItemIconCache {
             block_texture_images: HashMap::new(),
             logged_stone_fallback: HashSet::new(),
             logged_model_fallback: HashSet::new(),
+            animation: ItemAnimationState::default(),
+            galactic_font: None,
         }
     }
 }
 
 impl ItemIconCache {
+    /// The texture pack's Standard Galactic font, loaded on first use.
+    pub(crate) fn galactic_font(&mut self, ctx: &egui::Context) -> Option<&GalacticFont> {
+        self.galactic_font
+            .get_or_insert_with(|| GalacticFont::load(ctx))
+            .as_ref()
+    }
+
+    /// Compass and clock icons follow the frames of the latest client tick.
+    pub(crate) fn set_animation(&mut self, animation: ItemAnimationState) {
+        self.animation = animation;
+    }
+
     pub(crate) fn texture_for_stack(
         &mut self,
         ctx: &egui::Context,
         stack: &InventoryItemStack,
+    ) -> Option<egui::TextureId> {
+        match self.animation.display_stack(stack) {
+            Some(display) => self.texture_for_display_stack(ctx, &display),
+            None => self.texture_for_display_stack(ctx, stack),
+        }
+    }
+
+    fn texture_for_display_stack(
+        &mut self,
+        ctx: &egui::Context,
+        stack: &InventoryItemStack,
     ) -> Option<egui::TextureId> {
         let key = (stack.item_id, stack.damage);
         if let Some(handle) = self.loaded.get(&key) {
@@ -53,7 +81,9 @@ impl ItemIconCache {
             if rel_path.starts_with("items/") {
                 has_explicit_item_texture = true;
             }
-            let Some(color_image) = load_color_image(&full_path) else {
+            let Some(color_image) = load_color_image(&full_path, |frames| {
+                item_strip_frame(stack.item_id, stack.damage, frames)
+            }) else {
                 continue;
             };
             first_candidate_image = Some((rel_path.clone(), color_image));
@@ -466,7 +496,7 @@ fn load_model_texture(
         return cached.clone();
     }
     let path = texturepack_textures_root().join(texture_path);
-    let image = load_color_image(&path);
+    let image = load_color_image(&path, |_| 0);
     cache.insert(texture_path.to_string(), image.clone());
     image
 }
@@ -632,18 +662,22 @@ fn edge_fn(a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> f32 {
     (c[0] - a[0]) * (b[1] - a[1]) - (c[1] - a[1]) * (b[0] - a[0])
 }
 
-fn texturepack_textures_root() -> PathBuf {
+pub(crate) fn texturepack_textures_root() -> PathBuf {
     rs_utils::texturepack_textures_root()
 }
 
-fn load_color_image(path: &Path) -> Option<egui::ColorImage> {
+pub(crate) fn load_color_image(
+    path: &Path,
+    strip_frame: impl FnOnce(u32) -> u32,
+) -> Option<egui::ColorImage> {
     let bytes = std::fs::read(path).ok()?;
     let mut rgba = image::load_from_memory(&bytes).ok()?.to_rgba8();
-    // For animated texture sheets (e.g. frame stacks), use first frame only.
-    // Vanilla atlas animation advances frames over time; debug icons should not stretch the full sheet.
+    // For animated texture sheets (e.g. frame stacks), pick a single frame: the dial frame for
+    // compasses and clocks, the first one otherwise. Icons should not stretch the full sheet.
     if rgba.height() > rgba.width() && rgba.height() % rgba.width() == 0 {
         let w = rgba.width();
-        let frame = image::imageops::crop(&mut rgba, 0, 0, w, w).to_image();
+        let index = strip_frame(rgba.height() / w);
+        let frame = image::imageops::crop(&mut rgba, 0, index * w, w, w).to_image();
         rgba = frame;
     }
     let size = [rgba.width() as usize, rgba.height() as usize];
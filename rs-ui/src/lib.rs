@@ -17,17 +17,26 @@ use rs_render::{
 };
 use rs_utils::{
     AppState, ApplicationState, AuthMode, BlockFace, BlockModelKind, BreakIndicator, Chat,
-    InventoryItemStack, InventoryState, InventoryWindowInfo, PerfTimings, PlayerStatus,
-    ScoreboardState, SoundSettings, TabListHeaderFooter, TitleOverlayState, ToNet, ToNetMessage,
-    UiState, WorldTime, block_model_kind, block_registry_key, block_texture_name,
-    item_max_durability, item_name, item_registry_key, item_texture_candidates,
+    CombatState, CombatTimelineEvent, InventoryItemStack, InventoryState, InventoryWindowInfo,
+    ItemAnimationState, PLAYER_ACTION_LEAVE_BED, PerfTimings, PlayerStatus,
+    SPECTATOR_MENU_PAGE_SIZE, ScoreboardState, ServerDifficultyState, SleepState, SoundSettings,
+    SpectatorMenuState, StatisticsState, TabListHeaderFooter, TitleOverlayState, ToNet,
+    ToNetMessage, UiState, WorldTime, block_model_kind, block_registry_key, block_texture_name,
+    item_max_durability, item_name, item_registry_key, item_strip_frame, item_texture_candidates,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tracing::warn;
 
+mod anvil_ui;
+mod beacon_ui;
+mod book_ui;
 mod connect;
+mod container_ui;
+mod creative_ui;
 mod debug_items;
+mod enchanting_ui;
+mod horse_ui;
 mod hud;
 mod inventory_interaction;
 mod inventory_ui;
@@ -36,7 +45,9 @@ mod options_persistence;
 mod options_ui;
 mod overlays;
 mod state;
+mod stats_ui;
 mod tooltips;
+mod trading_ui;
 
 pub use connect::UiPlugin;
 pub use item_icons::ItemIconCache;
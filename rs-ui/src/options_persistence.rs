@@ -4,6 +4,10 @@ fn default_simulation_distance_chunks() -> i32 {
     RenderDebugSettings::default().simulation_distance_chunks
 }
 
+fn default_entity_interpolation_delay_ms() -> f32 {
+    RenderDebugSettings::default().entity_interpolation_delay_ms
+}
+
 fn default_voxel_ao_foliage_boost() -> f32 {
     RenderDebugSettings::default().voxel_ao_foliage_boost
 }
@@ -32,6 +36,8 @@ struct ClientOptionsFile {
     pub render_held_items: bool,
     pub render_first_person_arms: bool,
     pub render_self_model: bool,
+    #[serde(default = "default_entity_interpolation_delay_ms")]
+    pub entity_interpolation_delay_ms: f32,
     pub show_chunk_borders: bool,
     pub shading_model: String,
     pub shader_quality_mode: u8,
@@ -148,6 +154,7 @@ impl Default for ClientOptionsFile {
             render_held_items: render.render_held_items,
             render_first_person_arms: render.render_first_person_arms,
             render_self_model: render.render_self_model,
+            entity_interpolation_delay_ms: render.entity_interpolation_delay_ms,
             show_chunk_borders: render.show_chunk_borders,
             shading_model: render.shading_model.as_options_value().to_string(),
             shader_quality_mode: render.shader_quality_mode,
@@ -267,6 +274,7 @@ pub(crate) fn options_to_file(
         render_held_items: render.render_held_items,
         render_first_person_arms: render.render_first_person_arms,
         render_self_model: render.render_self_model,
+        entity_interpolation_delay_ms: render.entity_interpolation_delay_ms,
         show_chunk_borders: render.show_chunk_borders,
         shading_model: render.shading_model.as_options_value().to_string(),
         shader_quality_mode: render.shader_quality_mode,
@@ -389,6 +397,7 @@ pub fn apply_options(
     render.render_held_items = options.render_held_items;
     render.render_first_person_arms = options.render_first_person_arms;
     render.render_self_model = options.render_self_model;
+    render.entity_interpolation_delay_ms = options.entity_interpolation_delay_ms.clamp(0.0, 300.0);
     render.show_chunk_borders = options.show_chunk_borders;
     render.shading_model = ShadingModel::from_options_value(&options.shading_model)
         .unwrap_or(if options.enable_pbr_terrain_lighting {
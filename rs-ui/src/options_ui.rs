@@ -101,6 +101,7 @@ enum SettingId {
     RenderHeldItems,
     RenderFirstPersonArms,
     RenderSelfModel,
+    EntityInterpolationDelay,
     Vsync,
     ShadingPreset,
     SyncSunWithTime,
@@ -178,6 +179,7 @@ enum SettingId {
     FrustumFovDebug,
     FrustumFov,
     ShowChunkBorders,
+    ShowProjectileTrajectories,
     MeshJobsPerFrame,
     MeshUploadsPerFrame,
     MaxAsyncMeshing,
@@ -244,6 +246,7 @@ const SETTINGS: &[SettingEntry] = &[
     SettingEntry { id: SettingId::RenderHeldItems, title: "Render held items", category: SettingsCategory::General, aliases: &["held items"], visible: always },
     SettingEntry { id: SettingId::RenderFirstPersonArms, title: "Render first-person arms", category: SettingsCategory::General, aliases: &["arms", "first person"], visible: always },
     SettingEntry { id: SettingId::RenderSelfModel, title: "Render self model", category: SettingsCategory::General, aliases: &["self model"], visible: always },
+    SettingEntry { id: SettingId::EntityInterpolationDelay, title: "Entity interpolation delay", category: SettingsCategory::General, aliases: &["interpolation", "entity smoothing", "lag"], visible: always },
     SettingEntry { id: SettingId::Vsync, title: "VSync", category: SettingsCategory::General, aliases: &["vsync", "vertical sync"], visible: always },
     SettingEntry { id: SettingId::ShadingPreset, title: "Shading preset", category: SettingsCategory::Lighting, aliases: &["shading", "lighting mode", "preset"], visible: always },
     SettingEntry { id: SettingId::SyncSunWithTime, title: "Sync sun with world time", category: SettingsCategory::Lighting, aliases: &["sun sync", "time"], visible: always },
@@ -321,6 +324,7 @@ const SETTINGS: &[SettingEntry] = &[
     SettingEntry { id: SettingId::FrustumFovDebug, title: "Frustum FOV debug", category: SettingsCategory::Diagnostics, aliases: &["frustum"], visible: always },
     SettingEntry { id: SettingId::FrustumFov, title: "Frustum FOV", category: SettingsCategory::Diagnostics, aliases: &["frustum fov"], visible: frustum_fov_visible },
     SettingEntry { id: SettingId::ShowChunkBorders, title: "Show chunk borders", category: SettingsCategory::Diagnostics, aliases: &["chunk borders"], visible: always },
+    SettingEntry { id: SettingId::ShowProjectileTrajectories, title: "Show projectile trajectories", category: SettingsCategory::Diagnostics, aliases: &["projectile", "trajectory", "arrow path"], visible: always },
     SettingEntry { id: SettingId::MeshJobsPerFrame, title: "Mesh jobs per frame", category: SettingsCategory::Diagnostics, aliases: &["mesh jobs"], visible: always },
     SettingEntry { id: SettingId::MeshUploadsPerFrame, title: "Mesh uploads per frame", category: SettingsCategory::Diagnostics, aliases: &["mesh uploads"], visible: always },
     SettingEntry { id: SettingId::MaxAsyncMeshing, title: "Max async meshing", category: SettingsCategory::Diagnostics, aliases: &["async meshing"], visible: always },
@@ -517,6 +521,7 @@ fn render_setting_row(
         SettingId::RenderHeldItems => ui.checkbox(&mut render_debug.render_held_items, "Render held items").changed(),
         SettingId::RenderFirstPersonArms => ui.checkbox(&mut render_debug.render_first_person_arms, "Render first-person arms").changed(),
         SettingId::RenderSelfModel => ui.checkbox(&mut render_debug.render_self_model, "Render self model").changed(),
+        SettingId::EntityInterpolationDelay => ui.add(egui::Slider::new(&mut render_debug.entity_interpolation_delay_ms, 0.0..=300.0).text("Entity interpolation delay (ms)")).changed(),
         SettingId::Vsync => {
             let changed = ui.checkbox(&mut state.vsync_enabled, "VSync").changed();
             if changed {
@@ -667,6 +672,7 @@ fn render_setting_row(
         SettingId::FrustumFovDebug => ui.checkbox(&mut render_debug.frustum_fov_debug, "Frustum FOV debug").changed(),
         SettingId::FrustumFov => ui.add(egui::Slider::new(&mut render_debug.frustum_fov_deg, 30.0..=140.0).text("Frustum FOV")).changed(),
         SettingId::ShowChunkBorders => ui.checkbox(&mut render_debug.show_chunk_borders, "Show chunk borders").changed(),
+        SettingId::ShowProjectileTrajectories => ui.checkbox(&mut render_debug.show_projectile_trajectories, "Show projectile trajectories").changed(),
         SettingId::MeshJobsPerFrame => ui.add(egui::Slider::new(&mut render_debug.mesh_enqueue_budget_per_frame, 1..=128).text("Mesh jobs per frame")).changed(),
         SettingId::MeshUploadsPerFrame => ui.add(egui::Slider::new(&mut render_debug.mesh_apply_budget_per_frame, 1..=64).text("Mesh uploads per frame")).changed(),
         SettingId::MaxAsyncMeshing => ui.add(egui::Slider::new(&mut render_debug.mesh_max_in_flight, 1..=256).text("Max async meshing")).changed(),
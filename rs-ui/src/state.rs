@@ -1,6 +1,11 @@
 use super::*;
+use crate::anvil_ui::AnvilUiState;
+use crate::creative_ui::CreativeUiState;
 use crate::options_ui::SettingsCategoryState;
 use crate::options_persistence::default_prism_accounts_path;
+use crate::stats_ui::StatisticsUiState;
+use crate::trading_ui::TradingUiState;
+use rs_utils::BookScreen;
 
 #[derive(Resource)]
 pub struct ConnectUiState {
@@ -29,6 +34,16 @@ pub struct ConnectUiState {
     pub debug_items_filter: String,
     pub debug_items: Vec<InventoryItemStack>,
     pub inventory_drag: Option<InventoryDragUiState>,
+    pub statistics: StatisticsUiState,
+    pub anvil: AnvilUiState,
+    pub trading: TradingUiState,
+    pub creative: CreativeUiState,
+    /// Set by a container screen that closes itself, like the beacon's "Done".
+    pub close_inventory: bool,
+    /// Book open in place of the inventory window.
+    pub book: Option<BookScreen>,
+    /// The open horse window's horse, rendered offscreen by the entity renderer.
+    pub horse_preview: Option<egui::TextureId>,
 }
 impl Default for ConnectUiState {
     fn default() -> Self {
@@ -58,6 +73,13 @@ impl Default for ConnectUiState {
             debug_items_filter: String::new(),
             debug_items: Vec::new(),
             inventory_drag: None,
+            statistics: StatisticsUiState::default(),
+            anvil: AnvilUiState::default(),
+            trading: TradingUiState::default(),
+            creative: CreativeUiState::default(),
+            close_inventory: false,
+            book: None,
+            horse_preview: None,
         }
     }
 }
@@ -0,0 +1,260 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatisticsTab {
+    #[default]
+    General,
+    Blocks,
+    Items,
+    Mobs,
+    Achievements,
+}
+
+impl StatisticsTab {
+    const ALL: [StatisticsTab; 5] = [
+        StatisticsTab::General,
+        StatisticsTab::Blocks,
+        StatisticsTab::Items,
+        StatisticsTab::Mobs,
+        StatisticsTab::Achievements,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            StatisticsTab::General => "General",
+            StatisticsTab::Blocks => "Blocks",
+            StatisticsTab::Items => "Items",
+            StatisticsTab::Mobs => "Mobs",
+            StatisticsTab::Achievements => "Achievements",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StatisticsUiState {
+    pub open: bool,
+    tab: StatisticsTab,
+    sort_column: usize,
+    sort_descending: bool,
+    /// `StatisticsState::revision` when the request went out; unchanged means still waiting.
+    requested_revision: Option<u32>,
+}
+
+enum Cell {
+    Text(String),
+    Number(i32, String),
+}
+
+impl Cell {
+    fn count(value: i32) -> Self {
+        Cell::Number(value, value.to_string())
+    }
+
+    fn display(&self) -> &str {
+        match self {
+            Cell::Text(text) | Cell::Number(_, text) => text,
+        }
+    }
+
+    fn compare(&self, other: &Cell) -> Ordering {
+        match (self, other) {
+            (Cell::Number(a, _), Cell::Number(b, _)) => a.cmp(b),
+            _ => self
+                .display()
+                .to_lowercase()
+                .cmp(&other.display().to_lowercase()),
+        }
+    }
+}
+
+/// Opens the screen and asks the server for fresh numbers (`ClientStatus` request-stats).
+pub(crate) fn open_statistics_screen(
+    stats_ui: &mut StatisticsUiState,
+    statistics: &StatisticsState,
+    to_net: &ToNet,
+) {
+    stats_ui.open = true;
+    stats_ui.requested_revision = Some(statistics.revision);
+    let _ = to_net.0.send(ToNetMessage::RequestStats);
+}
+
+pub(crate) fn draw_statistics_screen(
+    ctx: &egui::Context,
+    stats_ui: &mut StatisticsUiState,
+    statistics: &StatisticsState,
+) {
+    egui::Window::new("Statistics")
+        .collapsible(false)
+        .resizable(true)
+        .default_size(egui::vec2(560.0, 420.0))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            if stats_ui.requested_revision == Some(statistics.revision) {
+                ui.label("Downloading statistics...");
+                ui.add_space(8.0);
+                if ui.button("Done").clicked() {
+                    stats_ui.open = false;
+                }
+                return;
+            }
+
+            ui.horizontal(|ui| {
+                for tab in StatisticsTab::ALL {
+                    if ui
+                        .selectable_label(stats_ui.tab == tab, tab.label())
+                        .clicked()
+                        && stats_ui.tab != tab
+                    {
+                        stats_ui.tab = tab;
+                        stats_ui.sort_column = 0;
+                        stats_ui.sort_descending = false;
+                    }
+                }
+            });
+            ui.separator();
+
+            let (columns, rows) = statistics_table(stats_ui.tab, statistics);
+            egui::ScrollArea::vertical()
+                .max_height(ui.available_height() - 32.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    draw_sortable_table(ui, stats_ui, &columns, rows);
+                });
+            ui.add_space(6.0);
+            if ui.button("Done").clicked() {
+                stats_ui.open = false;
+            }
+        });
+}
+
+fn statistics_table(
+    tab: StatisticsTab,
+    statistics: &StatisticsState,
+) -> (Vec<&'static str>, Vec<Vec<Cell>>) {
+    match tab {
+        StatisticsTab::General => (
+            vec!["Statistic", "Value"],
+            statistics
+                .general_rows()
+                .into_iter()
+                .map(|row| vec![Cell::Text(row.label), Cell::Number(row.value, row.display)])
+                .collect(),
+        ),
+        StatisticsTab::Blocks => (
+            vec!["Block", "Crafted", "Placed", "Mined"],
+            statistics
+                .block_rows()
+                .into_iter()
+                .map(|row| {
+                    vec![
+                        Cell::Text(row.name),
+                        Cell::count(row.crafted),
+                        Cell::count(row.placed),
+                        Cell::count(row.mined),
+                    ]
+                })
+                .collect(),
+        ),
+        StatisticsTab::Items => (
+            vec!["Item", "Crafted", "Used", "Broken"],
+            statistics
+                .item_rows()
+                .into_iter()
+                .map(|row| {
+                    vec![
+                        Cell::Text(row.name),
+                        Cell::count(row.crafted),
+                        Cell::count(row.used),
+                        Cell::count(row.broken),
+                    ]
+                })
+                .collect(),
+        ),
+        StatisticsTab::Mobs => (
+            vec!["Mob", "Killed", "Killed You"],
+            statistics
+                .mob_rows()
+                .into_iter()
+                .map(|row| {
+                    vec![
+                        Cell::Text(row.name),
+                        Cell::count(row.kills),
+                        Cell::count(row.killed_by),
+                    ]
+                })
+                .collect(),
+        ),
+        StatisticsTab::Achievements => (
+            vec!["Achievement", "Status"],
+            statistics
+                .achievement_rows()
+                .into_iter()
+                .map(|row| {
+                    let status = if row.unlocked { "Taken" } else { "Locked" };
+                    vec![
+                        Cell::Text(row.name),
+                        Cell::Number(i32::from(row.unlocked), status.to_string()),
+                    ]
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Grid with clickable headers: first click sorts a column (numbers high-to-low), the next
+/// click flips the direction.
+fn draw_sortable_table(
+    ui: &mut egui::Ui,
+    stats_ui: &mut StatisticsUiState,
+    columns: &[&str],
+    mut rows: Vec<Vec<Cell>>,
+) {
+    if rows.is_empty() {
+        ui.label("Nothing recorded yet.");
+        return;
+    }
+    let sort_column = stats_ui.sort_column.min(columns.len() - 1);
+    rows.sort_by(|a, b| {
+        let ordering = a[sort_column].compare(&b[sort_column]);
+        if stats_ui.sort_descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    egui::Grid::new(("statistics_table", stats_ui.tab.label()))
+        .striped(true)
+        .num_columns(columns.len())
+        .min_col_width(90.0)
+        .show(ui, |ui| {
+            for (index, title) in columns.iter().enumerate() {
+                let text = if index == sort_column {
+                    let arrow = if stats_ui.sort_descending { "v" } else { "^" };
+                    format!("{title} {arrow}")
+                } else {
+                    title.to_string()
+                };
+                if ui
+                    .add(egui::Button::new(egui::RichText::new(text).strong()).frame(false))
+                    .clicked()
+                {
+                    if index == sort_column {
+                        stats_ui.sort_descending = !stats_ui.sort_descending;
+                    } else {
+                        stats_ui.sort_column = index;
+                        stats_ui.sort_descending = index > 0;
+                    }
+                }
+            }
+            ui.end_row();
+            for row in &rows {
+                for cell in row {
+                    ui.label(cell.display());
+                }
+                ui.end_row();
+            }
+        });
+}
@@ -1,12 +1,21 @@
 use super::*;
 
 pub(crate) fn draw_item_tooltip(ui: &mut egui::Ui, stack: &InventoryItemStack) {
+    let book = stack.meta.book.as_ref();
     let display_name = stack
         .meta
         .display_name
         .as_deref()
+        .or_else(|| book.and_then(|book| book.title.as_deref()))
         .unwrap_or_else(|| item_name(stack.item_id));
     ui.label(egui::RichText::new(display_name).strong());
+    if let Some(author) = book.and_then(|book| book.author.as_deref()) {
+        ui.label(
+            egui::RichText::new(format!("by {author}"))
+                .small()
+                .color(egui::Color32::from_gray(170)),
+        );
+    }
     ui.label(egui::RichText::new(format!("Count: {}", stack.count)).small());
     ui.label(egui::RichText::new(format!("ID: {}  Meta: {}", stack.item_id, stack.damage)).small());
 
@@ -45,7 +54,7 @@ pub(crate) fn draw_item_tooltip(ui: &mut egui::Ui, stack: &InventoryItemStack) {
     }
 }
 
-fn enchantment_name(id: i16) -> &'static str {
+pub(crate) fn enchantment_name(id: i16) -> &'static str {
     match id {
         0 => "Protection",
         1 => "Fire Protection",
@@ -76,7 +85,7 @@ fn enchantment_name(id: i16) -> &'static str {
     }
 }
 
-fn format_enchantment_level(level: i16) -> String {
+pub(crate) fn format_enchantment_level(level: i16) -> String {
     match level {
         1 => "I".to_string(),
         2 => "II".to_string(),
@@ -0,0 +1,126 @@
+use super::*;
+use crate::container_ui::{ContainerSlots, horizontal_arrow};
+use crate::inventory_ui::draw_slot;
+use rs_utils::{
+    TRADE_FIRST_INPUT_SLOT, TRADE_OUTPUT_SLOT, TRADE_SECOND_INPUT_SLOT, TradeOffer, page_trade,
+};
+
+const DISABLED_CROSS: egui::Color32 = egui::Color32::from_rgb(0xD0, 0x20, 0x20);
+
+/// Offer currently paged to; `GuiMerchant` starts every villager window on the first one.
+#[derive(Debug, Default)]
+pub struct TradingUiState {
+    window_id: Option<u8>,
+    selected: usize,
+}
+
+/// The selected offer above the two payment slots and the output (`GuiMerchant`).
+pub(crate) fn draw_villager(ui: &mut egui::Ui, slots: &mut ContainerSlots) {
+    if slots.state.trading.window_id != Some(slots.window_id) {
+        slots.state.trading = TradingUiState {
+            window_id: Some(slots.window_id),
+            selected: 0,
+        };
+    }
+    let offers = slots.inventory_state.trade_offers.clone();
+    let selected = slots
+        .state
+        .trading
+        .selected
+        .min(offers.len().saturating_sub(1));
+    let offer = offers.get(selected);
+
+    egui::Grid::new(format!("villager_{}", slots.window_id))
+        .spacing(egui::Vec2::splat(INVENTORY_SLOT_SPACING))
+        .show(ui, |ui| {
+            page_button(ui, slots, "<", selected, offers.len(), false);
+            offer_item(ui, slots, offer.map(|offer| &offer.input));
+            offer_item(
+                ui,
+                slots,
+                offer.and_then(|offer| offer.second_input.as_ref()),
+            );
+            trade_arrow(ui, slots, offer);
+            offer_item(ui, slots, offer.map(|offer| &offer.output));
+            page_button(ui, slots, ">", selected, offers.len(), true);
+            ui.end_row();
+
+            ui.label("");
+            slots.slot(ui, TRADE_FIRST_INPUT_SLOT);
+            slots.slot(ui, TRADE_SECOND_INPUT_SLOT);
+            trade_arrow(ui, slots, offer);
+            slots.slot(ui, TRADE_OUTPUT_SLOT);
+            ui.end_row();
+        });
+
+    if let Some(offer) = offer {
+        ui.label(
+            egui::RichText::new(format!(
+                "Trade {}/{} - used {}/{}",
+                selected + 1,
+                offers.len(),
+                offer.uses,
+                offer.max_uses
+            ))
+            .small(),
+        );
+    }
+}
+
+fn page_button(
+    ui: &mut egui::Ui,
+    slots: &mut ContainerSlots,
+    label: &str,
+    selected: usize,
+    offer_count: usize,
+    forward: bool,
+) {
+    let next = page_trade(selected, offer_count, forward);
+    let response = ui.add_enabled(next != selected, egui::Button::new(label));
+    if response.hovered() {
+        slots.hovered_any_slot = true;
+    }
+    if response.clicked() {
+        slots.state.trading.selected = next;
+        let _ = slots
+            .to_net
+            .0
+            .send(ToNetMessage::SelectTrade { index: next as i32 });
+    }
+}
+
+/// An item the offer asks for or gives, drawn without a slot behind it.
+fn offer_item(ui: &mut egui::Ui, slots: &mut ContainerSlots, item: Option<&InventoryItemStack>) {
+    let response = draw_slot(
+        slots.ctx,
+        slots.item_icons,
+        ui,
+        item,
+        false,
+        INVENTORY_SLOT_SIZE,
+        false,
+    );
+    if response.hovered() {
+        slots.hovered_any_slot = true;
+        slots.hovered_item = item.cloned();
+    }
+}
+
+/// Arrow towards the output, crossed out while the villager refuses the offer.
+fn trade_arrow(ui: &mut egui::Ui, slots: &mut ContainerSlots, offer: Option<&TradeOffer>) {
+    let cell = horizontal_arrow(ui, if offer.is_some() { 1.0 } else { 0.0 });
+    if !offer.is_some_and(|offer| offer.disabled) {
+        return;
+    }
+    let cross = egui::Rect::from_center_size(cell.center(), egui::Vec2::splat(cell.height() * 0.6));
+    let stroke = egui::Stroke::new(3.0, DISABLED_CROSS);
+    let painter = ui.painter();
+    painter.line_segment([cross.left_top(), cross.right_bottom()], stroke);
+    painter.line_segment([cross.right_top(), cross.left_bottom()], stroke);
+    let response = ui
+        .interact(cell, ui.next_auto_id(), egui::Sense::hover())
+        .on_hover_text("Trade something else to unlock!");
+    if response.hovered() {
+        slots.hovered_any_slot = true;
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::inventory::InventoryItemStack;
+use crate::registry::item_name;
+
+pub const ANVIL_PROPERTY_COST: i16 = 0;
+pub const ANVIL_LEFT_SLOT: usize = 0;
+pub const ANVIL_RIGHT_SLOT: usize = 1;
+pub const ANVIL_OUTPUT_SLOT: usize = 2;
+/// `GuiRepair` caps the rename field at this many characters.
+pub const ANVIL_MAX_NAME_LENGTH: usize = 30;
+/// Survival players cannot pay 40 levels or more (`ContainerRepair#updateRepairOutput`).
+const ANVIL_TOO_EXPENSIVE: i32 = 40;
+
+/// What `GuiRepair` prints above the output slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnvilCost {
+    Hidden,
+    Affordable(i32),
+    Unaffordable(i32),
+    TooExpensive,
+}
+
+pub fn anvil_cost(
+    properties: &HashMap<i16, i16>,
+    has_output: bool,
+    level: i32,
+    creative: bool,
+) -> AnvilCost {
+    let cost = i32::from(properties.get(&ANVIL_PROPERTY_COST).copied().unwrap_or(0));
+    if cost <= 0 {
+        AnvilCost::Hidden
+    } else if cost >= ANVIL_TOO_EXPENSIVE && !creative {
+        AnvilCost::TooExpensive
+    } else if !has_output {
+        AnvilCost::Hidden
+    } else if creative || level >= cost {
+        AnvilCost::Affordable(cost)
+    } else {
+        AnvilCost::Unaffordable(cost)
+    }
+}
+
+/// The name the rename field starts with for `stack`.
+pub fn anvil_display_name(stack: &InventoryItemStack) -> String {
+    stack
+        .meta
+        .display_name
+        .clone()
+        .unwrap_or_else(|| item_name(stack.item_id).to_string())
+}
+
+/// `GuiRepair#renameItem`: typing an unnamed item's own name back in clears the rename.
+pub fn anvil_rename_payload(text: &str, input: Option<&InventoryItemStack>) -> String {
+    match input {
+        Some(stack) if stack.meta.display_name.is_none() && text == item_name(stack.item_id) => {
+            String::new()
+        }
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::InventoryItemMeta;
+
+    #[test]
+    fn anvil_cost_and_rename_follow_gui_repair() {
+        let properties = HashMap::from([(ANVIL_PROPERTY_COST, 5)]);
+        assert_eq!(
+            anvil_cost(&properties, true, 5, false),
+            AnvilCost::Affordable(5)
+        );
+        assert_eq!(
+            anvil_cost(&properties, true, 4, false),
+            AnvilCost::Unaffordable(5)
+        );
+        assert_eq!(anvil_cost(&properties, false, 30, false), AnvilCost::Hidden);
+        let expensive = HashMap::from([(ANVIL_PROPERTY_COST, 40)]);
+        assert_eq!(
+            anvil_cost(&expensive, false, 50, false),
+            AnvilCost::TooExpensive
+        );
+        assert_eq!(
+            anvil_cost(&expensive, true, 0, true),
+            AnvilCost::Affordable(40)
+        );
+
+        let mut sword = InventoryItemStack {
+            item_id: 276,
+            count: 1,
+            damage: 0,
+            meta: InventoryItemMeta::default(),
+        };
+        let default_name = anvil_display_name(&sword);
+        assert_eq!(anvil_rename_payload(&default_name, Some(&sword)), "");
+        assert_eq!(anvil_rename_payload("Excalibur", Some(&sword)), "Excalibur");
+        sword.meta.display_name = Some("Excalibur".to_string());
+        assert_eq!(anvil_display_name(&sword), "Excalibur");
+        assert_eq!(
+            anvil_rename_payload(&default_name, Some(&sword)),
+            default_name
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::ecs::resource::Resource;
+use rs_protocol::protocol::UUID;
 
 #[derive(Resource)]
 pub struct AppState(pub ApplicationState);
@@ -17,3 +18,39 @@ pub struct UiState {
     pub inventory_open: bool,
     pub ui_hidden: bool,
 }
+
+/// Players shown per page of the spectator menu; key 9 turns the page.
+pub const SPECTATOR_MENU_PAGE_SIZE: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct SpectatorMenuEntry {
+    pub uuid: UUID,
+    pub name: String,
+}
+
+/// Vanilla spectator hotbar menu: number keys pick a player to teleport to.
+#[derive(Resource, Default)]
+pub struct SpectatorMenuState {
+    pub open: bool,
+    pub page: usize,
+    pub idle_secs: f32,
+    pub players: Vec<SpectatorMenuEntry>,
+}
+
+impl SpectatorMenuState {
+    pub fn page_count(&self) -> usize {
+        self.players.len().div_ceil(SPECTATOR_MENU_PAGE_SIZE).max(1)
+    }
+
+    pub fn page_entries(&self) -> &[SpectatorMenuEntry] {
+        let start = (self.page * SPECTATOR_MENU_PAGE_SIZE).min(self.players.len());
+        let end = (start + SPECTATOR_MENU_PAGE_SIZE).min(self.players.len());
+        &self.players[start..end]
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.page = 0;
+        self.idle_secs = 0.0;
+    }
+}
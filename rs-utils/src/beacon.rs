@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+pub const BEACON_PAYMENT_SLOT: usize = 0;
+pub const BEACON_PROPERTY_LEVELS: i16 = 0;
+pub const BEACON_PROPERTY_PRIMARY: i16 = 1;
+pub const BEACON_PROPERTY_SECONDARY: i16 = 2;
+/// Pyramid tiers offering primary powers; the fourth tier unlocks the secondary row.
+pub const BEACON_PRIMARY_TIERS: usize = 3;
+
+/// `TileEntityBeacon.effectsList`: potion ids unlocked by each pyramid tier.
+const BEACON_TIER_POWERS: [&[i32]; 4] = [&[1, 3], &[11, 8], &[5], &[10]];
+
+/// Items `ContainerBeacon.BeaconSlot` takes as payment: emerald, diamond, gold and iron ingot.
+pub fn is_beacon_payment(item_id: i32) -> bool {
+    matches!(item_id, 264 | 265 | 266 | 388)
+}
+
+pub fn beacon_power_name(effect_id: i32) -> &'static str {
+    match effect_id {
+        1 => "Speed",
+        3 => "Haste",
+        5 => "Strength",
+        8 => "Jump Boost",
+        10 => "Regeneration",
+        11 => "Resistance",
+        _ => "Unknown",
+    }
+}
+
+/// Pyramid levels and chosen powers, from the window properties `GuiBeacon` reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BeaconState {
+    pub levels: i32,
+    pub primary: i32,
+    pub secondary: i32,
+}
+
+impl BeaconState {
+    pub fn from_properties(properties: &HashMap<i16, i16>) -> Self {
+        let property = |id| i32::from(properties.get(&id).copied().unwrap_or(0));
+        Self {
+            levels: property(BEACON_PROPERTY_LEVELS),
+            primary: property(BEACON_PROPERTY_PRIMARY),
+            secondary: property(BEACON_PROPERTY_SECONDARY),
+        }
+    }
+
+    /// Powers `GuiBeacon` offers for `tier`; tier 3 is the secondary row, which adds the
+    /// selected primary power at level II.
+    pub fn tier_powers(&self, tier: usize) -> Vec<i32> {
+        let mut powers = BEACON_TIER_POWERS
+            .get(tier)
+            .map_or_else(Vec::new, |powers| powers.to_vec());
+        if tier == BEACON_PRIMARY_TIERS && self.primary > 0 {
+            powers.push(self.primary);
+        }
+        powers
+    }
+
+    /// Buttons of a tier stay disabled until the pyramid has more levels than the tier index.
+    pub fn tier_unlocked(&self, tier: usize) -> bool {
+        self.levels > tier as i32
+    }
+
+    pub fn is_selected(&self, tier: usize, effect_id: i32) -> bool {
+        if tier < BEACON_PRIMARY_TIERS {
+            self.primary == effect_id
+        } else {
+            self.secondary == effect_id
+        }
+    }
+
+    /// Property a power button writes, like `GuiBeacon#actionPerformed` setting the tile field.
+    pub fn selection_property(tier: usize) -> i16 {
+        if tier < BEACON_PRIMARY_TIERS {
+            BEACON_PROPERTY_PRIMARY
+        } else {
+            BEACON_PROPERTY_SECONDARY
+        }
+    }
+
+    /// The confirm button needs a payment item and a primary power.
+    pub fn can_confirm(&self, has_payment: bool) -> bool {
+        has_payment && self.primary > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beacon_tiers_unlock_with_pyramid_levels() {
+        let beacon = BeaconState::from_properties(&HashMap::from([
+            (BEACON_PROPERTY_LEVELS, 2),
+            (BEACON_PROPERTY_PRIMARY, 11),
+        ]));
+        assert!(beacon.tier_unlocked(1));
+        assert!(!beacon.tier_unlocked(2));
+        assert!(beacon.is_selected(1, 11));
+        assert_eq!(beacon.tier_powers(0), vec![1, 3]);
+        // The secondary row offers regeneration and the primary power at level II.
+        assert_eq!(beacon.tier_powers(3), vec![10, 11]);
+        assert!(beacon.can_confirm(true));
+        assert!(!BeaconState::default().can_confirm(true));
+        assert_eq!(
+            BeaconState::selection_property(3),
+            BEACON_PROPERTY_SECONDARY
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use rs_protocol::format::Component;
+
+use crate::chat::component_to_legacy;
+use crate::inventory::{InventoryItemMeta, InventoryItemStack, InventoryState};
+
+pub const WRITABLE_BOOK_ITEM_ID: i32 = 386;
+pub const WRITTEN_BOOK_ITEM_ID: i32 = 387;
+pub const BOOK_MAX_PAGES: usize = 50;
+pub const BOOK_PAGE_MAX_CHARS: usize = 256;
+pub const BOOK_TITLE_MAX_CHARS: usize = 16;
+
+/// `pages`, `title` and `author` of a book's tag. Written book pages hold the JSON text
+/// components the server sent; book and quill pages are plain text.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InventoryBookMeta {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub pages: Vec<String>,
+}
+
+/// `GuiScreenBook` over the held stack: reads a written book or edits a book and quill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookScreen {
+    pub hotbar_slot: u8,
+    pub editable: bool,
+    pub pages: Vec<String>,
+    pub page: usize,
+    /// Title typed on the signing page.
+    pub title: String,
+    pub signing: bool,
+    pub modified: bool,
+}
+
+impl BookScreen {
+    /// Opens the selected hotbar item if it is a book; written books are read-only.
+    pub fn open_held(inventory: &InventoryState) -> Option<Self> {
+        let held = inventory.selected_hotbar_item()?;
+        let editable = match held.item_id {
+            WRITABLE_BOOK_ITEM_ID => true,
+            WRITTEN_BOOK_ITEM_ID => false,
+            _ => return None,
+        };
+        let mut pages = held.meta.book.map(|book| book.pages).unwrap_or_default();
+        if !editable {
+            pages = pages
+                .iter()
+                .map(|page| component_to_legacy(&Component::from_str(page)))
+                .collect();
+        }
+        if pages.is_empty() {
+            pages.push(String::new());
+        }
+        Some(Self {
+            hotbar_slot: inventory.selected_hotbar_slot,
+            editable,
+            pages,
+            page: 0,
+            title: String::new(),
+            signing: false,
+            modified: false,
+        })
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.page > 0
+    }
+
+    /// The last page of a book and quill turns into a fresh one, up to `BOOK_MAX_PAGES`.
+    pub fn can_go_forward(&self) -> bool {
+        self.page + 1 < self.pages.len() || (self.editable && self.pages.len() < BOOK_MAX_PAGES)
+    }
+
+    pub fn previous_page(&mut self) {
+        self.page = self.page.saturating_sub(1);
+    }
+
+    pub fn next_page(&mut self) {
+        if !self.can_go_forward() {
+            return;
+        }
+        if self.page + 1 == self.pages.len() {
+            self.pages.push(String::new());
+            self.modified = true;
+        }
+        self.page += 1;
+    }
+
+    /// Pages to send, without the empty pages `GuiScreenBook#sendBookToServer` drops at the end.
+    fn trimmed_pages(&self) -> Vec<String> {
+        let mut pages = self.pages.clone();
+        while pages.len() > 1 && pages.last().is_some_and(String::is_empty) {
+            pages.pop();
+        }
+        pages
+    }
+
+    /// The book and quill sent with `MC|BEdit`.
+    pub fn edited_book(&self) -> InventoryItemStack {
+        self.book(WRITABLE_BOOK_ITEM_ID, None, None)
+    }
+
+    /// The written book sent with `MC|BSign`.
+    pub fn signed_book(&self, author: &str) -> InventoryItemStack {
+        self.book(
+            WRITTEN_BOOK_ITEM_ID,
+            Some(self.title.trim().to_string()),
+            Some(author.to_string()),
+        )
+    }
+
+    fn book(
+        &self,
+        item_id: i32,
+        title: Option<String>,
+        author: Option<String>,
+    ) -> InventoryItemStack {
+        InventoryItemStack {
+            item_id,
+            count: 1,
+            damage: 0,
+            meta: InventoryItemMeta {
+                book: Some(InventoryBookMeta {
+                    title,
+                    author,
+                    pages: self.trimmed_pages(),
+                }),
+                ..InventoryItemMeta::default()
+            },
+        }
+    }
+}
+
+impl InventoryState {
+    /// Keeps the edited book in hand until the server's next `SetSlot`.
+    pub fn set_held_book(&mut self, hotbar_slot: u8, book: InventoryItemStack) {
+        if let Some(slot) = self.hotbar_slot_index(hotbar_slot) {
+            self.player_slots[slot] = Some(book);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inventory_holding(item_id: i32, pages: &[&str]) -> InventoryState {
+        let mut inventory = InventoryState::default();
+        inventory.player_slots = vec![None; 45];
+        inventory.selected_hotbar_slot = 2;
+        inventory.player_slots[38] = Some(InventoryItemStack {
+            item_id,
+            count: 1,
+            damage: 0,
+            meta: InventoryItemMeta {
+                book: Some(InventoryBookMeta {
+                    pages: pages.iter().map(|page| page.to_string()).collect(),
+                    ..InventoryBookMeta::default()
+                }),
+                ..InventoryItemMeta::default()
+            },
+        });
+        inventory
+    }
+
+    #[test]
+    fn editing_adds_pages_and_trims_trailing_empty_ones() {
+        let inventory = inventory_holding(WRITABLE_BOOK_ITEM_ID, &["first"]);
+        let mut screen = BookScreen::open_held(&inventory).expect("book and quill opens");
+        assert!(screen.editable);
+        assert!(!screen.can_go_back());
+        screen.next_page();
+        screen.next_page();
+        assert_eq!(screen.pages.len(), 3);
+        assert_eq!(screen.page, 2);
+
+        let edited = screen.edited_book();
+        assert_eq!(edited.item_id, WRITABLE_BOOK_ITEM_ID);
+        assert_eq!(edited.meta.book.expect("book meta").pages, vec!["first"]);
+
+        screen.title = " Rules ".to_string();
+        let signed = screen.signed_book("Steve");
+        let book = signed.meta.book.expect("book meta");
+        assert_eq!(signed.item_id, WRITTEN_BOOK_ITEM_ID);
+        assert_eq!(book.title.as_deref(), Some("Rules"));
+        assert_eq!(book.author.as_deref(), Some("Steve"));
+    }
+
+    #[test]
+    fn written_books_only_page_through_existing_pages() {
+        let pages = [r#"{"text":"one"}"#, r#"{"text":"two","color":"red"}"#];
+        let inventory = inventory_holding(WRITTEN_BOOK_ITEM_ID, &pages);
+        let mut screen = BookScreen::open_held(&inventory).expect("written book opens");
+        assert!(!screen.editable);
+        assert_eq!(screen.pages, vec!["one", "§ctwo"]);
+        screen.next_page();
+        assert!(!screen.can_go_forward());
+        screen.next_page();
+        assert_eq!((screen.page, screen.pages.len()), (1, 2));
+
+        let inventory = inventory_holding(1, &[]);
+        assert!(BookScreen::open_held(&inventory).is_none());
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use bevy::ecs::resource::Resource;
+
+/// Decoded `CombatEvent`; entity ids stay raw so the client can resolve names.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CombatEventMessage {
+    Enter,
+    End {
+        duration_ticks: i32,
+        /// Best attacker of the fight, or -1.
+        opponent_id: i32,
+    },
+    EntityDead {
+        player_id: i32,
+        /// Killer entity id, or -1 for environmental deaths.
+        killer_id: i32,
+        message: String,
+    },
+}
+
+/// Difficulty from `JoinGame`/`ServerDifficulty`.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct ServerDifficultyState {
+    pub difficulty: Option<u8>,
+    pub locked: bool,
+}
+
+impl ServerDifficultyState {
+    pub fn label(&self) -> &'static str {
+        match self.difficulty {
+            Some(0) => "Peaceful",
+            Some(1) => "Easy",
+            Some(2) => "Normal",
+            Some(3) => "Hard",
+            _ => "Unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CombatTimelineEvent {
+    Entered,
+    Damaged {
+        amount: f32,
+        health: f32,
+    },
+    Ended {
+        duration_ticks: i32,
+        opponent: Option<String>,
+    },
+    Died {
+        killer: Option<String>,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct CombatTimelineEntry {
+    pub at: Instant,
+    pub event: CombatTimelineEvent,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeathInfo {
+    pub killer: Option<String>,
+    /// Server-provided death message, already flattened to legacy formatting codes.
+    pub message: String,
+}
+
+/// Local view of the player's fights: combat events from the server plus health drops.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CombatState {
+    pub in_combat_since: Option<Instant>,
+    pub last_damage_at: Option<Instant>,
+    pub last_damage_source: Option<String>,
+    pub last_combat_duration_ticks: Option<i32>,
+    pub death: Option<DeathInfo>,
+    pub timeline: VecDeque<CombatTimelineEntry>,
+}
+
+impl CombatState {
+    pub const TIMELINE_LEN: usize = 32;
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn enter(&mut self, now: Instant) {
+        self.in_combat_since = Some(now);
+        self.push(now, CombatTimelineEvent::Entered);
+    }
+
+    pub fn record_damage(&mut self, amount: f32, health: f32, now: Instant) {
+        self.last_damage_at = Some(now);
+        self.push(now, CombatTimelineEvent::Damaged { amount, health });
+    }
+
+    pub fn end(&mut self, duration_ticks: i32, opponent: Option<String>, now: Instant) {
+        self.in_combat_since = None;
+        self.last_combat_duration_ticks = Some(duration_ticks);
+        if opponent.is_some() {
+            self.last_damage_source = opponent.clone();
+        }
+        self.push(
+            now,
+            CombatTimelineEvent::Ended {
+                duration_ticks,
+                opponent,
+            },
+        );
+    }
+
+    pub fn died(&mut self, killer: Option<String>, message: String, now: Instant) {
+        self.in_combat_since = None;
+        if killer.is_some() {
+            self.last_damage_source = killer.clone();
+        }
+        self.death = Some(DeathInfo {
+            killer: killer.clone(),
+            message: message.clone(),
+        });
+        self.push(now, CombatTimelineEvent::Died { killer, message });
+    }
+
+    /// Called once health is back above zero; keeps the timeline for review.
+    pub fn respawned(&mut self) {
+        self.death = None;
+        self.in_combat_since = None;
+    }
+
+    pub fn combat_duration(&self, now: Instant) -> Option<Duration> {
+        self.in_combat_since
+            .map(|since| now.saturating_duration_since(since))
+    }
+
+    fn push(&mut self, at: Instant, event: CombatTimelineEvent) {
+        if self.timeline.len() == Self::TIMELINE_LEN {
+            self.timeline.pop_front();
+        }
+        self.timeline.push_back(CombatTimelineEntry { at, event });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combat_timeline_tracks_fights_and_deaths() {
+        let now = std::time::Instant::now();
+        let mut combat = CombatState::default();
+        combat.enter(now);
+        combat.record_damage(4.0, 16.0, now);
+        assert!(combat.combat_duration(now).is_some());
+
+        combat.end(60, Some("Zombie".to_string()), now);
+        assert!(combat.combat_duration(now).is_none());
+        assert_eq!(combat.last_combat_duration_ticks, Some(60));
+        assert_eq!(combat.last_damage_source.as_deref(), Some("Zombie"));
+
+        combat.died(None, "Steve fell from a high place".to_string(), now);
+        let death = combat.death.clone().unwrap();
+        assert_eq!(death.killer, None);
+        assert_eq!(death.message, "Steve fell from a high place");
+        assert_eq!(combat.last_damage_source.as_deref(), Some("Zombie"));
+        assert_eq!(combat.timeline.len(), 4);
+
+        combat.respawned();
+        assert!(combat.death.is_none());
+        for _ in 0..CombatState::TIMELINE_LEN {
+            combat.enter(now);
+        }
+        assert_eq!(combat.timeline.len(), CombatState::TIMELINE_LEN);
+    }
+}
@@ -0,0 +1,264 @@
+use std::ops::Range;
+
+use crate::inventory::{InventoryItemStack, can_stack, max_stack_for_item, merge_into_routes};
+use crate::windows::{ShiftClickRoute, WindowKind};
+use recipes::{output, recipe_book};
+
+mod recipes;
+mod special;
+
+/// Every crafting window puts its output in slot 0, followed by the grid.
+pub const CRAFTING_RESULT_SLOT: usize = 0;
+/// Upper bound for one shift-click craft, well above what 36 slots can take.
+const MAX_SHIFT_CRAFTS: usize = 64 * 36;
+
+/// Grid shape of a crafting window: the 2x2 player grid or the 3x3 crafting table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CraftingLayout {
+    pub width: usize,
+}
+
+impl CraftingLayout {
+    pub fn for_window(window_id: u8, kind: WindowKind) -> Option<Self> {
+        match (window_id, kind) {
+            (0, _) => Some(Self { width: 2 }),
+            (_, WindowKind::CraftingTable) => Some(Self { width: 3 }),
+            _ => None,
+        }
+    }
+
+    pub fn grid(&self) -> Range<usize> {
+        1..1 + self.width * self.width
+    }
+}
+
+/// Result the server will put in the output slot for `grid` (row-major, `grid_width` wide).
+pub fn predict_crafting_result(
+    grid: &[Option<InventoryItemStack>],
+    grid_width: usize,
+) -> Option<InventoryItemStack> {
+    if grid.iter().all(Option::is_none) {
+        return None;
+    }
+    special::special_result(grid).or_else(|| {
+        recipe_book()
+            .iter()
+            .find(|recipe| recipe.matches(grid, grid_width))
+            .map(|recipe| recipe.output.clone())
+    })
+}
+
+/// Recomputes the output slot after a click changed the grid.
+pub(crate) fn refresh_crafting_result(
+    slots: &mut [Option<InventoryItemStack>],
+    layout: CraftingLayout,
+) {
+    if let Some(grid) = slots.get(layout.grid()) {
+        slots[CRAFTING_RESULT_SLOT] = predict_crafting_result(grid, layout.width);
+    }
+}
+
+/// Item left behind in the grid after an ingredient is used up (`Item#getContainerItem`).
+fn container_item(item_id: i32) -> Option<i32> {
+    match item_id {
+        326 | 327 | 335 => Some(325),
+        _ => None,
+    }
+}
+
+/// `SlotCrafting#onPickupFromSlot`: one of every ingredient is used up.
+fn consume_ingredients(slots: &mut [Option<InventoryItemStack>], layout: CraftingLayout) {
+    let kept = slots
+        .get(layout.grid())
+        .and_then(special::kept_ingredient)
+        .map(|idx| layout.grid().start + idx);
+    for idx in layout.grid() {
+        if Some(idx) == kept {
+            continue;
+        }
+        let Some(stack) = slots.get_mut(idx) else {
+            continue;
+        };
+        let Some(mut ingredient) = stack.take() else {
+            continue;
+        };
+        ingredient.count = ingredient.count.saturating_sub(1);
+        *stack = if ingredient.count > 0 {
+            Some(ingredient)
+        } else {
+            container_item(ingredient.item_id).map(|item_id| output(item_id, 1, 0))
+        };
+    }
+}
+
+/// Clicks on the output slot: items can be taken (crafting once each time) but never placed.
+pub(crate) fn apply_crafting_result_click(
+    slots: &mut [Option<InventoryItemStack>],
+    cursor_item: &mut Option<InventoryItemStack>,
+    layout: CraftingLayout,
+    mode: u8,
+    button: u8,
+) {
+    let Some(result) = slots.get(CRAFTING_RESULT_SLOT).cloned().flatten() else {
+        return;
+    };
+    match mode {
+        0 => match cursor_item {
+            // `InventoryCraftResult` hands out the whole stack whichever button is used.
+            None => *cursor_item = Some(result),
+            Some(cursor)
+                if can_stack(cursor, &result)
+                    && cursor.count + result.count <= max_stack_for_item(cursor.item_id) =>
+            {
+                cursor.count += result.count;
+            }
+            Some(_) => return,
+        },
+        1 => {
+            shift_craft(slots, layout, result);
+            return;
+        }
+        2 => {
+            let hotbar = slots.len().saturating_sub(9) + usize::from(button);
+            if button > 8 || slots.get(hotbar).is_none_or(Option::is_some) {
+                return;
+            }
+            slots[hotbar] = Some(result);
+        }
+        // Dropping crafts once and throws the result away.
+        4 => {}
+        _ => return,
+    }
+    consume_ingredients(slots, layout);
+    refresh_crafting_result(slots, layout);
+}
+
+/// Shift-clicking the output crafts repeatedly, as long as the same item comes out and the
+/// whole result still fits in the player inventory.
+fn shift_craft(
+    slots: &mut [Option<InventoryItemStack>],
+    layout: CraftingLayout,
+    first: InventoryItemStack,
+) {
+    let player = [ShiftClickRoute {
+        range: layout.grid().end..slots.len(),
+        reverse: true,
+    }];
+    for _ in 0..MAX_SHIFT_CRAFTS {
+        let Some(result) = slots[CRAFTING_RESULT_SLOT].clone() else {
+            break;
+        };
+        if result.item_id != first.item_id {
+            break;
+        }
+        let mut trial = slots.to_vec();
+        let mut moving = result;
+        merge_into_routes(&mut trial, &mut moving, &player);
+        if moving.count > 0 {
+            break;
+        }
+        slots.clone_from_slice(&trial);
+        consume_ingredients(slots, layout);
+        refresh_crafting_result(slots, layout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::recipes::{COBBLESTONE, IRON_INGOT, PLANKS, STICK};
+    use super::*;
+    use crate::inventory::{InventoryState, InventoryWindowInfo};
+
+    fn stack(item_id: i32, count: u8, damage: i16) -> InventoryItemStack {
+        output(item_id, count, damage)
+    }
+
+    #[test]
+    fn shaped_recipes_match_anywhere_and_mirrored() {
+        let mut grid = vec![None; 9];
+        // An axe in the right two columns, handle on the left side (mirrored pattern).
+        grid[1] = Some(stack(IRON_INGOT, 1, 0));
+        grid[2] = Some(stack(IRON_INGOT, 1, 0));
+        grid[4] = Some(stack(STICK, 1, 0));
+        grid[5] = Some(stack(IRON_INGOT, 1, 0));
+        grid[7] = Some(stack(STICK, 1, 0));
+        assert_eq!(predict_crafting_result(&grid, 3), Some(stack(258, 1, 0)));
+
+        grid[8] = Some(stack(STICK, 1, 0));
+        assert_eq!(predict_crafting_result(&grid, 3), None);
+
+        let mut small = vec![None; 4];
+        small[3] = Some(stack(17, 1, 2));
+        assert_eq!(
+            predict_crafting_result(&small, 2),
+            Some(stack(PLANKS, 4, 2))
+        );
+        // Four planks in the player grid make a crafting table.
+        let chest = vec![Some(stack(PLANKS, 1, 0)); 4];
+        assert_eq!(predict_crafting_result(&chest, 2), Some(stack(58, 1, 0)));
+        // 131 durability: 31 + 41 left, plus a 6 point bonus.
+        let worn = [
+            Some(stack(257, 1, 100)),
+            None,
+            None,
+            Some(stack(257, 1, 90)),
+        ];
+        assert_eq!(predict_crafting_result(&worn, 2), Some(stack(257, 1, 53)));
+    }
+
+    #[test]
+    fn result_slot_takes_crafts_and_refuses_placement() {
+        let mut inventory = InventoryState::default();
+        inventory.set_window_items(0, vec![None; 45]);
+        inventory.set_slot(0, 1, Some(stack(17, 3, 0)));
+        inventory.apply_local_click_player_window(1, 0, 0);
+        inventory.apply_local_click_player_window(2, 0, 0);
+        assert_eq!(inventory.player_slots[0], Some(stack(PLANKS, 4, 0)));
+
+        inventory.apply_local_click_player_window(0, 0, 0);
+        assert_eq!(inventory.cursor_item, Some(stack(PLANKS, 4, 0)));
+        assert_eq!(inventory.player_slots[2], Some(stack(17, 2, 0)));
+        inventory.apply_local_click_player_window(0, 0, 0);
+        assert_eq!(inventory.cursor_item, Some(stack(PLANKS, 8, 0)));
+
+        // A cursor that cannot merge with the result stays on the cursor.
+        inventory.cursor_item = Some(stack(COBBLESTONE, 5, 0));
+        inventory.apply_local_click_player_window(0, 0, 0);
+        assert_eq!(inventory.cursor_item, Some(stack(COBBLESTONE, 5, 0)));
+        assert_eq!(inventory.player_slots[0], Some(stack(PLANKS, 4, 0)));
+
+        // Right-clicking takes the whole result too, here with the last log.
+        inventory.cursor_item = None;
+        inventory.apply_local_click_player_window(0, 1, 0);
+        assert_eq!(inventory.cursor_item, Some(stack(PLANKS, 4, 0)));
+        assert_eq!(inventory.player_slots[2], None);
+        assert_eq!(inventory.player_slots[0], None);
+    }
+
+    #[test]
+    fn shift_clicking_the_crafting_table_result_crafts_max() {
+        let mut inventory = InventoryState::default();
+        inventory.open_window = Some(InventoryWindowInfo {
+            id: 2,
+            kind: "minecraft:crafting_table".to_string(),
+            title: String::new(),
+            slot_count: 10,
+            entity_id: None,
+            horse_type: None,
+        });
+        inventory.set_window_items(2, vec![None; 46]);
+        inventory.set_slot(2, 1, Some(stack(PLANKS, 5, 0)));
+        inventory.set_slot(2, 4, Some(stack(PLANKS, 3, 0)));
+        inventory.set_slot(2, 0, Some(stack(STICK, 4, 0)));
+
+        inventory.apply_local_click_window(2, 10, 0, 0, 1);
+
+        let slots = &inventory.window_slots[&2];
+        assert_eq!(slots[45], Some(stack(STICK, 12, 0)));
+        assert_eq!(inventory.player_slots[44], Some(stack(STICK, 12, 0)));
+        assert_eq!(slots[1], Some(stack(PLANKS, 2, 0)));
+        assert_eq!(slots[4], None);
+        // The last plank only makes a button, which stops the loop.
+        assert_eq!(slots[0], Some(stack(143, 1, 0)));
+    }
+}
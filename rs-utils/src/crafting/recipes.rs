@@ -0,0 +1,295 @@
+use std::cmp::Reverse;
+use std::sync::OnceLock;
+
+use crate::inventory::{InventoryItemMeta, InventoryItemStack};
+
+mod manager;
+mod sets;
+
+/// Damage value recipe ingredients use to accept any variant (`OreDictionary.WILDCARD_VALUE`).
+const ANY_DAMAGE: i16 = 32767;
+
+pub(super) const PLANKS: i32 = 5;
+pub(super) const COBBLESTONE: i32 = 4;
+pub(super) const STICK: i32 = 280;
+pub(super) const IRON_INGOT: i32 = 265;
+const STONE: i32 = 1;
+const GOLD_INGOT: i32 = 266;
+const DIAMOND: i32 = 264;
+const REDSTONE: i32 = 331;
+const STRING: i32 = 287;
+const WOOL: i32 = 35;
+const DYE: i32 = 351;
+const GOLD_NUGGET: i32 = 371;
+const PAPER: i32 = 339;
+const GLASS: i32 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Ingredient {
+    item_id: i32,
+    damage: i16,
+}
+
+impl Ingredient {
+    fn matches(&self, stack: &InventoryItemStack) -> bool {
+        stack.item_id == self.item_id && (self.damage == ANY_DAMAGE || stack.damage == self.damage)
+    }
+}
+
+const fn any(item_id: i32) -> Ingredient {
+    Ingredient {
+        item_id,
+        damage: ANY_DAMAGE,
+    }
+}
+
+const fn exact(item_id: i32, damage: i16) -> Ingredient {
+    Ingredient { item_id, damage }
+}
+
+/// `CraftingManager` keys a bare `Item` as its damage 0 stack and a bare `Block` as `any`.
+const fn item(item_id: i32) -> Ingredient {
+    exact(item_id, 0)
+}
+
+#[derive(Debug, Clone)]
+enum RecipeInput {
+    Shaped {
+        width: usize,
+        height: usize,
+        cells: Vec<Option<Ingredient>>,
+    },
+    Shapeless(Vec<Ingredient>),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Recipe {
+    input: RecipeInput,
+    pub(super) output: InventoryItemStack,
+}
+
+impl Recipe {
+    fn size(&self) -> usize {
+        match &self.input {
+            RecipeInput::Shaped { width, height, .. } => width * height,
+            RecipeInput::Shapeless(ingredients) => ingredients.len(),
+        }
+    }
+
+    pub(super) fn matches(&self, grid: &[Option<InventoryItemStack>], grid_width: usize) -> bool {
+        match &self.input {
+            RecipeInput::Shaped {
+                width,
+                height,
+                cells,
+            } => matches_shaped(grid, grid_width, *width, *height, cells),
+            RecipeInput::Shapeless(ingredients) => matches_shapeless(grid, ingredients),
+        }
+    }
+}
+
+/// `ShapedRecipes#matches`: the pattern may sit anywhere in the grid and may be mirrored.
+fn matches_shaped(
+    grid: &[Option<InventoryItemStack>],
+    grid_width: usize,
+    width: usize,
+    height: usize,
+    cells: &[Option<Ingredient>],
+) -> bool {
+    let grid_height = grid.len() / grid_width.max(1);
+    if width > grid_width || height > grid_height {
+        return false;
+    }
+    let fits = |dx: usize, dy: usize, mirrored: bool| {
+        (0..grid_height).all(|y| {
+            (0..grid_width).all(|x| {
+                let expected = if (dx..dx + width).contains(&x) && (dy..dy + height).contains(&y) {
+                    let px = if mirrored {
+                        width - 1 - (x - dx)
+                    } else {
+                        x - dx
+                    };
+                    cells[(y - dy) * width + px]
+                } else {
+                    None
+                };
+                match (expected, &grid[y * grid_width + x]) {
+                    (None, None) => true,
+                    (Some(ingredient), Some(stack)) => ingredient.matches(stack),
+                    _ => false,
+                }
+            })
+        })
+    };
+    (0..=grid_height - height)
+        .any(|dy| (0..=grid_width - width).any(|dx| fits(dx, dy, false) || fits(dx, dy, true)))
+}
+
+/// `ShapelessRecipes#matches`: every grid item claims one unused ingredient.
+fn matches_shapeless(grid: &[Option<InventoryItemStack>], ingredients: &[Ingredient]) -> bool {
+    let mut remaining = ingredients.to_vec();
+    for stack in grid.iter().flatten() {
+        let Some(idx) = remaining
+            .iter()
+            .position(|ingredient| ingredient.matches(stack))
+        else {
+            return false;
+        };
+        remaining.swap_remove(idx);
+    }
+    remaining.is_empty()
+}
+
+pub(super) fn output(item_id: i32, count: u8, damage: i16) -> InventoryItemStack {
+    InventoryItemStack {
+        item_id,
+        count,
+        damage,
+        meta: InventoryItemMeta::default(),
+    }
+}
+
+#[derive(Default)]
+struct RecipeBook {
+    recipes: Vec<Recipe>,
+}
+
+impl RecipeBook {
+    fn shaped(&mut self, result: InventoryItemStack, pattern: &[&str], key: &[(char, Ingredient)]) {
+        let width = pattern.iter().map(|row| row.len()).max().unwrap_or(0);
+        let cells = pattern
+            .iter()
+            .flat_map(|row| {
+                let padded = row.chars().chain(std::iter::repeat(' '));
+                padded.take(width).map(|symbol| {
+                    key.iter()
+                        .find(|(k, _)| *k == symbol)
+                        .map(|(_, ingredient)| *ingredient)
+                })
+            })
+            .collect();
+        self.recipes.push(Recipe {
+            input: RecipeInput::Shaped {
+                width,
+                height: pattern.len(),
+                cells,
+            },
+            output: result,
+        });
+    }
+
+    fn shapeless(&mut self, result: InventoryItemStack, ingredients: &[Ingredient]) {
+        self.recipes.push(Recipe {
+            input: RecipeInput::Shapeless(ingredients.to_vec()),
+            output: result,
+        });
+    }
+
+    /// `RecipesIngots`: nine items into a storage block and back.
+    fn storage(&mut self, block: i32, item: Ingredient) {
+        self.shaped(output(block, 1, 0), &["###", "###", "###"], &[('#', item)]);
+        self.shaped(
+            output(item.item_id, 9, item.damage),
+            &["#"],
+            &[('#', any(block))],
+        );
+    }
+
+    /// Like `CraftingManager`, shaped recipes win over shapeless ones and bigger over smaller.
+    fn finish(mut self) -> Vec<Recipe> {
+        self.recipes.sort_by_key(|recipe| {
+            (
+                matches!(recipe.input, RecipeInput::Shapeless(_)),
+                Reverse(recipe.size()),
+            )
+        });
+        self.recipes
+    }
+}
+
+/// Every table recipe of vanilla 1.8's `CraftingManager`; `special` covers its `IRecipe` classes.
+pub(super) fn recipe_book() -> &'static [Recipe] {
+    static RECIPES: OnceLock<Vec<Recipe>> = OnceLock::new();
+    RECIPES.get_or_init(|| {
+        let mut book = RecipeBook::default();
+        sets::add_tools(&mut book);
+        sets::add_weapons(&mut book);
+        sets::add_ingots(&mut book);
+        sets::add_food(&mut book);
+        sets::add_crafting(&mut book);
+        sets::add_armor(&mut book);
+        sets::add_dyes(&mut book);
+        sets::add_banners(&mut book);
+        manager::add_manager_recipes(&mut book);
+        book.finish()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(add: fn(&mut RecipeBook)) -> usize {
+        let mut book = RecipeBook::default();
+        add(&mut book);
+        book.recipes.len()
+    }
+
+    #[test]
+    fn every_crafting_manager_recipe_is_ported() {
+        assert_eq!(count(sets::add_tools), 21);
+        assert_eq!(count(sets::add_weapons), 7);
+        assert_eq!(count(sets::add_ingots), 20);
+        assert_eq!(count(sets::add_food), 11);
+        assert_eq!(count(sets::add_crafting), 33);
+        assert_eq!(count(sets::add_armor), 20);
+        assert_eq!(count(sets::add_dyes), 107);
+        assert_eq!(count(sets::add_banners), 16);
+        assert_eq!(count(manager::add_manager_recipes), 134);
+        assert_eq!(recipe_book().len(), 369);
+    }
+
+    #[test]
+    fn recipes_from_every_vanilla_set_match() {
+        let craft = |grid: &[Option<InventoryItemStack>], width| {
+            recipe_book()
+                .iter()
+                .find(|recipe| recipe.matches(grid, width))
+                .map(|recipe| recipe.output.clone())
+        };
+        let stack = |item_id, damage| Some(output(item_id, 1, damage));
+        // A red banner: six red wool over a stick.
+        let mut grid = vec![stack(WOOL, 14); 6];
+        grid.extend([None, stack(STICK, 0), None]);
+        assert_eq!(craft(&grid, 3), Some(output(425, 1, 1)));
+        // Rabbit stew works with either mushroom.
+        let stew = [
+            None,
+            stack(412, 0),
+            None,
+            stack(391, 0),
+            stack(393, 0),
+            stack(40, 0),
+            None,
+            stack(281, 0),
+            None,
+        ];
+        assert_eq!(craft(&stew, 3), Some(output(413, 1, 0)));
+        let minecart = [stack(154, 0), None, stack(328, 0), None];
+        assert_eq!(craft(&minecart, 2), Some(output(408, 1, 0)));
+        let panes = [vec![stack(95, 3); 6], vec![None; 3]].concat();
+        assert_eq!(craft(&panes, 3), Some(output(160, 16, 3)));
+        let red_sand = vec![stack(12, 1); 4];
+        assert_eq!(craft(&red_sand, 2), Some(output(179, 1, 0)));
+        let shards = vec![stack(409, 0); 9];
+        assert_eq!(craft(&shards, 3), Some(output(168, 1, 1)));
+        let fence = [vec![stack(112, 0); 6], vec![None; 3]].concat();
+        assert_eq!(craft(&fence, 3), Some(output(113, 6, 0)));
+        // Plain sand makes plain sandstone, and a bare item key only takes damage 0.
+        assert_eq!(craft(&vec![stack(12, 0); 4], 2), Some(output(24, 1, 0)));
+        let quill = [stack(340, 0), stack(DYE, 0), stack(288, 0), None];
+        assert_eq!(craft(&quill, 2), Some(output(386, 1, 0)));
+        let red_quill = [stack(340, 0), stack(DYE, 1), stack(288, 0), None];
+        assert_eq!(craft(&red_quill, 2), None);
+    }
+}
@@ -0,0 +1,403 @@
+//! The recipes `CraftingManager` adds itself, after the `Recipes*` sets.
+
+use super::*;
+
+/// Wood variants in `BlockPlanks` order: oak, spruce, birch, jungle, acacia, dark oak.
+const FENCES: [i32; 6] = [85, 188, 189, 190, 192, 191];
+const FENCE_GATES: [i32; 6] = [107, 183, 184, 185, 187, 186];
+const DOORS: [i32; 6] = [324, 427, 428, 429, 430, 431];
+const WOOD_STAIRS: [i32; 6] = [53, 134, 135, 136, 163, 164];
+
+pub(super) fn add_manager_recipes(book: &mut RecipeBook) {
+    add_paper_and_wood(book);
+    add_blocks(book);
+    add_transport_and_tools(book);
+    add_redstone(book);
+}
+
+fn add_paper_and_wood(book: &mut RecipeBook) {
+    book.shaped(output(PAPER, 3, 0), &["###"], &[('#', item(338))]);
+    book.shapeless(
+        output(340, 1, 0),
+        &[item(PAPER), item(PAPER), item(PAPER), item(334)],
+    );
+    book.shapeless(output(386, 1, 0), &[item(340), exact(DYE, 0), item(288)]);
+    for (variant, fence) in (0..).zip(FENCES) {
+        book.shaped(
+            output(fence, 3, 0),
+            &["W#W", "W#W"],
+            &[('#', item(STICK)), ('W', exact(PLANKS, variant))],
+        );
+    }
+    book.shaped(
+        output(139, 6, 0),
+        &["###", "###"],
+        &[('#', any(COBBLESTONE))],
+    );
+    book.shaped(output(139, 6, 1), &["###", "###"], &[('#', any(48))]);
+    book.shaped(output(113, 6, 0), &["###", "###"], &[('#', any(112))]);
+    for (variant, gate) in (0..).zip(FENCE_GATES) {
+        book.shaped(
+            output(gate, 1, 0),
+            &["#W#", "#W#"],
+            &[('#', item(STICK)), ('W', exact(PLANKS, variant))],
+        );
+    }
+    book.shaped(
+        output(84, 1, 0),
+        &["###", "#X#", "###"],
+        &[('#', any(PLANKS)), ('X', item(DIAMOND))],
+    );
+    book.shaped(
+        output(420, 2, 0),
+        &["~~ ", "~O ", "  ~"],
+        &[('~', item(STRING)), ('O', item(341))],
+    );
+    book.shaped(
+        output(25, 1, 0),
+        &["###", "#X#", "###"],
+        &[('#', any(PLANKS)), ('X', item(REDSTONE))],
+    );
+    book.shaped(
+        output(47, 1, 0),
+        &["###", "XXX", "###"],
+        &[('#', any(PLANKS)), ('X', item(340))],
+    );
+}
+
+fn add_blocks(book: &mut RecipeBook) {
+    // Two-by-two blocks from their items.
+    for (block, from) in [
+        (80, 332),
+        (82, 337),
+        (45, 336),
+        (89, 348),
+        (155, 406),
+        (WOOL, STRING),
+    ] {
+        book.shaped(output(block, 1, 0), &["##", "##"], &[('#', item(from))]);
+    }
+    book.shaped(output(78, 6, 0), &["###"], &[('#', any(80))]);
+    book.shaped(
+        output(46, 1, 0),
+        &["X#X", "#X#", "X#X"],
+        &[('X', item(289)), ('#', any(12))],
+    );
+    // Stone slab variants by metadata, then the red sandstone slab.
+    let slabs = [
+        (3, any(COBBLESTONE)),
+        (0, exact(STONE, 0)),
+        (1, any(24)),
+        (4, any(45)),
+        (5, any(98)),
+        (6, any(112)),
+        (7, any(155)),
+    ];
+    for (damage, block) in slabs {
+        book.shaped(output(44, 6, damage), &["###"], &[('#', block)]);
+    }
+    book.shaped(output(182, 6, 0), &["###"], &[('#', any(179))]);
+    for variant in 0..6 {
+        book.shaped(
+            output(126, 6, variant),
+            &["###"],
+            &[('#', exact(PLANKS, variant))],
+        );
+    }
+    book.shaped(
+        output(65, 3, 0),
+        &["# #", "###", "# #"],
+        &[('#', item(STICK))],
+    );
+    for (variant, door) in (0..).zip(DOORS) {
+        book.shaped(
+            output(door, 3, 0),
+            &["##", "##", "##"],
+            &[('#', exact(PLANKS, variant))],
+        );
+    }
+    book.shaped(output(96, 2, 0), &["###", "###"], &[('#', any(PLANKS))]);
+    book.shaped(
+        output(330, 3, 0),
+        &["##", "##", "##"],
+        &[('#', item(IRON_INGOT))],
+    );
+    book.shaped(output(167, 1, 0), &["##", "##"], &[('#', item(IRON_INGOT))]);
+    book.shaped(
+        output(323, 3, 0),
+        &["###", "###", " X "],
+        &[('#', any(PLANKS)), ('X', item(STICK))],
+    );
+    book.shaped(
+        output(354, 1, 0),
+        &["AAA", "BEB", "CCC"],
+        &[
+            ('A', item(335)),
+            ('B', item(353)),
+            ('C', item(296)),
+            ('E', item(344)),
+        ],
+    );
+    book.shaped(output(353, 1, 0), &["#"], &[('#', item(338))]);
+    // Oak, spruce, birch and jungle planks from `log`, acacia and dark oak from `log2`.
+    for variant in 0..6 {
+        let log = if variant < 4 {
+            exact(17, variant)
+        } else {
+            exact(162, variant - 4)
+        };
+        book.shaped(output(PLANKS, 4, variant), &["#"], &[('#', log)]);
+    }
+    book.shaped(output(STICK, 4, 0), &["#", "#"], &[('#', any(PLANKS))]);
+    for coal in [exact(263, 0), exact(263, 1)] {
+        book.shaped(
+            output(50, 4, 0),
+            &["X", "#"],
+            &[('X', coal), ('#', item(STICK))],
+        );
+    }
+    book.shaped(output(281, 4, 0), &["# #", " # "], &[('#', any(PLANKS))]);
+    book.shaped(output(374, 3, 0), &["# #", " # "], &[('#', any(GLASS))]);
+}
+
+fn add_transport_and_tools(book: &mut RecipeBook) {
+    book.shaped(
+        output(66, 16, 0),
+        &["X X", "X#X", "X X"],
+        &[('X', item(IRON_INGOT)), ('#', item(STICK))],
+    );
+    book.shaped(
+        output(27, 6, 0),
+        &["X X", "X#X", "XRX"],
+        &[
+            ('X', item(GOLD_INGOT)),
+            ('R', item(REDSTONE)),
+            ('#', item(STICK)),
+        ],
+    );
+    book.shaped(
+        output(157, 6, 0),
+        &["XSX", "X#X", "XSX"],
+        &[('X', item(IRON_INGOT)), ('#', any(76)), ('S', item(STICK))],
+    );
+    book.shaped(
+        output(28, 6, 0),
+        &["X X", "X#X", "XRX"],
+        &[
+            ('X', item(IRON_INGOT)),
+            ('R', item(REDSTONE)),
+            ('#', any(70)),
+        ],
+    );
+    book.shaped(
+        output(328, 1, 0),
+        &["# #", "###"],
+        &[('#', item(IRON_INGOT))],
+    );
+    book.shaped(
+        output(380, 1, 0),
+        &["# #", "# #", "###"],
+        &[('#', item(IRON_INGOT))],
+    );
+    book.shaped(
+        output(379, 1, 0),
+        &[" B ", "###"],
+        &[('#', any(COBBLESTONE)), ('B', item(369))],
+    );
+    book.shaped(
+        output(91, 1, 0),
+        &["A", "B"],
+        &[('A', any(86)), ('B', any(50))],
+    );
+    // Chest, furnace, TNT and hopper minecarts.
+    for (block, cart) in [(54, 342), (61, 343), (46, 407), (154, 408)] {
+        book.shaped(
+            output(cart, 1, 0),
+            &["A", "B"],
+            &[('A', any(block)), ('B', item(328))],
+        );
+    }
+    book.shaped(output(333, 1, 0), &["# #", "###"], &[('#', any(PLANKS))]);
+    book.shaped(
+        output(325, 1, 0),
+        &["# #", " # "],
+        &[('#', item(IRON_INGOT))],
+    );
+    book.shaped(output(390, 1, 0), &["# #", " # "], &[('#', item(336))]);
+    book.shapeless(output(259, 1, 0), &[item(IRON_INGOT), item(318)]);
+    book.shaped(output(297, 1, 0), &["###"], &[('#', item(296))]);
+    for (variant, stairs) in (0..).zip(WOOD_STAIRS) {
+        book.shaped(
+            output(stairs, 4, 0),
+            &["#  ", "## ", "###"],
+            &[('#', exact(PLANKS, variant))],
+        );
+    }
+    book.shaped(
+        output(346, 1, 0),
+        &["  #", " #X", "# X"],
+        &[('#', item(STICK)), ('X', item(STRING))],
+    );
+    book.shaped(
+        output(398, 1, 0),
+        &["# ", " X"],
+        &[('#', item(346)), ('X', item(391))],
+    );
+    // Cobblestone, brick, stone brick, nether brick, sandstone, red sandstone and quartz.
+    for (stairs, block) in [
+        (67, COBBLESTONE),
+        (108, 45),
+        (109, 98),
+        (114, 112),
+        (128, 24),
+        (180, 179),
+        (156, 155),
+    ] {
+        book.shaped(
+            output(stairs, 4, 0),
+            &["#  ", "## ", "###"],
+            &[('#', any(block))],
+        );
+    }
+    book.shaped(
+        output(321, 1, 0),
+        &["###", "#X#", "###"],
+        &[('#', item(STICK)), ('X', any(WOOL))],
+    );
+    book.shaped(
+        output(389, 1, 0),
+        &["###", "#X#", "###"],
+        &[('#', item(STICK)), ('X', item(334))],
+    );
+    // Golden apples, golden carrot and glistering melon.
+    for (result, gold, center) in [
+        (output(322, 1, 0), item(GOLD_INGOT), item(260)),
+        (output(322, 1, 1), any(41), item(260)),
+        (output(396, 1, 0), item(GOLD_NUGGET), item(391)),
+        (output(382, 1, 0), item(GOLD_NUGGET), item(360)),
+    ] {
+        book.shaped(
+            result,
+            &["###", "#X#", "###"],
+            &[('#', gold), ('X', center)],
+        );
+    }
+}
+
+fn add_redstone(book: &mut RecipeBook) {
+    let stone = exact(STONE, 0);
+    book.shaped(
+        output(69, 1, 0),
+        &["X", "#"],
+        &[('#', any(COBBLESTONE)), ('X', item(STICK))],
+    );
+    book.shaped(
+        output(131, 2, 0),
+        &["I", "S", "#"],
+        &[
+            ('#', any(PLANKS)),
+            ('S', item(STICK)),
+            ('I', item(IRON_INGOT)),
+        ],
+    );
+    book.shaped(
+        output(76, 1, 0),
+        &["X", "#"],
+        &[('#', item(STICK)), ('X', item(REDSTONE))],
+    );
+    book.shaped(
+        output(356, 1, 0),
+        &["#X#", "III"],
+        &[('#', any(76)), ('X', item(REDSTONE)), ('I', stone)],
+    );
+    book.shaped(
+        output(404, 1, 0),
+        &[" # ", "#X#", "III"],
+        &[('#', any(76)), ('X', item(406)), ('I', stone)],
+    );
+    book.shaped(
+        output(347, 1, 0),
+        &[" # ", "#X#", " # "],
+        &[('#', item(GOLD_INGOT)), ('X', item(REDSTONE))],
+    );
+    book.shaped(
+        output(345, 1, 0),
+        &[" # ", "#X#", " # "],
+        &[('#', item(IRON_INGOT)), ('X', item(REDSTONE))],
+    );
+    book.shaped(
+        output(395, 1, 0),
+        &["###", "#X#", "###"],
+        &[('#', item(PAPER)), ('X', item(345))],
+    );
+    book.shaped(output(77, 1, 0), &["#"], &[('#', stone)]);
+    book.shaped(output(143, 1, 0), &["#"], &[('#', any(PLANKS))]);
+    book.shaped(output(70, 1, 0), &["##"], &[('#', stone)]);
+    book.shaped(output(72, 1, 0), &["##"], &[('#', any(PLANKS))]);
+    book.shaped(output(148, 1, 0), &["##"], &[('#', item(IRON_INGOT))]);
+    book.shaped(output(147, 1, 0), &["##"], &[('#', item(GOLD_INGOT))]);
+    book.shaped(
+        output(23, 1, 0),
+        &["###", "#X#", "#R#"],
+        &[
+            ('#', any(COBBLESTONE)),
+            ('X', item(261)),
+            ('R', item(REDSTONE)),
+        ],
+    );
+    book.shaped(
+        output(158, 1, 0),
+        &["###", "# #", "#R#"],
+        &[('#', any(COBBLESTONE)), ('R', item(REDSTONE))],
+    );
+    book.shaped(
+        output(33, 1, 0),
+        &["TTT", "#X#", "#R#"],
+        &[
+            ('#', any(COBBLESTONE)),
+            ('X', item(IRON_INGOT)),
+            ('R', item(REDSTONE)),
+            ('T', any(PLANKS)),
+        ],
+    );
+    book.shaped(
+        output(29, 1, 0),
+        &["S", "P"],
+        &[('S', item(341)), ('P', any(33))],
+    );
+    book.shaped(
+        output(355, 1, 0),
+        &["###", "XXX"],
+        &[('#', any(WOOL)), ('X', any(PLANKS))],
+    );
+    book.shaped(
+        output(116, 1, 0),
+        &[" B ", "D#D", "###"],
+        &[('#', any(49)), ('B', item(340)), ('D', item(DIAMOND))],
+    );
+    book.shaped(
+        output(145, 1, 0),
+        &["III", " i ", "iii"],
+        &[('I', any(42)), ('i', item(IRON_INGOT))],
+    );
+    book.shaped(output(334, 1, 0), &["##", "##"], &[('#', item(415))]);
+    book.shapeless(output(381, 1, 0), &[item(368), item(377)]);
+    for coal in [exact(263, 0), exact(263, 1)] {
+        book.shapeless(output(385, 3, 0), &[item(289), item(377), coal]);
+    }
+    book.shaped(
+        output(151, 1, 0),
+        &["GGG", "QQQ", "WWW"],
+        &[('G', any(GLASS)), ('Q', item(406)), ('W', any(126))],
+    );
+    book.shaped(
+        output(154, 1, 0),
+        &["I I", "ICI", " I "],
+        &[('I', item(IRON_INGOT)), ('C', any(54))],
+    );
+    book.shaped(
+        output(416, 1, 0),
+        &["///", " / ", "/_/"],
+        &[('/', item(STICK)), ('_', exact(44, 0))],
+    );
+}
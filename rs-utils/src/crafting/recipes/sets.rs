@@ -0,0 +1,307 @@
+//! The `Recipes*` classes `CraftingManager` runs before adding its own recipes.
+
+use super::*;
+
+/// `RecipesTools`: pickaxe, shovel, axe and hoe for each material, then shears.
+pub(super) fn add_tools(book: &mut RecipeBook) {
+    let patterns: [&[&str]; 4] = [
+        &["XXX", " # ", " # "],
+        &["X", "#", "#"],
+        &["XX", "X#", " #"],
+        &["XX", " #", " #"],
+    ];
+    let tools = [
+        (any(PLANKS), [270, 269, 271, 290]),
+        (any(COBBLESTONE), [274, 273, 275, 291]),
+        (item(IRON_INGOT), [257, 256, 258, 292]),
+        (item(DIAMOND), [278, 277, 279, 293]),
+        (item(GOLD_INGOT), [285, 284, 286, 294]),
+    ];
+    for (material, items) in tools {
+        for (tool, pattern) in items.into_iter().zip(patterns) {
+            book.shaped(
+                output(tool, 1, 0),
+                pattern,
+                &[('#', item(STICK)), ('X', material)],
+            );
+        }
+    }
+    book.shaped(output(359, 1, 0), &[" #", "# "], &[('#', item(IRON_INGOT))]);
+}
+
+/// `RecipesWeapons`: a sword for each material, the bow and arrows.
+pub(super) fn add_weapons(book: &mut RecipeBook) {
+    let swords = [
+        (any(PLANKS), 268),
+        (any(COBBLESTONE), 272),
+        (item(IRON_INGOT), 267),
+        (item(DIAMOND), 276),
+        (item(GOLD_INGOT), 283),
+    ];
+    for (material, sword) in swords {
+        book.shaped(
+            output(sword, 1, 0),
+            &["X", "X", "#"],
+            &[('#', item(STICK)), ('X', material)],
+        );
+    }
+    book.shaped(
+        output(261, 1, 0),
+        &[" #X", "# X", " #X"],
+        &[('X', item(STRING)), ('#', item(STICK))],
+    );
+    book.shaped(
+        output(262, 4, 0),
+        &["X", "#", "Y"],
+        &[('Y', item(288)), ('X', item(318)), ('#', item(STICK))],
+    );
+}
+
+/// `RecipesIngots`: storage blocks and back, and gold nuggets.
+pub(super) fn add_ingots(book: &mut RecipeBook) {
+    book.storage(41, item(GOLD_INGOT));
+    book.storage(42, item(IRON_INGOT));
+    book.storage(57, item(DIAMOND));
+    book.storage(133, item(388));
+    book.storage(22, exact(DYE, 4));
+    book.storage(152, item(REDSTONE));
+    book.storage(173, item(263));
+    book.storage(170, item(296));
+    book.storage(165, item(341));
+    book.shaped(
+        output(GOLD_INGOT, 1, 0),
+        &["###", "###", "###"],
+        &[('#', item(GOLD_NUGGET))],
+    );
+    book.shaped(
+        output(GOLD_NUGGET, 9, 0),
+        &["#"],
+        &[('#', item(GOLD_INGOT))],
+    );
+}
+
+/// `RecipesFood`: stews, cookies, melon and pumpkin recipes and brewing ingredients.
+pub(super) fn add_food(book: &mut RecipeBook) {
+    book.shapeless(output(282, 1, 0), &[any(39), any(40), item(281)]);
+    book.shaped(
+        output(357, 8, 0),
+        &["#X#"],
+        &[('X', exact(DYE, 3)), ('#', item(296))],
+    );
+    // Rabbit stew takes either mushroom.
+    for mushroom in [39, 40] {
+        book.shaped(
+            output(413, 1, 0),
+            &[" R ", "CPM", " B "],
+            &[
+                ('R', item(412)),
+                ('C', item(391)),
+                ('P', item(393)),
+                ('M', any(mushroom)),
+                ('B', item(281)),
+            ],
+        );
+    }
+    book.shaped(
+        output(103, 1, 0),
+        &["MMM", "MMM", "MMM"],
+        &[('M', item(360))],
+    );
+    book.shaped(output(362, 1, 0), &["M"], &[('M', item(360))]);
+    book.shaped(output(361, 4, 0), &["M"], &[('M', any(86))]);
+    book.shapeless(output(400, 1, 0), &[any(86), item(353), item(344)]);
+    book.shapeless(output(376, 1, 0), &[item(375), any(39), item(353)]);
+    book.shapeless(output(377, 2, 0), &[item(369)]);
+    book.shapeless(output(378, 1, 0), &[item(377), item(341)]);
+}
+
+/// `RecipesCrafting`: containers, sandstone and quartz variants, stone types and prismarine.
+pub(super) fn add_crafting(book: &mut RecipeBook) {
+    book.shaped(
+        output(54, 1, 0),
+        &["###", "# #", "###"],
+        &[('#', any(PLANKS))],
+    );
+    book.shapeless(output(146, 1, 0), &[any(54), any(131)]);
+    book.shaped(
+        output(130, 1, 0),
+        &["###", "#E#", "###"],
+        &[('#', any(49)), ('E', item(381))],
+    );
+    book.shaped(
+        output(61, 1, 0),
+        &["###", "# #", "###"],
+        &[('#', any(COBBLESTONE))],
+    );
+    book.shaped(output(58, 1, 0), &["##", "##"], &[('#', any(PLANKS))]);
+    // Sandstone and red sandstone: from sand, smooth from four, chiseled from two slabs.
+    for (sandstone, sand, slab) in [(24, 0, exact(44, 1)), (179, 1, exact(182, 0))] {
+        book.shaped(
+            output(sandstone, 1, 0),
+            &["##", "##"],
+            &[('#', exact(12, sand))],
+        );
+        book.shaped(
+            output(sandstone, 4, 2),
+            &["##", "##"],
+            &[('#', exact(sandstone, 0))],
+        );
+        book.shaped(output(sandstone, 1, 1), &["#", "#"], &[('#', slab)]);
+    }
+    book.shaped(output(155, 1, 1), &["#", "#"], &[('#', exact(44, 7))]);
+    book.shaped(output(155, 2, 2), &["#", "#"], &[('#', exact(155, 0))]);
+    book.shaped(output(98, 4, 0), &["##", "##"], &[('#', exact(STONE, 0))]);
+    book.shaped(output(98, 1, 3), &["#", "#"], &[('#', exact(44, 5))]);
+    book.shapeless(output(98, 1, 1), &[any(98), any(106)]);
+    book.shapeless(output(48, 1, 0), &[any(COBBLESTONE), any(106)]);
+    book.shaped(
+        output(101, 16, 0),
+        &["###", "###"],
+        &[('#', item(IRON_INGOT))],
+    );
+    book.shaped(output(102, 16, 0), &["###", "###"], &[('#', any(GLASS))]);
+    book.shaped(
+        output(123, 1, 0),
+        &[" R ", "RGR", " R "],
+        &[('R', item(REDSTONE)), ('G', any(89))],
+    );
+    book.shaped(
+        output(138, 1, 0),
+        &["GGG", "GSG", "OOO"],
+        &[('G', any(GLASS)), ('S', item(399)), ('O', any(49))],
+    );
+    book.shaped(output(112, 1, 0), &["NN", "NN"], &[('N', item(405))]);
+    // Diorite, granite and andesite, then their polished forms.
+    book.shaped(
+        output(STONE, 2, 3),
+        &["CQ", "QC"],
+        &[('C', any(COBBLESTONE)), ('Q', item(406))],
+    );
+    book.shapeless(output(STONE, 1, 1), &[exact(STONE, 3), item(406)]);
+    book.shapeless(output(STONE, 2, 5), &[exact(STONE, 3), any(COBBLESTONE)]);
+    book.shaped(
+        output(3, 4, 1),
+        &["DG", "GD"],
+        &[('D', exact(3, 0)), ('G', any(13))],
+    );
+    for (stone, polished) in [(3, 4), (1, 2), (5, 6)] {
+        book.shaped(
+            output(STONE, 4, polished),
+            &["SS", "SS"],
+            &[('S', exact(STONE, stone))],
+        );
+    }
+    // Prismarine, prismarine bricks, dark prismarine and sea lanterns.
+    let shard = item(409);
+    book.shaped(output(168, 1, 0), &["SS", "SS"], &[('S', shard)]);
+    book.shaped(output(168, 1, 1), &["SSS", "SSS", "SSS"], &[('S', shard)]);
+    book.shaped(
+        output(168, 1, 2),
+        &["SSS", "SIS", "SSS"],
+        &[('S', shard), ('I', exact(DYE, 0))],
+    );
+    book.shaped(
+        output(169, 1, 0),
+        &["SCS", "CCC", "SCS"],
+        &[('S', shard), ('C', item(410))],
+    );
+}
+
+/// `RecipesArmor`: helmet, chestplate, leggings and boots for each material.
+pub(super) fn add_armor(book: &mut RecipeBook) {
+    let patterns: [&[&str]; 4] = [
+        &["XXX", "X X"],
+        &["X X", "XXX", "XXX"],
+        &["XXX", "X X", "X X"],
+        &["X X", "X X"],
+    ];
+    // Chainmail is keyed on fire, which never sits in an inventory.
+    let materials = [
+        (item(334), 298),
+        (any(51), 302),
+        (item(IRON_INGOT), 306),
+        (item(DIAMOND), 310),
+        (item(GOLD_INGOT), 314),
+    ];
+    for (material, helmet) in materials {
+        for (offset, pattern) in (0..).zip(patterns) {
+            book.shaped(output(helmet + offset, 1, 0), pattern, &[('X', material)]);
+        }
+    }
+}
+
+/// `RecipesDyes`: colored wool, clay, glass and panes, dyes from flowers and mixing, carpet.
+pub(super) fn add_dyes(book: &mut RecipeBook) {
+    // Blocks are colored by wool metadata, which is `15 - dye`.
+    for dye in 0..16i16 {
+        let color = 15 - dye;
+        book.shapeless(output(WOOL, 1, color), &[exact(DYE, dye), exact(WOOL, 0)]);
+        book.shaped(
+            output(159, 8, color),
+            &["###", "#X#", "###"],
+            &[('#', exact(172, 0)), ('X', exact(DYE, dye))],
+        );
+        book.shaped(
+            output(95, 8, color),
+            &["###", "#X#", "###"],
+            &[('#', exact(GLASS, 0)), ('X', exact(DYE, dye))],
+        );
+        book.shaped(
+            output(160, 16, dye),
+            &["###", "###"],
+            &[('#', exact(95, dye))],
+        );
+    }
+    // (dye made, count, ingredients) for bone meal, flowers and dye mixing.
+    let dyes: [(i16, u8, &[Ingredient]); 27] = [
+        (11, 1, &[exact(37, 0)]),
+        (1, 1, &[exact(38, 0)]),
+        (15, 3, &[item(352)]),
+        (9, 2, &[exact(DYE, 1), exact(DYE, 15)]),
+        (14, 2, &[exact(DYE, 1), exact(DYE, 11)]),
+        (10, 2, &[exact(DYE, 2), exact(DYE, 15)]),
+        (8, 2, &[exact(DYE, 0), exact(DYE, 15)]),
+        (7, 2, &[exact(DYE, 8), exact(DYE, 15)]),
+        (7, 3, &[exact(DYE, 0), exact(DYE, 15), exact(DYE, 15)]),
+        (12, 2, &[exact(DYE, 4), exact(DYE, 15)]),
+        (6, 2, &[exact(DYE, 4), exact(DYE, 2)]),
+        (5, 2, &[exact(DYE, 4), exact(DYE, 1)]),
+        (13, 2, &[exact(DYE, 5), exact(DYE, 9)]),
+        (13, 3, &[exact(DYE, 4), exact(DYE, 1), exact(DYE, 9)]),
+        (
+            13,
+            4,
+            &[exact(DYE, 4), exact(DYE, 1), exact(DYE, 1), exact(DYE, 15)],
+        ),
+        (12, 1, &[exact(38, 1)]),
+        (13, 1, &[exact(38, 2)]),
+        (7, 1, &[exact(38, 3)]),
+        (1, 1, &[exact(38, 4)]),
+        (14, 1, &[exact(38, 5)]),
+        (7, 1, &[exact(38, 6)]),
+        (9, 1, &[exact(38, 7)]),
+        (7, 1, &[exact(38, 8)]),
+        (11, 2, &[exact(175, 0)]),
+        (13, 2, &[exact(175, 1)]),
+        (1, 2, &[exact(175, 4)]),
+        (9, 2, &[exact(175, 5)]),
+    ];
+    for (dye, count, ingredients) in dyes {
+        book.shapeless(output(DYE, count, dye), ingredients);
+    }
+    for color in 0..16i16 {
+        book.shaped(output(171, 3, color), &["##"], &[('#', exact(WOOL, color))]);
+    }
+}
+
+/// `RecipesBanners`: a banner of each color; patterns are `special` recipes.
+pub(super) fn add_banners(book: &mut RecipeBook) {
+    // Banner damage is the dye damage of its base color.
+    for color in 0..16i16 {
+        book.shaped(
+            output(425, 1, 15 - color),
+            &["###", "###", " | "],
+            &[('#', exact(WOOL, color)), ('|', item(STICK))],
+        );
+    }
+}
@@ -0,0 +1,652 @@
+//! The `IRecipe` classes `CraftingManager` registers next to its table recipes. None of them
+//! can match a grid a table recipe matches, so they are simply tried first.
+
+use std::collections::HashMap;
+
+use rs_protocol::nbt::{NamedTag, Tag};
+
+use super::recipes::output;
+use crate::books::{WRITABLE_BOOK_ITEM_ID, WRITTEN_BOOK_ITEM_ID};
+use crate::inventory::{InventoryItemMeta, InventoryItemStack, RawItemTag, item_max_durability};
+
+const DYE: i32 = 351;
+const PAPER: i32 = 339;
+const GUNPOWDER: i32 = 289;
+const FILLED_MAP: i32 = 358;
+const EMPTY_MAP: i32 = 395;
+const FIREWORKS: i32 = 401;
+const FIREWORK_CHARGE: i32 = 402;
+const BANNER: i32 = 425;
+/// Banners take a new pattern only while they have fewer than this many.
+const MAX_BANNER_PATTERNS: usize = 6;
+
+/// `ItemDye.dyeColors`, the firework colors, by dye damage.
+const DYE_FIREWORK_COLORS: [i32; 16] = [
+    1973019, 11743532, 3887386, 5320730, 2437522, 8073150, 2651799, 11250603, 4408131, 14188952,
+    4312372, 14602026, 6719955, 12801229, 15435844, 15790320,
+];
+
+/// `EntitySheep.DYE_TO_RGB`, the leather armor colors, by wool metadata (`15 - dye`).
+const DYE_ARMOR_RGB: [[f32; 3]; 16] = [
+    [1.0, 1.0, 1.0],
+    [0.85, 0.5, 0.2],
+    [0.7, 0.3, 0.85],
+    [0.4, 0.6, 0.85],
+    [0.9, 0.9, 0.2],
+    [0.5, 0.8, 0.1],
+    [0.95, 0.5, 0.65],
+    [0.3, 0.3, 0.3],
+    [0.6, 0.6, 0.6],
+    [0.3, 0.5, 0.6],
+    [0.5, 0.25, 0.7],
+    [0.2, 0.3, 0.7],
+    [0.4, 0.3, 0.2],
+    [0.4, 0.5, 0.2],
+    [0.6, 0.2, 0.2],
+    [0.1, 0.1, 0.1],
+];
+
+/// How a `TileEntityBanner.EnumBannerPattern` is crafted: a dye layout or one extra item.
+enum PatternRecipe {
+    Layout([&'static str; 3]),
+    Item(i32, i16),
+}
+
+/// Banner patterns in `EnumBannerPattern` order, by their tag id.
+const BANNER_PATTERNS: [(&str, PatternRecipe); 38] = [
+    ("bl", PatternRecipe::Layout(["   ", "   ", "#  "])),
+    ("br", PatternRecipe::Layout(["   ", "   ", "  #"])),
+    ("tl", PatternRecipe::Layout(["#  ", "   ", "   "])),
+    ("tr", PatternRecipe::Layout(["  #", "   ", "   "])),
+    ("bs", PatternRecipe::Layout(["   ", "   ", "###"])),
+    ("ts", PatternRecipe::Layout(["###", "   ", "   "])),
+    ("ls", PatternRecipe::Layout(["#  ", "#  ", "#  "])),
+    ("rs", PatternRecipe::Layout(["  #", "  #", "  #"])),
+    ("cs", PatternRecipe::Layout([" # ", " # ", " # "])),
+    ("ms", PatternRecipe::Layout(["   ", "###", "   "])),
+    ("drs", PatternRecipe::Layout(["#  ", " # ", "  #"])),
+    ("dls", PatternRecipe::Layout(["  #", " # ", "#  "])),
+    ("ss", PatternRecipe::Layout(["# #", "# #", "   "])),
+    ("cr", PatternRecipe::Layout(["# #", " # ", "# #"])),
+    ("sc", PatternRecipe::Layout([" # ", "###", " # "])),
+    ("bt", PatternRecipe::Layout(["   ", " # ", "# #"])),
+    ("tt", PatternRecipe::Layout(["# #", " # ", "   "])),
+    ("bts", PatternRecipe::Layout(["   ", "# #", " # "])),
+    ("tts", PatternRecipe::Layout([" # ", "# #", "   "])),
+    ("ld", PatternRecipe::Layout(["## ", "#  ", "   "])),
+    ("rd", PatternRecipe::Layout(["   ", "  #", " ##"])),
+    ("lud", PatternRecipe::Layout(["   ", "#  ", "## "])),
+    ("rud", PatternRecipe::Layout([" ##", "  #", "   "])),
+    ("mc", PatternRecipe::Layout(["   ", " # ", "   "])),
+    ("mr", PatternRecipe::Layout([" # ", "# #", " # "])),
+    ("vh", PatternRecipe::Layout(["## ", "## ", "## "])),
+    ("hh", PatternRecipe::Layout(["###", "###", "   "])),
+    ("vhr", PatternRecipe::Layout([" ##", " ##", " ##"])),
+    ("hhb", PatternRecipe::Layout(["   ", "###", "###"])),
+    ("bo", PatternRecipe::Layout(["###", "# #", "###"])),
+    ("cbo", PatternRecipe::Item(106, 0)),
+    ("cre", PatternRecipe::Item(397, 4)),
+    ("gra", PatternRecipe::Layout(["# #", " # ", " # "])),
+    ("gru", PatternRecipe::Layout([" # ", " # ", "# #"])),
+    ("bri", PatternRecipe::Item(45, 0)),
+    ("sku", PatternRecipe::Item(397, 1)),
+    ("flo", PatternRecipe::Item(38, 8)),
+    ("moj", PatternRecipe::Item(322, 1)),
+];
+
+/// Result of the first special recipe that matches `grid`.
+pub(super) fn special_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    armor_dye_result(grid)
+        .or_else(|| firework_result(grid))
+        .or_else(|| book_clone_result(grid))
+        .or_else(|| map_clone_result(grid))
+        .or_else(|| map_extend_result(grid))
+        .or_else(|| add_pattern_result(grid))
+        .or_else(|| repair_result(grid))
+        .or_else(|| duplicate_pattern_result(grid))
+}
+
+/// `getRemainingItems`: the written book being copied and the banner whose patterns are
+/// copied stay in the grid.
+pub(super) fn kept_ingredient(grid: &[Option<InventoryItemStack>]) -> Option<usize> {
+    if book_clone_result(grid).is_some() {
+        return grid.iter().position(|stack| {
+            stack
+                .as_ref()
+                .is_some_and(|s| s.item_id == WRITTEN_BOOK_ITEM_ID)
+        });
+    }
+    duplicate_pattern_sources(grid).map(|(patterned, _)| patterned)
+}
+
+fn tag(stack: &InventoryItemStack) -> Option<&HashMap<String, Tag>> {
+    stack.meta.raw_tag.as_ref()?.0.1.as_compound()
+}
+
+fn block_entity_tag(stack: &InventoryItemStack) -> Option<&HashMap<String, Tag>> {
+    tag(stack)?.get("BlockEntityTag")?.as_compound()
+}
+
+fn as_compound_mut(tag: &mut Tag) -> &mut HashMap<String, Tag> {
+    if !tag.is_compound() {
+        *tag = Tag::new_compound();
+    }
+    match tag {
+        Tag::Compound(map) => map,
+        _ => unreachable!("tag was just made a compound"),
+    }
+}
+
+/// The root compound of a result's tag, starting an empty one for stacks without a tag.
+fn tag_mut(meta: &mut InventoryItemMeta) -> &mut HashMap<String, Tag> {
+    let raw = meta
+        .raw_tag
+        .get_or_insert_with(|| RawItemTag(Box::new(NamedTag(String::new(), Tag::new_compound()))));
+    as_compound_mut(&mut raw.0.1)
+}
+
+fn child_mut<'a>(map: &'a mut HashMap<String, Tag>, key: &str) -> &'a mut HashMap<String, Tag> {
+    as_compound_mut(map.entry(key.to_string()).or_insert_with(Tag::new_compound))
+}
+
+/// `RecipesArmorDyes`: one piece of leather armor and dyes, averaged with its old color.
+fn armor_dye_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let mut armor = None;
+    let mut has_dye = false;
+    let (mut sum, mut brightness, mut colors) = ([0i32; 3], 0i32, 0i32);
+    let mut mix = |rgb: [f32; 3]| {
+        let rgb = rgb.map(|channel| (channel * 255.0) as i32);
+        brightness += rgb.into_iter().max().unwrap_or(0);
+        for (total, channel) in sum.iter_mut().zip(rgb) {
+            *total += channel;
+        }
+        colors += 1;
+    };
+    for stack in grid.iter().flatten() {
+        match stack.item_id {
+            298..=301 => {
+                if armor.replace(stack).is_some() {
+                    return None;
+                }
+                if let Some(color) = stack.meta.display_color {
+                    mix([16, 8, 0].map(|shift| ((color >> shift) & 255) as f32 / 255.0));
+                }
+            }
+            DYE => {
+                has_dye = true;
+                mix(DYE_ARMOR_RGB[15 - (stack.damage & 15) as usize]);
+            }
+            _ => return None,
+        }
+    }
+    let armor = armor.filter(|_| has_dye)?;
+    let average = sum.map(|total| total / colors);
+    let scale = brightness as f32 / colors as f32;
+    let peak = average.into_iter().max().unwrap_or(0) as f32;
+    let [red, green, blue] = average.map(|channel| (channel as f32 * scale / peak) as i32);
+    let color = (red << 16) | (green << 8) | blue;
+
+    let mut result = InventoryItemStack {
+        count: 1,
+        ..armor.clone()
+    };
+    result.meta.display_color = Some(color as u32);
+    if result.meta.raw_tag.is_some() {
+        child_mut(tag_mut(&mut result.meta), "display")
+            .insert("color".to_string(), Tag::Int(color));
+    }
+    Some(result)
+}
+
+/// `RecipeFireworks`: rockets from paper, gunpowder and stars, stars from gunpowder and dyes,
+/// and fade colors added to a star.
+fn firework_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let (mut gunpowder, mut paper, mut stars) = (0i8, 0, 0);
+    let (mut dyes, mut effects, mut shapes) = (0, 0, 0);
+    for stack in grid.iter().flatten() {
+        match stack.item_id {
+            GUNPOWDER => gunpowder += 1,
+            FIREWORK_CHARGE => stars += 1,
+            DYE => dyes += 1,
+            PAPER => paper += 1,
+            348 | 264 => effects += 1,
+            385 | 288 | 371 | 397 => shapes += 1,
+            _ => return None,
+        }
+    }
+    if gunpowder > 3 || paper > 1 {
+        return None;
+    }
+    if (1..=3).contains(&gunpowder) && paper == 1 && dyes + effects + shapes == 0 {
+        return Some(firework_rocket(grid, gunpowder));
+    }
+    if gunpowder == 1 && paper == 0 && stars == 0 && dyes > 0 && shapes <= 1 {
+        return Some(firework_star(grid));
+    }
+    if gunpowder == 0 && paper == 0 && stars == 1 && dyes > 0 && effects + shapes == 0 {
+        return firework_fade(grid);
+    }
+    None
+}
+
+fn dye_colors(grid: &[Option<InventoryItemStack>]) -> Vec<i32> {
+    grid.iter()
+        .flatten()
+        .filter(|stack| stack.item_id == DYE)
+        .map(|stack| DYE_FIREWORK_COLORS[(stack.damage & 15) as usize])
+        .collect()
+}
+
+fn firework_rocket(grid: &[Option<InventoryItemStack>], flight: i8) -> InventoryItemStack {
+    let explosions = grid
+        .iter()
+        .flatten()
+        .filter(|stack| stack.item_id == FIREWORK_CHARGE)
+        .filter_map(|star| tag(star)?.get("Explosion").cloned())
+        .collect();
+    let mut rocket = output(FIREWORKS, 3, 0);
+    let fireworks = child_mut(tag_mut(&mut rocket.meta), "Fireworks");
+    fireworks.insert("Explosions".to_string(), Tag::List(explosions));
+    fireworks.insert("Flight".to_string(), Tag::Byte(flight));
+    rocket
+}
+
+fn firework_star(grid: &[Option<InventoryItemStack>]) -> InventoryItemStack {
+    let mut explosion = HashMap::from([("Colors".to_string(), Tag::IntArray(dye_colors(grid)))]);
+    let mut shape = 0;
+    for stack in grid.iter().flatten() {
+        match stack.item_id {
+            348 => {
+                explosion.insert("Flicker".to_string(), Tag::Byte(1));
+            }
+            264 => {
+                explosion.insert("Trail".to_string(), Tag::Byte(1));
+            }
+            385 => shape = 1,
+            371 => shape = 2,
+            397 => shape = 3,
+            288 => shape = 4,
+            _ => {}
+        }
+    }
+    explosion.insert("Type".to_string(), Tag::Byte(shape));
+    let mut star = output(FIREWORK_CHARGE, 1, 0);
+    tag_mut(&mut star.meta).insert("Explosion".to_string(), Tag::Compound(explosion));
+    star
+}
+
+fn firework_fade(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let star = grid
+        .iter()
+        .flatten()
+        .find(|stack| stack.item_id == FIREWORK_CHARGE)?;
+    let mut result = InventoryItemStack {
+        count: 1,
+        ..star.clone()
+    };
+    child_mut(tag_mut(&mut result.meta), "Explosion")
+        .insert("FadeColors".to_string(), Tag::IntArray(dye_colors(grid)));
+    Some(result)
+}
+
+/// `RecipeBookCloning`: a written book and book and quills make that many copies, one
+/// generation further, up to copies of copies.
+fn book_clone_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let mut original = None;
+    let mut copies = 0;
+    for stack in grid.iter().flatten() {
+        match stack.item_id {
+            WRITTEN_BOOK_ITEM_ID if original.is_none() => original = Some(stack),
+            WRITABLE_BOOK_ITEM_ID => copies += 1,
+            _ => return None,
+        }
+    }
+    let original = original?;
+    let generation = tag(original)?
+        .get("generation")
+        .and_then(Tag::as_int)
+        .unwrap_or(0);
+    if copies == 0 || generation >= 2 {
+        return None;
+    }
+    let mut copy = InventoryItemStack {
+        count: copies,
+        ..original.clone()
+    };
+    tag_mut(&mut copy.meta).insert("generation".to_string(), Tag::Int(generation + 1));
+    Some(copy)
+}
+
+/// `RecipesMapCloning`: a filled map and empty maps make as many copies of it.
+fn map_clone_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let mut original = None;
+    let mut blanks = 0;
+    for stack in grid.iter().flatten() {
+        match stack.item_id {
+            FILLED_MAP if original.is_none() => original = Some(stack),
+            EMPTY_MAP => blanks += 1,
+            _ => return None,
+        }
+    }
+    let original = original.filter(|_| blanks > 0)?;
+    let mut copy = output(FILLED_MAP, blanks + 1, original.damage);
+    copy.meta.display_name = original.meta.display_name.clone();
+    Some(copy)
+}
+
+/// `RecipesMapExtending`: a filled map ringed by paper zooms out. Whether the map can still
+/// zoom depends on map data only the server has, so the server's result decides that.
+fn map_extend_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    if grid.len() != 9 {
+        return None;
+    }
+    let ringed = grid
+        .iter()
+        .enumerate()
+        .all(|(idx, stack)| match (idx, stack) {
+            (4, Some(stack)) => stack.item_id == FILLED_MAP,
+            (_, Some(stack)) => stack.item_id == PAPER && stack.damage == 0,
+            (_, None) => false,
+        });
+    if !ringed {
+        return None;
+    }
+    let mut map = InventoryItemStack {
+        count: 1,
+        ..grid[4].clone()?
+    };
+    tag_mut(&mut map.meta).insert("map_is_scaling".to_string(), Tag::Byte(1));
+    Some(map)
+}
+
+fn banner_pattern_count(stack: &InventoryItemStack) -> usize {
+    block_entity_tag(stack)
+        .and_then(|tag| tag.get("Patterns"))
+        .and_then(Tag::as_list)
+        .map_or(0, <[Tag]>::len)
+}
+
+fn banner_base_color(stack: &InventoryItemStack) -> i32 {
+    block_entity_tag(stack)
+        .and_then(|tag| tag.get("Base"))
+        .and_then(Tag::as_int)
+        .unwrap_or(i32::from(stack.damage))
+}
+
+/// `RecipeAddPattern#func_179533_c`: the pattern the dyes and items around a banner draw.
+fn banner_pattern(grid: &[Option<InventoryItemStack>]) -> Option<&'static str> {
+    let extras: Vec<(usize, &InventoryItemStack)> = grid
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, stack)| Some((idx, stack.as_ref()?)))
+        .filter(|(_, stack)| stack.item_id != BANNER)
+        .collect();
+    BANNER_PATTERNS.iter().find_map(|(id, recipe)| {
+        let matched = match recipe {
+            PatternRecipe::Item(item_id, damage) => {
+                let (dyes, items): (Vec<_>, Vec<_>) =
+                    extras.iter().partition(|(_, stack)| stack.item_id == DYE);
+                dyes.len() <= 1
+                    && matches!(items.as_slice(), [(_, stack)]
+                        if stack.item_id == *item_id && stack.damage == *damage)
+            }
+            PatternRecipe::Layout(rows) => {
+                grid.len() == 9
+                    && (0..9).all(|idx| {
+                        let drawn = rows[idx / 3].as_bytes()[idx % 3] == b'#';
+                        match extras.iter().find(|(at, _)| *at == idx) {
+                            Some((_, stack)) => {
+                                drawn && stack.item_id == DYE && stack.damage == extras[0].1.damage
+                            }
+                            None => !drawn,
+                        }
+                    })
+            }
+        };
+        matched.then_some(*id)
+    })
+}
+
+/// `RecipeAddPattern`: one banner with room for another pattern, plus what draws it.
+fn add_pattern_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let mut banners = grid
+        .iter()
+        .flatten()
+        .filter(|stack| stack.item_id == BANNER);
+    let (Some(banner), None) = (banners.next(), banners.next()) else {
+        return None;
+    };
+    if banner_pattern_count(banner) >= MAX_BANNER_PATTERNS {
+        return None;
+    }
+    let pattern = banner_pattern(grid)?;
+    let color = grid
+        .iter()
+        .flatten()
+        .find(|stack| stack.item_id == DYE)
+        .map_or(0, |dye| i32::from(dye.damage));
+    let mut result = InventoryItemStack {
+        count: 1,
+        ..banner.clone()
+    };
+    let entry = Tag::Compound(HashMap::from([
+        ("Pattern".to_string(), Tag::String(pattern.to_string())),
+        ("Color".to_string(), Tag::Int(color)),
+    ]));
+    let block_entity = child_mut(tag_mut(&mut result.meta), "BlockEntityTag");
+    match block_entity
+        .entry("Patterns".to_string())
+        .or_insert_with(Tag::new_list)
+    {
+        Tag::List(patterns) => patterns.push(entry),
+        other => *other = Tag::List(vec![entry]),
+    }
+    Some(result)
+}
+
+/// `RecipeDuplicatePattern#matches`: grid indices of a patterned banner and a blank one of the
+/// same base color, with nothing else in the grid.
+fn duplicate_pattern_sources(grid: &[Option<InventoryItemStack>]) -> Option<(usize, usize)> {
+    let mut patterned = None;
+    let mut blank = None;
+    for (idx, stack) in grid.iter().enumerate() {
+        let Some(stack) = stack else {
+            continue;
+        };
+        if stack.item_id != BANNER {
+            return None;
+        }
+        let slot = if banner_pattern_count(stack) > 0 {
+            &mut patterned
+        } else {
+            &mut blank
+        };
+        if slot.replace(idx).is_some() {
+            return None;
+        }
+    }
+    let (patterned, blank) = (patterned?, blank?);
+    let base = |idx: usize| grid[idx].as_ref().map(banner_base_color);
+    (base(patterned) == base(blank)).then_some((patterned, blank))
+}
+
+/// `RecipeDuplicatePattern`: the blank banner takes the patterned one's patterns.
+fn duplicate_pattern_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let (patterned, _) = duplicate_pattern_sources(grid)?;
+    Some(InventoryItemStack {
+        count: 1,
+        ..grid[patterned].clone()?
+    })
+}
+
+/// `RecipeRepairItem`: two worn copies of a tool merge with a 5% durability bonus.
+fn repair_result(grid: &[Option<InventoryItemStack>]) -> Option<InventoryItemStack> {
+    let mut stacks = grid.iter().flatten();
+    let (Some(first), Some(second), None) = (stacks.next(), stacks.next(), stacks.next()) else {
+        return None;
+    };
+    let max = item_max_durability(first.item_id)?;
+    if first.item_id != second.item_id || first.count != 1 || second.count != 1 {
+        return None;
+    }
+    let remaining = |stack: &InventoryItemStack| i32::from(max) - i32::from(stack.damage);
+    let repaired = remaining(first) + remaining(second) + i32::from(max) * 5 / 100;
+    let damage = (i32::from(max) - repaired).max(0) as i16;
+    Some(output(first.item_id, 1, damage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::InventoryState;
+
+    fn stack(item_id: i32, damage: i16) -> Option<InventoryItemStack> {
+        Some(output(item_id, 1, damage))
+    }
+
+    fn with_tag(
+        mut stack: InventoryItemStack,
+        key: &str,
+        value: Tag,
+    ) -> Option<InventoryItemStack> {
+        tag_mut(&mut stack.meta).insert(key.to_string(), value);
+        Some(stack)
+    }
+
+    fn tag_of<'a>(stack: &'a InventoryItemStack, key: &str) -> &'a Tag {
+        tag(stack).and_then(|tag| tag.get(key)).expect("tag entry")
+    }
+
+    #[test]
+    fn fireworks_make_stars_then_rockets() {
+        let star_grid = [
+            stack(GUNPOWDER, 0),
+            stack(DYE, 1),
+            stack(264, 0),
+            stack(288, 0),
+        ];
+        let star = special_result(&star_grid).expect("firework star");
+        assert_eq!((star.item_id, star.count), (FIREWORK_CHARGE, 1));
+        let explosion = tag_of(&star, "Explosion").as_compound().expect("explosion");
+        assert_eq!(explosion["Colors"].as_int_array(), Some(&[11743532][..]));
+        assert_eq!(explosion["Type"].as_byte(), Some(4));
+        assert_eq!(explosion["Trail"].as_byte(), Some(1));
+
+        let rocket_grid = [
+            stack(PAPER, 0),
+            stack(GUNPOWDER, 0),
+            stack(GUNPOWDER, 0),
+            Some(star),
+        ];
+        let rocket = special_result(&rocket_grid).expect("firework rocket");
+        assert_eq!((rocket.item_id, rocket.count), (FIREWORKS, 3));
+        let fireworks = tag_of(&rocket, "Fireworks")
+            .as_compound()
+            .expect("fireworks");
+        assert_eq!(fireworks["Flight"].as_byte(), Some(2));
+        assert_eq!(fireworks["Explosions"].as_list().map(<[Tag]>::len), Some(1));
+
+        // Dye next to a rocket recipe is neither a rocket nor a star.
+        let muddled = [stack(PAPER, 0), stack(GUNPOWDER, 0), stack(DYE, 1), None];
+        assert_eq!(special_result(&muddled), None);
+    }
+
+    #[test]
+    fn leather_armor_mixes_dyes_into_its_color() {
+        let red = [stack(300, 0), stack(DYE, 1), None, None];
+        let dyed = special_result(&red).expect("dyed leggings");
+        assert_eq!(dyed.meta.display_color, Some(0x993333));
+
+        let mut leggings = output(300, 1, 0);
+        leggings.meta.display_color = Some(0x993333);
+        let blue = [Some(leggings), stack(DYE, 4), None, None];
+        let mixed = special_result(&blue).expect("mixed leggings");
+        assert_eq!(mixed.meta.display_color, Some(0x945BA5));
+        assert_eq!(special_result(&[stack(300, 0), None, None, None]), None);
+    }
+
+    #[test]
+    fn written_books_copy_up_to_the_second_generation() {
+        let original = with_tag(
+            output(WRITTEN_BOOK_ITEM_ID, 1, 0),
+            "generation",
+            Tag::Int(0),
+        );
+        let grid = [
+            original.clone(),
+            stack(WRITABLE_BOOK_ITEM_ID, 0),
+            None,
+            stack(386, 0),
+        ];
+        let copies = special_result(&grid).expect("book copies");
+        assert_eq!(copies.count, 2);
+        assert_eq!(tag_of(&copies, "generation").as_int(), Some(1));
+        assert_eq!(kept_ingredient(&grid), Some(0));
+
+        let copy_of_copy = with_tag(
+            output(WRITTEN_BOOK_ITEM_ID, 1, 0),
+            "generation",
+            Tag::Int(2),
+        );
+        let grid = [copy_of_copy, stack(WRITABLE_BOOK_ITEM_ID, 0), None, None];
+        assert_eq!(special_result(&grid), None);
+
+        // Taking the copies leaves the original book behind.
+        let mut inventory = InventoryState::default();
+        inventory.set_window_items(0, vec![None; 45]);
+        inventory.cursor_item = original.clone();
+        inventory.apply_local_click_player_window(1, 0, 0);
+        inventory.cursor_item = stack(WRITABLE_BOOK_ITEM_ID, 0);
+        inventory.apply_local_click_player_window(2, 0, 0);
+        inventory.apply_local_click_player_window(0, 0, 0);
+        assert_eq!(inventory.cursor_item.map(|book| book.count), Some(1));
+        assert_eq!(inventory.player_slots[1], original);
+        assert_eq!(inventory.player_slots[2], None);
+    }
+
+    #[test]
+    fn maps_copy_and_extend() {
+        let grid = [
+            stack(FILLED_MAP, 7),
+            stack(EMPTY_MAP, 0),
+            stack(EMPTY_MAP, 0),
+            None,
+        ];
+        assert_eq!(special_result(&grid), Some(output(FILLED_MAP, 3, 7)));
+
+        let mut ring = vec![stack(PAPER, 0); 9];
+        ring[4] = stack(FILLED_MAP, 7);
+        let zoomed = special_result(&ring).expect("zoomed map");
+        assert_eq!(
+            (zoomed.item_id, zoomed.count, zoomed.damage),
+            (FILLED_MAP, 1, 7)
+        );
+        assert_eq!(tag_of(&zoomed, "map_is_scaling").as_byte(), Some(1));
+    }
+
+    #[test]
+    fn banners_take_patterns_and_copy_them() {
+        // Black base with a red lower stripe.
+        let mut grid = vec![None; 9];
+        grid[4] = stack(BANNER, 0);
+        grid[6..9].fill(stack(DYE, 1));
+        let striped = special_result(&grid).expect("striped banner");
+        let patterns = block_entity_tag(&striped)
+            .and_then(|tag| tag["Patterns"].as_list())
+            .expect("patterns");
+        let pattern = patterns[0].as_compound().expect("pattern");
+        assert_eq!(pattern["Pattern"].as_str(), Some("bs"));
+        assert_eq!(pattern["Color"].as_int(), Some(1));
+
+        // A vine makes a bordure whatever the grid size; a lone banner makes nothing.
+        let vine = [stack(BANNER, 0), stack(106, 0), None, None];
+        assert!(special_result(&vine).is_some());
+        assert_eq!(special_result(&[stack(BANNER, 0), None, None, None]), None);
+
+        let copy = [Some(striped.clone()), stack(BANNER, 0), None, None];
+        assert_eq!(special_result(&copy), Some(striped.clone()));
+        assert_eq!(kept_ingredient(&copy), Some(0));
+        let other_color = [Some(striped), stack(BANNER, 4), None, None];
+        assert_eq!(special_result(&other_color), None);
+    }
+}
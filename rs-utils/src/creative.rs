@@ -0,0 +1,541 @@
+use crate::inventory::{
+    InventoryEnchantment, InventoryItemMeta, InventoryItemStack, InventoryState, can_stack,
+    item_max_durability, max_stack_for_item,
+};
+use crate::registry::{block_registry_key, item_name, item_registry_key};
+use tabs::{BookTab, ENCHANTMENTS};
+
+mod tabs;
+
+pub const ENCHANTED_BOOK_ITEM_ID: i32 = 403;
+/// `CreativeInventoryAction` slot that drops the stack instead of placing it.
+pub const CREATIVE_DROP_SLOT: i16 = -1;
+/// Player window slots the server accepts creative stacks for: the crafting grid, armor, main
+/// inventory and hotbar.
+const CREATIVE_PLAYER_SLOTS: std::ops::Range<i16> = 1..45;
+const HOTBAR_FIRST_SLOT: usize = 36;
+const OUTSIDE_SLOT: i16 = -999;
+
+/// `CreativeTabs`, in the order `GuiContainerCreative` lays them out over its two tab rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CreativeTab {
+    #[default]
+    BuildingBlocks,
+    Decorations,
+    Redstone,
+    Transportation,
+    Misc,
+    Search,
+    Food,
+    Tools,
+    Combat,
+    Brewing,
+    Materials,
+    Inventory,
+}
+
+impl CreativeTab {
+    pub const ALL: [Self; 12] = [
+        Self::BuildingBlocks,
+        Self::Decorations,
+        Self::Redstone,
+        Self::Transportation,
+        Self::Misc,
+        Self::Search,
+        Self::Food,
+        Self::Tools,
+        Self::Combat,
+        Self::Brewing,
+        Self::Materials,
+        Self::Inventory,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::BuildingBlocks => "Building Blocks",
+            Self::Decorations => "Decoration Blocks",
+            Self::Redstone => "Redstone",
+            Self::Transportation => "Transportation",
+            Self::Misc => "Miscellaneous",
+            Self::Search => "Search Items",
+            Self::Food => "Foodstuffs",
+            Self::Tools => "Tools",
+            Self::Combat => "Combat",
+            Self::Brewing => "Brewing",
+            Self::Materials => "Materials",
+            Self::Inventory => "Survival Inventory",
+        }
+    }
+
+    /// `CreativeTabs#getTabIconItem`.
+    pub fn icon(self) -> InventoryItemStack {
+        let (item_id, damage) = match self {
+            Self::BuildingBlocks => (45, 0),
+            Self::Decorations => (175, 5),
+            Self::Redstone => (331, 0),
+            Self::Transportation => (27, 0),
+            Self::Misc => (327, 0),
+            Self::Search => (345, 0),
+            Self::Food => (260, 0),
+            Self::Tools => (258, 0),
+            Self::Combat => (283, 0),
+            Self::Brewing => (373, 0),
+            Self::Materials => (280, 0),
+            Self::Inventory => (54, 0),
+        };
+        creative_stack(item_id, damage)
+    }
+
+    /// Everything the tab lists, in `CreativeTabs#displayAllReleventItems` order.
+    pub fn items(self) -> Vec<InventoryItemStack> {
+        match self {
+            Self::Search => return search_items(),
+            Self::Inventory => return Vec::new(),
+            _ => {}
+        }
+        let mut items: Vec<_> = (1..=tabs::LAST_ITEM_ID)
+            .filter(|&item_id| registry_key(item_id).is_some())
+            .filter(|&item_id| tabs::item_tab(item_id) == Some(self))
+            .flat_map(|item_id| {
+                tabs::sub_items(item_id)
+                    .iter()
+                    .map(move |&damage| creative_stack(item_id, damage))
+            })
+            .collect();
+        let book_tab = match self {
+            Self::Tools => Some(BookTab::Tools),
+            Self::Combat => Some(BookTab::Combat),
+            _ => None,
+        };
+        // Tools and combat end with a max level book for each of their enchantments.
+        items.extend(
+            ENCHANTMENTS
+                .iter()
+                .filter(|&&(_, _, tab)| Some(tab) == book_tab)
+                .map(|&(id, max_level, _)| enchanted_book(id, max_level)),
+        );
+        items
+    }
+}
+
+/// The search tab: every other tab's items plus a book for each enchantment level.
+fn search_items() -> Vec<InventoryItemStack> {
+    let mut items: Vec<_> = CreativeTab::ALL
+        .iter()
+        .filter(|tab| !matches!(tab, CreativeTab::Search | CreativeTab::Inventory))
+        .flat_map(|tab| tab.items())
+        .filter(|stack| stack.item_id != ENCHANTED_BOOK_ITEM_ID)
+        .collect();
+    items.sort_by_key(|stack| stack.item_id);
+    items.extend(ENCHANTMENTS.iter().flat_map(|&(id, max_level, _)| {
+        (1..=max_level).map(move |level| enchanted_book(id, level))
+    }));
+    items
+}
+
+/// Filters the search tab by item name or registry key, ignoring case.
+pub fn creative_search(items: &[InventoryItemStack], query: &str) -> Vec<InventoryItemStack> {
+    let query = query.trim().to_ascii_lowercase();
+    items
+        .iter()
+        .filter(|stack| {
+            query.is_empty()
+                || item_name(stack.item_id)
+                    .to_ascii_lowercase()
+                    .contains(&query)
+                || registry_key(stack.item_id).is_some_and(|key| key.contains(&query))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Items take their registry key from the block they place when they have one.
+fn registry_key(item_id: i32) -> Option<&'static str> {
+    item_registry_key(item_id).or_else(|| u16::try_from(item_id).ok().and_then(block_registry_key))
+}
+
+fn creative_stack(item_id: i32, damage: i16) -> InventoryItemStack {
+    InventoryItemStack {
+        item_id,
+        count: 1,
+        damage,
+        meta: InventoryItemMeta::default(),
+    }
+}
+
+fn enchanted_book(id: i16, level: i16) -> InventoryItemStack {
+    InventoryItemStack {
+        meta: InventoryItemMeta {
+            enchantments: vec![InventoryEnchantment { id, level }],
+            ..InventoryItemMeta::default()
+        },
+        ..creative_stack(ENCHANTED_BOOK_ITEM_ID, 0)
+    }
+}
+
+/// Middle clicks and number keys over a tab always take a full stack.
+pub fn creative_full_stack(stack: &InventoryItemStack) -> InventoryItemStack {
+    InventoryItemStack {
+        count: max_stack_for_item(stack.item_id),
+        ..stack.clone()
+    }
+}
+
+/// `GuiContainerCreative#handleMouseClick` on an item of a tab: picks it up, adds to or takes
+/// from a matching cursor, and deletes any other cursor stack.
+pub fn apply_creative_tab_click(
+    cursor: &mut Option<InventoryItemStack>,
+    item: &InventoryItemStack,
+    button: u8,
+    shift: bool,
+) {
+    match cursor {
+        Some(held) if can_stack(held, item) => {
+            if button == 0 {
+                let max = max_stack_for_item(held.item_id);
+                held.count = if shift {
+                    max
+                } else {
+                    (held.count + 1).min(max)
+                };
+            } else if held.count <= 1 {
+                *cursor = None;
+            } else {
+                held.count -= 1;
+            }
+        }
+        Some(_) => *cursor = None,
+        None if shift => *cursor = Some(creative_full_stack(item)),
+        None => *cursor = Some(item.clone()),
+    }
+}
+
+/// `Block#getItem` and `Block#getDamageValue`: the item a middle click on `block_state` picks.
+pub fn pick_block_item(block_state: u16) -> Option<InventoryItemStack> {
+    let block_id = i32::from(block_state >> 4);
+    let meta = (block_state & 0xF) as i16;
+    let (item_id, damage) = match block_id {
+        0 | 8..=11 | 34 | 36 | 51 | 52 | 90 | 119 => return None,
+        26 => (355, 0),
+        43 => (44, meta & 7),
+        55 => (331, 0),
+        59 => (295, 0),
+        60 => (3, 0),
+        62 => (61, 0),
+        63 | 68 => (323, 0),
+        64 => (324, 0),
+        71 => (330, 0),
+        74 => (73, 0),
+        75 => (76, 0),
+        83 => (338, 0),
+        92 => (354, 0),
+        93 | 94 => (356, 0),
+        104 => (361, 0),
+        105 => (362, 0),
+        115 => (372, 0),
+        117 => (379, 0),
+        118 => (380, 0),
+        124 => (123, 0),
+        125 => (126, meta & 7),
+        127 => (351, 3),
+        132 => (287, 0),
+        140 => (390, 0),
+        141 => (391, 0),
+        142 => (392, 0),
+        144 => (397, 0),
+        149 | 150 => (404, 0),
+        176 | 177 => (425, 0),
+        178 => (151, 0),
+        181 => (182, 0),
+        193..=197 => (427 + block_id - 193, 0),
+        1 | 3 | 5 | 12 | 19 | 24 | 31 | 35 | 38 | 95 | 97 | 98 | 139 | 159 | 160 | 168 | 171
+        | 179 => (block_id, meta),
+        6 | 44 | 126 | 175 => (block_id, meta & 7),
+        17 | 18 | 161 | 162 => (block_id, meta & 3),
+        145 => (block_id, meta >> 2),
+        // Pillar quartz keeps its axis in the metadata.
+        155 => (block_id, meta.min(2)),
+        _ => (block_id, 0),
+    };
+    Some(creative_stack(item_id, damage))
+}
+
+/// What a middle click changed: the hotbar slot to select and player slots to report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickBlock {
+    pub hotbar_slot: u8,
+    pub changed_slots: Vec<(i16, Option<InventoryItemStack>)>,
+}
+
+impl InventoryState {
+    /// `InventoryPlayer#setCurrentItem`: selects a hotbar slot already holding `picked`, and in
+    /// creative otherwise moves it (or a fresh stack) into the hotbar.
+    pub fn pick_block(&mut self, picked: &InventoryItemStack, creative: bool) -> Option<PickBlock> {
+        if self.player_slots.len() < HOTBAR_FIRST_SLOT + 9 {
+            return None;
+        }
+        // `InventoryPlayer#mainInventory` order: hotbar first, then the main inventory.
+        let order: Vec<usize> = (HOTBAR_FIRST_SLOT..HOTBAR_FIRST_SLOT + 9)
+            .chain(9..HOTBAR_FIRST_SLOT)
+            .collect();
+        let find = |slots: &[Option<InventoryItemStack>], item_id: i32, damage: i16| {
+            order.iter().copied().find(|&slot| {
+                slots[slot]
+                    .as_ref()
+                    .is_some_and(|stack| stack.item_id == item_id && stack.damage == damage)
+            })
+        };
+        let found = find(&self.player_slots, picked.item_id, picked.damage);
+        if let Some(slot) = found.filter(|&slot| slot >= HOTBAR_FIRST_SLOT) {
+            self.selected_hotbar_slot = (slot - HOTBAR_FIRST_SLOT) as u8;
+            return Some(PickBlock {
+                hotbar_slot: self.selected_hotbar_slot,
+                changed_slots: Vec::new(),
+            });
+        }
+        if !creative {
+            return None;
+        }
+        if let Some(empty) = order
+            .iter()
+            .copied()
+            .find(|&slot| self.player_slots[slot].is_none())
+            .filter(|&slot| slot >= HOTBAR_FIRST_SLOT)
+        {
+            self.selected_hotbar_slot = (empty - HOTBAR_FIRST_SLOT) as u8;
+        }
+        let current = HOTBAR_FIRST_SLOT + usize::from(self.selected_hotbar_slot);
+        let mut changed_slots = Vec::new();
+        // An unenchanted tool in hand is never replaced.
+        let keep_current = self.player_slots[current].as_ref().is_some_and(|held| {
+            item_max_durability(held.item_id).is_some()
+                && held.meta.enchantments.is_empty()
+                && find(&self.player_slots, held.item_id, held.damage) == Some(current)
+        });
+        if !keep_current {
+            let count = match found {
+                Some(slot) => {
+                    let count = self.player_slots[slot]
+                        .as_ref()
+                        .map_or(1, |stack| stack.count);
+                    self.player_slots[slot] = self.player_slots[current].take();
+                    changed_slots.push((slot as i16, self.player_slots[slot].clone()));
+                    count
+                }
+                None => 1,
+            };
+            self.player_slots[current] = Some(InventoryItemStack {
+                count,
+                ..picked.clone()
+            });
+            changed_slots.push((current as i16, self.player_slots[current].clone()));
+        }
+        Some(PickBlock {
+            hotbar_slot: self.selected_hotbar_slot,
+            changed_slots,
+        })
+    }
+
+    /// A click on a player slot of the creative screen. The server does not track the cursor in
+    /// creative, so instead of `ClickWindow` every slot the click changed is reported, and
+    /// stacks thrown out of the window go to `CREATIVE_DROP_SLOT`.
+    pub fn apply_creative_click(
+        &mut self,
+        slot: i16,
+        button: u8,
+        mode: u8,
+    ) -> Vec<(i16, Option<InventoryItemStack>)> {
+        let before = self.player_slots.clone();
+        let cursor = self.cursor_item.clone();
+        self.apply_local_click_player_window(slot, button, mode);
+        let mut changed: Vec<_> = CREATIVE_PLAYER_SLOTS
+            .filter(|&slot| {
+                let slot = slot as usize;
+                before.get(slot) != self.player_slots.get(slot)
+            })
+            .map(|slot| {
+                (
+                    slot,
+                    self.player_slots.get(slot as usize).cloned().flatten(),
+                )
+            })
+            .collect();
+        if slot == OUTSIDE_SLOT
+            && mode == 0
+            && let Some(mut dropped) = cursor
+        {
+            if button == 1 {
+                dropped.count = 1;
+            }
+            changed.push((CREATIVE_DROP_SLOT, Some(dropped)));
+        }
+        changed
+    }
+
+    /// Puts a full stack of `item` into hotbar slot `hotbar`, as a number key over a tab does.
+    pub fn set_creative_hotbar(
+        &mut self,
+        hotbar: u8,
+        item: &InventoryItemStack,
+    ) -> Option<(i16, Option<InventoryItemStack>)> {
+        let slot = self.hotbar_slot_index(hotbar)?;
+        let stack = Some(creative_full_stack(item));
+        self.player_slots[slot] = stack.clone();
+        Some((slot as i16, stack))
+    }
+
+    /// Shift-clicking the destroy slot empties every creative slot.
+    pub fn clear_creative_inventory(&mut self) -> Vec<(i16, Option<InventoryItemStack>)> {
+        CREATIVE_PLAYER_SLOTS
+            .filter_map(|slot| {
+                let stack = self.player_slots.get_mut(slot as usize)?;
+                *stack = None;
+                Some((slot, None))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(item_id: i32, count: u8, damage: i16) -> InventoryItemStack {
+        InventoryItemStack {
+            count,
+            ..creative_stack(item_id, damage)
+        }
+    }
+
+    #[test]
+    fn creative_tabs_list_variants_and_search_finds_them() {
+        let blocks = CreativeTab::BuildingBlocks.items();
+        assert_eq!(blocks[0], stack(1, 1, 0));
+        assert_eq!(
+            blocks.iter().filter(|stack| stack.item_id == 35).count(),
+            16
+        );
+        assert!(!blocks.contains(&stack(44, 1, 2)));
+        let combat = CreativeTab::Combat.items();
+        assert_eq!(
+            combat.last().map(|book| book.item_id),
+            Some(ENCHANTED_BOOK_ITEM_ID)
+        );
+        assert!(CreativeTab::Inventory.items().is_empty());
+
+        let all = CreativeTab::Search.items();
+        let wool = creative_search(&all, " WOOL");
+        assert_eq!(wool.len(), 16);
+        assert!(creative_search(&all, "").len() > 500);
+        assert_eq!(
+            all.iter()
+                .filter(|stack| stack.item_id == ENCHANTED_BOOK_ITEM_ID)
+                .count(),
+            ENCHANTMENTS
+                .iter()
+                .map(|&(_, max, _)| max as usize)
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn tab_categories_only_name_registry_entries() {
+        let listed: Vec<_> = (1..=tabs::LAST_ITEM_ID)
+            .filter(|&item_id| tabs::item_tab(item_id).is_some())
+            .collect();
+        assert!(
+            listed
+                .iter()
+                .all(|&item_id| registry_key(item_id).is_some())
+        );
+        // Blocks without an item of their own, like the bed block, have no tab.
+        assert_eq!(tabs::item_tab(26), None);
+        assert_eq!(tabs::item_tab(355), Some(CreativeTab::Decorations));
+        let per_tab = |tab| {
+            listed
+                .iter()
+                .filter(|&&item_id| tabs::item_tab(item_id) == Some(tab))
+                .count()
+        };
+        assert_eq!(per_tab(CreativeTab::BuildingBlocks), 70);
+        assert_eq!(per_tab(CreativeTab::Materials), 31);
+    }
+
+    #[test]
+    fn tab_clicks_follow_gui_container_creative() {
+        let dirt = stack(3, 1, 0);
+        let mut cursor = None;
+        apply_creative_tab_click(&mut cursor, &dirt, 0, false);
+        assert_eq!(cursor, Some(dirt.clone()));
+        apply_creative_tab_click(&mut cursor, &dirt, 0, false);
+        assert_eq!(cursor, Some(stack(3, 2, 0)));
+        apply_creative_tab_click(&mut cursor, &dirt, 0, true);
+        assert_eq!(cursor, Some(stack(3, 64, 0)));
+        apply_creative_tab_click(&mut cursor, &dirt, 1, false);
+        assert_eq!(cursor, Some(stack(3, 63, 0)));
+        // Clicking a different item deletes the held stack.
+        apply_creative_tab_click(&mut cursor, &stack(4, 1, 0), 0, false);
+        assert_eq!(cursor, None);
+    }
+
+    #[test]
+    fn pick_block_selects_or_fills_the_hotbar() {
+        assert_eq!(pick_block_item(17 << 4 | 5), Some(stack(17, 1, 1)));
+        assert_eq!(pick_block_item(64 << 4 | 3), Some(stack(324, 1, 0)));
+        assert_eq!(pick_block_item(9 << 4), None);
+
+        let mut inventory = InventoryState::default();
+        inventory.set_window_items(0, vec![None; 45]);
+        inventory.set_slot(0, 38, Some(stack(1, 5, 0)));
+        inventory.set_slot(0, 36, Some(stack(4, 3, 0)));
+        inventory.set_slot(0, 12, Some(stack(5, 20, 2)));
+        inventory.set_slot(0, 37, Some(stack(20, 1, 0)));
+        inventory.set_slot(0, 39, Some(stack(20, 1, 0)));
+        for slot in 40..45 {
+            inventory.set_slot(0, slot, Some(stack(20, 1, 0)));
+        }
+
+        let picked = inventory.pick_block(&stack(1, 1, 0), false).unwrap();
+        assert_eq!(picked.hotbar_slot, 2);
+        assert!(picked.changed_slots.is_empty());
+        assert_eq!(inventory.pick_block(&stack(5, 1, 2), false), None);
+
+        // A full hotbar swaps the stack found in the main inventory into the held slot.
+        inventory.set_selected_hotbar_slot(0);
+        let picked = inventory.pick_block(&stack(5, 1, 2), true).unwrap();
+        assert_eq!(picked.hotbar_slot, 0);
+        assert_eq!(
+            picked.changed_slots,
+            vec![(12, Some(stack(4, 3, 0))), (36, Some(stack(5, 20, 2))),]
+        );
+
+        inventory.set_slot(0, 41, None);
+        let picked = inventory.pick_block(&stack(45, 1, 0), true).unwrap();
+        assert_eq!(picked.hotbar_slot, 5);
+        assert_eq!(picked.changed_slots, vec![(41, Some(stack(45, 1, 0)))]);
+    }
+
+    #[test]
+    fn creative_clicks_report_changed_slots() {
+        let mut inventory = InventoryState::default();
+        inventory.set_window_items(0, vec![None; 45]);
+        inventory.cursor_item = Some(stack(1, 64, 0));
+        assert_eq!(
+            inventory.apply_creative_click(36, 0, 0),
+            vec![(36, Some(stack(1, 64, 0)))]
+        );
+        assert_eq!(inventory.cursor_item, None);
+        inventory.cursor_item = Some(stack(3, 10, 0));
+        assert_eq!(
+            inventory.apply_creative_click(OUTSIDE_SLOT, 1, 0),
+            vec![(CREATIVE_DROP_SLOT, Some(stack(3, 1, 0)))]
+        );
+        assert_eq!(
+            inventory.set_creative_hotbar(1, &stack(3, 1, 0)),
+            Some((37, Some(stack(3, 64, 0))))
+        );
+        let cleared = inventory.clear_creative_inventory();
+        assert_eq!(cleared.len(), 44);
+        assert_eq!(inventory.player_slots[36], None);
+    }
+}
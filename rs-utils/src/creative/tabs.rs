@@ -0,0 +1,271 @@
+//! Creative tab contents. `CreativeTabs#displayAllReleventItems` walks the item registry in id
+//! order, so each tab is the registry filtered by the 1.8.9 `setCreativeTab` category below,
+//! with every entry expanded into the variants its `getSubItems` override lists.
+
+use super::CreativeTab;
+
+/// The last id in `Item.itemRegistry`, the final music disc.
+pub(super) const LAST_ITEM_ID: i32 = 2267;
+
+const PLAIN: &[i16] = &[0];
+const TWO: &[i16] = &[0, 1];
+const THREE: &[i16] = &[0, 1, 2];
+const FOUR: &[i16] = &[0, 1, 2, 3];
+const SIX: &[i16] = &[0, 1, 2, 3, 4, 5];
+const COLORS: &[i16] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+/// `Block#setCreativeTab` and `Item#setCreativeTab`: the tab a registry entry is listed in.
+pub(super) const fn item_tab(item_id: i32) -> Option<CreativeTab> {
+    let tab = match item_id {
+        1..=5
+        | 7
+        | 12..=17
+        | 19..=22
+        | 24
+        | 35
+        | 41
+        | 42
+        | 44
+        | 45
+        | 47..=49
+        | 53
+        | 56
+        | 57
+        | 67
+        | 73
+        | 79
+        | 80
+        | 82
+        | 86..=89
+        | 91
+        | 95
+        | 98
+        | 103
+        | 108..=110
+        | 112
+        | 114
+        | 121
+        | 126
+        | 128
+        | 129
+        | 133..=136
+        | 153
+        | 155
+        | 156
+        | 159
+        | 162..=164
+        | 168..=170
+        | 172..=174
+        | 179
+        | 180
+        | 182 => CreativeTab::BuildingBlocks,
+        6
+        | 18
+        | 30..=32
+        | 37..=40
+        | 50
+        | 54
+        | 58
+        | 61
+        | 65
+        | 78
+        | 81
+        | 84
+        | 85
+        | 97
+        | 101
+        | 102
+        | 106
+        | 111
+        | 113
+        | 116
+        | 120
+        | 122
+        | 130
+        | 139
+        | 145
+        | 160
+        | 161
+        | 165
+        | 171
+        | 175
+        | 188..=192
+        | 321
+        | 323
+        | 355
+        | 389
+        | 390
+        | 397
+        | 416
+        | 425 => CreativeTab::Decorations,
+        23
+        | 25
+        | 29
+        | 33
+        | 46
+        | 69
+        | 70
+        | 72
+        | 76
+        | 77
+        | 96
+        | 107
+        | 123
+        | 131
+        | 143
+        | 146..=148
+        | 151
+        | 152
+        | 154
+        | 158
+        | 167
+        | 183..=187
+        | 324
+        | 330
+        | 331
+        | 356
+        | 404
+        | 427..=431 => CreativeTab::Redstone,
+        27 | 28 | 66 | 157 | 328 | 329 | 333 | 342 | 343 | 398 | 407 | 408 => {
+            CreativeTab::Transportation
+        }
+        138
+        | 325..=327
+        | 332
+        | 335
+        | 339..=341
+        | 344
+        | 352
+        | 368
+        | 381
+        | 383..=386
+        | 395
+        | 401
+        | 402
+        | 417..=419
+        | 2256..=2267 => CreativeTab::Misc,
+        260
+        | 282
+        | 297
+        | 319
+        | 320
+        | 322
+        | 349
+        | 350
+        | 354
+        | 357
+        | 360
+        | 363..=367
+        | 375
+        | 391..=394
+        | 400
+        | 411..=413
+        | 423
+        | 424 => CreativeTab::Food,
+        256..=259
+        | 269..=271
+        | 273..=275
+        | 277..=279
+        | 284..=286
+        | 290..=294
+        | 345..=347
+        | 359
+        | 420
+        | 421 => CreativeTab::Tools,
+        261 | 262 | 267 | 268 | 272 | 276 | 283 | 298..=317 => CreativeTab::Combat,
+        370 | 373 | 374 | 376..=380 | 382 | 396 | 414 => CreativeTab::Brewing,
+        263..=266
+        | 280
+        | 281
+        | 287..=289
+        | 295
+        | 296
+        | 318
+        | 334
+        | 336..=338
+        | 348
+        | 351
+        | 353
+        | 361
+        | 362
+        | 369
+        | 371
+        | 372
+        | 388
+        | 399
+        | 405
+        | 406
+        | 409
+        | 410
+        | 415 => CreativeTab::Materials,
+        _ => return None,
+    };
+    Some(tab)
+}
+
+/// `getSubItems`: the damage values an entry is listed with in its tab.
+pub(super) const fn sub_items(item_id: i32) -> &'static [i16] {
+    match item_id {
+        1 => &[0, 1, 2, 3, 4, 5, 6],
+        3 | 24 | 145 | 155 | 168 | 179 => THREE,
+        5 | 6 | 97 | 126 | 175 => SIX,
+        12 | 19 | 139 | 161 | 162 | 263 | 322 | 350 => TWO,
+        17 | 18 | 98 | 349 => FOUR,
+        31 => &[1, 2],
+        35 | 95 | 159 | 160 | 171 | 351 | 425 => COLORS,
+        38 => &[0, 1, 2, 3, 4, 5, 6, 7, 8],
+        // The old wooden stone slab (2) is left out.
+        44 => &[0, 1, 3, 4, 5, 6, 7],
+        // Water bottle, then each drinkable potion and its splash form (+8192).
+        373 => &[
+            0, 8193, 8225, 8257, 8194, 8226, 8258, 8195, 8259, 8196, 8228, 8260, 8197, 8229, 8198,
+            8262, 8200, 8264, 8201, 8233, 8265, 8202, 8266, 8203, 8235, 8267, 8204, 8236, 8205,
+            8269, 8206, 8270, 16385, 16417, 16449, 16386, 16418, 16450, 16387, 16451, 16388, 16420,
+            16452, 16389, 16421, 16390, 16454, 16392, 16456, 16393, 16425, 16457, 16394, 16458,
+            16395, 16427, 16459, 16396, 16428, 16397, 16461, 16398, 16462,
+        ],
+        // One egg per `EntityList.entityEggs` entry, keyed by entity type id.
+        383 => &[
+            50, 51, 52, 54, 55, 56, 57, 58, 59, 60, 61, 62, 65, 66, 67, 68, 90, 91, 92, 93, 94, 95,
+            96, 98, 100, 101, 120,
+        ],
+        397 => &[0, 1, 2, 3, 4],
+        _ => PLAIN,
+    }
+}
+
+/// Which tab lists books for an enchantment (`CreativeTabs#setRelevantEnchantmentTypes`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BookTab {
+    Tools,
+    Combat,
+}
+
+/// `Enchantment.enchantmentsBookList`: id, max level and the tab its books appear in.
+pub(super) const ENCHANTMENTS: &[(i16, i16, BookTab)] = &[
+    (0, 4, BookTab::Combat),
+    (1, 4, BookTab::Combat),
+    (2, 4, BookTab::Combat),
+    (3, 4, BookTab::Combat),
+    (4, 4, BookTab::Combat),
+    (5, 3, BookTab::Combat),
+    (6, 1, BookTab::Combat),
+    (7, 3, BookTab::Combat),
+    (8, 3, BookTab::Combat),
+    (16, 5, BookTab::Combat),
+    (17, 5, BookTab::Combat),
+    (18, 5, BookTab::Combat),
+    (19, 2, BookTab::Combat),
+    (20, 2, BookTab::Combat),
+    (21, 3, BookTab::Combat),
+    (32, 5, BookTab::Tools),
+    (33, 1, BookTab::Tools),
+    (34, 3, BookTab::Tools),
+    (35, 3, BookTab::Tools),
+    (48, 5, BookTab::Combat),
+    (49, 2, BookTab::Combat),
+    (50, 1, BookTab::Combat),
+    (51, 1, BookTab::Combat),
+    (61, 3, BookTab::Tools),
+    (62, 3, BookTab::Tools),
+];
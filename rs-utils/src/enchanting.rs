@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::java_random::JavaRandom;
+
+/// Enchanting table properties: level costs in 0..=2, the glyph seed in 3, hints in 4..=9.
+pub const ENCHANT_PROPERTY_SEED: i16 = 3;
+const ENCHANT_PROPERTY_HINT: i16 = 4;
+const ENCHANT_PROPERTY_HINT_LEVEL: i16 = 7;
+pub const ENCHANT_ITEM_SLOT: usize = 0;
+pub const ENCHANT_LAPIS_SLOT: usize = 1;
+pub const LAPIS_ITEM_ID: i32 = 351;
+pub const LAPIS_DAMAGE: i16 = 4;
+
+/// `EnchantmentNameParts`: the words the table's glyph lines are drawn from.
+const NAME_PARTS: [&str; 55] = [
+    "the",
+    "elder",
+    "scrolls",
+    "klaatu",
+    "berata",
+    "niktu",
+    "xyzzy",
+    "bless",
+    "curse",
+    "light",
+    "darkness",
+    "fire",
+    "air",
+    "earth",
+    "water",
+    "hot",
+    "dry",
+    "cold",
+    "wet",
+    "ignite",
+    "snuff",
+    "embiggen",
+    "twist",
+    "shorten",
+    "stretch",
+    "fiddle",
+    "destroy",
+    "imbue",
+    "galvanize",
+    "enchant",
+    "free",
+    "limited",
+    "range",
+    "of",
+    "towards",
+    "inside",
+    "sphere",
+    "cube",
+    "self",
+    "other",
+    "ball",
+    "mental",
+    "physical",
+    "grow",
+    "shrink",
+    "demon",
+    "elemental",
+    "spirit",
+    "animal",
+    "creature",
+    "beast",
+    "humanoid",
+    "undead",
+    "fresh",
+    "stale",
+];
+
+/// One of the three buttons of the enchanting table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnchantOption {
+    /// Required XP level; zero when the slot offers nothing.
+    pub cost: i32,
+    /// Enchantment id and level the table reveals for this option.
+    pub hint: Option<(i16, i16)>,
+}
+
+impl EnchantOption {
+    /// `ContainerEnchantment#enchantItem`: option `index` needs `index + 1` lapis and levels,
+    /// and at least its cost in levels; creative players skip both checks.
+    pub fn is_affordable(&self, index: usize, lapis: u8, level: i32, creative: bool) -> bool {
+        let tier = index as i32 + 1;
+        self.cost > 0
+            && (creative || (i32::from(lapis) >= tier && level >= tier && level >= self.cost))
+    }
+}
+
+/// Reads the three options from the window properties.
+///
+/// 1.8 servers pack each hint as `id | level << 8` into properties 4..=6; later protocols split
+/// the level out into 7..=9, which takes precedence when present.
+pub fn enchant_options(properties: &HashMap<i16, i16>) -> [EnchantOption; 3] {
+    std::array::from_fn(|index| {
+        let index = index as i16;
+        let cost = properties.get(&index).copied().unwrap_or(0);
+        let hint = properties
+            .get(&(ENCHANT_PROPERTY_HINT + index))
+            .copied()
+            .filter(|&hint| hint >= 0)
+            .map(
+                |hint| match properties.get(&(ENCHANT_PROPERTY_HINT_LEVEL + index)) {
+                    Some(&level) => (hint, level),
+                    None => (hint & 0xFF, hint >> 8),
+                },
+            );
+        EnchantOption {
+            cost: i32::from(cost.max(0)),
+            hint,
+        }
+    })
+}
+
+/// The three lines of gibberish `GuiEnchantment` renders in the Standard Galactic alphabet.
+pub fn enchant_glyph_lines(properties: &HashMap<i16, i16>) -> [String; 3] {
+    let seed = properties.get(&ENCHANT_PROPERTY_SEED).copied().unwrap_or(0);
+    let mut rng = JavaRandom::new(i64::from(seed));
+    std::array::from_fn(|_| {
+        let words = rng.next_int(2) + 3;
+        (0..words)
+            .map(|_| NAME_PARTS[rng.next_int(NAME_PARTS.len() as i32) as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enchant_options_decode_costs_and_hints() {
+        let properties = HashMap::from([
+            (0, 3),
+            (1, 12),
+            (2, 30),
+            // Sharpness II packed the 1.8 way, nothing for the middle row, Efficiency V split.
+            (4, 16 | 2 << 8),
+            (5, -1),
+            (6, 32),
+            (9, 5),
+        ]);
+        let options = enchant_options(&properties);
+        assert_eq!(options[0].hint, Some((16, 2)));
+        assert_eq!(options[1].hint, None);
+        assert_eq!(options[2].hint, Some((32, 5)));
+
+        assert!(options[0].is_affordable(0, 1, 3, false));
+        assert!(!options[1].is_affordable(1, 1, 30, false));
+        assert!(!options[2].is_affordable(2, 3, 29, false));
+        assert!(options[2].is_affordable(2, 0, 0, true));
+        let empty = EnchantOption {
+            cost: 0,
+            hint: None,
+        };
+        assert!(!empty.is_affordable(0, 64, 30, true));
+
+        let lines = enchant_glyph_lines(&properties);
+        assert_eq!(lines, enchant_glyph_lines(&properties));
+        assert!(
+            lines
+                .iter()
+                .all(|line| (3..=4).contains(&line.split(' ').count()))
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use bevy::prelude::Vec3;
 use rs_protocol::protocol::UUID;
 
+use crate::entity_metadata::EntityMetadataValue;
 use crate::inventory::InventoryItemStack;
+use crate::paintings::PaintingArt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NetEntityKind {
@@ -78,6 +80,7 @@ pub enum ObjectKind {
     ArmorStand,
     EndCrystal,
     FallingBlock,
+    Painting,
     Unknown(u8),
 }
 
@@ -104,6 +107,20 @@ pub enum NetEntityMessage {
         pitch: f32,
         on_ground: Option<bool>,
     },
+    /// Nonzero `SpawnObject` data: the shooter of arrows and fireballs, the angler of a fishing
+    /// hook, or the potion damage of a splash potion.
+    ObjectData {
+        entity_id: i32,
+        data: i32,
+    },
+    /// Paintings have their own spawn packet; `pos` is already the corrected center.
+    SpawnPainting {
+        entity_id: i32,
+        uuid: Option<UUID>,
+        art: PaintingArt,
+        pos: Vec3,
+        facing: u8,
+    },
     MoveDelta {
         entity_id: i32,
         delta: Vec3,
@@ -126,10 +143,6 @@ pub enum NetEntityMessage {
         entity_id: i32,
         velocity: Vec3,
     },
-    Pose {
-        entity_id: i32,
-        sneaking: bool,
-    },
     HeadLook {
         entity_id: i32,
         head_yaw: f32,
@@ -143,10 +156,10 @@ pub enum NetEntityMessage {
         entity_id: i32,
         stack: Option<InventoryItemStack>,
     },
-    SheepAppearance {
+    /// Changed `EntityMetadata` entries by index; `EntityMetadata::apply` gives them meaning.
+    Metadata {
         entity_id: i32,
-        fleece_color: u8,
-        sheared: bool,
+        values: Vec<(u8, EntityMetadataValue)>,
     },
     Animation {
         entity_id: i32,
@@ -160,6 +173,17 @@ pub enum NetEntityMessage {
         collected_entity_id: i32,
         collector_entity_id: i32,
     },
+    /// Passenger (or leash) attachment; `vehicle_id` is `None` when detaching.
+    Attach {
+        entity_id: i32,
+        vehicle_id: Option<i32>,
+        leash: bool,
+    },
+    /// `EntityUsedBed`; `location` is the head half of the bed.
+    UseBed {
+        entity_id: i32,
+        location: (i32, i32, i32),
+    },
     Destroy {
         entity_ids: Vec<i32>,
     },
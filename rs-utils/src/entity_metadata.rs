@@ -0,0 +1,582 @@
+use crate::entities::{MobKind, NetEntityKind, ObjectKind};
+use crate::inventory::InventoryItemStack;
+
+/// One `EntityMetadata` entry in its 1.8 wire type, before the entity kind gives it a meaning.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityMetadataValue {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Float(f32),
+    String(String),
+    Item(Option<InventoryItemStack>),
+    Position([i32; 3]),
+    Rotation([f32; 3]),
+}
+
+impl EntityMetadataValue {
+    fn byte(&self) -> Option<i8> {
+        match self {
+            Self::Byte(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn flag(&self) -> Option<bool> {
+        self.byte().map(|value| value != 0)
+    }
+
+    fn short(&self) -> Option<i16> {
+        match self {
+            Self::Short(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn int(&self) -> Option<i32> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn float(&self) -> Option<f32> {
+        match self {
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn rotation(&self) -> Option<[f32; 3]> {
+        match self {
+            Self::Rotation(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+const FLAG_ON_FIRE: i8 = 0x01;
+const FLAG_SNEAKING: i8 = 0x02;
+const FLAG_SPRINTING: i8 = 0x08;
+const FLAG_EATING: i8 = 0x10;
+const FLAG_INVISIBLE: i8 = 0x20;
+
+const WOLF_SITTING: i8 = 0x01;
+const WOLF_ANGRY: i8 = 0x02;
+const WOLF_TAMED: i8 = 0x04;
+
+const HORSE_SADDLED: i32 = 0x04;
+const HORSE_CHESTED: i32 = 0x08;
+
+const GUARDIAN_MOVING: i32 = 0x02;
+const GUARDIAN_ELDER: i32 = 0x04;
+
+const ARMOR_STAND_SMALL: i8 = 0x01;
+const ARMOR_STAND_ARMS: i8 = 0x04;
+const ARMOR_STAND_NO_BASE_PLATE: i8 = 0x08;
+const ARMOR_STAND_MARKER: i8 = 0x10;
+
+pub const HORSE_TYPE_HORSE: u8 = 0;
+pub const HORSE_TYPE_DONKEY: u8 = 1;
+pub const HORSE_TYPE_MULE: u8 = 2;
+pub const HORSE_TYPE_ZOMBIE: u8 = 3;
+pub const HORSE_TYPE_SKELETON: u8 = 4;
+
+/// Horse fields from indices 16 (flags), 19 (type), 20 (variant) and 22 (armor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HorseMetadata {
+    pub horse_type: u8,
+    /// Coat color in the low byte, markings in the second byte.
+    pub variant: i32,
+    /// 0 none, then iron, gold and diamond (`HorseArmorType`).
+    pub armor: i32,
+    pub saddled: bool,
+    pub chested: bool,
+}
+
+impl HorseMetadata {
+    pub fn color(&self) -> u8 {
+        (self.variant & 0xFF) as u8
+    }
+
+    pub fn markings(&self) -> u8 {
+        ((self.variant >> 8) & 0xFF) as u8
+    }
+}
+
+/// Armor stand flags and part rotations in degrees, indices 10 to 16.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArmorStandPose {
+    pub small: bool,
+    pub has_arms: bool,
+    pub no_base_plate: bool,
+    pub marker: bool,
+    pub head: [f32; 3],
+    pub body: [f32; 3],
+    pub left_arm: [f32; 3],
+    pub right_arm: [f32; 3],
+    pub left_leg: [f32; 3],
+    pub right_leg: [f32; 3],
+}
+
+impl Default for ArmorStandPose {
+    /// `EntityArmorStand`'s default rotations.
+    fn default() -> Self {
+        Self {
+            small: false,
+            has_arms: false,
+            no_base_plate: false,
+            marker: false,
+            head: [0.0; 3],
+            body: [0.0; 3],
+            left_arm: [-10.0, 0.0, -10.0],
+            right_arm: [-15.0, 0.0, 10.0],
+            left_leg: [-1.0, 0.0, -1.0],
+            right_leg: [1.0, 0.0, 1.0],
+        }
+    }
+}
+
+/// Metadata only one kind of entity carries. Indices from 16 up are reused across mobs.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MobMetadata {
+    #[default]
+    None,
+    Creeper {
+        /// -1 idle, 1 while the fuse is lit.
+        fuse_state: i8,
+        charged: bool,
+    },
+    Slime {
+        size: u8,
+    },
+    Ghast {
+        /// Set while the ghast charges a fireball, index 16.
+        attacking: bool,
+    },
+    Guardian {
+        /// Flags at index 16; moving guardians retract their spikes.
+        moving: bool,
+        elder: bool,
+        /// Entity id of the laser target at index 17, 0 for none.
+        target: i32,
+    },
+    Sheep {
+        fleece_color: u8,
+        sheared: bool,
+    },
+    Wolf {
+        sitting: bool,
+        angry: bool,
+        tamed: bool,
+        collar_color: u8,
+    },
+    Horse(HorseMetadata),
+    Villager {
+        profession: i32,
+    },
+    Zombie {
+        villager: bool,
+        converting: bool,
+    },
+    Skeleton {
+        wither: bool,
+    },
+    Enderman {
+        carried_block: i16,
+        carried_data: u8,
+        screaming: bool,
+    },
+    ArmorStand(ArmorStandPose),
+    ItemFrame {
+        /// Eighth turns of the framed item, index 9.
+        rotation: u8,
+    },
+}
+
+impl MobMetadata {
+    /// The values an entity of `kind` starts with before any update arrives.
+    fn for_kind(kind: NetEntityKind) -> Self {
+        match kind {
+            NetEntityKind::Mob(MobKind::Creeper) => Self::Creeper {
+                fuse_state: -1,
+                charged: false,
+            },
+            NetEntityKind::Mob(MobKind::Slime | MobKind::MagmaCube) => Self::Slime { size: 1 },
+            NetEntityKind::Mob(MobKind::Ghast) => Self::Ghast { attacking: false },
+            NetEntityKind::Mob(MobKind::Guardian) => Self::Guardian {
+                moving: false,
+                elder: false,
+                target: 0,
+            },
+            NetEntityKind::Mob(MobKind::Sheep) => Self::Sheep {
+                fleece_color: 0,
+                sheared: false,
+            },
+            NetEntityKind::Mob(MobKind::Wolf) => Self::Wolf {
+                sitting: false,
+                angry: false,
+                tamed: false,
+                // Red, `EnumDyeColor.RED`'s dye damage.
+                collar_color: 1,
+            },
+            NetEntityKind::Mob(MobKind::Horse) => Self::Horse(HorseMetadata::default()),
+            NetEntityKind::Mob(MobKind::Villager) => Self::Villager { profession: 0 },
+            NetEntityKind::Mob(MobKind::Zombie | MobKind::PigZombie) => Self::Zombie {
+                villager: false,
+                converting: false,
+            },
+            NetEntityKind::Mob(MobKind::Skeleton) => Self::Skeleton { wither: false },
+            NetEntityKind::Mob(MobKind::Enderman) => Self::Enderman {
+                carried_block: 0,
+                carried_data: 0,
+                screaming: false,
+            },
+            NetEntityKind::Object(ObjectKind::ArmorStand) => {
+                Self::ArmorStand(ArmorStandPose::default())
+            }
+            NetEntityKind::Object(ObjectKind::ItemFrame) => Self::ItemFrame { rotation: 0 },
+            _ => Self::None,
+        }
+    }
+
+    fn apply(&mut self, index: u8, value: &EntityMetadataValue) {
+        match (self, index) {
+            (Self::Creeper { fuse_state, .. }, 16) => set(fuse_state, value.byte()),
+            (Self::Creeper { charged, .. }, 17) => set(charged, value.flag()),
+            (Self::Slime { size }, 16) => set(size, value.byte().map(|size| size as u8)),
+            (Self::Ghast { attacking }, 16) => set(attacking, value.flag()),
+            (Self::Guardian { moving, elder, .. }, 16) => {
+                if let Some(flags) = value.int() {
+                    *moving = flags & GUARDIAN_MOVING != 0;
+                    *elder = flags & GUARDIAN_ELDER != 0;
+                }
+            }
+            (Self::Guardian { target, .. }, 17) => set(target, value.int()),
+            (
+                Self::Sheep {
+                    fleece_color,
+                    sheared,
+                },
+                16,
+            ) => {
+                if let Some(flags) = value.byte() {
+                    *fleece_color = (flags & 0x0F) as u8;
+                    *sheared = flags & 0x10 != 0;
+                }
+            }
+            (
+                Self::Wolf {
+                    sitting,
+                    angry,
+                    tamed,
+                    ..
+                },
+                16,
+            ) => {
+                if let Some(flags) = value.byte() {
+                    *sitting = flags & WOLF_SITTING != 0;
+                    *angry = flags & WOLF_ANGRY != 0;
+                    *tamed = flags & WOLF_TAMED != 0;
+                }
+            }
+            (Self::Wolf { collar_color, .. }, 20) => {
+                set(collar_color, value.byte().map(|color| color as u8))
+            }
+            (Self::Horse(horse), 16) => {
+                if let Some(flags) = value.int() {
+                    horse.saddled = flags & HORSE_SADDLED != 0;
+                    horse.chested = flags & HORSE_CHESTED != 0;
+                }
+            }
+            (Self::Horse(horse), 19) => set(&mut horse.horse_type, value.byte().map(|ty| ty as u8)),
+            (Self::Horse(horse), 20) => set(&mut horse.variant, value.int()),
+            (Self::Horse(horse), 22) => set(&mut horse.armor, value.int()),
+            (Self::Villager { profession }, 16) => set(profession, value.int()),
+            (Self::Zombie { villager, .. }, 13) => set(villager, value.flag()),
+            (Self::Zombie { converting, .. }, 14) => set(converting, value.flag()),
+            (Self::Skeleton { wither }, 13) => set(wither, value.flag()),
+            (Self::Enderman { carried_block, .. }, 16) => set(carried_block, value.short()),
+            (Self::Enderman { carried_data, .. }, 17) => {
+                set(carried_data, value.byte().map(|data| data as u8))
+            }
+            (Self::Enderman { screaming, .. }, 18) => set(screaming, value.flag()),
+            (Self::ArmorStand(pose), 10) => {
+                if let Some(flags) = value.byte() {
+                    pose.small = flags & ARMOR_STAND_SMALL != 0;
+                    pose.has_arms = flags & ARMOR_STAND_ARMS != 0;
+                    pose.no_base_plate = flags & ARMOR_STAND_NO_BASE_PLATE != 0;
+                    pose.marker = flags & ARMOR_STAND_MARKER != 0;
+                }
+            }
+            (Self::ArmorStand(pose), 11..=16) => {
+                let part = match index {
+                    11 => &mut pose.head,
+                    12 => &mut pose.body,
+                    13 => &mut pose.left_arm,
+                    14 => &mut pose.right_arm,
+                    15 => &mut pose.left_leg,
+                    _ => &mut pose.right_leg,
+                };
+                set(part, value.rotation());
+            }
+            (Self::ItemFrame { rotation }, 9) => {
+                set(rotation, value.byte().map(|turns| (turns & 7) as u8))
+            }
+            _ => {}
+        }
+    }
+}
+
+fn set<T>(field: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *field = value;
+    }
+}
+
+/// Typed metadata of one entity, kept current by applying each partial `EntityMetadata` update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityMetadata {
+    kind: NetEntityKind,
+    pub on_fire: bool,
+    pub sneaking: bool,
+    pub sprinting: bool,
+    pub eating: bool,
+    pub invisible: bool,
+    pub air: i16,
+    pub custom_name: String,
+    pub custom_name_visible: bool,
+    pub silent: bool,
+    pub health: f32,
+    pub potion_color: i32,
+    pub potion_ambient: bool,
+    pub arrow_count: u8,
+    /// Negative age of ageable mobs, or the zombie child flag.
+    pub child: bool,
+    /// Item in an item frame, index 8.
+    pub framed_item: Option<InventoryItemStack>,
+    pub mob: MobMetadata,
+}
+
+impl EntityMetadata {
+    pub fn new(kind: NetEntityKind) -> Self {
+        Self {
+            kind,
+            on_fire: false,
+            sneaking: false,
+            sprinting: false,
+            eating: false,
+            invisible: false,
+            air: 300,
+            custom_name: String::new(),
+            custom_name_visible: false,
+            silent: false,
+            health: 1.0,
+            potion_color: 0,
+            potion_ambient: false,
+            arrow_count: 0,
+            child: false,
+            framed_item: None,
+            mob: MobMetadata::for_kind(kind),
+        }
+    }
+
+    pub fn kind(&self) -> NetEntityKind {
+        self.kind
+    }
+
+    pub fn apply(&mut self, values: &[(u8, EntityMetadataValue)]) {
+        for (index, value) in values {
+            self.apply_value(*index, value);
+        }
+    }
+
+    fn apply_value(&mut self, index: u8, value: &EntityMetadataValue) {
+        match index {
+            0 => {
+                if let Some(flags) = value.byte() {
+                    self.on_fire = flags & FLAG_ON_FIRE != 0;
+                    self.sneaking = flags & FLAG_SNEAKING != 0;
+                    self.sprinting = flags & FLAG_SPRINTING != 0;
+                    self.eating = flags & FLAG_EATING != 0;
+                    self.invisible = flags & FLAG_INVISIBLE != 0;
+                }
+            }
+            1 => set(&mut self.air, value.short()),
+            2 => {
+                if let EntityMetadataValue::String(name) = value {
+                    self.custom_name = name.clone();
+                }
+            }
+            3 => set(&mut self.custom_name_visible, value.flag()),
+            4 => set(&mut self.silent, value.flag()),
+            6 if self.is_living() => set(&mut self.health, value.float()),
+            7 if self.is_living() => set(&mut self.potion_color, value.int()),
+            8 if self.is_living() => set(&mut self.potion_ambient, value.flag()),
+            9 if self.is_living() => set(&mut self.arrow_count, value.byte().map(|n| n as u8)),
+            12 if self.is_ageable() => set(&mut self.child, value.byte().map(|age| age < 0)),
+            12 if self.is_zombie() => set(&mut self.child, value.flag()),
+            8 if self.kind == NetEntityKind::Object(ObjectKind::ItemFrame) => {
+                if let EntityMetadataValue::Item(item) = value {
+                    self.framed_item = item.clone();
+                }
+            }
+            _ => self.mob.apply(index, value),
+        }
+    }
+
+    fn is_living(&self) -> bool {
+        matches!(
+            self.kind,
+            NetEntityKind::Player
+                | NetEntityKind::Mob(_)
+                | NetEntityKind::Object(ObjectKind::ArmorStand)
+        )
+    }
+
+    /// `EntityAgeable` subclasses: the passive animals and villagers.
+    fn is_ageable(&self) -> bool {
+        matches!(
+            self.kind,
+            NetEntityKind::Mob(
+                MobKind::Pig
+                    | MobKind::Sheep
+                    | MobKind::Cow
+                    | MobKind::Chicken
+                    | MobKind::Wolf
+                    | MobKind::Mooshroom
+                    | MobKind::Ocelot
+                    | MobKind::Horse
+                    | MobKind::Rabbit
+                    | MobKind::Villager
+            )
+        )
+    }
+
+    fn is_zombie(&self) -> bool {
+        matches!(
+            self.kind,
+            NetEntityKind::Mob(MobKind::Zombie | MobKind::PigZombie)
+        )
+    }
+
+    /// The custom name without its `§` formatting codes, if the entity has one.
+    pub fn plain_custom_name(&self) -> Option<String> {
+        let mut out = String::with_capacity(self.custom_name.len());
+        let mut chars = self.custom_name.chars();
+        while let Some(ch) = chars.next() {
+            if ch == '§' {
+                let _ = chars.next();
+                continue;
+            }
+            out.push(ch);
+        }
+        (!out.is_empty()).then_some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_flags_and_mob_fields_follow_the_entity_kind() {
+        let mut zombie = EntityMetadata::new(NetEntityKind::Mob(MobKind::Zombie));
+        zombie.apply(&[
+            (0, EntityMetadataValue::Byte(FLAG_ON_FIRE | FLAG_INVISIBLE)),
+            (2, EntityMetadataValue::String("§cBob".to_string())),
+            (12, EntityMetadataValue::Byte(1)),
+            (13, EntityMetadataValue::Byte(1)),
+        ]);
+        assert!(zombie.on_fire && zombie.invisible && !zombie.sneaking);
+        assert!(zombie.child);
+        assert_eq!(zombie.plain_custom_name().as_deref(), Some("Bob"));
+        assert_eq!(
+            zombie.mob,
+            MobMetadata::Zombie {
+                villager: true,
+                converting: false,
+            }
+        );
+
+        // Index 12 is the age of ageable mobs; 16 means something different for each mob.
+        let mut horse = EntityMetadata::new(NetEntityKind::Mob(MobKind::Horse));
+        horse.apply(&[
+            (12, EntityMetadataValue::Byte(-24)),
+            (16, EntityMetadataValue::Int(HORSE_SADDLED)),
+            (19, EntityMetadataValue::Byte(HORSE_TYPE_DONKEY as i8)),
+            (20, EntityMetadataValue::Int(0x0203)),
+            (22, EntityMetadataValue::Int(2)),
+        ]);
+        let MobMetadata::Horse(meta) = horse.mob else {
+            panic!("horse metadata");
+        };
+        assert!(horse.child && meta.saddled && !meta.chested);
+        assert_eq!((meta.horse_type, meta.color(), meta.markings()), (1, 3, 2));
+        assert_eq!(meta.armor, 2);
+
+        let mut slime = EntityMetadata::new(NetEntityKind::Mob(MobKind::Slime));
+        slime.apply(&[(16, EntityMetadataValue::Byte(4))]);
+        assert_eq!(slime.mob, MobMetadata::Slime { size: 4 });
+        assert!(!slime.child);
+    }
+
+    #[test]
+    fn armor_stand_pose_keeps_defaults_until_updated() {
+        let mut stand = EntityMetadata::new(NetEntityKind::Object(ObjectKind::ArmorStand));
+        stand.apply(&[
+            (
+                10,
+                EntityMetadataValue::Byte(ARMOR_STAND_SMALL | ARMOR_STAND_ARMS),
+            ),
+            (11, EntityMetadataValue::Rotation([10.0, 20.0, 0.0])),
+        ]);
+        let MobMetadata::ArmorStand(pose) = stand.mob else {
+            panic!("armor stand metadata");
+        };
+        assert!(pose.small && pose.has_arms && !pose.marker);
+        assert_eq!(pose.head, [10.0, 20.0, 0.0]);
+        assert_eq!(pose.right_arm, ArmorStandPose::default().right_arm);
+    }
+
+    #[test]
+    fn item_frames_keep_their_item_and_rotation() {
+        let mut frame = EntityMetadata::new(NetEntityKind::Object(ObjectKind::ItemFrame));
+        let stack = InventoryItemStack {
+            item_id: 358,
+            count: 1,
+            damage: 3,
+            meta: Default::default(),
+        };
+        frame.apply(&[
+            (8, EntityMetadataValue::Item(Some(stack.clone()))),
+            (9, EntityMetadataValue::Byte(5)),
+        ]);
+        assert_eq!(frame.framed_item, Some(stack));
+        assert_eq!(frame.mob, MobMetadata::ItemFrame { rotation: 5 });
+
+        frame.apply(&[(8, EntityMetadataValue::Item(None))]);
+        assert_eq!(frame.framed_item, None);
+    }
+
+    #[test]
+    fn guardians_track_elder_flag_and_laser_target() {
+        let mut guardian = EntityMetadata::new(NetEntityKind::Mob(MobKind::Guardian));
+        guardian.apply(&[
+            (16, EntityMetadataValue::Int(GUARDIAN_ELDER)),
+            (17, EntityMetadataValue::Int(42)),
+        ]);
+        assert_eq!(
+            guardian.mob,
+            MobMetadata::Guardian {
+                moving: false,
+                elder: true,
+                target: 42,
+            }
+        );
+
+        let mut ghast = EntityMetadata::new(NetEntityKind::Mob(MobKind::Ghast));
+        ghast.apply(&[(16, EntityMetadataValue::Byte(1))]);
+        assert_eq!(ghast.mob, MobMetadata::Ghast { attacking: true });
+    }
+}
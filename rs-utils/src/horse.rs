@@ -0,0 +1,27 @@
+use crate::entity_metadata::HORSE_TYPE_HORSE;
+
+pub const HORSE_SADDLE_SLOT: usize = 0;
+pub const HORSE_ARMOR_SLOT: usize = 1;
+pub const HORSE_CHEST_FIRST_SLOT: usize = 2;
+/// `GuiScreenHorseInventory` lays the donkey and mule chest out five slots wide.
+pub const HORSE_CHEST_COLUMNS: usize = 5;
+pub const SADDLE_ITEM_ID: i32 = 329;
+
+/// Iron, gold and diamond horse armor (`EntityHorse#isArmorItem`).
+pub fn is_horse_armor(item_id: i32) -> bool {
+    matches!(item_id, 417..=419)
+}
+
+/// Donkeys and mules carrying a chest send more than the saddle and armor slots.
+pub fn horse_has_chest(unique_slots: usize) -> bool {
+    unique_slots > HORSE_CHEST_FIRST_SLOT
+}
+
+/// `EntityHorse#canWearArmor`: only plain horses have an armor slot. Before the horse's type
+/// is known, chested windows are the only ones that can be ruled out.
+pub fn horse_can_wear_armor(horse_type: Option<u8>, unique_slots: usize) -> bool {
+    match horse_type {
+        Some(horse_type) => horse_type == HORSE_TYPE_HORSE,
+        None => !horse_has_chest(unique_slots),
+    }
+}
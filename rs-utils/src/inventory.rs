@@ -1,7 +1,14 @@
 use std::collections::HashMap;
 
 use bevy::ecs::resource::Resource;
+use rs_protocol::nbt::NamedTag;
 use crate::block_registry_key;
+use crate::books::InventoryBookMeta;
+use crate::crafting::{
+    CRAFTING_RESULT_SLOT, CraftingLayout, apply_crafting_result_click, refresh_crafting_result,
+};
+use crate::trading::TradeOffer;
+use crate::windows::{ShiftClickRoute, WindowKind, shift_click_routes};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InventoryEnchantment {
@@ -9,6 +16,15 @@ pub struct InventoryEnchantment {
     pub level: i16,
 }
 
+/// A stack's tag exactly as the server sent it, including the parts `InventoryItemMeta` does
+/// not model (skull owners, potion effects, fireworks, banner patterns, block entity data).
+/// Boxed so untagged stacks stay small.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawItemTag(pub Box<NamedTag>);
+
+// Server tags carry no NaN floats, so tag equality is a full equivalence for stacking.
+impl Eq for RawItemTag {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct InventoryItemMeta {
     pub display_name: Option<String>,
@@ -17,6 +33,9 @@ pub struct InventoryItemMeta {
     pub enchantments: Vec<InventoryEnchantment>,
     pub repair_cost: Option<i32>,
     pub unbreakable: bool,
+    pub book: Option<InventoryBookMeta>,
+    /// Set for stacks that came from the server; `None` for stacks the client built itself.
+    pub raw_tag: Option<RawItemTag>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -33,6 +52,10 @@ pub struct InventoryWindowInfo {
     pub kind: String,
     pub title: String,
     pub slot_count: u8,
+    /// The horse an `EntityHorse` window belongs to.
+    pub entity_id: Option<i32>,
+    /// That horse's `HORSE_TYPE_*`, filled in once its metadata is known.
+    pub horse_type: Option<u8>,
 }
 
 #[derive(Debug, Clone)]
@@ -58,12 +81,25 @@ pub enum InventoryMessage {
     SetCurrentHotbarSlot {
         slot: u8,
     },
+    WindowProperty {
+        id: u8,
+        property: i16,
+        value: i16,
+    },
+    TradeList {
+        id: i32,
+        offers: Vec<TradeOffer>,
+    },
 }
 
 #[derive(Resource, Debug, Default, Clone)]
 pub struct InventoryState {
     pub player_slots: Vec<Option<InventoryItemStack>>,
     pub open_window: Option<InventoryWindowInfo>,
+    /// `WindowProperty` values of the open window (furnace progress, brew time, ...).
+    pub window_properties: HashMap<i16, i16>,
+    /// Offers of the open villager window.
+    pub trade_offers: Vec<TradeOffer>,
     pub window_slots: HashMap<u8, Vec<Option<InventoryItemStack>>>,
     pub cursor_item: Option<InventoryItemStack>,
     pub selected_hotbar_slot: u8,
@@ -98,6 +134,18 @@ impl InventoryState {
         }
     }
 
+    pub fn set_window_property(&mut self, id: u8, property: i16, value: i16) {
+        if self.active_window_id() == Some(id) {
+            self.window_properties.insert(property, value);
+        }
+    }
+
+    pub fn open_window_kind(&self) -> Option<WindowKind> {
+        self.open_window
+            .as_ref()
+            .map(|window| WindowKind::from_window_type(&window.kind))
+    }
+
     pub fn set_slot(&mut self, id: i8, slot: i16, item: Option<InventoryItemStack>) {
         if id == -1 && slot == -1 {
             self.cursor_item = item;
@@ -227,22 +275,56 @@ impl InventoryState {
                 .unwrap_or_default()
         };
         let mut cursor = self.cursor_item.clone();
-
-        match mode {
-            0 => apply_mode_normal_click(&mut slots, &mut cursor, slot, button),
-            1 => apply_mode_shift_click(&mut slots, &cursor, window_unique_slots, slot),
-            2 => apply_mode_number_key(&mut slots, &cursor, slot, button),
-            4 => apply_mode_drop(&mut slots, &cursor, slot, button),
-            5 => apply_mode_drag_paint(
-                &mut slots,
-                &mut cursor,
-                &mut self.drag_state,
-                window_id,
-                slot,
-                button,
-            ),
-            6 => apply_mode_double_click(&mut slots, &mut cursor, slot, button),
-            _ => {}
+        let kind = self
+            .open_window_kind()
+            .filter(|_| window_id != 0 && self.active_window_id() == Some(window_id))
+            .unwrap_or(WindowKind::Other);
+
+        let crafting = CraftingLayout::for_window(window_id, kind);
+        if let Some(layout) = crafting.filter(|_| slot == CRAFTING_RESULT_SLOT as i16) {
+            apply_crafting_result_click(&mut slots, &mut cursor, layout, mode, button);
+        } else {
+            let grid_before =
+                crafting.and_then(|layout| slots.get(layout.grid()).map(<[_]>::to_vec));
+            // Double-clicks never gather from the output slot.
+            let result = crafting
+                .filter(|_| mode == 6)
+                .and_then(|_| slots.get_mut(CRAFTING_RESULT_SLOT)?.take());
+            match mode {
+                0 => apply_mode_normal_click(&mut slots, &mut cursor, slot, button),
+                1 => apply_mode_shift_click(
+                    &mut slots,
+                    &cursor,
+                    kind,
+                    window_unique_slots,
+                    slot,
+                    self.open_window
+                        .as_ref()
+                        .and_then(|window| window.horse_type),
+                ),
+                2 => apply_mode_number_key(&mut slots, &cursor, slot, button),
+                4 => apply_mode_drop(&mut slots, &cursor, slot, button),
+                5 => apply_mode_drag_paint(
+                    &mut slots,
+                    &mut cursor,
+                    &mut self.drag_state,
+                    window_id,
+                    slot,
+                    button,
+                ),
+                6 => apply_mode_double_click(&mut slots, &mut cursor, slot, button),
+                _ => {}
+            }
+            if let Some(result) = result {
+                slots[CRAFTING_RESULT_SLOT] = Some(result);
+            }
+            // The server's `WindowSetSlot` for the output stays authoritative; only predict
+            // when this click actually changed the grid.
+            if let (Some(layout), Some(before)) = (crafting, grid_before)
+                && slots.get(layout.grid()) != Some(before.as_slice())
+            {
+                refresh_crafting_result(&mut slots, layout);
+            }
         }
         let clicked_item = slot_item_after_click(&slots, slot);
 
@@ -295,7 +377,7 @@ impl InventoryState {
         self.drag_state = snapshot.drag_state;
     }
 
-    fn active_window_id(&self) -> Option<u8> {
+    pub(crate) fn active_window_id(&self) -> Option<u8> {
         self.open_window
             .as_ref()
             .filter(|window| window.id != 0)
@@ -522,8 +604,10 @@ fn apply_mode_normal_click(
 fn apply_mode_shift_click(
     slots: &mut [Option<InventoryItemStack>],
     cursor_item: &Option<InventoryItemStack>,
+    kind: WindowKind,
     window_unique_slots: usize,
     slot: i16,
+    horse_type: Option<u8>,
 ) {
     if slot < 0 || slots.is_empty() {
         return;
@@ -538,34 +622,37 @@ fn apply_mode_shift_click(
 
     let total = slots.len();
     let player_base = total.saturating_sub(36);
-    let player_main_start = player_base;
-    let player_hotbar_start = player_base + 27;
-    let player_end = total;
     let unique = window_unique_slots.min(player_base);
-
-    let mut targets: Vec<std::ops::Range<usize>> = Vec::new();
-    if slot_index < unique {
-        targets.push(player_main_start..player_hotbar_start.min(player_end));
-        targets.push(player_hotbar_start.min(player_end)..player_end);
-    } else if (player_main_start..player_hotbar_start.min(player_end)).contains(&slot_index) {
-        targets.push(player_hotbar_start.min(player_end)..player_end);
-        if unique > 0 {
-            targets.push(0..unique);
-        }
-    } else if (player_hotbar_start.min(player_end)..player_end).contains(&slot_index) {
-        targets.push(player_main_start..player_hotbar_start.min(player_end));
-        if unique > 0 {
-            targets.push(0..unique);
-        }
-    }
+    let targets = shift_click_routes(kind, unique, slots, slot_index, &moving, horse_type)
+        .unwrap_or_else(|| generic_shift_click_routes(unique, total, slot_index));
 
     if targets.is_empty() {
         slots[slot_index] = Some(moving);
         return;
     }
+    merge_into_routes(slots, &mut moving, &targets);
+    if moving.count > 0 {
+        slots[slot_index] = Some(moving);
+    }
+    let _ = cursor_item;
+}
+
+/// Vanilla `mergeItemStack` over each route: top up matching stacks first, then fill empties.
+pub(crate) fn merge_into_routes(
+    slots: &mut [Option<InventoryItemStack>],
+    moving: &mut InventoryItemStack,
+    targets: &[ShiftClickRoute],
+) {
+    let ordered = |route: &ShiftClickRoute| -> Vec<usize> {
+        if route.reverse {
+            route.range.clone().rev().collect()
+        } else {
+            route.range.clone().collect()
+        }
+    };
 
-    for range in &targets {
-        for idx in range.clone() {
+    for route in targets {
+        for idx in ordered(route) {
             if moving.count == 0 {
                 break;
             }
@@ -573,7 +660,7 @@ fn apply_mode_shift_click(
                 continue;
             };
             if let Some(stack) = existing.as_mut() {
-                if can_stack(stack, &moving) && stack.count < max_stack_for_item(stack.item_id) {
+                if can_stack(stack, moving) && stack.count < max_stack_for_item(stack.item_id) {
                     let max = max_stack_for_item(stack.item_id);
                     let space = max.saturating_sub(stack.count);
                     let moved = space.min(moving.count);
@@ -584,8 +671,8 @@ fn apply_mode_shift_click(
         }
     }
 
-    for range in targets {
-        for idx in range {
+    for route in targets {
+        for idx in ordered(route) {
             if moving.count == 0 {
                 break;
             }
@@ -598,11 +685,37 @@ fn apply_mode_shift_click(
             }
         }
     }
+}
 
-    if moving.count > 0 {
-        slots[slot_index] = Some(moving);
+/// Main inventory to hotbar (and back), then into the container.
+fn generic_shift_click_routes(
+    unique: usize,
+    total: usize,
+    slot_index: usize,
+) -> Vec<ShiftClickRoute> {
+    let player_main_start = total.saturating_sub(36);
+    let player_hotbar_start = (player_main_start + 27).min(total);
+    let forward = |range| ShiftClickRoute {
+        range,
+        reverse: false,
+    };
+
+    let mut targets = Vec::new();
+    if slot_index < unique {
+        targets.push(forward(player_main_start..player_hotbar_start));
+        targets.push(forward(player_hotbar_start..total));
+    } else if (player_main_start..player_hotbar_start).contains(&slot_index) {
+        targets.push(forward(player_hotbar_start..total));
+        if unique > 0 {
+            targets.push(forward(0..unique));
+        }
+    } else if (player_hotbar_start..total).contains(&slot_index) {
+        targets.push(forward(player_main_start..player_hotbar_start));
+        if unique > 0 {
+            targets.push(forward(0..unique));
+        }
     }
-    let _ = cursor_item;
+    targets
 }
 
 fn apply_mode_number_key(
@@ -847,7 +960,7 @@ fn recompute_drag_distribution(
     *cursor_item = if cursor.count == 0 { None } else { Some(cursor) };
 }
 
-fn can_stack(a: &InventoryItemStack, b: &InventoryItemStack) -> bool {
+pub(crate) fn can_stack(a: &InventoryItemStack, b: &InventoryItemStack) -> bool {
     a.item_id == b.item_id && a.damage == b.damage && a.meta == b.meta
 }
 
@@ -861,7 +974,7 @@ fn slot_item_after_click(
     slots.get(slot as usize).cloned().flatten()
 }
 
-fn max_stack_for_item(item_id: i32) -> u8 {
+pub(crate) fn max_stack_for_item(item_id: i32) -> u8 {
     if is_single_stack_item(item_id) { 1 } else { 64 }
 }
 
@@ -1105,12 +1218,12 @@ mod tests {
         };
 
         let _ = inventory.apply_local_click_player_window(-999, 0, 5);
-        let _ = inventory.apply_local_click_player_window(0, 1, 5);
-        let _ = inventory.apply_local_click_player_window(1, 1, 5);
+        let _ = inventory.apply_local_click_player_window(9, 1, 5);
+        let _ = inventory.apply_local_click_player_window(10, 1, 5);
         let _ = inventory.apply_local_click_player_window(-999, 2, 5);
 
-        assert_eq!(inventory.player_slots[0], Some(stack(1, 4)));
-        assert_eq!(inventory.player_slots[1], Some(stack(1, 4)));
+        assert_eq!(inventory.player_slots[9], Some(stack(1, 4)));
+        assert_eq!(inventory.player_slots[10], Some(stack(1, 4)));
         assert_eq!(inventory.cursor_item, None);
     }
 
@@ -1123,12 +1236,12 @@ mod tests {
         };
 
         let _ = inventory.apply_local_click_player_window(-999, 4, 5);
-        let _ = inventory.apply_local_click_player_window(0, 5, 5);
-        let _ = inventory.apply_local_click_player_window(1, 5, 5);
+        let _ = inventory.apply_local_click_player_window(9, 5, 5);
+        let _ = inventory.apply_local_click_player_window(10, 5, 5);
         let _ = inventory.apply_local_click_player_window(-999, 6, 5);
 
-        assert_eq!(inventory.player_slots[0], Some(stack(1, 1)));
-        assert_eq!(inventory.player_slots[1], Some(stack(1, 1)));
+        assert_eq!(inventory.player_slots[9], Some(stack(1, 1)));
+        assert_eq!(inventory.player_slots[10], Some(stack(1, 1)));
         assert_eq!(inventory.cursor_item, Some(stack(1, 3)));
     }
 }
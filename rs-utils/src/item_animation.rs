@@ -0,0 +1,168 @@
+use std::f64::consts::{PI, TAU};
+
+use bevy::ecs::resource::Resource;
+
+use crate::inventory::InventoryItemStack;
+
+pub const COMPASS_ITEM_ID: i32 = 345;
+pub const CLOCK_ITEM_ID: i32 = 347;
+/// Vanilla ships `compass_00..31` and `clock_00..63`.
+pub const COMPASS_FRAME_COUNT: u8 = 32;
+pub const CLOCK_FRAME_COUNT: u8 = 64;
+
+/// World spawn from `SpawnPosition` plus the current dimension; compasses and clocks only
+/// work in the overworld.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct WorldSpawnState {
+    pub position: Option<(i32, i32, i32)>,
+    pub dimension: i32,
+}
+
+impl WorldSpawnState {
+    pub fn is_overworld(&self) -> bool {
+        self.dimension == 0
+    }
+}
+
+/// Needle of vanilla's `TextureCompass`/`TextureClock`: eases toward the target with a little
+/// overshoot instead of snapping.
+#[derive(Debug, Clone, Copy, Default)]
+struct Dial {
+    angle: f64,
+    delta: f64,
+}
+
+impl Dial {
+    fn step(&mut self, target: f64, period: f64) {
+        let diff = (target - self.angle + period / 2.0).rem_euclid(period) - period / 2.0;
+        self.delta += diff.clamp(-1.0, 1.0) * 0.1;
+        self.delta *= 0.8;
+        self.angle += self.delta;
+    }
+
+    fn frame(&self, period: f64, frames: u8) -> u8 {
+        ((self.angle / period).rem_euclid(1.0) * f64::from(frames)) as u8 % frames
+    }
+}
+
+/// Current compass and clock frames, advanced once per client tick.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct ItemAnimationState {
+    compass: Dial,
+    clock: Dial,
+    spin_seed: u32,
+    pub compass_frame: u8,
+    pub clock_frame: u8,
+}
+
+impl ItemAnimationState {
+    /// `None` targets make the needle spin at random, like vanilla outside the overworld.
+    pub fn tick(&mut self, compass_target: Option<f64>, clock_target: Option<f64>) {
+        let compass_target = compass_target.unwrap_or_else(|| self.random_unit() * TAU);
+        let clock_target = clock_target.unwrap_or_else(|| self.random_unit());
+        self.compass.step(compass_target, TAU);
+        self.clock.step(clock_target, 1.0);
+        self.compass_frame = self.compass.frame(TAU, COMPASS_FRAME_COUNT);
+        self.clock_frame = self.clock.frame(1.0, CLOCK_FRAME_COUNT);
+    }
+
+    pub fn frame_for(&self, item_id: i32) -> Option<u8> {
+        match item_id {
+            COMPASS_ITEM_ID => Some(self.compass_frame),
+            CLOCK_ITEM_ID => Some(self.clock_frame),
+            _ => None,
+        }
+    }
+
+    /// Copy of `stack` to use for texture lookups; compasses and clocks have no subtypes, so
+    /// the current frame rides in `damage`.
+    pub fn display_stack(&self, stack: &InventoryItemStack) -> Option<InventoryItemStack> {
+        let frame = self.frame_for(stack.item_id)?;
+        let mut display = stack.clone();
+        display.damage = i16::from(frame);
+        Some(display)
+    }
+
+    fn random_unit(&mut self) -> f64 {
+        // xorshift32; only needs to look erratic.
+        let mut x = self.spin_seed.max(1);
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.spin_seed = x;
+        f64::from(x) / f64::from(u32::MAX)
+    }
+}
+
+pub fn animated_item_frame_count(item_id: i32) -> Option<u8> {
+    match item_id {
+        COMPASS_ITEM_ID => Some(COMPASS_FRAME_COUNT),
+        CLOCK_ITEM_ID => Some(CLOCK_FRAME_COUNT),
+        _ => None,
+    }
+}
+
+/// Frame to cut out of a vertical animation strip with `strip_frames` frames (1.8 packs ship
+/// `compass.png`/`clock.png` as strips). Other items show their first frame.
+pub fn item_strip_frame(item_id: i32, damage: i16, strip_frames: u32) -> u32 {
+    match animated_item_frame_count(item_id) {
+        Some(count) if strip_frames > 0 => {
+            let count = u32::from(count);
+            (damage.max(0) as u32 % count) * strip_frames / count
+        }
+        _ => 0,
+    }
+}
+
+/// Needle angle (radians) pointing at spawn, as in `TextureCompass#updateCompass`.
+/// `yaw_degrees` uses the vanilla convention.
+pub fn compass_target_angle(spawn: (i32, i32, i32), x: f64, z: f64, yaw_degrees: f64) -> f64 {
+    let dx = f64::from(spawn.0) - x;
+    let dz = f64::from(spawn.2) - z;
+    -((yaw_degrees % 360.0 - 90.0) * PI / 180.0 - dz.atan2(dx))
+}
+
+/// Sun angle in turns for a time of day, matching `WorldProvider#calculateCelestialAngle`.
+pub fn celestial_angle(time_of_day: f64) -> f64 {
+    let mut angle = time_of_day.rem_euclid(24000.0) / 24000.0 - 0.25;
+    if angle < 0.0 {
+        angle += 1.0;
+    }
+    let eased = 1.0 - ((angle * PI).cos() + 1.0) / 2.0;
+    angle + (eased - angle) / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::InventoryItemMeta;
+
+    #[test]
+    fn compass_and_clock_frames_settle_on_targets() {
+        // Spawn due east; a needle 8.5 frames round sits in the middle of compass_08.
+        let target = compass_target_angle((100, 64, 0), 0.0, 0.0, -5.625);
+        assert!((target - 8.5 / 32.0 * std::f64::consts::TAU).abs() < 1e-9);
+        assert!(celestial_angle(6000.0).abs() < 1e-9);
+        assert!((celestial_angle(18000.0) - 0.5).abs() < 1e-9);
+
+        let mut animation = ItemAnimationState::default();
+        for _ in 0..200 {
+            animation.tick(Some(target), Some(16.5 / 64.0));
+        }
+        assert_eq!(animation.frame_for(COMPASS_ITEM_ID), Some(8));
+        assert_eq!(animation.frame_for(CLOCK_ITEM_ID), Some(16));
+        assert_eq!(animation.frame_for(1), None);
+
+        let stack = InventoryItemStack {
+            item_id: CLOCK_ITEM_ID,
+            count: 1,
+            damage: 0,
+            meta: InventoryItemMeta::default(),
+        };
+        assert_eq!(animation.display_stack(&stack).map(|s| s.damage), Some(16));
+        // 1.8 strips have the same frame count; coarser packs scale down.
+        assert_eq!(item_strip_frame(CLOCK_ITEM_ID, 16, 64), 16);
+        assert_eq!(item_strip_frame(COMPASS_ITEM_ID, 8, 16), 4);
+        assert_eq!(item_strip_frame(1, 3, 4), 0);
+    }
+}
@@ -4,6 +4,7 @@
 // common survival items/blocks and metadata variants well enough for UI icons and basic in-world
 // item sprites.
 
+use crate::item_animation::{CLOCK_FRAME_COUNT, COMPASS_FRAME_COUNT};
 use crate::registry::{block_registry_key, item_registry_key};
 
 /// Returns prioritized texture path candidates relative to `assets/minecraft/textures/`.
@@ -225,6 +226,15 @@ pub fn item_texture_candidates(item_id: i32, damage: i16) -> Vec<String> {
             };
             push_candidate(&mut out, skull.to_string());
         }
+        // Compass/clock: display stacks carry the dial frame in damage (see item_animation).
+        345 => {
+            let frame = damage.rem_euclid(i16::from(COMPASS_FRAME_COUNT));
+            push_candidate(&mut out, format!("items/compass_{frame:02}.png"));
+        }
+        347 => {
+            let frame = damage.rem_euclid(i16::from(CLOCK_FRAME_COUNT));
+            push_candidate(&mut out, format!("items/clock_{frame:02}.png"));
+        }
         // Flowers (red flower has meta variants in 1.8)
         38 => {
             let flower = match damage {
@@ -453,4 +463,14 @@ mod tests {
         );
         assert!(splash.iter().any(|c| c == "items/potion.png"));
     }
+
+    #[test]
+    fn compass_candidates_use_frame_then_strip() {
+        let compass = item_texture_candidates(345, 7);
+        assert_eq!(
+            compass.first().map(String::as_str),
+            Some("items/compass_07.png")
+        );
+        assert!(compass.iter().any(|c| c == "items/compass.png"));
+    }
 }
@@ -1,40 +1,119 @@
+pub mod anvil;
 pub mod app;
 pub mod assets;
+pub mod beacon;
+pub mod books;
 pub mod chat;
+pub mod combat;
+pub mod crafting;
+pub mod creative;
+pub mod enchanting;
 pub mod entities;
+pub mod entity_metadata;
+pub mod horse;
 pub mod inventory;
+pub mod item_animation;
 pub mod item_textures;
+mod java_random;
+pub mod lightning;
 pub mod net_messages;
+pub mod paintings;
 pub mod registry;
 pub mod scoreboard;
+pub mod sleep;
 pub mod sound;
+pub mod stats;
+pub mod trading;
+pub mod windows;
 pub mod world;
 
-pub use app::{AppState, ApplicationState, UiState};
+pub use anvil::{
+    ANVIL_LEFT_SLOT, ANVIL_MAX_NAME_LENGTH, ANVIL_OUTPUT_SLOT, ANVIL_PROPERTY_COST,
+    ANVIL_RIGHT_SLOT, AnvilCost, anvil_cost, anvil_display_name, anvil_rename_payload,
+};
+pub use app::{
+    AppState, ApplicationState, SPECTATOR_MENU_PAGE_SIZE, SpectatorMenuEntry, SpectatorMenuState,
+    UiState,
+};
 pub use assets::{
     RUSTSTONE_ASSETS_ROOT_ENV, ruststone_assets_root, sound_cache_minecraft_root,
     sound_cache_root, texturepack_minecraft_root, texturepack_textures_root,
 };
-pub use chat::{Chat, TitleMessage};
+pub use beacon::{
+    BEACON_PAYMENT_SLOT, BEACON_PRIMARY_TIERS, BeaconState, beacon_power_name, is_beacon_payment,
+};
+pub use books::{
+    BOOK_MAX_PAGES, BOOK_PAGE_MAX_CHARS, BOOK_TITLE_MAX_CHARS, BookScreen, InventoryBookMeta,
+    WRITABLE_BOOK_ITEM_ID, WRITTEN_BOOK_ITEM_ID,
+};
+pub use chat::{Chat, TitleMessage, component_to_legacy};
+pub use combat::{
+    CombatEventMessage, CombatState, CombatTimelineEntry, CombatTimelineEvent, DeathInfo,
+    ServerDifficultyState,
+};
+pub use crafting::{CRAFTING_RESULT_SLOT, CraftingLayout, predict_crafting_result};
+pub use creative::{
+    CREATIVE_DROP_SLOT, CreativeTab, PickBlock, apply_creative_tab_click, creative_full_stack,
+    creative_search, pick_block_item,
+};
+pub use enchanting::{
+    ENCHANT_ITEM_SLOT, ENCHANT_LAPIS_SLOT, ENCHANT_PROPERTY_SEED, EnchantOption, LAPIS_DAMAGE,
+    LAPIS_ITEM_ID, enchant_glyph_lines, enchant_options,
+};
 pub use entities::{
     MobKind, NetEntityAnimation, NetEntityKind, NetEntityMessage, ObjectKind, PlayerSkinModel,
 };
+pub use entity_metadata::{
+    ArmorStandPose, EntityMetadata, EntityMetadataValue, HORSE_TYPE_DONKEY, HORSE_TYPE_HORSE,
+    HORSE_TYPE_MULE, HORSE_TYPE_SKELETON, HORSE_TYPE_ZOMBIE, HorseMetadata, MobMetadata,
+};
+pub use horse::{
+    HORSE_ARMOR_SLOT, HORSE_CHEST_COLUMNS, HORSE_CHEST_FIRST_SLOT, HORSE_SADDLE_SLOT,
+    horse_can_wear_armor, horse_has_chest,
+};
 pub use inventory::{
     InventoryEnchantment, InventoryItemMeta, InventoryItemStack, InventoryMessage, InventoryState,
-    InventoryWindowInfo, item_max_durability,
+    InventoryWindowInfo, RawItemTag, item_max_durability,
+};
+pub use item_animation::{
+    CLOCK_ITEM_ID, COMPASS_ITEM_ID, ItemAnimationState, WorldSpawnState,
+    animated_item_frame_count, celestial_angle, compass_target_angle, item_strip_frame,
 };
 pub use item_textures::item_texture_candidates;
+pub use lightning::{
+    BoltSegment, LIGHTNING_GLOBAL_ENTITY_TYPE, LIGHTNING_IMPACT_SOUND_EVENT, LightningBolt,
+    LightningState, THUNDER_SOUND_EVENT, lightning_bolt_segments,
+};
 pub use net_messages::{AuthMode, EntityUseAction, FromNet, FromNetMessage, ToNet, ToNetMessage};
+pub use paintings::{
+    PAINTING_ARTS, PaintingArt, item_frame_center, item_frame_half_extents, painting_art,
+    painting_center, painting_facing_normal, painting_half_extents,
+};
 pub use registry::{
     BlockFace, BlockModelKind, TEXTUREPACK_BLOCKS_BASE, TEXTUREPACK_ITEMS_BASE, block_model_kind,
     block_name, block_registry_key, block_state_id, block_state_meta, block_texture_name,
     item_name, item_registry_key,
 };
 pub use scoreboard::{ScoreboardMessage, ScoreboardObjectiveState, ScoreboardState, ScoreboardTeamState};
+pub use sleep::{
+    BED_BLOCK_ID, BED_SLEEP_Y, PLAYER_ACTION_LEAVE_BED, PLAYER_SLEEP_EYE_HEIGHT, SleepState,
+    bed_direction, bed_head_position,
+};
 pub use sound::{SoundCategory, SoundEvent, SoundEventQueue, SoundSettings, SoundStopScope};
+pub use stats::{
+    AchievementRow, BlockStatRow, GeneralStatRow, ItemStatRow, MobStatRow, StatKey,
+    StatisticsState,
+};
+pub use trading::{
+    TRADE_FIRST_INPUT_SLOT, TRADE_OUTPUT_SLOT, TRADE_SECOND_INPUT_SLOT, TradeOffer, page_trade,
+};
+pub use windows::{
+    BrewingProgress, FurnaceProgress, WindowKind, brewing_progress, furnace_progress,
+};
 pub use world::{
-    BlockUpdate, BreakIndicator, ChunkData, ChunkSection, PerfTimings, PlayerPosition,
-    PlayerStatus, TabListHeaderFooter, TitleOverlayState, TitleTimes, WorldTime,
+    BlockBreakAnimation, BlockBreakStages, BlockUpdate, BreakIndicator, ChunkData, ChunkSection,
+    PerfTimings, PlayerPosition, PlayerStatus, TabListHeaderFooter, TitleOverlayState, TitleTimes,
+    WorldTime,
 };
 
 #[cfg(test)]
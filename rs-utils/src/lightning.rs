@@ -0,0 +1,259 @@
+use std::collections::VecDeque;
+
+use bevy::ecs::resource::Resource;
+use bevy::prelude::Vec3;
+
+use crate::java_random::JavaRandom;
+use crate::sound::{SoundCategory, SoundEvent};
+
+/// `SpawnGlobalEntity` type for lightning, the only global entity in 1.8.
+pub const LIGHTNING_GLOBAL_ENTITY_TYPE: u8 = 1;
+pub const THUNDER_SOUND_EVENT: &str = "minecraft:ambient.weather.thunder";
+pub const LIGHTNING_IMPACT_SOUND_EVENT: &str = "minecraft:random.explode";
+/// How long after a strike server copies of its sounds are still treated as duplicates.
+const STRIKE_SOUND_WINDOW_TICKS: u8 = 40;
+const STRIKE_SOUND_RADIUS: f32 = 4.0;
+
+/// Client-side `EntityLightningBolt`: flickers a few times, re-rolling its shape each flash.
+#[derive(Debug, Clone)]
+pub struct LightningBolt {
+    pub entity_id: i32,
+    pub position: Vec3,
+    /// Seed for [`lightning_bolt_segments`]; changes on every re-flash.
+    pub shape_seed: i64,
+    state: i32,
+    flashes_left: i32,
+    rng: JavaRandom,
+}
+
+impl LightningBolt {
+    pub fn new(entity_id: i32, position: Vec3) -> Self {
+        let seed = i64::from(entity_id)
+            ^ (i64::from(position.x.to_bits()) << 32)
+            ^ (i64::from(position.z.to_bits()) << 16)
+            ^ i64::from(position.y.to_bits());
+        let mut rng = JavaRandom::new(seed);
+        let shape_seed = rng.next_long();
+        let flashes_left = rng.next_int(3) + 1;
+        Self {
+            entity_id,
+            position,
+            shape_seed,
+            state: 2,
+            flashes_left,
+            rng,
+        }
+    }
+
+    /// Whether the bolt currently lights up the sky.
+    pub fn is_lit(&self) -> bool {
+        self.state >= 0
+    }
+
+    /// One game tick; returns false once the bolt is gone.
+    fn tick(&mut self) -> bool {
+        self.state -= 1;
+        if self.state < 0 {
+            if self.flashes_left == 0 {
+                return false;
+            }
+            if self.state < -self.rng.next_int(10) {
+                self.flashes_left -= 1;
+                self.state = 1;
+                self.shape_seed = self.rng.next_long();
+            }
+        }
+        true
+    }
+}
+
+/// Active bolts plus the world's `lastLightningBolt` counter that drives the sky flash.
+#[derive(Resource, Debug, Default)]
+pub struct LightningState {
+    pub bolts: Vec<LightningBolt>,
+    flash_ticks: u8,
+    recent_strikes: VecDeque<(Vec3, u8)>,
+}
+
+impl LightningState {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Starts a bolt and returns its thunder and impact sounds, delayed by distance.
+    pub fn strike(&mut self, entity_id: i32, position: Vec3) -> [SoundEvent; 2] {
+        self.bolts.retain(|bolt| bolt.entity_id != entity_id);
+        let mut bolt = LightningBolt::new(entity_id, position);
+        let sound = |event_id: &str, volume: f32, pitch: f32, category| SoundEvent::World {
+            event_id: event_id.to_string(),
+            position,
+            volume,
+            pitch,
+            category_override: Some(category),
+            distance_delay: true,
+        };
+        let sounds = [
+            sound(
+                THUNDER_SOUND_EVENT,
+                10_000.0,
+                0.8 + bolt.rng.next_float() * 0.2,
+                SoundCategory::Weather,
+            ),
+            sound(
+                LIGHTNING_IMPACT_SOUND_EVENT,
+                2.0,
+                0.5 + bolt.rng.next_float() * 0.2,
+                SoundCategory::Weather,
+            ),
+        ];
+        self.bolts.push(bolt);
+        self.recent_strikes
+            .push_back((position, STRIKE_SOUND_WINDOW_TICKS));
+        self.flash_ticks = 2;
+        sounds
+    }
+
+    pub fn tick(&mut self) {
+        self.flash_ticks = self.flash_ticks.saturating_sub(1);
+        self.bolts.retain_mut(LightningBolt::tick);
+        if self.bolts.iter().any(LightningBolt::is_lit) {
+            self.flash_ticks = 2;
+        }
+        for (_, ticks) in &mut self.recent_strikes {
+            *ticks = ticks.saturating_sub(1);
+        }
+        while self
+            .recent_strikes
+            .front()
+            .is_some_and(|(_, ticks)| *ticks == 0)
+        {
+            self.recent_strikes.pop_front();
+        }
+    }
+
+    /// Sky brightening in `0..=1`; vanilla holds it for two ticks after the last lit frame.
+    pub fn flash(&self) -> f32 {
+        f32::from(self.flash_ticks.min(2)) / 2.0
+    }
+
+    /// True for the server's own thunder/impact sounds of a bolt the client already played.
+    pub fn is_strike_sound(&self, event_id: &str, position: Vec3) -> bool {
+        let unprefixed = |id: &'static str| id.strip_prefix("minecraft:").unwrap_or(id);
+        let name = event_id.strip_prefix("minecraft:").unwrap_or(event_id);
+        let is_bolt_sound = name == unprefixed(THUNDER_SOUND_EVENT)
+            || name == unprefixed(LIGHTNING_IMPACT_SOUND_EVENT);
+        is_bolt_sound
+            && self.recent_strikes.iter().any(|(strike, _)| {
+                strike.distance_squared(position) <= STRIKE_SOUND_RADIUS * STRIKE_SOUND_RADIUS
+            })
+    }
+}
+
+/// One square tube section of a bolt, relative to the strike position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoltSegment {
+    /// 0 is the thin core; each of the four layers is wider and drawn on top.
+    pub layer: u8,
+    pub bottom: Vec3,
+    pub top: Vec3,
+    pub bottom_half_width: f32,
+    pub top_half_width: f32,
+}
+
+/// Bolt geometry as `RenderLightningBolt` builds it: a 128-block trunk of 16-block sections
+/// plus two shorter branches, each repeated in four widening layers.
+pub fn lightning_bolt_segments(shape_seed: i64) -> Vec<BoltSegment> {
+    let mut trunk_x = [0.0f64; 8];
+    let mut trunk_z = [0.0f64; 8];
+    let (mut end_x, mut end_z) = (0.0f64, 0.0f64);
+    let mut rng = JavaRandom::new(shape_seed);
+    for i in (0..8).rev() {
+        trunk_x[i] = end_x;
+        trunk_z[i] = end_z;
+        end_x += f64::from(rng.next_int(11) - 5);
+        end_z += f64::from(rng.next_int(11) - 5);
+    }
+
+    let mut segments = Vec::new();
+    for layer in 0..4u8 {
+        let mut rng = JavaRandom::new(shape_seed);
+        for branch in 0..3 {
+            let top_index = if branch > 0 { 7 - branch } else { 7 };
+            let bottom_index = if branch > 0 { top_index - 2 } else { 0 };
+            let mut x = trunk_x[top_index] - end_x;
+            let mut z = trunk_z[top_index] - end_z;
+            for i in (bottom_index..=top_index).rev() {
+                let (upper_x, upper_z) = (x, z);
+                if branch == 0 {
+                    x += f64::from(rng.next_int(11) - 5);
+                    z += f64::from(rng.next_int(11) - 5);
+                } else {
+                    x += f64::from(rng.next_int(31) - 15);
+                    z += f64::from(rng.next_int(31) - 15);
+                }
+                let width = 0.1 + f32::from(layer) * 0.2;
+                let (top_half_width, bottom_half_width) = if branch == 0 {
+                    (
+                        width * (i as f32 * 0.1 + 1.0),
+                        width * ((i as f32 - 1.0) * 0.1 + 1.0),
+                    )
+                } else {
+                    (width, width)
+                };
+                segments.push(BoltSegment {
+                    layer,
+                    bottom: Vec3::new(0.5 + x as f32, i as f32 * 16.0, 0.5 + z as f32),
+                    top: Vec3::new(
+                        0.5 + upper_x as f32,
+                        (i + 1) as f32 * 16.0,
+                        0.5 + upper_z as f32,
+                    ),
+                    bottom_half_width,
+                    top_half_width,
+                });
+            }
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lightning_bolts_flicker_out_and_keep_vanilla_shape() {
+        let mut lightning = LightningState::default();
+        let [thunder, impact] = lightning.strike(7, Vec3::new(10.0, 64.0, -3.0));
+        assert!(matches!(
+            thunder,
+            SoundEvent::World { ref event_id, distance_delay: true, .. } if event_id == THUNDER_SOUND_EVENT
+        ));
+        assert!(matches!(
+            impact,
+            SoundEvent::World { pitch, .. } if (0.5..0.7).contains(&pitch)
+        ));
+        assert_eq!(lightning.flash(), 1.0);
+        assert!(lightning.is_strike_sound("ambient.weather.thunder", Vec3::new(10.0, 64.0, -3.0)));
+        assert!(!lightning.is_strike_sound("random.click", Vec3::new(10.0, 64.0, -3.0)));
+        assert!(!lightning.is_strike_sound(THUNDER_SOUND_EVENT, Vec3::new(90.0, 64.0, -3.0)));
+
+        // At most three re-flashes of up to ten dark ticks each, then the sky settles.
+        for _ in 0..60 {
+            lightning.tick();
+        }
+        assert!(lightning.bolts.is_empty());
+        assert_eq!(lightning.flash(), 0.0);
+
+        let segments = lightning_bolt_segments(0x1234_5678);
+        assert_eq!(segments, lightning_bolt_segments(0x1234_5678));
+        // Trunk of eight sections plus two three-section branches, in four layers.
+        assert_eq!(segments.len(), 4 * (8 + 3 + 3));
+        let trunk: Vec<_> = segments.iter().take(8).collect();
+        assert_eq!(trunk[0].top.y, 128.0);
+        // The trunk's random walk replays the one that placed its top, so it lands on the strike.
+        assert_eq!(trunk[7].bottom, Vec3::new(0.5, 0.0, 0.5));
+        assert!(segments.iter().all(|s| s.top.y - s.bottom.y == 16.0));
+        assert!(segments[8 + 3 + 3..].iter().all(|s| s.layer >= 1));
+    }
+}
@@ -1,13 +1,16 @@
 use bevy::ecs::resource::Resource;
+use bevy::prelude::Vec3;
 use crossbeam::channel::{Receiver, Sender};
+use rs_protocol::protocol::UUID;
 use rs_protocol::protocol::packet::Packet;
 
 use crate::chat::TitleMessage;
+use crate::combat::CombatEventMessage;
 use crate::entities::NetEntityMessage;
 use crate::inventory::{InventoryItemStack, InventoryMessage};
 use crate::scoreboard::ScoreboardMessage;
 use crate::sound::SoundEvent;
-use crate::world::{BlockUpdate, ChestAction, ChunkData, PlayerPosition};
+use crate::world::{BlockBreakAnimation, BlockUpdate, ChestAction, ChunkData, PlayerPosition};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AuthMode {
@@ -71,6 +74,16 @@ pub enum ToNetMessage {
         entity_id: i32,
         action_id: i8,
     },
+    SteerVehicle {
+        sideways: f32,
+        forward: f32,
+        jump: bool,
+        unmount: bool,
+    },
+    SpectateTeleport {
+        target: UUID,
+    },
+    RequestStats,
     ClientAbilities {
         flags: u8,
         flying_speed: f32,
@@ -100,6 +113,36 @@ pub enum ToNetMessage {
     CloseWindow {
         id: u8,
     },
+    EnchantItem {
+        id: u8,
+        enchantment: u8,
+    },
+    /// New name for the item in the open anvil (`MC|ItemName`).
+    RenameItem {
+        name: String,
+    },
+    /// A creative player setting a slot of their own inventory, or dropping a stack on slot -1.
+    CreativeInventoryAction {
+        slot: i16,
+        item: Option<InventoryItemStack>,
+    },
+    /// Villager offer picked with the trading screen's paging buttons (`MC|TrSel`).
+    SelectTrade {
+        index: i32,
+    },
+    /// Powers picked in the open beacon window (`MC|Beacon`).
+    ConfirmBeacon {
+        primary: i32,
+        secondary: i32,
+    },
+    /// Pages of the held book and quill (`MC|BEdit`).
+    EditBook {
+        book: InventoryItemStack,
+    },
+    /// The held book and quill signed into a written book (`MC|BSign`).
+    SignBook {
+        book: InventoryItemStack,
+    },
     DigStart {
         x: i32,
         y: i32,
@@ -150,6 +193,7 @@ pub enum FromNetMessage {
     },
     BlockUpdates(Vec<BlockUpdate>),
     ChestAction(ChestAction),
+    BlockBreakAnimation(BlockBreakAnimation),
     PlayerPosition(PlayerPosition),
     UpdateHealth {
         health: f32,
@@ -164,6 +208,31 @@ pub enum FromNetMessage {
     GameMode {
         gamemode: u8,
     },
+    /// Server moved the view onto another entity; the local entity id means "back to self".
+    Camera {
+        target_id: i32,
+    },
+    Statistics(Vec<(String, i32)>),
+    /// `MC|BOpen`: show the written book in hand.
+    OpenBook,
+    CombatEvent(CombatEventMessage),
+    ServerDifficulty {
+        difficulty: u8,
+        locked: bool,
+    },
+    Dimension {
+        dimension: i32,
+    },
+    /// Lightning from `SpawnGlobalEntity`.
+    LightningStrike {
+        entity_id: i32,
+        position: Vec3,
+    },
+    SpawnPosition {
+        x: i32,
+        y: i32,
+        z: i32,
+    },
     TimeUpdate {
         world_age: i64,
         time_of_day: i64,
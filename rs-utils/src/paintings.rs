@@ -0,0 +1,156 @@
+use bevy::prelude::Vec3;
+
+/// One motif on `painting/paintings_kristoffer_zetterstrand.png` (vanilla `EntityPainting.EnumArt`).
+/// Sizes and atlas offsets are in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaintingArt {
+    pub title: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub u: u32,
+    pub v: u32,
+}
+
+const fn art(title: &'static str, width: u32, height: u32, u: u32, v: u32) -> PaintingArt {
+    PaintingArt {
+        title,
+        width,
+        height,
+        u,
+        v,
+    }
+}
+
+/// In registry order, so 1.13+ numeric motive ids index straight into it.
+pub const PAINTING_ARTS: [PaintingArt; 26] = [
+    art("Kebab", 16, 16, 0, 0),
+    art("Aztec", 16, 16, 16, 0),
+    art("Alban", 16, 16, 32, 0),
+    art("Aztec2", 16, 16, 48, 0),
+    art("Bomb", 16, 16, 64, 0),
+    art("Plant", 16, 16, 80, 0),
+    art("Wasteland", 16, 16, 96, 0),
+    art("Pool", 32, 16, 0, 32),
+    art("Courbet", 32, 16, 32, 32),
+    art("Sea", 32, 16, 64, 32),
+    art("Sunset", 32, 16, 96, 32),
+    art("Creebet", 32, 16, 128, 32),
+    art("Wanderer", 16, 32, 0, 64),
+    art("Graham", 16, 32, 16, 64),
+    art("Match", 32, 32, 0, 128),
+    art("Bust", 32, 32, 32, 128),
+    art("Stage", 32, 32, 64, 128),
+    art("Void", 32, 32, 96, 128),
+    art("SkullAndRoses", 32, 32, 128, 128),
+    art("Wither", 32, 32, 160, 128),
+    art("Fighters", 64, 32, 0, 96),
+    art("Pointer", 64, 64, 0, 192),
+    art("Pigscene", 64, 64, 64, 192),
+    art("BurningSkull", 64, 64, 128, 192),
+    art("Skeleton", 64, 48, 192, 64),
+    art("DonkeyKong", 64, 48, 192, 112),
+];
+
+/// Looks a motif up by its 1.8 title; also accepts 1.13+ `minecraft:snake_case` names.
+pub fn painting_art(title: &str) -> Option<PaintingArt> {
+    let name = title.strip_prefix("minecraft:").unwrap_or(title);
+    PAINTING_ARTS.iter().copied().find(|art| {
+        art.title.eq_ignore_ascii_case(name)
+            || art.title.eq_ignore_ascii_case(&name.replace('_', ""))
+    })
+}
+
+/// Unit vector out of the wall for the protocol's horizontal facing index
+/// (0 = south, 1 = west, 2 = north, 3 = east).
+pub fn painting_facing_normal(facing: u8) -> Vec3 {
+    match facing & 3 {
+        0 => Vec3::Z,
+        1 => Vec3::NEG_X,
+        2 => Vec3::NEG_Z,
+        _ => Vec3::X,
+    }
+}
+
+/// `EntityItemFrame` is 12 pixels square.
+const ITEM_FRAME_PIXELS: u32 = 12;
+
+/// Center of a painting hung in block `location`, as `EntityHanging#updateBoundingBox` places
+/// it: pushed back against the wall and shifted half a block for even sizes.
+pub fn painting_center(art: &PaintingArt, location: (i32, i32, i32), facing: u8) -> Vec3 {
+    hanging_center(art.width, art.height, location, facing)
+}
+
+/// Half extents of the painting's bounding box; one pixel deep.
+pub fn painting_half_extents(art: &PaintingArt, facing: u8) -> Vec3 {
+    hanging_half_extents(art.width, art.height, facing)
+}
+
+/// Center of an item frame; the server spawns it at the block it hangs in.
+pub fn item_frame_center(location: (i32, i32, i32), facing: u8) -> Vec3 {
+    hanging_center(ITEM_FRAME_PIXELS, ITEM_FRAME_PIXELS, location, facing)
+}
+
+pub fn item_frame_half_extents(facing: u8) -> Vec3 {
+    hanging_half_extents(ITEM_FRAME_PIXELS, ITEM_FRAME_PIXELS, facing)
+}
+
+fn hanging_center(width: u32, height: u32, location: (i32, i32, i32), facing: u8) -> Vec3 {
+    let offset = |pixels: u32| if pixels.is_multiple_of(32) { 0.5 } else { 0.0 };
+    let normal = painting_facing_normal(facing);
+    // `EnumFacing#rotateYCCW` of the facing.
+    let along = Vec3::new(normal.z, 0.0, -normal.x);
+    Vec3::new(
+        location.0 as f32 + 0.5,
+        location.1 as f32 + 0.5 + offset(height),
+        location.2 as f32 + 0.5,
+    ) - normal * 0.46875
+        + along * offset(width)
+}
+
+fn hanging_half_extents(width: u32, height: u32, facing: u8) -> Vec3 {
+    let normal = painting_facing_normal(facing);
+    let width = width as f32 / 32.0;
+    let depth = 1.0 / 32.0;
+    if normal.z != 0.0 {
+        Vec3::new(width, height as f32 / 32.0, depth)
+    } else {
+        Vec3::new(depth, height as f32 / 32.0, width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paintings_resolve_titles_and_hang_against_the_wall() {
+        assert_eq!(PAINTING_ARTS.len(), 26);
+        let fighters = painting_art("Fighters").expect("fighters");
+        assert_eq!((fighters.width, fighters.height), (64, 32));
+        assert_eq!(
+            painting_art("minecraft:donkey_kong").map(|a| a.title),
+            Some("DonkeyKong")
+        );
+        assert!(painting_art("Mona Lisa").is_none());
+
+        // Facing north (2): pushed toward +Z onto the wall, even sizes shift half a block
+        // west (rotateYCCW of north) and up.
+        let center = painting_center(&fighters, (10, 64, -5), 2);
+        assert!((center - Vec3::new(10.0, 65.0, -4.03125)).length() < 1e-5);
+        let kebab = painting_art("Kebab").expect("kebab");
+        let center = painting_center(&kebab, (0, 70, 0), 3);
+        assert!((center - Vec3::new(0.03125, 70.5, 0.5)).length() < 1e-5);
+        assert_eq!(
+            painting_half_extents(&fighters, 2),
+            Vec3::new(2.0, 1.0, 1.0 / 32.0)
+        );
+
+        // Item frames hang like a 12px painting: centered in the block face, never shifted.
+        let center = item_frame_center((4, 65, 8), 1);
+        assert!((center - Vec3::new(4.96875, 65.5, 8.5)).length() < 1e-5);
+        assert_eq!(
+            item_frame_half_extents(1),
+            Vec3::new(1.0 / 32.0, 0.375, 0.375)
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::ecs::resource::Resource;
+use bevy::prelude::Vec3;
+
+/// Vanilla `EntityPlayer#sleepTimer` caps at this while asleep, then counts on to
+/// [`SLEEP_FADE_OUT_END`] after waking.
+const SLEEP_FADE_FULL: u32 = 100;
+const SLEEP_FADE_OUT_END: u32 = 110;
+pub const BED_BLOCK_ID: u16 = 26;
+/// Height of a lying player above the bed block, from `EntityPlayer#trySleep`.
+pub const BED_SLEEP_Y: f32 = 0.6875;
+pub const PLAYER_SLEEP_EYE_HEIGHT: f32 = 0.2;
+/// `PlayerAction` id for leaving a bed.
+pub const PLAYER_ACTION_LEAVE_BED: i8 = 2;
+
+/// Bed the local player is sleeping in, from `EntityUsedBed`.
+#[derive(Debug, Default, Resource, Clone, Copy)]
+pub struct SleepState {
+    pub bed: Option<(i32, i32, i32)>,
+    /// Horizontal facing index of the bed's head (0 = south, 1 = west, 2 = north, 3 = east).
+    pub facing: u8,
+    /// The local player's entity id, echoed back in the leave-bed `PlayerAction`.
+    pub entity_id: i32,
+    timer: u32,
+}
+
+impl SleepState {
+    pub fn is_sleeping(&self) -> bool {
+        self.bed.is_some()
+    }
+
+    pub fn enter(&mut self, entity_id: i32, bed: (i32, i32, i32), facing: u8) {
+        self.bed = Some(bed);
+        self.facing = facing & 3;
+        self.entity_id = entity_id;
+        self.timer = 0;
+    }
+
+    /// Leaving the bed fades the screen back in instead of cutting.
+    pub fn wake(&mut self) {
+        if self.bed.take().is_some() {
+            self.timer = SLEEP_FADE_FULL;
+        }
+    }
+
+    pub fn tick(&mut self) {
+        if self.bed.is_some() {
+            self.timer = (self.timer + 1).min(SLEEP_FADE_FULL);
+        } else if self.timer > 0 {
+            self.timer += 1;
+            if self.timer >= SLEEP_FADE_OUT_END {
+                self.timer = 0;
+            }
+        }
+    }
+
+    /// Opacity of the dark sleep overlay, as `GuiIngame` draws it (at most 220/255).
+    pub fn fade_alpha(&self) -> f32 {
+        let progress = if self.timer > SLEEP_FADE_FULL {
+            1.0 - (self.timer - SLEEP_FADE_FULL) as f32
+                / (SLEEP_FADE_OUT_END - SLEEP_FADE_FULL) as f32
+        } else {
+            self.timer as f32 / SLEEP_FADE_FULL as f32
+        };
+        progress * 220.0 / 255.0
+    }
+
+    pub fn head_position(&self) -> Option<Vec3> {
+        self.bed.map(|bed| bed_head_position(bed, self.facing))
+    }
+}
+
+/// Unit vector from the foot of a bed towards its head.
+pub fn bed_direction(facing: u8) -> Vec3 {
+    match facing & 3 {
+        0 => Vec3::Z,
+        1 => Vec3::NEG_X,
+        2 => Vec3::NEG_Z,
+        _ => Vec3::X,
+    }
+}
+
+/// Where `EntityPlayer#trySleep` puts a sleeper: on the pillow, 0.1 blocks from the headboard.
+pub fn bed_head_position(bed: (i32, i32, i32), facing: u8) -> Vec3 {
+    Vec3::new(
+        bed.0 as f32 + 0.5,
+        bed.1 as f32 + BED_SLEEP_Y,
+        bed.2 as f32 + 0.5,
+    ) + bed_direction(facing) * 0.4
+}
@@ -0,0 +1,449 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bevy::ecs::resource::Resource;
+
+use crate::registry::{block_name, block_registry_key, item_name, item_registry_key};
+
+/// Highest item id in the 1.8 registry (`record_wait`).
+const MAX_ITEM_ID: i32 = 2267;
+const MAX_BLOCK_ID: u16 = 255;
+
+type StatKeyCtor<'a> = fn(&'a str) -> StatKey<'a>;
+
+/// A statistic name from the `Statistics` packet, split by its vanilla translation key prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatKey<'a> {
+    General(&'a str),
+    MineBlock(&'a str),
+    CraftItem(&'a str),
+    UseItem(&'a str),
+    BreakItem(&'a str),
+    KillEntity(&'a str),
+    EntityKilledBy(&'a str),
+    Achievement(&'a str),
+}
+
+impl<'a> StatKey<'a> {
+    pub fn parse(name: &'a str) -> Self {
+        let prefixes: [(&str, StatKeyCtor<'a>); 6] = [
+            ("stat.mineBlock.", StatKey::MineBlock),
+            ("stat.craftItem.", StatKey::CraftItem),
+            ("stat.useItem.", StatKey::UseItem),
+            ("stat.breakItem.", StatKey::BreakItem),
+            ("stat.killEntity.", StatKey::KillEntity),
+            ("stat.entityKilledBy.", StatKey::EntityKilledBy),
+        ];
+        for (prefix, kind) in prefixes {
+            if let Some(rest) = name.strip_prefix(prefix) {
+                // 1.8 writes `minecraft.stone`; older servers sent the numeric id.
+                return kind(rest.strip_prefix("minecraft.").unwrap_or(rest));
+            }
+        }
+        if let Some(rest) = name.strip_prefix("achievement.") {
+            return StatKey::Achievement(rest);
+        }
+        StatKey::General(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneralStatRow {
+    pub key: String,
+    pub label: String,
+    pub value: i32,
+    pub display: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockStatRow {
+    pub block_id: u16,
+    pub name: String,
+    pub crafted: i32,
+    pub placed: i32,
+    pub mined: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ItemStatRow {
+    pub item_id: i32,
+    pub name: String,
+    pub crafted: i32,
+    pub used: i32,
+    pub broken: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MobStatRow {
+    pub entity: String,
+    pub name: String,
+    pub kills: i32,
+    pub killed_by: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AchievementRow {
+    pub key: String,
+    pub name: String,
+    pub unlocked: bool,
+}
+
+/// Values from the last `Statistics` packet, answered after a `ClientStatus` stats request.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct StatisticsState {
+    pub values: BTreeMap<String, i32>,
+    /// Bumped on every received packet so screens can tell a fresh answer from a stale one.
+    pub revision: u32,
+}
+
+impl StatisticsState {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn apply(&mut self, entries: Vec<(String, i32)>) {
+        self.values.extend(entries);
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    pub fn general_rows(&self) -> Vec<GeneralStatRow> {
+        self.values
+            .iter()
+            .filter_map(|(key, &value)| match StatKey::parse(key) {
+                StatKey::General(key) => Some(GeneralStatRow {
+                    key: key.to_string(),
+                    label: general_stat_label(key),
+                    value,
+                    display: format_general_stat(key, value),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn block_rows(&self) -> Vec<BlockStatRow> {
+        let mut rows: HashMap<u16, BlockStatRow> = HashMap::new();
+        for (key, &value) in &self.values {
+            let (id, field): (_, fn(&mut BlockStatRow) -> &mut i32) = match StatKey::parse(key) {
+                StatKey::MineBlock(id) => (id, |row| &mut row.mined),
+                StatKey::UseItem(id) => (id, |row| &mut row.placed),
+                StatKey::CraftItem(id) => (id, |row| &mut row.crafted),
+                _ => continue,
+            };
+            let Some(block_id) = block_id_for_stat(id) else {
+                continue;
+            };
+            let row = rows.entry(block_id).or_insert_with(|| BlockStatRow {
+                block_id,
+                name: block_name(block_id).to_string(),
+                ..Default::default()
+            });
+            *field(row) += value;
+        }
+        rows.into_values().collect()
+    }
+
+    pub fn item_rows(&self) -> Vec<ItemStatRow> {
+        let mut rows: HashMap<i32, ItemStatRow> = HashMap::new();
+        for (key, &value) in &self.values {
+            let (id, field): (_, fn(&mut ItemStatRow) -> &mut i32) = match StatKey::parse(key) {
+                StatKey::UseItem(id) => (id, |row| &mut row.used),
+                StatKey::BreakItem(id) => (id, |row| &mut row.broken),
+                StatKey::CraftItem(id) => (id, |row| &mut row.crafted),
+                _ => continue,
+            };
+            let Some(item_id) = item_id_for_stat(id) else {
+                continue;
+            };
+            let row = rows.entry(item_id).or_insert_with(|| ItemStatRow {
+                item_id,
+                name: item_name(item_id).to_string(),
+                ..Default::default()
+            });
+            *field(row) += value;
+        }
+        rows.into_values().collect()
+    }
+
+    pub fn mob_rows(&self) -> Vec<MobStatRow> {
+        let mut rows: HashMap<&str, MobStatRow> = HashMap::new();
+        for (key, &value) in &self.values {
+            let (entity, killed_by) = match StatKey::parse(key) {
+                StatKey::KillEntity(entity) => (entity, false),
+                StatKey::EntityKilledBy(entity) => (entity, true),
+                _ => continue,
+            };
+            let row = rows.entry(entity).or_insert_with(|| MobStatRow {
+                entity: entity.to_string(),
+                name: mob_stat_label(entity),
+                ..Default::default()
+            });
+            if killed_by {
+                row.killed_by += value;
+            } else {
+                row.kills += value;
+            }
+        }
+        rows.into_values().collect()
+    }
+
+    pub fn achievement_rows(&self) -> Vec<AchievementRow> {
+        self.values
+            .iter()
+            .filter_map(|(key, &value)| match StatKey::parse(key) {
+                StatKey::Achievement(key) => Some(AchievementRow {
+                    key: key.to_string(),
+                    name: achievement_label(key),
+                    unlocked: value > 0,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Blocks only count when the key names a placeable block (item ids below 256).
+fn block_id_for_stat(id: &str) -> Option<u16> {
+    if let Ok(numeric) = id.parse::<u16>() {
+        return (1..=MAX_BLOCK_ID).contains(&numeric).then_some(numeric);
+    }
+    (1..=MAX_BLOCK_ID).find(|&block_id| block_registry_key(block_id) == Some(id))
+}
+
+/// Items are everything from id 256 up; block items are listed in the block table instead.
+fn item_id_for_stat(id: &str) -> Option<i32> {
+    if let Ok(numeric) = id.parse::<i32>() {
+        return (numeric > i32::from(MAX_BLOCK_ID)).then_some(numeric);
+    }
+    (i32::from(MAX_BLOCK_ID) + 1..=MAX_ITEM_ID)
+        .find(|&item_id| item_registry_key(item_id) == Some(id))
+}
+
+/// English text for the vanilla `stat.*` translation keys.
+pub fn general_stat_label(key: &str) -> String {
+    let label = match key {
+        "stat.leaveGame" => "Games Quit",
+        "stat.playOneMinute" => "Time Played",
+        "stat.timeSinceDeath" => "Since Last Death",
+        "stat.walkOneCm" => "Distance Walked",
+        "stat.crouchOneCm" => "Distance Crouched",
+        "stat.sprintOneCm" => "Distance Sprinted",
+        "stat.swimOneCm" => "Distance Swum",
+        "stat.fallOneCm" => "Distance Fallen",
+        "stat.climbOneCm" => "Distance Climbed",
+        "stat.flyOneCm" => "Distance Flown",
+        "stat.diveOneCm" => "Distance Dove",
+        "stat.minecartOneCm" => "Distance by Minecart",
+        "stat.boatOneCm" => "Distance by Boat",
+        "stat.pigOneCm" => "Distance by Pig",
+        "stat.horseOneCm" => "Distance by Horse",
+        "stat.jump" => "Jumps",
+        "stat.drop" => "Items Dropped",
+        "stat.damageDealt" => "Damage Dealt",
+        "stat.damageTaken" => "Damage Taken",
+        "stat.deaths" => "Number of Deaths",
+        "stat.mobKills" => "Mob Kills",
+        "stat.animalsBred" => "Animals Bred",
+        "stat.playerKills" => "Player Kills",
+        "stat.fishCaught" => "Fish Caught",
+        "stat.junkFished" => "Junk Fished",
+        "stat.treasureFished" => "Treasure Fished",
+        "stat.talkedToVillager" => "Talked to Villagers",
+        "stat.tradedWithVillager" => "Traded with Villagers",
+        "stat.cakeSlicesEaten" => "Cake Slices Eaten",
+        "stat.cauldronFilled" => "Cauldrons Filled",
+        "stat.cauldronUsed" => "Water Taken from Cauldron",
+        "stat.armorCleaned" => "Armor Pieces Cleaned",
+        "stat.bannerCleaned" => "Banners Cleaned",
+        "stat.craftingTableInteraction" => "Interactions with Crafting Table",
+        "stat.beaconInteraction" => "Interactions with Beacon",
+        "stat.dropperInspected" => "Droppers Searched",
+        "stat.hopperInspected" => "Hoppers Searched",
+        "stat.dispenserInspected" => "Dispensers Searched",
+        "stat.noteblockPlayed" => "Noteblocks Played",
+        "stat.noteblockTuned" => "Noteblocks Tuned",
+        "stat.flowerPotted" => "Plants Potted",
+        "stat.trappedChestTriggered" => "Trapped Chests Triggered",
+        "stat.enderchestOpened" => "Ender Chests Opened",
+        "stat.itemEnchanted" => "Items Enchanted",
+        "stat.recordPlayed" => "Records Played",
+        "stat.furnaceInteraction" => "Interactions with Furnace",
+        "stat.brewingstandInteraction" => "Interactions with Brewing Stand",
+        "stat.chestOpened" => "Chests Opened",
+        _ => return humanize_key(key.strip_prefix("stat.").unwrap_or(key)),
+    };
+    label.to_string()
+}
+
+/// Formats a general stat the way vanilla's `IStatType`s do (time, distance, damage, plain).
+pub fn format_general_stat(key: &str, value: i32) -> String {
+    if matches!(key, "stat.playOneMinute" | "stat.timeSinceDeath") {
+        return format_ticks(value);
+    }
+    if key.ends_with("OneCm") {
+        return format_distance_cm(value);
+    }
+    if matches!(key, "stat.damageDealt" | "stat.damageTaken") {
+        return format!("{:.1}", value as f32 * 0.1);
+    }
+    value.to_string()
+}
+
+fn format_ticks(ticks: i32) -> String {
+    let seconds = ticks as f64 / 20.0;
+    let minutes = seconds / 60.0;
+    let hours = minutes / 60.0;
+    let days = hours / 24.0;
+    let years = days / 365.0;
+    if years > 0.5 {
+        format!("{years:.2} y")
+    } else if days > 0.5 {
+        format!("{days:.2} d")
+    } else if hours > 0.5 {
+        format!("{hours:.2} h")
+    } else if minutes > 0.5 {
+        format!("{minutes:.2} m")
+    } else {
+        format!("{seconds:.2} s")
+    }
+}
+
+fn format_distance_cm(cm: i32) -> String {
+    let meters = cm as f64 / 100.0;
+    let kilometers = meters / 1000.0;
+    if kilometers > 0.5 {
+        format!("{kilometers:.2} km")
+    } else if meters > 0.5 {
+        format!("{meters:.2} m")
+    } else {
+        format!("{cm} cm")
+    }
+}
+
+/// English text for `entity.<Name>.name`, keyed by the 1.8 `EntityList` string id.
+pub fn mob_stat_label(entity: &str) -> String {
+    let label = match entity {
+        "PigZombie" => "Zombie Pigman",
+        "CaveSpider" => "Cave Spider",
+        "LavaSlime" => "Magma Cube",
+        "EnderDragon" => "Ender Dragon",
+        "WitherBoss" => "Wither",
+        "MushroomCow" => "Mooshroom",
+        "SnowMan" => "Snow Golem",
+        "Ozelot" => "Ocelot",
+        "VillagerGolem" => "Iron Golem",
+        "EntityHorse" => "Horse",
+        _ => return humanize_key(entity),
+    };
+    label.to_string()
+}
+
+/// English text for the vanilla `achievement.*` translation keys.
+pub fn achievement_label(key: &str) -> String {
+    let label = match key {
+        "openInventory" => "Taking Inventory",
+        "mineWood" => "Getting Wood",
+        "buildWorkBench" => "Benchmarking",
+        "buildPickaxe" => "Time to Mine!",
+        "buildFurnace" => "Hot Topic",
+        "acquireIron" => "Acquire Hardware",
+        "buildHoe" => "Time to Farm!",
+        "makeBread" => "Bake Bread",
+        "bakeCake" => "The Lie",
+        "buildBetterPickaxe" => "Getting an Upgrade",
+        "cookFish" => "Delicious Fish",
+        "onARail" => "On A Rail",
+        "buildSword" => "Time to Strike!",
+        "killEnemy" => "Monster Hunter",
+        "killCow" => "Cow Tipper",
+        "flyPig" => "When Pigs Fly",
+        "snipeSkeleton" => "Sniper Duel",
+        "diamonds" => "DIAMONDS!",
+        "diamondsToYou" => "Diamonds to you!",
+        "portal" => "We Need to Go Deeper",
+        "ghast" => "Return to Sender",
+        "blazeRod" => "Into Fire",
+        "potion" => "Local Brewery",
+        "theEnd" => "The End?",
+        "theEnd2" => "The End.",
+        "enchantments" => "Enchanter",
+        "overkill" => "Overkill",
+        "bookcase" => "Librarian",
+        "breedCow" => "Repopulation",
+        "spawnWither" => "The Beginning?",
+        "killWither" => "The Beginning.",
+        "fullBeacon" => "Beaconator",
+        "exploreAllBiomes" => "Adventuring Time",
+        "overpowered" => "Overpowered",
+        _ => return humanize_key(key),
+    };
+    label.to_string()
+}
+
+/// Fallback for keys without a translation: `trappedChest` / `Some_Name` -> `Trapped Chest`.
+fn humanize_key(key: &str) -> String {
+    let mut out = String::with_capacity(key.len() + 4);
+    let mut prev_lower = false;
+    for ch in key.chars() {
+        if ch == '_' || ch == '.' {
+            out.push(' ');
+            prev_lower = false;
+            continue;
+        }
+        if ch.is_ascii_uppercase() && prev_lower {
+            out.push(' ');
+        }
+        if out.is_empty() || out.ends_with(' ') {
+            out.extend(ch.to_uppercase());
+        } else {
+            out.push(ch);
+        }
+        prev_lower = ch.is_ascii_lowercase();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics_group_blocks_items_and_mobs() {
+        let mut stats = StatisticsState::default();
+        stats.apply(vec![
+            ("stat.mineBlock.minecraft.stone".to_string(), 12),
+            ("stat.useItem.minecraft.stone".to_string(), 3),
+            ("stat.useItem.minecraft.iron_pickaxe".to_string(), 40),
+            ("stat.breakItem.minecraft.iron_pickaxe".to_string(), 1),
+            ("stat.killEntity.PigZombie".to_string(), 5),
+            ("stat.entityKilledBy.PigZombie".to_string(), 2),
+            ("stat.walkOneCm".to_string(), 123_456),
+            ("achievement.openInventory".to_string(), 1),
+        ]);
+
+        let blocks = stats.block_rows();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(
+            (blocks[0].block_id, blocks[0].mined, blocks[0].placed),
+            (1, 12, 3)
+        );
+
+        let items = stats.item_rows();
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            (items[0].item_id, items[0].used, items[0].broken),
+            (257, 40, 1)
+        );
+
+        let mobs = stats.mob_rows();
+        assert_eq!(mobs[0].name, "Zombie Pigman");
+        assert_eq!((mobs[0].kills, mobs[0].killed_by), (5, 2));
+
+        let general = stats.general_rows();
+        assert_eq!(general[0].label, "Distance Walked");
+        assert_eq!(general[0].display, "1.23 km");
+
+        let achievements = stats.achievement_rows();
+        assert_eq!(achievements[0].name, "Taking Inventory");
+        assert!(achievements[0].unlocked);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::inventory::{InventoryItemStack, InventoryState};
+
+pub const TRADE_FIRST_INPUT_SLOT: usize = 0;
+pub const TRADE_SECOND_INPUT_SLOT: usize = 1;
+pub const TRADE_OUTPUT_SLOT: usize = 2;
+
+/// One `MerchantRecipe` from the `MC|TrList` plugin message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradeOffer {
+    pub input: InventoryItemStack,
+    pub second_input: Option<InventoryItemStack>,
+    pub output: InventoryItemStack,
+    /// The villager refuses this trade until it unlocks a new one.
+    pub disabled: bool,
+    pub uses: i32,
+    pub max_uses: i32,
+}
+
+impl InventoryState {
+    /// `GuiMerchant` ignores lists sent for a window other than the open one.
+    pub fn set_trade_offers(&mut self, id: i32, offers: Vec<TradeOffer>) {
+        if self
+            .active_window_id()
+            .is_some_and(|window_id| i32::from(window_id) == id)
+        {
+            self.trade_offers = offers;
+        }
+    }
+}
+
+/// Offer shown after pressing a paging button, clamped to the list like `GuiMerchant`.
+pub fn page_trade(selected: usize, offer_count: usize, forward: bool) -> usize {
+    if forward {
+        (selected + 1).min(offer_count.saturating_sub(1))
+    } else {
+        selected.saturating_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{InventoryItemMeta, InventoryWindowInfo};
+
+    fn stack(item_id: i32, count: u8) -> InventoryItemStack {
+        InventoryItemStack {
+            item_id,
+            count,
+            damage: 0,
+            meta: InventoryItemMeta::default(),
+        }
+    }
+
+    #[test]
+    fn trade_offers_follow_the_open_villager_window() {
+        let offer = TradeOffer {
+            input: stack(388, 1),
+            second_input: None,
+            output: stack(297, 3),
+            disabled: false,
+            uses: 0,
+            max_uses: 7,
+        };
+        let mut inventory = InventoryState::default();
+        inventory.set_trade_offers(3, vec![offer.clone()]);
+        assert!(inventory.trade_offers.is_empty());
+
+        inventory.open_window = Some(InventoryWindowInfo {
+            id: 3,
+            kind: "minecraft:villager".to_string(),
+            title: "Farmer".to_string(),
+            slot_count: 3,
+            entity_id: None,
+            horse_type: None,
+        });
+        inventory.set_trade_offers(4, vec![offer.clone()]);
+        assert!(inventory.trade_offers.is_empty());
+        inventory.set_trade_offers(3, vec![offer.clone()]);
+        assert_eq!(inventory.trade_offers, vec![offer]);
+
+        assert_eq!(page_trade(0, 3, true), 1);
+        assert_eq!(page_trade(2, 3, true), 2);
+        assert_eq!(page_trade(0, 3, false), 0);
+        assert_eq!(page_trade(0, 0, true), 0);
+    }
+}
@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::anvil::{ANVIL_LEFT_SLOT, ANVIL_OUTPUT_SLOT};
+use crate::beacon::{BEACON_PAYMENT_SLOT, is_beacon_payment};
+use crate::enchanting::{ENCHANT_LAPIS_SLOT, LAPIS_DAMAGE, LAPIS_ITEM_ID};
+use crate::horse::{
+    HORSE_ARMOR_SLOT, HORSE_CHEST_FIRST_SLOT, HORSE_SADDLE_SLOT, SADDLE_ITEM_ID,
+    horse_can_wear_armor, horse_has_chest, is_horse_armor,
+};
+use crate::inventory::InventoryItemStack;
+use crate::trading::TRADE_OUTPUT_SLOT;
+
+/// Container type from `OpenWindow`, which decides the slot map and property meanings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowKind {
+    Chest,
+    CraftingTable,
+    Furnace,
+    Dispenser,
+    EnchantingTable,
+    BrewingStand,
+    Villager,
+    Beacon,
+    Anvil,
+    Hopper,
+    Horse,
+    Other,
+}
+
+impl WindowKind {
+    /// Parses the 1.8 string ids as well as the numeric `type_N` ids of older protocols.
+    pub fn from_window_type(kind: &str) -> Self {
+        let normalized = kind.to_ascii_lowercase();
+        match normalized.strip_prefix("minecraft:").unwrap_or(&normalized) {
+            "chest" | "container" | "type_0" => Self::Chest,
+            "crafting_table" | "type_1" => Self::CraftingTable,
+            "furnace" | "type_2" => Self::Furnace,
+            "dispenser" | "dropper" | "type_3" | "type_10" => Self::Dispenser,
+            "enchanting_table" | "type_4" => Self::EnchantingTable,
+            "brewing_stand" | "type_5" => Self::BrewingStand,
+            "villager" | "type_6" => Self::Villager,
+            "beacon" | "type_7" => Self::Beacon,
+            "anvil" | "type_8" => Self::Anvil,
+            "hopper" | "type_9" => Self::Hopper,
+            "entityhorse" | "type_11" => Self::Horse,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Furnace properties: remaining fuel ticks, fuel item burn time, cook ticks, total cook ticks.
+pub const FURNACE_PROPERTY_BURN_TIME: i16 = 0;
+pub const FURNACE_PROPERTY_FUEL_TIME: i16 = 1;
+pub const FURNACE_PROPERTY_COOK_TIME: i16 = 2;
+pub const FURNACE_PROPERTY_COOK_TOTAL: i16 = 3;
+pub const BREWING_PROPERTY_BREW_TIME: i16 = 0;
+const BREWING_TOTAL_TICKS: f32 = 400.0;
+/// Bubble heights `GuiBrewingStand` cycles through, out of 29 pixels.
+const BREWING_BUBBLE_LENGTHS: [u8; 7] = [29, 24, 20, 16, 11, 6, 0];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FurnaceProgress {
+    /// Flame height in `0..=1`; zero when nothing is burning.
+    pub burn: f32,
+    /// Arrow fill in `0..=1`.
+    pub cook: f32,
+}
+
+/// Flame and arrow as `GuiFurnace` scales them from the window properties.
+pub fn furnace_progress(properties: &HashMap<i16, i16>) -> FurnaceProgress {
+    let property = |id| f32::from(properties.get(&id).copied().unwrap_or(0).max(0));
+    // Vanilla falls back to 200 ticks before the first fuel item is known.
+    let fuel_time = match properties.get(&FURNACE_PROPERTY_FUEL_TIME) {
+        Some(&fuel_time) if fuel_time > 0 => f32::from(fuel_time),
+        _ => 200.0,
+    };
+    let cook_total = property(FURNACE_PROPERTY_COOK_TOTAL);
+    FurnaceProgress {
+        burn: (property(FURNACE_PROPERTY_BURN_TIME) / fuel_time).min(1.0),
+        cook: if cook_total > 0.0 {
+            (property(FURNACE_PROPERTY_COOK_TIME) / cook_total).min(1.0)
+        } else {
+            0.0
+        },
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrewingProgress {
+    /// Downward arrow fill in `0..=1`.
+    pub arrow: f32,
+    /// Rising bubble height in `0..=1`.
+    pub bubbles: f32,
+}
+
+/// Arrow and bubbles as `GuiBrewingStand` draws them while a brew is running.
+pub fn brewing_progress(properties: &HashMap<i16, i16>) -> BrewingProgress {
+    let brew_time = properties
+        .get(&BREWING_PROPERTY_BREW_TIME)
+        .copied()
+        .unwrap_or(0);
+    if brew_time <= 0 {
+        return BrewingProgress {
+            arrow: 0.0,
+            bubbles: 0.0,
+        };
+    }
+    let bubble = BREWING_BUBBLE_LENGTHS[(brew_time as usize / 2) % BREWING_BUBBLE_LENGTHS.len()];
+    BrewingProgress {
+        arrow: (1.0 - f32::from(brew_time) / BREWING_TOTAL_TICKS).clamp(0.0, 1.0),
+        bubbles: f32::from(bubble) / 29.0,
+    }
+}
+
+/// One `mergeItemStack` call: a slot range, walked backwards when `reverse` is set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ShiftClickRoute {
+    pub(crate) range: Range<usize>,
+    pub(crate) reverse: bool,
+}
+
+fn route(range: Range<usize>, reverse: bool) -> Vec<ShiftClickRoute> {
+    vec![ShiftClickRoute { range, reverse }]
+}
+
+/// Where a shift-clicked stack goes, following each container's `transferStackInSlot`.
+/// `slots` is the window with the moving stack already taken out, and `horse_type` the open
+/// horse window's type if known. `None` keeps the generic main/hotbar/container routing used
+/// for unmapped windows.
+pub(crate) fn shift_click_routes(
+    kind: WindowKind,
+    unique: usize,
+    slots: &[Option<InventoryItemStack>],
+    slot_index: usize,
+    item: &InventoryItemStack,
+    horse_type: Option<u8>,
+) -> Option<Vec<ShiftClickRoute>> {
+    let total = slots.len();
+    // Items with no container slot hop between main inventory and hotbar.
+    let main = unique..unique + 27;
+    let player_half = if main.contains(&slot_index) {
+        route(unique + 27..total, false)
+    } else {
+        route(main, false)
+    };
+    let routes = match kind {
+        WindowKind::Chest | WindowKind::Dispenser | WindowKind::Hopper => {
+            if slot_index < unique {
+                route(unique..total, true)
+            } else {
+                route(0..unique, false)
+            }
+        }
+        // Grid and output slots empty into the player inventory; the output is handled by
+        // the crafting click model.
+        WindowKind::CraftingTable => {
+            if slot_index < unique {
+                route(unique..total, false)
+            } else {
+                player_half
+            }
+        }
+        WindowKind::Anvil => {
+            if slot_index < unique {
+                route(unique..total, slot_index == ANVIL_OUTPUT_SLOT)
+            } else {
+                route(ANVIL_LEFT_SLOT..ANVIL_OUTPUT_SLOT, false)
+            }
+        }
+        // Inputs and output empty into the player inventory; nothing is shift-clicked in.
+        WindowKind::Villager => {
+            if slot_index < unique {
+                route(unique..total, slot_index == TRADE_OUTPUT_SLOT)
+            } else {
+                player_half
+            }
+        }
+        WindowKind::EnchantingTable => {
+            if slot_index < unique {
+                route(unique..total, true)
+            } else if item.item_id == LAPIS_ITEM_ID && item.damage == LAPIS_DAMAGE {
+                route(ENCHANT_LAPIS_SLOT..ENCHANT_LAPIS_SLOT + 1, false)
+            } else {
+                player_half
+            }
+        }
+        WindowKind::Furnace => {
+            if slot_index < unique {
+                route(unique..total, slot_index == 2)
+            } else if is_smeltable(item) {
+                route(0..1, false)
+            } else if is_furnace_fuel(item.item_id) {
+                route(1..2, false)
+            } else {
+                player_half
+            }
+        }
+        // Ingredients only go into an empty ingredient slot, like `ContainerBrewingStand`.
+        WindowKind::BrewingStand => {
+            if slot_index < unique {
+                route(unique..total, true)
+            } else if is_potion_ingredient(item) && slots[3].is_none() {
+                route(3..4, false)
+            } else if matches!(item.item_id, 373 | 374) {
+                route(0..3, false)
+            } else {
+                player_half
+            }
+        }
+        // Only single items go into the payment slot, and only while it is empty.
+        WindowKind::Beacon => {
+            if slot_index < unique {
+                route(unique..total, true)
+            } else if is_beacon_payment(item.item_id)
+                && item.count == 1
+                && slots[BEACON_PAYMENT_SLOT].is_none()
+            {
+                route(BEACON_PAYMENT_SLOT..BEACON_PAYMENT_SLOT + 1, false)
+            } else {
+                player_half
+            }
+        }
+        // Saddle and armor go to their slots, anything else only into a donkey's chest.
+        WindowKind::Horse => {
+            if slot_index < unique {
+                route(unique..total, true)
+            } else if is_horse_armor(item.item_id) && horse_can_wear_armor(horse_type, unique) {
+                route(HORSE_ARMOR_SLOT..HORSE_ARMOR_SLOT + 1, false)
+            } else if item.item_id == SADDLE_ITEM_ID {
+                route(HORSE_SADDLE_SLOT..HORSE_SADDLE_SLOT + 1, false)
+            } else if horse_has_chest(unique) {
+                route(HORSE_CHEST_FIRST_SLOT..unique, false)
+            } else {
+                Vec::new()
+            }
+        }
+        _ => return None,
+    };
+    Some(routes)
+}
+
+/// Inputs with a `FurnaceRecipes` entry.
+fn is_smeltable(item: &InventoryItemStack) -> bool {
+    match item.item_id {
+        // Ores, sand, cobblestone, logs, clay, netherrack and cactus.
+        4 | 12 | 14 | 15 | 16 | 17 | 21 | 56 | 73 | 81 | 82 | 87 | 129 | 153 | 162 => true,
+        // Wet sponge and plain stone bricks.
+        19 => item.damage == 1,
+        98 => item.damage == 0,
+        // Clay ball, raw meats, potato.
+        319 | 337 | 363 | 365 | 392 | 411 | 423 => true,
+        // Raw cod and salmon; clownfish and pufferfish do not cook.
+        349 => matches!(item.damage, 0 | 1),
+        _ => false,
+    }
+}
+
+/// Items `TileEntityFurnace#getItemBurnTime` accepts.
+fn is_furnace_fuel(item_id: i32) -> bool {
+    matches!(
+        item_id,
+        // Wooden blocks and saplings.
+        5 | 6 | 17 | 25 | 47 | 53 | 54 | 58 | 72 | 84 | 85 | 96 | 99 | 100 | 107 | 126
+            | 134..=136 | 146 | 151 | 162..=164 | 173 | 178 | 183..=192
+            // Wooden tools, stick, coal, lava bucket and blaze rod.
+            | 263 | 268..=271 | 280 | 290 | 327 | 369
+    )
+}
+
+/// Items with `Item#isPotionIngredient`.
+fn is_potion_ingredient(item: &InventoryItemStack) -> bool {
+    match item.item_id {
+        289 | 331 | 348 | 353 | 370 | 372 | 375..=378 | 382 | 396 | 414 => true,
+        // Pufferfish.
+        349 => item.damage == 3,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_metadata::{HORSE_TYPE_HORSE, HORSE_TYPE_ZOMBIE};
+    use crate::inventory::{InventoryItemMeta, InventoryState, InventoryWindowInfo};
+
+    fn stack(item_id: i32, count: u8) -> InventoryItemStack {
+        InventoryItemStack {
+            item_id,
+            count,
+            damage: 0,
+            meta: InventoryItemMeta::default(),
+        }
+    }
+
+    fn open_window(kind: &str, unique: usize) -> InventoryState {
+        let mut inventory = InventoryState::default();
+        inventory.open_window = Some(InventoryWindowInfo {
+            id: 1,
+            kind: kind.to_string(),
+            title: String::new(),
+            slot_count: unique as u8,
+            entity_id: None,
+            horse_type: None,
+        });
+        inventory.set_window_items(1, vec![None; unique + 36]);
+        inventory
+    }
+
+    #[test]
+    fn furnace_shift_click_routes_by_item() {
+        let mut inventory = open_window("minecraft:furnace", 3);
+        inventory.set_slot(1, 3, Some(stack(15, 4)));
+        inventory.set_slot(1, 4, Some(stack(263, 8)));
+        inventory.set_slot(1, 5, Some(stack(1, 16)));
+
+        inventory.apply_local_click_window(1, 3, 3, 0, 1);
+        inventory.apply_local_click_window(1, 3, 4, 0, 1);
+        inventory.apply_local_click_window(1, 3, 5, 0, 1);
+
+        let slots = &inventory.window_slots[&1];
+        assert_eq!(slots[0], Some(stack(15, 4)));
+        assert_eq!(slots[1], Some(stack(263, 8)));
+        // Stone is neither smeltable nor fuel, so it drops to the hotbar.
+        assert_eq!(slots[5], None);
+        assert_eq!(slots[30], Some(stack(1, 16)));
+    }
+
+    #[test]
+    fn brewing_stand_ingredient_needs_an_empty_slot() {
+        let mut inventory = open_window("minecraft:brewing_stand", 4);
+        inventory.set_slot(1, 3, Some(stack(372, 1)));
+        inventory.set_slot(1, 4, Some(stack(372, 5)));
+
+        inventory.apply_local_click_window(1, 4, 4, 0, 1);
+
+        let slots = &inventory.window_slots[&1];
+        assert_eq!(slots[3], Some(stack(372, 1)));
+        assert_eq!(slots[31], Some(stack(372, 5)));
+    }
+
+    #[test]
+    fn container_shift_click_fills_hotbar_from_the_right() {
+        let mut inventory = open_window("minecraft:hopper", 5);
+        inventory.set_slot(1, 2, Some(stack(4, 10)));
+
+        inventory.apply_local_click_window(1, 5, 2, 0, 1);
+
+        assert_eq!(inventory.window_slots[&1][2], None);
+        assert_eq!(inventory.window_slots[&1][40], Some(stack(4, 10)));
+        assert_eq!(inventory.player_slots[44], Some(stack(4, 10)));
+    }
+
+    #[test]
+    fn horse_and_beacon_shift_clicks_fill_their_slots() {
+        let mut inventory = open_window("EntityHorse", 17);
+        inventory.set_slot(1, 17, Some(stack(SADDLE_ITEM_ID, 1)));
+        inventory.set_slot(1, 18, Some(stack(417, 1)));
+        inventory.set_slot(1, 19, Some(stack(4, 10)));
+
+        for slot in 17..20 {
+            inventory.apply_local_click_window(1, 17, slot, 0, 1);
+        }
+
+        let slots = &inventory.window_slots[&1];
+        assert_eq!(slots[HORSE_SADDLE_SLOT], Some(stack(SADDLE_ITEM_ID, 1)));
+        // A chested donkey cannot wear armor, so the armor goes into the chest like anything else.
+        assert_eq!(slots[HORSE_ARMOR_SLOT], None);
+        assert_eq!(slots[HORSE_CHEST_FIRST_SLOT], Some(stack(417, 1)));
+        assert_eq!(slots[HORSE_CHEST_FIRST_SLOT + 1], Some(stack(4, 10)));
+
+        let mut inventory = open_window("minecraft:beacon", 1);
+        inventory.set_slot(1, 1, Some(stack(388, 2)));
+        inventory.set_slot(1, 2, Some(stack(264, 1)));
+        inventory.apply_local_click_window(1, 1, 1, 0, 1);
+        inventory.apply_local_click_window(1, 1, 2, 0, 1);
+        let slots = &inventory.window_slots[&1];
+        assert_eq!(slots[BEACON_PAYMENT_SLOT], Some(stack(264, 1)));
+        assert_eq!(slots[28], Some(stack(388, 2)));
+
+        // A filled payment slot sends the next payment to the hotbar instead of stacking it.
+        inventory.set_slot(1, 3, Some(stack(264, 1)));
+        inventory.apply_local_click_window(1, 1, 3, 0, 1);
+        let slots = &inventory.window_slots[&1];
+        assert_eq!(slots[BEACON_PAYMENT_SLOT], Some(stack(264, 1)));
+        assert_eq!(slots[29], Some(stack(264, 1)));
+    }
+
+    #[test]
+    fn horse_armor_slot_follows_horse_type() {
+        for (horse_type, armor) in [
+            (None, Some(stack(417, 1))),
+            (Some(HORSE_TYPE_HORSE), Some(stack(417, 1))),
+            (Some(HORSE_TYPE_ZOMBIE), None),
+        ] {
+            let mut inventory = open_window("EntityHorse", 2);
+            if let Some(window) = inventory.open_window.as_mut() {
+                window.horse_type = horse_type;
+            }
+            inventory.set_slot(1, 2, Some(stack(417, 1)));
+            inventory.apply_local_click_window(1, 2, 2, 0, 1);
+            assert_eq!(inventory.window_slots[&1][HORSE_ARMOR_SLOT], armor);
+        }
+    }
+
+    #[test]
+    fn brewing_stand_progress_follows_brew_time() {
+        let properties = HashMap::from([(BREWING_PROPERTY_BREW_TIME, 100)]);
+        let progress = brewing_progress(&properties);
+        assert_eq!(progress.arrow, 0.75);
+        assert_eq!(progress.bubbles, 24.0 / 29.0);
+        assert_eq!(brewing_progress(&HashMap::new()).arrow, 0.0);
+
+        let furnace = furnace_progress(&HashMap::from([
+            (FURNACE_PROPERTY_BURN_TIME, 800),
+            (FURNACE_PROPERTY_FUEL_TIME, 1600),
+            (FURNACE_PROPERTY_COOK_TIME, 50),
+            (FURNACE_PROPERTY_COOK_TOTAL, 200),
+        ]));
+        assert_eq!(
+            furnace,
+            FurnaceProgress {
+                burn: 0.5,
+                cook: 0.25
+            }
+        );
+    }
+}
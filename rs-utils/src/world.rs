@@ -1,4 +1,5 @@
-use std::time::Instant;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use bevy::ecs::resource::Resource;
 
@@ -148,6 +149,7 @@ pub struct BreakIndicator {
     pub progress: f32,
     pub elapsed_secs: f32,
     pub total_secs: f32,
+    pub target_block: Option<(i32, i32, i32)>,
 }
 
 impl Default for BreakIndicator {
@@ -157,10 +159,90 @@ impl Default for BreakIndicator {
             progress: 0.0,
             elapsed_secs: 0.0,
             total_secs: 0.0,
+            target_block: None,
         }
     }
 }
 
+impl BreakIndicator {
+    /// Crack texture stage (0..=9) for the local estimate, matching vanilla `(progress * 10) - 1`.
+    pub fn destroy_stage(&self) -> Option<u8> {
+        if !self.active {
+            return None;
+        }
+        let stage = (self.progress.clamp(0.0, 1.0) * 10.0) as i32 - 1;
+        (0..=9).contains(&stage).then_some(stage as u8)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BlockBreakAnimation {
+    pub entity_id: i32,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub stage: i8,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BlockBreakStageEntry {
+    stage: u8,
+    updated_at: Instant,
+}
+
+/// Destroy stages reported by the server for other players, keyed by (breaker entity, block).
+#[derive(Resource, Debug, Default, Clone)]
+pub struct BlockBreakStages {
+    entries: HashMap<(i32, (i32, i32, i32)), BlockBreakStageEntry>,
+}
+
+impl BlockBreakStages {
+    /// Vanilla drops damaged blocks that saw no update for 400 ticks.
+    pub const TIMEOUT: Duration = Duration::from_secs(20);
+
+    pub fn apply(&mut self, anim: BlockBreakAnimation, now: Instant) {
+        let key = (anim.entity_id, (anim.x, anim.y, anim.z));
+        if (0..10).contains(&anim.stage) {
+            self.entries.insert(
+                key,
+                BlockBreakStageEntry {
+                    stage: anim.stage as u8,
+                    updated_at: now,
+                },
+            );
+        } else {
+            self.entries.remove(&key);
+        }
+    }
+
+    pub fn expire(&mut self, now: Instant) {
+        self.entries
+            .retain(|_, entry| now.saturating_duration_since(entry.updated_at) < Self::TIMEOUT);
+    }
+
+    pub fn remove_entity(&mut self, entity_id: i32) {
+        self.entries.retain(|(breaker, _), _| *breaker != entity_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Highest stage per block across all breakers.
+    pub fn blocks(&self) -> HashMap<(i32, i32, i32), u8> {
+        let mut out = HashMap::new();
+        for ((_, block), entry) in &self.entries {
+            let stage = out.entry(*block).or_insert(entry.stage);
+            *stage = (*stage).max(entry.stage);
+        }
+        out
+    }
+}
+
 #[derive(Resource, Debug, Clone, Copy)]
 pub struct PlayerStatus {
     pub health: f32,
@@ -205,3 +287,34 @@ pub struct TabListHeaderFooter {
     pub header: String,
     pub footer: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_break_stages_track_breakers_and_expire() {
+        let now = std::time::Instant::now();
+        let mut stages = BlockBreakStages::default();
+        let anim = |entity_id, stage| BlockBreakAnimation {
+            entity_id,
+            x: 1,
+            y: 64,
+            z: -3,
+            stage,
+        };
+        stages.apply(anim(7, 3), now);
+        stages.apply(anim(9, 6), now);
+        assert_eq!(stages.blocks().get(&(1, 64, -3)), Some(&6));
+
+        stages.apply(anim(9, -1), now);
+        assert_eq!(stages.blocks().get(&(1, 64, -3)), Some(&3));
+
+        stages.expire(now + BlockBreakStages::TIMEOUT);
+        assert!(stages.is_empty());
+
+        stages.apply(anim(7, 4), now);
+        stages.remove_entity(7);
+        assert!(stages.is_empty());
+    }
+}